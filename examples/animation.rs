@@ -73,6 +73,10 @@ async fn demo(client: &HyprlandClient, monitor: &Monitor) -> anyhow::Result<()>
         scale: monitor.scale,
         is_focused: monitor.focused,
         refresh_rate: monitor.refresh_rate,
+        reserved_top: monitor.reserved.1,
+        reserved_bottom: monitor.reserved.3,
+        reserved_left: monitor.reserved.0,
+        reserved_right: monitor.reserved.2,
     };
 
     loop {
@@ -1,5 +1,13 @@
 use serde::{Deserialize, Serialize};
 use std::f32::consts::PI;
+use tracing::warn;
+
+/// Newton-Raphson steps tried when solving a bezier's x(t) = x
+const BEZIER_NEWTON_ITERATIONS: usize = 8;
+/// Bisection steps used when Newton-Raphson does not converge
+const BEZIER_BISECTION_ITERATIONS: usize = 24;
+/// Accuracy of the x(t) = x solve
+const BEZIER_EPSILON: f32 = 1e-6;
 
 /// Advanced easing functions for smooth animations
 /// Supports traditional CSS easing plus physics-based functions
@@ -91,32 +99,59 @@ impl EasingFunction {
                 stiffness: 300.0,
                 damping: 30.0,
             },
-            _ => {
-                // Try to parse as cubic-bezier
-                if name.starts_with("cubic-bezier(") && name.ends_with(")") {
-                    if let Some(bezier) = Self::parse_cubic_bezier(name) {
-                        return bezier;
-                    }
-                }
-                EasingFunction::EaseInOut // Default fallback
+            lowered if lowered.trim_start().starts_with("cubic-bezier") => {
+                Self::parse_cubic_bezier(lowered).unwrap_or_else(|| {
+                    warn!(
+                        "⚠️ Malformed easing '{}', expected cubic-bezier(x1, y1, x2, y2) with x1 and x2 in 0..1; using ease-out-cubic",
+                        name
+                    );
+                    EasingFunction::EaseOutCubic
+                })
             }
+            _ => EasingFunction::EaseInOut, // Default fallback
         }
     }
 
-    /// Parse cubic-bezier(x1,y1,x2,y2) format
+    /// Parse cubic-bezier(x1,y1,x2,y2) format, tolerating whitespace anywhere.
+    /// As in CSS, x1 and x2 must lie in 0..=1 so the curve is a function of time
     fn parse_cubic_bezier(input: &str) -> Option<Self> {
-        let inner = input.strip_prefix("cubic-bezier(")?.strip_suffix(")")?;
-        let parts: Vec<&str> = inner.split(',').map(|s| s.trim()).collect();
+        let inner = input
+            .trim()
+            .strip_prefix("cubic-bezier")?
+            .trim_start()
+            .strip_prefix('(')?
+            .strip_suffix(')')?;
+        let parts = inner
+            .split(',')
+            .map(|s| s.trim().parse::<f32>().ok().filter(|v| v.is_finite()))
+            .collect::<Option<Vec<f32>>>()?;
 
-        if parts.len() == 4 {
-            let x1 = parts[0].parse::<f32>().ok()?;
-            let y1 = parts[1].parse::<f32>().ok()?;
-            let x2 = parts[2].parse::<f32>().ok()?;
-            let y2 = parts[3].parse::<f32>().ok()?;
+        match parts[..] {
+            [x1, y1, x2, y2] if (0.0..=1.0).contains(&x1) && (0.0..=1.0).contains(&x2) => {
+                Some(EasingFunction::CubicBezier { x1, y1, x2, y2 })
+            }
+            _ => None,
+        }
+    }
 
-            Some(EasingFunction::CubicBezier { x1, y1, x2, y2 })
-        } else {
-            None
+    /// Whether the curve leaves the 0.0..=1.0 range, pushing animated values past
+    /// their target (or behind their start) before settling
+    pub fn overshoots(&self) -> bool {
+        match self {
+            EasingFunction::EaseInBack
+            | EasingFunction::EaseOutBack
+            | EasingFunction::EaseInOutBack
+            | EasingFunction::EaseInElastic
+            | EasingFunction::EaseOutElastic
+            | EasingFunction::EaseInOutElastic => true,
+            EasingFunction::Spring { stiffness, damping } => {
+                // Underdamped springs oscillate around the target
+                *damping < 2.0 * stiffness.sqrt()
+            }
+            EasingFunction::CubicBezier { y1, y2, .. } => {
+                !(0.0..=1.0).contains(y1) || !(0.0..=1.0).contains(y2)
+            }
+            _ => false,
         }
     }
 
@@ -336,18 +371,54 @@ impl EasingFunction {
     }
 
     /// Cubic bezier implementation for custom curves
-    fn cubic_bezier(&self, t: f32, _x1: f32, y1: f32, _x2: f32, y2: f32) -> f32 {
-        // Simplified cubic bezier - in production would use Newton-Raphson method
-        // This is a basic approximation for demonstration
-        let u = 1.0 - t;
-        let tt = t * t;
-        let uu = u * u;
-        let uuu = uu * u;
-        let ttt = tt * t;
-
-        // Cubic bezier formula: B(t) = (1-t)³P₀ + 3(1-t)²tP₁ + 3(1-t)t²P₂ + t³P₃
-        // Where P₀ = (0,0), P₁ = (x1,y1), P₂ = (x2,y2), P₃ = (1,1)
-        uuu * 0.0 + 3.0 * uu * t * y1 + 3.0 * u * tt * y2 + ttt * 1.0
+    /// Value of the curve through (0,0), (x1,y1), (x2,y2), (1,1) at horizontal
+    /// position `x`: solve x(s) = x for the curve parameter s, then return y(s)
+    fn cubic_bezier(&self, x: f32, x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
+        let s = Self::solve_bezier_parameter(x, x1, x2);
+        Self::bezier_coordinate(s, y1, y2)
+    }
+
+    /// One coordinate of the bezier at parameter `s`:
+    /// B(s) = 3(1-s)²s·p1 + 3(1-s)s²·p2 + s³ (P₀ = 0, P₃ = 1)
+    fn bezier_coordinate(s: f32, p1: f32, p2: f32) -> f32 {
+        let u = 1.0 - s;
+        3.0 * u * u * s * p1 + 3.0 * u * s * s * p2 + s * s * s
+    }
+
+    /// Parameter s with x(s) = x. Newton-Raphson first, bisection if it stalls;
+    /// both are bounded so no curve can hang a frame
+    fn solve_bezier_parameter(x: f32, x1: f32, x2: f32) -> f32 {
+        let mut s = x;
+        for _ in 0..BEZIER_NEWTON_ITERATIONS {
+            let error = Self::bezier_coordinate(s, x1, x2) - x;
+            if error.abs() < BEZIER_EPSILON {
+                return s;
+            }
+            // dx/ds = 3(1-s)²·x1 + 6(1-s)s·(x2-x1) + 3s²·(1-x2)
+            let u = 1.0 - s;
+            let slope = 3.0 * u * u * x1 + 6.0 * u * s * (x2 - x1) + 3.0 * s * s * (1.0 - x2);
+            if slope.abs() < BEZIER_EPSILON {
+                break;
+            }
+            s -= error / slope;
+        }
+
+        // x(s) is monotonic for x1, x2 in 0..=1
+        let (mut low, mut high) = (0.0_f32, 1.0_f32);
+        s = x;
+        for _ in 0..BEZIER_BISECTION_ITERATIONS {
+            let error = Self::bezier_coordinate(s, x1, x2) - x;
+            if error.abs() < BEZIER_EPSILON {
+                break;
+            }
+            if error > 0.0 {
+                high = s;
+            } else {
+                low = s;
+            }
+            s = (low + high) / 2.0;
+        }
+        s
     }
 }
 
@@ -378,6 +449,32 @@ mod tests {
         assert!(result > 0.8 && result <= 1.0);
     }
 
+    #[test]
+    fn test_overshooting_easings() {
+        assert!(EasingFunction::EaseOutBack.overshoots());
+        assert!(EasingFunction::EaseOutElastic.overshoots());
+        assert!(EasingFunction::Spring {
+            stiffness: 300.0,
+            damping: 10.0
+        }
+        .overshoots());
+        assert!(EasingFunction::CubicBezier {
+            x1: 0.3,
+            y1: 1.5,
+            x2: 0.6,
+            y2: 1.0
+        }
+        .overshoots());
+
+        assert!(!EasingFunction::EaseOutCubic.overshoots());
+        assert!(!EasingFunction::EaseOutBounce.overshoots());
+        assert!(!EasingFunction::Spring {
+            stiffness: 300.0,
+            damping: 40.0
+        }
+        .overshoots());
+    }
+
     #[test]
     fn test_from_name() {
         let easing = EasingFunction::from_name("ease-in-out");
@@ -400,4 +497,57 @@ mod tests {
             _ => panic!("Failed to parse cubic-bezier"),
         }
     }
+
+    #[test]
+    fn test_cubic_bezier_whitespace_and_fallback() {
+        assert_eq!(
+            EasingFunction::from_name("cubic-bezier(0.25, 0.1, 0.25, 1.0)"),
+            EasingFunction::CubicBezier {
+                x1: 0.25,
+                y1: 0.1,
+                x2: 0.25,
+                y2: 1.0
+            }
+        );
+        assert_eq!(
+            EasingFunction::from_name("  Cubic-Bezier ( 0.3,1.5 ,0.6 , 1 ) "),
+            EasingFunction::CubicBezier {
+                x1: 0.3,
+                y1: 1.5,
+                x2: 0.6,
+                y2: 1.0
+            }
+        );
+
+        for malformed in [
+            "cubic-bezier(0.25, 0.1, 0.25)",
+            "cubic-bezier(0.25, 0.1, 0.25, abc)",
+            "cubic-bezier(1.5, 0.1, 0.25, 1.0)",
+            "cubic-bezier(0.25, 0.1, 0.25, 1.0",
+        ] {
+            assert_eq!(
+                EasingFunction::from_name(malformed),
+                EasingFunction::EaseOutCubic,
+                "{malformed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cubic_bezier_solves_for_time() {
+        // cubic-bezier(0, 0, 1, 1) is linear once x(s) = t is solved for s
+        let linear = EasingFunction::from_name("cubic-bezier(0, 0, 1, 1)");
+        for t in [0.1, 0.25, 0.5, 0.9] {
+            assert!((linear.apply(t) - t).abs() < 1e-4, "t={t}");
+        }
+
+        // CSS "ease" at t=0.5 is about 0.8024
+        assert!((EasingFunction::Ease.apply(0.5) - 0.8024).abs() < 1e-3);
+
+        // Flat tangents at both ends stall Newton-Raphson; bisection still converges
+        let steep = EasingFunction::from_name("cubic-bezier(1, 0, 0, 1)");
+        assert!((steep.apply(0.5) - 0.5).abs() < 1e-3);
+        assert!(steep.apply(0.01) < 0.01);
+        assert_eq!(steep.apply(1.0), 1.0);
+    }
 }
@@ -1,21 +1,104 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio::sync::watch;
 use tokio::time::sleep;
-use tracing::{debug, info, warn};
+use tracing::{debug, info, instrument, warn};
+
+use crate::ipc::MonitorInfo;
 
 pub mod easing;
+pub mod preset;
 pub mod properties;
+pub mod spring;
 pub mod timeline;
 pub mod window_animator;
 
 // Re-export commonly used types
 pub use easing::EasingFunction;
-pub use properties::{AnimationProperty, Color, PropertyValue, Transform};
+pub use preset::AnimationPreset;
+pub use properties::{AnimationProperty, Axis, Color, Offset, PropertyValue, Transform};
+pub use spring::{SpringAnimation, SpringParams, SpringState, DEFAULT_SETTLE_EPSILON};
 pub use timeline::{AnimationDirection, Keyframe, Timeline, TimelineBuilder};
 pub use window_animator::WindowAnimator;
 
+/// Monitor edge a directional animation crosses
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SlideEdge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// Edges a `fromX` show animation enters across, as (vertical, horizontal);
+/// both None for non-directional types
+fn slide_edges(animation_type: &str) -> (Option<SlideEdge>, Option<SlideEdge>) {
+    use SlideEdge::*;
+
+    match animation_type {
+        "fromTop" => (Some(Top), None),
+        "fromBottom" => (Some(Bottom), None),
+        "fromLeft" => (None, Some(Left)),
+        "fromRight" => (None, Some(Right)),
+        "fromTopLeft" => (Some(Top), Some(Left)),
+        "fromTopRight" => (Some(Top), Some(Right)),
+        "fromBottomLeft" => (Some(Bottom), Some(Left)),
+        "fromBottomRight" => (Some(Bottom), Some(Right)),
+        _ => (None, None),
+    }
+}
+
+/// Slide animations, named after the edge or corner the window enters from or
+/// leaves towards
+const DIRECTIONAL_ANIMATIONS: [&str; 16] = [
+    "fromTop",
+    "fromBottom",
+    "fromLeft",
+    "fromRight",
+    "fromTopLeft",
+    "fromTopRight",
+    "fromBottomLeft",
+    "fromBottomRight",
+    "toTop",
+    "toBottom",
+    "toLeft",
+    "toRight",
+    "toTopLeft",
+    "toTopRight",
+    "toBottomLeft",
+    "toBottomRight",
+];
+
+/// Animation types that change something other than the position
+const EFFECT_ANIMATIONS: [&str; 3] = ["bounce", "fade", "scale"];
+
+/// Easings driven by a simulation rather than a fixed curve
+const PHYSICS_EASINGS: [&str; 1] = ["spring"];
+
+/// Every animation type and easing a config may name, as sorted groups
+/// (directional, effects, physics, named curves). Custom `cubic-bezier(x1, y1, x2, y2)`
+/// easings are accepted on top of these
+pub fn supported_animations() -> Vec<(&'static str, Vec<&'static str>)> {
+    let sorted = |mut names: Vec<&'static str>| {
+        names.sort_unstable();
+        names
+    };
+    let curves = AnimationEngine::supported_easings()
+        .into_iter()
+        .filter(|easing| !PHYSICS_EASINGS.contains(easing))
+        .collect();
+
+    vec![
+        ("directional", sorted(DIRECTIONAL_ANIMATIONS.to_vec())),
+        ("effects", sorted(EFFECT_ANIMATIONS.to_vec())),
+        ("physics", sorted(PHYSICS_EASINGS.to_vec())),
+        ("named curves", sorted(curves)),
+    ]
+}
+
 /// Advanced animation configuration with physics support
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AnimationConfig {
@@ -34,7 +117,8 @@ pub struct AnimationConfig {
     #[serde(default)]
     pub delay: u32,
 
-    /// Offset distance for slide animations (pixels or percentage)
+    /// Distance slides start/end beyond the monitor edge: one value (`200px`, `10%`)
+    /// for the slide's axis, or an `"x y"` pair (see `Offset`)
     #[serde(default = "default_offset")]
     pub offset: String,
 
@@ -53,6 +137,11 @@ pub struct AnimationConfig {
     #[serde(default)]
     pub target_fps: u32,
 
+    /// Spring physics. When set (or with the `spring` easing) the animation is
+    /// driven by a damped spring and ends once it settles, not after `duration`
+    #[serde(default)]
+    pub spring: Option<SpringConfig>,
+
     /// Position cible pré-calculée pour l'animation (optionnelle)
     /// Si présente, WindowAnimator utilisera cette position au lieu de la calculer
     #[serde(skip)] // Ne pas sérialiser - utilisé seulement en runtime
@@ -67,6 +156,177 @@ pub struct AnimationPropertyConfig {
     pub easing: Option<EasingFunction>,
 }
 
+/// Physical parameters of a spring-driven animation
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct SpringConfig {
+    #[serde(default = "default_spring_stiffness")]
+    pub stiffness: f32,
+    #[serde(default = "default_spring_damping")]
+    pub damping: f32,
+    #[serde(default = "default_spring_mass")]
+    pub mass: f32,
+    /// Velocity at the start, in distances-to-target per second
+    #[serde(default)]
+    pub initial_velocity: f32,
+    /// Displacement and velocity under which the spring counts as settled
+    #[serde(default = "default_settle_epsilon")]
+    pub settle_epsilon: f32,
+}
+
+impl Default for SpringConfig {
+    fn default() -> Self {
+        Self {
+            stiffness: default_spring_stiffness(),
+            damping: default_spring_damping(),
+            mass: default_spring_mass(),
+            initial_velocity: 0.0,
+            settle_epsilon: default_settle_epsilon(),
+        }
+    }
+}
+
+/// Why an animation driven by `run_to_completion` did not finish
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AnimationError {
+    #[error("Animation '{0}' not found")]
+    NotFound(String),
+    #[error("Animation '{0}' was stopped before completing")]
+    Stopped(String),
+}
+
+/// Playback state shared between an animation and its handles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Playback {
+    Running,
+    Paused,
+    Stopped,
+}
+
+/// Handle returned by `start_animation`. Pausing or stopping through it takes
+/// effect even while `run_to_completion` is driving the animation
+#[derive(Debug, Clone)]
+pub struct AnimationHandle {
+    id: String,
+    playback: Arc<watch::Sender<Playback>>,
+}
+
+impl AnimationHandle {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn playback(&self) -> Playback {
+        *self.playback.borrow()
+    }
+
+    pub fn pause(&self) {
+        self.playback.send_replace(Playback::Paused);
+    }
+
+    pub fn resume(&self) {
+        self.playback.send_if_modified(|playback| {
+            let paused = *playback == Playback::Paused;
+            if paused {
+                *playback = Playback::Running;
+            }
+            paused
+        });
+    }
+
+    pub fn stop(&self) {
+        self.playback.send_replace(Playback::Stopped);
+    }
+}
+
+/// Geometry of a window scaled by `scale` around the center of `position`/`size`,
+/// as `(position, size)`. Used as the first frame of `scale` animations
+pub fn scaled_about_center(
+    position: (i32, i32),
+    size: (i32, i32),
+    scale: f32,
+) -> ((i32, i32), (i32, i32)) {
+    let width = (size.0 as f32 * scale).round() as i32;
+    let height = (size.1 as f32 * scale).round() as i32;
+    (
+        (
+            position.0 + (size.0 - width) / 2,
+            position.1 + (size.1 - height) / 2,
+        ),
+        (width, height),
+    )
+}
+
+/// Window `(position, size)` of an animation frame. Properties the frame lacks keep
+/// `position`/`size`; without explicit `width`/`height`, a `scale` property resizes
+/// the window about its center
+pub fn frame_geometry(
+    properties: &HashMap<String, PropertyValue>,
+    position: (i32, i32),
+    size: (i32, i32),
+) -> ((i32, i32), (i32, i32)) {
+    let pixels = |name: &str, default: i32| {
+        properties
+            .get(name)
+            .map(|p| p.as_pixels())
+            .unwrap_or(default)
+    };
+    let position = (pixels("x", position.0), pixels("y", position.1));
+    if properties.contains_key("width") || properties.contains_key("height") {
+        return (
+            position,
+            (pixels("width", size.0), pixels("height", size.1)),
+        );
+    }
+    match properties.get("scale") {
+        Some(PropertyValue::Float(scale)) => scaled_about_center(position, size, *scale),
+        _ => (position, size),
+    }
+}
+
+/// One step of a chained position animation
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationPhase {
+    pub from: (i32, i32),
+    pub to: (i32, i32),
+    pub duration: u32,
+    pub easing: EasingFunction,
+}
+
+/// Longest overshoot of the arrival bounce, in pixels
+pub const ARRIVAL_BOUNCE_OVERSHOOT: i32 = 12;
+/// Duration of the settle phase of the arrival bounce, in milliseconds
+pub const ARRIVAL_BOUNCE_DURATION: u32 = 180;
+
+/// Slide from `from` to `to` with a smooth easing, slightly past the target along
+/// the direction of travel, then settle back onto it with a short bounce. The last
+/// phase always ends exactly on `to`
+pub fn bounce_on_arrival(from: (i32, i32), to: (i32, i32), duration: u32) -> Vec<AnimationPhase> {
+    // A tenth of the travel, capped, so short moves only get a nudge
+    let overshoot = |from: i32, to: i32| {
+        let travel = to - from;
+        (travel / 10).clamp(-ARRIVAL_BOUNCE_OVERSHOOT, ARRIVAL_BOUNCE_OVERSHOOT)
+    };
+    let past = (
+        to.0 + overshoot(from.0, to.0),
+        to.1 + overshoot(from.1, to.1),
+    );
+
+    vec![
+        AnimationPhase {
+            from,
+            to: past,
+            duration,
+            easing: EasingFunction::EaseOutCubic,
+        },
+        AnimationPhase {
+            from: past,
+            to,
+            duration: ARRIVAL_BOUNCE_DURATION,
+            easing: EasingFunction::EaseOutBounce,
+        },
+    ]
+}
+
 /// Runtime animation state
 #[derive(Debug)]
 pub struct AnimationState {
@@ -75,16 +335,21 @@ pub struct AnimationState {
     pub current_progress: f32,
     pub is_running: bool,
     pub is_paused: bool,
+    pub playback: Arc<watch::Sender<Playback>>,
     pub timeline: Timeline,
     pub properties: HashMap<String, PropertyValue>,
     pub target_properties: HashMap<String, PropertyValue>,
     pub start_properties: HashMap<String, PropertyValue>, // Fixed start values for proper interpolation
+    pub spring: Option<SpringAnimation>, // Physics state for spring-driven animations
+    pub monitor: MonitorInfo,            // Monitor percentages are resolved against
+    pub last_frame: Option<Instant>,     // When the spring was last advanced
 }
 
 /// Advanced animation engine
 pub struct AnimationEngine {
     active_animations: HashMap<String, AnimationState>,
     performance_monitor: PerformanceMonitor,
+    monitor: MonitorInfo,
 }
 
 #[derive(Debug)]
@@ -92,7 +357,18 @@ struct PerformanceMonitor {
     frame_times: Vec<Duration>,
     target_frame_time: Duration,
     adaptive_quality: bool,
-    resolution: (i32, i32),
+}
+
+/// Monitor percentages refer to until a real one is known: a common 1080p screen,
+/// so a percentage never resolves against 0×0
+pub(crate) fn fallback_monitor() -> MonitorInfo {
+    MonitorInfo {
+        width: 1920,
+        height: 1080,
+        scale: 1.0,
+        refresh_rate: 60.0,
+        ..MonitorInfo::new()
+    }
 }
 
 impl Default for AnimationEngine {
@@ -109,23 +385,26 @@ impl AnimationEngine {
                 frame_times: Vec::with_capacity(60),
                 target_frame_time: Duration::from_millis(16), // 60fps
                 adaptive_quality: true,
-                resolution: (1980, 1080),
             },
+            monitor: fallback_monitor(),
         }
     }
 
+    /// Monitor that percentage values of animations started from now on refer to
+    pub fn set_monitor(&mut self, monitor: MonitorInfo) {
+        self.monitor = monitor;
+    }
+
     /// Start a new animation
+    #[instrument(skip_all, fields(animation_id = %id, animation_type = %config.animation_type))]
     pub async fn start_animation(
         &mut self,
         id: String,
         config: AnimationConfig,
         initial_properties: HashMap<String, PropertyValue>,
         end_properties: HashMap<String, PropertyValue>,
-    ) -> Result<()> {
-        debug!(
-            "🎬 Starting animation '{}' with type '{}', duration: {}ms",
-            id, config.animation_type, config.duration
-        );
+    ) -> Result<AnimationHandle> {
+        debug!(duration_ms = config.duration, "🎬 Starting animation");
 
         // Initialize properties correctly for multi-property animations
         let (final_initial_properties, target_properties) = if config.properties.is_some() {
@@ -146,13 +425,34 @@ impl AnimationEngine {
             );
             (multi_initial, multi_targets)
         } else {
-            // Traditional single-property animation
-            // initial_properties = where window should END (final position)
-            // We need to calculate where it should START and swap them
-            //let start_properties = self.calculate_start_properties(&config, &initial_properties)?;
-            (initial_properties, end_properties) // start_properties = where to START, initial_properties = where to END
+            // Start and target as given; callers holding only the on-screen target
+            // derive the start with `calculate_start_properties`
+            (initial_properties, end_properties)
         };
 
+        // A new request for a running animation retargets it: it continues from
+        // where the window is now rather than snapping back to the given start
+        let mut final_initial_properties = final_initial_properties;
+        if let Some(current) = self.interrupt_animation(&id) {
+            debug!("↪️ Retargeting running animation from its current frame");
+            for (key, value) in final_initial_properties.iter_mut() {
+                let Some(current_value) = current.get(key) else {
+                    continue;
+                };
+                // Positional frames are sub-pixel Floats, while targets are Pixels or
+                // Percentage: start from pixels so the two still interpolate
+                *value = match (current_value, target_properties.get(key)) {
+                    (
+                        PropertyValue::Float(pixels),
+                        Some(PropertyValue::Pixels(_) | PropertyValue::Percentage(_)),
+                    ) if Axis::of_property(key).is_some() => {
+                        PropertyValue::Pixels(pixels.round() as i32)
+                    }
+                    (current_value, _) => current_value.clone(),
+                };
+            }
+        }
+
         // Debug animation properties
         debug!("🎯 Animation setup:");
         for (key, value) in &final_initial_properties {
@@ -162,81 +462,278 @@ impl AnimationEngine {
             debug!("   Target {}: {:?}", key, value);
         }
 
+        let (playback, _) = watch::channel(Playback::Running);
+        let handle = AnimationHandle {
+            id: id.clone(),
+            playback: Arc::new(playback),
+        };
+
         let state = AnimationState {
             config: config.clone(),
             start_time: Instant::now() + Duration::from_millis(config.delay as u64),
             current_progress: 0.0,
             is_running: true,
             is_paused: false,
+            playback: Arc::clone(&handle.playback),
             timeline: Timeline::new(Duration::from_millis(config.duration as u64)),
             start_properties: final_initial_properties.clone(), // Keep original start values
             properties: final_initial_properties,
             target_properties,
+            spring: Self::setup_spring_animation(&config),
+            last_frame: None,
+            monitor: self.monitor.clone(),
         };
 
         self.active_animations.insert(id.clone(), state);
 
-        // Don't start animation loop here - let start_window_animation_loop handle it
+        // Don't start animation loop here - let start_window_animation_loop or
+        // run_to_completion drive it
         debug!("✅ Animation '{}' initialized and ready", id);
 
+        Ok(handle)
+    }
+
+    /// Stop the animation `id` if it is still running, returning its current
+    /// interpolated properties. Handles to it then report `Playback::Stopped`
+    fn interrupt_animation(&mut self, id: &str) -> Option<HashMap<String, PropertyValue>> {
+        let current = self.get_current_properties(id);
+        if let Some(animation) = self.active_animations.remove(id) {
+            animation.playback.send_replace(Playback::Stopped);
+        }
+        current
+    }
+
+    /// Drive an animation to its end (through `run_animation_loop`) and return its
+    /// final properties. Fails with `AnimationError::Stopped` if it is stopped first
+    pub async fn run_to_completion(
+        &mut self,
+        animation_id: &str,
+    ) -> Result<HashMap<String, PropertyValue>> {
+        let not_found = || AnimationError::NotFound(animation_id.to_string());
+        let animation = self
+            .active_animations
+            .get(animation_id)
+            .ok_or_else(not_found)?;
+        if !animation.is_running {
+            return Err(AnimationError::Stopped(animation_id.to_string()).into());
+        }
+
+        // Honor the configured start delay
+        sleep(
+            animation
+                .start_time
+                .saturating_duration_since(Instant::now()),
+        )
+        .await;
+
+        self.run_animation_loop(animation_id.to_string()).await?;
+
+        let animation = self
+            .active_animations
+            .get_mut(animation_id)
+            .ok_or_else(not_found)?;
+        animation.current_progress = 1.0;
+        animation.is_running = false;
+        Ok(animation.properties.clone())
+    }
+
+    /// Spring physics for the animation, if it is spring-driven: an explicit
+    /// `spring` section, or the `spring` easing with its stiffness and damping
+    fn setup_spring_animation(config: &AnimationConfig) -> Option<SpringAnimation> {
+        let spring = match (&config.spring, &config.easing) {
+            (Some(spring), _) => *spring,
+            (None, EasingFunction::Spring { stiffness, damping }) => SpringConfig {
+                stiffness: *stiffness,
+                damping: *damping,
+                ..SpringConfig::default()
+            },
+            _ => return None,
+        };
+
+        let params = SpringParams {
+            stiffness: spring.stiffness,
+            damping: spring.damping,
+            mass: spring.mass,
+        };
+        let mut animation =
+            SpringAnimation::new(params, spring.settle_epsilon, spring::MAX_SPRING_DURATION);
+        animation.state.velocity = spring.initial_velocity;
+        Some(animation)
+    }
+
+    /// Advance a spring-driven animation by the time since its previous frame and
+    /// update its properties. Returns false once the spring has settled (or hit its
+    /// cap), leaving the properties exactly on target
+    fn advance_spring(
+        animations: &mut HashMap<String, AnimationState>,
+        animation_id: &str,
+        since_last_frame: Duration,
+    ) -> Result<bool> {
+        let Some(animation) = animations.get_mut(animation_id) else {
+            return Ok(false);
+        };
+        let Some(position) = animation
+            .spring
+            .as_mut()
+            .and_then(|spring| spring.next_frame(since_last_frame))
+        else {
+            Self::interpolate_eased(animation, 1.0);
+            return Ok(false);
+        };
+
+        // The spring position already is the eased progress, overshoot included,
+        // so it bypasses the (clamping) easing functions
+        Self::interpolate_eased(animation, position);
+        Ok(true)
+    }
+
+    /// Set every animated property to its value at an already-eased progress
+    fn interpolate_eased(animation: &mut AnimationState, eased_progress: f32) {
+        let ranges: Vec<(String, PropertyValue, PropertyValue)> = match &animation.config.properties
+        {
+            Some(properties) => properties
+                .iter()
+                .map(|p| (p.property.clone(), p.from.clone(), p.to.clone()))
+                .collect(),
+            None => animation
+                .target_properties
+                .iter()
+                .filter_map(|(name, to)| {
+                    let from = animation.start_properties.get(name)?;
+                    Some((name.clone(), from.clone(), to.clone()))
+                })
+                .collect(),
+        };
+
+        for (name, from, to) in ranges {
+            let value =
+                Self::interpolate_property(&name, &from, &to, eased_progress, &animation.monitor);
+            animation.properties.insert(name, value);
+        }
+    }
+
+    /// Interpolate one property, resolving percentages of positional properties
+    /// against the animation's monitor so they can be mixed with pixels. Positions
+    /// and sizes stay sub-pixel floats until applied
+    fn interpolate_property(
+        name: &str,
+        from: &PropertyValue,
+        to: &PropertyValue,
+        progress: f32,
+        monitor: &MonitorInfo,
+    ) -> PropertyValue {
+        match Axis::of_property(name) {
+            Some(axis) => from.interpolate_subpixel(to, progress, axis, monitor),
+            None => from.interpolate(to, progress),
+        }
+    }
+
+    /// Frame loop for spring-driven animations: advance by real elapsed time (minus
+    /// time spent paused) so every refresh rate plays the same motion
+    async fn run_spring_loop(&mut self, animation_id: &str) -> Result<()> {
+        let Some(animation) = self.active_animations.get(animation_id) else {
+            return Ok(());
+        };
+        let mut playback = animation.playback.subscribe();
+        let mut last_frame = Instant::now();
+
+        loop {
+            let before_wait = Instant::now();
+            Self::wait_while_paused(animation_id, &mut playback).await?;
+            let frame_start = Instant::now();
+            let since_last_frame = before_wait.saturating_duration_since(last_frame);
+            last_frame = frame_start;
+
+            if !Self::advance_spring(&mut self.active_animations, animation_id, since_last_frame)? {
+                break;
+            }
+
+            let frame_time = frame_start.elapsed();
+            let target_frame_time = Duration::from_millis(16);
+            if frame_time < target_frame_time {
+                sleep(target_frame_time - frame_time).await;
+            }
+        }
+
+        if let Some(spring) = self
+            .active_animations
+            .get(animation_id)
+            .and_then(|animation| animation.spring.as_ref())
+        {
+            debug!(
+                "🌀 Spring animation '{}' finished after {}ms (settled: {})",
+                animation_id,
+                spring.elapsed().as_millis(),
+                spring.is_settled()
+            );
+        }
         Ok(())
     }
 
-    /// Calculate start properties based on animation type and direction
+    /// Wait while an animation is paused, without spinning. Errors once it is stopped
+    async fn wait_while_paused(
+        animation_id: &str,
+        playback: &mut watch::Receiver<Playback>,
+    ) -> Result<()> {
+        loop {
+            let state = *playback.borrow_and_update();
+            match state {
+                Playback::Running => return Ok(()),
+                Playback::Stopped => {
+                    return Err(AnimationError::Stopped(animation_id.to_string()).into())
+                }
+                Playback::Paused => {
+                    debug!("⏸️  Animation '{}' paused, waiting", animation_id);
+                    if playback.changed().await.is_err() {
+                        return Err(AnimationError::Stopped(animation_id.to_string()).into());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Where a show animation starts, given the window's final on-screen
+    /// properties (layout coordinates, like the engine's monitor) and the type.
+    ///
+    /// A directional `fromX` type starts the window just past edge X of the monitor,
+    /// `offset` further out: `fromTop` starts above the monitor (smaller y than the
+    /// target), `fromBottom` below it, `fromLeft` left of it (smaller x), `fromRight`
+    /// right of it. Corner types move on both axes; an axis a type doesn't name keeps
+    /// its final value, as do the size and any property the type doesn't touch
     fn calculate_start_properties(
         &self,
         config: &AnimationConfig,
         final_properties: &HashMap<String, PropertyValue>,
     ) -> Result<HashMap<String, PropertyValue>> {
         let mut start_props = final_properties.clone();
+        let pixels = |name: &str, default: i32| {
+            final_properties
+                .get(name)
+                .map(|p| p.as_pixels())
+                .unwrap_or(default)
+        };
+        let monitor = &self.monitor;
+
+        let (vertical, horizontal) = slide_edges(&config.animation_type);
+        if let Some(edge) = vertical {
+            let offset = self.parse_offset(&config.offset, Axis::Y)?;
+            let y = match edge {
+                SlideEdge::Top => monitor.y - pixels("height", 600) - offset,
+                _ => monitor.y + monitor.height as i32 + offset,
+            };
+            start_props.insert("y".to_string(), PropertyValue::Pixels(y));
+        }
+        if let Some(edge) = horizontal {
+            let offset = self.parse_offset(&config.offset, Axis::X)?;
+            let x = match edge {
+                SlideEdge::Left => monitor.x - pixels("width", 800) - offset,
+                _ => monitor.x + monitor.width as i32 + offset,
+            };
+            start_props.insert("x".to_string(), PropertyValue::Pixels(x));
+        }
 
         match config.animation_type.as_str() {
-            "fromTop" => {
-                let offset_pixels = self.parse_offset(&config.offset, "height")?;
-                // Get window height to calculate proper off-screen position
-                let window_height = start_props
-                    .get("height")
-                    .map(|h| h.as_pixels())
-                    .unwrap_or(600);
-                // Start from completely off-screen at the top: -window_height - offset
-                start_props.insert(
-                    "y".to_string(),
-                    PropertyValue::Pixels(-window_height - offset_pixels as i32),
-                );
-            }
-            "fromBottom" => {
-                let offset_pixels = self.parse_offset(&config.offset, "height")?;
-                // Get screen height to calculate proper off-screen position (simplified to 1080)
-                let screen_height = 1080; // TODO: Get actual screen height
-                                          // Start from completely off-screen at the bottom: screen_height + offset
-                start_props.insert(
-                    "y".to_string(),
-                    PropertyValue::Pixels(screen_height + offset_pixels as i32),
-                );
-            }
-            "fromLeft" => {
-                let offset_pixels = self.parse_offset(&config.offset, "width")?;
-                // Get window width to calculate proper off-screen position
-                let window_width = start_props
-                    .get("width")
-                    .map(|w| w.as_pixels())
-                    .unwrap_or(800);
-                // Start from completely off-screen to the left: -window_width - offset
-                start_props.insert(
-                    "x".to_string(),
-                    PropertyValue::Pixels(-window_width - offset_pixels as i32),
-                );
-            }
-            "fromRight" => {
-                let offset_pixels = self.parse_offset(&config.offset, "width")?;
-                // Get screen width to calculate proper off-screen position (simplified to 1920)
-                let screen_width = 1920; // TODO: Get actual screen width
-                                         // Start from completely off-screen to the right: screen_width + offset
-                start_props.insert(
-                    "x".to_string(),
-                    PropertyValue::Pixels(screen_width + offset_pixels as i32),
-                );
-            }
+            _ if vertical.is_some() || horizontal.is_some() => {}
             "fade" => {
                 start_props.insert(
                     "opacity".to_string(),
@@ -245,16 +742,26 @@ impl AnimationEngine {
             }
             "scale" => {
                 start_props.insert("scale".to_string(), PropertyValue::Float(config.scale_from));
+                // Grow the real geometry from `scale_from` of the target, keeping it centered
+                let pixels = |name: &str| start_props.get(name).map(|p| p.as_pixels());
+                if let (Some(x), Some(y), Some(width), Some(height)) =
+                    (pixels("x"), pixels("y"), pixels("width"), pixels("height"))
+                {
+                    let ((x, y), (width, height)) =
+                        scaled_about_center((x, y), (width, height), config.scale_from);
+                    start_props.insert("x".to_string(), PropertyValue::Pixels(x));
+                    start_props.insert("y".to_string(), PropertyValue::Pixels(y));
+                    start_props.insert("width".to_string(), PropertyValue::Pixels(width));
+                    start_props.insert("height".to_string(), PropertyValue::Pixels(height));
+                }
             }
             "bounce" => {
-                // Bounce animations start from top, completely off-screen
+                // Bounce animations drop in from above the monitor, completely off-screen
                 if let Some(height) = start_props.get("height") {
-                    // Start above screen: -window_height - extra_offset
-                    let window_height = height.as_pixels();
                     let extra_offset = 100; // Extra space to ensure completely hidden
                     start_props.insert(
                         "y".to_string(),
-                        PropertyValue::Pixels(-window_height - extra_offset),
+                        PropertyValue::Pixels(monitor.y - height.as_pixels() - extra_offset),
                     );
                 }
             }
@@ -263,89 +770,122 @@ impl AnimationEngine {
             }
         }
 
-        // Handle custom property animations - targets remain the same
+        // Explicit properties start at their own `from`
         if let Some(properties) = &config.properties {
             for prop_config in properties {
-                start_props.insert(prop_config.property.clone(), prop_config.to.clone());
+                start_props.insert(prop_config.property.clone(), prop_config.from.clone());
             }
         }
 
         Ok(start_props)
     }
 
-    /// Optimized 60fps animation loop with precise frame timing
+    /// Optimized 60fps animation loop with precise frame timing. Each iteration of
+    /// the state's timeline is played with its direction applied to the progress
+    #[instrument(skip_all, fields(animation_id = %animation_id))]
     async fn run_animation_loop(&mut self, animation_id: String) -> Result<()> {
-        info!("🎬 Starting 60fps animation loop for '{}'", animation_id);
+        info!("🎬 Starting 60fps animation loop");
+
+        // Springs end when they settle, so they ignore the timeline's iterations
+        if self
+            .active_animations
+            .get(&animation_id)
+            .is_some_and(|animation| animation.spring.is_some())
+        {
+            return self.run_spring_loop(&animation_id).await;
+        }
 
         // Get animation duration to calculate total frames
-        let (duration_ms, easing_function) = {
+        let (duration_ms, easing_function, timeline, mut playback) = {
             let animation = match self.active_animations.get(&animation_id) {
                 Some(anim) => anim,
                 None => return Ok(()),
             };
-            (animation.config.duration, animation.config.easing.clone())
+            (
+                animation.config.duration,
+                animation.config.easing.clone(),
+                animation.timeline.clone(),
+                animation.playback.subscribe(),
+            )
         };
 
-        let total_frames = ((duration_ms as f32 / 16.67).round() as u32).max(1); // 60fps = 16.67ms per frame
-                                                                                 // Note: easing is now handled per-property in multi-property animations
+        let total_frames = Self::frames_per_iteration(duration_ms);
+        // Note: easing is now handled per-property in multi-property animations
+
+        let mut iteration = 0;
+        'iterations: while timeline.iterations().is_none_or(|count| iteration < count) {
+            // Precise 60fps loop with frame-perfect timing
+            for frame in 0..total_frames {
+                Self::wait_while_paused(&animation_id, &mut playback).await?;
+                let frame_start = Instant::now();
+
+                let progress = Self::frame_progress(&timeline, iteration, frame, total_frames);
+
+                // Update animation properties with per-property easing support
+                Self::interpolate_properties_with_individual_easing(
+                    &mut self.active_animations,
+                    &animation_id,
+                    progress, // Raw progress, not eased yet
+                    &easing_function,
+                )?;
+
+                // Check if animation was removed during the loop
+                if !self.active_animations.contains_key(&animation_id) {
+                    debug!("Animation was removed during loop");
+                    break 'iterations;
+                }
 
-        // Precise 60fps loop with frame-perfect timing
-        for frame in 0..total_frames {
-            let frame_start = Instant::now();
+                // Performance monitoring
+                let frame_time = frame_start.elapsed();
+                self.performance_monitor.frame_times.push(frame_time);
+                if self.performance_monitor.frame_times.len() > 60 {
+                    self.performance_monitor.frame_times.remove(0);
+                }
 
-            // Calculate progress (0.0 to 1.0)
-            let progress = if total_frames == 1 {
-                1.0 // Handle single frame case
-            } else {
-                frame as f32 / (total_frames - 1) as f32
-            };
+                // Frame timing debug (every 10th frame to avoid spam)
+                if frame % 10 == 0 {
+                    debug!(
+                        iteration = iteration + 1,
+                        frame = frame + 1,
+                        total_frames,
+                        progress,
+                        frame_time_ms = frame_time.as_millis() as u64,
+                        "Animation frame"
+                    );
+                }
 
-            // Update animation properties with per-property easing support
-            Self::interpolate_properties_with_individual_easing(
-                &mut self.active_animations,
-                &animation_id,
-                progress, // Raw progress, not eased yet
-                &easing_function,
-            )?;
-
-            // Check if animation was stopped
-            if !self
-                .active_animations
-                .get(&animation_id)
-                .map(|anim| anim.is_running && !anim.is_paused)
-                .unwrap_or(false)
-            {
-                debug!("Animation '{}' was stopped during loop", animation_id);
-                break;
+                // Maintain 60fps (16.67ms per frame)
+                let target_frame_time = Duration::from_millis(16);
+                if frame_time < target_frame_time {
+                    sleep(target_frame_time - frame_time).await;
+                }
             }
+            iteration += 1;
+        }
 
-            // Performance monitoring
-            let frame_time = frame_start.elapsed();
-            self.performance_monitor.frame_times.push(frame_time);
-            if self.performance_monitor.frame_times.len() > 60 {
-                self.performance_monitor.frame_times.remove(0);
-            }
+        Ok(())
+    }
 
-            // Frame timing debug (every 10th frame to avoid spam)
-            if frame % 10 == 0 {
-                debug!(
-                    "Animation '{}' frame {}/{}: progress={:.3}, frame_time={:.1}ms",
-                    animation_id,
-                    frame + 1,
-                    total_frames,
-                    progress,
-                    frame_time.as_millis()
-                );
-            }
+    /// Frames in one timeline iteration at 60fps (16.67ms per frame), at least one
+    fn frames_per_iteration(duration_ms: u32) -> u32 {
+        ((duration_ms as f32 / 16.67).round() as u32).max(1)
+    }
 
-            // Maintain 60fps (16.67ms per frame)
-            let target_frame_time = Duration::from_millis(16);
-            if frame_time < target_frame_time {
-                sleep(target_frame_time - frame_time).await;
-            }
-        }
+    /// Progress (0.0 to 1.0) of a frame, with the timeline's direction applied
+    fn frame_progress(timeline: &Timeline, iteration: u32, frame: u32, total_frames: u32) -> f32 {
+        let progress = if total_frames == 1 {
+            1.0 // Handle single frame case
+        } else {
+            frame as f32 / (total_frames - 1) as f32
+        };
+        timeline.directed_progress(iteration, progress)
+    }
 
-        Ok(())
+    /// Replace the timeline (direction, repeat count, keyframes) of a running animation
+    pub fn set_timeline(&mut self, animation_id: &str, timeline: Timeline) {
+        if let Some(animation) = self.active_animations.get_mut(animation_id) {
+            animation.timeline = timeline;
+        }
     }
 
     /// Advanced interpolation with per-property easing support
@@ -365,9 +905,13 @@ impl AnimationEngine {
                     let eased_progress = easing.apply(raw_progress);
 
                     // Interpolate from configured 'from' to configured 'to' value
-                    let interpolated = prop_config
-                        .from
-                        .interpolate(&prop_config.to, eased_progress);
+                    let interpolated = Self::interpolate_property(
+                        &prop_config.property,
+                        &prop_config.from,
+                        &prop_config.to,
+                        eased_progress,
+                        &animation.monitor,
+                    );
 
                     debug!(
                         "Property '{}': easing={:?}, progress={:.3}, eased={:.3}, value={:?}",
@@ -392,7 +936,13 @@ impl AnimationEngine {
 
                 for (property_name, target_value) in &animation.target_properties.clone() {
                     if let Some(start_value) = animation.start_properties.get(property_name) {
-                        let interpolated = start_value.interpolate(target_value, eased_progress);
+                        let interpolated = Self::interpolate_property(
+                            property_name,
+                            start_value,
+                            target_value,
+                            eased_progress,
+                            &animation.monitor,
+                        );
 
                         // Debug X position for fromLeft animations
                         if (animation.config.animation_type == "fromLeft"
@@ -493,29 +1043,17 @@ impl AnimationEngine {
         }
     }
 
-    /// Parse offset string (pixels or percentage)
-    fn parse_offset(&self, offset: &str, dimension: &str) -> Result<f32> {
-        if offset.ends_with('%') {
-            let percent = offset.trim_end_matches('%').parse::<f32>()?;
-            // Get screen dimension (simplified - would use actual screen resolution)
-            let screen_size = match dimension {
-                "width" => self.performance_monitor.resolution.0,
-                "height" => self.performance_monitor.resolution.1,
-                _ => 1920,
-            };
-            Ok(screen_size as f32 * percent / 100.0)
-        } else if offset.ends_with("px") {
-            Ok(offset.trim_end_matches("px").parse::<f32>()?)
-        } else {
-            Ok(offset.parse::<f32>()?)
-        }
+    /// Pixels of the `offset` setting along `axis` (see `Offset`)
+    fn parse_offset(&self, offset: &str, axis: Axis) -> Result<i32> {
+        Ok(Offset::parse(offset)?.along(axis, &self.monitor))
     }
 
     /// Stop an animation
     pub fn stop_animation(&mut self, animation_id: &str) -> Result<()> {
         if let Some(animation) = self.active_animations.get_mut(animation_id) {
             animation.is_running = false;
-            info!("⏹️  Stopped animation '{}'", animation_id);
+            animation.playback.send_replace(Playback::Stopped);
+            info!(animation_id, "⏹️  Stopped animation");
         }
         Ok(())
     }
@@ -524,6 +1062,18 @@ impl AnimationEngine {
     pub fn pause_animation(&mut self, animation_id: &str, paused: bool) -> Result<()> {
         if let Some(animation) = self.active_animations.get_mut(animation_id) {
             animation.is_paused = paused;
+            animation.playback.send_if_modified(|playback| {
+                // A stopped animation stays stopped
+                if *playback == Playback::Stopped {
+                    return false;
+                }
+                *playback = if paused {
+                    Playback::Paused
+                } else {
+                    Playback::Running
+                };
+                true
+            });
             let action = if paused {
                 "⏸️  Paused"
             } else {
@@ -541,6 +1091,11 @@ impl AnimationEngine {
     ) -> Option<HashMap<String, PropertyValue>> {
         let (raw_progress, duration_completed, easing_function) = {
             if let Some(animation) = self.active_animations.get(animation_id) {
+                // Stopped animations report completion so frame loops end
+                if !animation.is_running || *animation.playback.borrow() == Playback::Stopped {
+                    return None;
+                }
+
                 let now = Instant::now();
                 let duration = Duration::from_millis(animation.config.duration as u64);
 
@@ -550,6 +1105,26 @@ impl AnimationEngine {
                     return Some(animation.start_properties.clone());
                 }
 
+                // Spring-driven: advance by the time since the previous frame
+                if animation.spring.is_some() {
+                    let last_frame = animation.last_frame.unwrap_or(animation.start_time);
+                    let since_last_frame = now.duration_since(last_frame);
+                    let running = Self::advance_spring(
+                        &mut self.active_animations,
+                        animation_id,
+                        since_last_frame,
+                    )
+                    .ok()?;
+                    let animation = self.active_animations.get_mut(animation_id)?;
+                    animation.last_frame = Some(now);
+                    if !running {
+                        animation.current_progress = 1.0;
+                        animation.is_running = false;
+                        return None; // Signal completion
+                    }
+                    return Some(animation.properties.clone());
+                }
+
                 // Calculate elapsed time since animation actually started
                 let elapsed = now.duration_since(animation.start_time);
 
@@ -602,6 +1177,11 @@ impl AnimationEngine {
 
     /// Get list of all supported easing functions
     pub fn get_supported_easings(&self) -> Vec<&'static str> {
+        Self::supported_easings()
+    }
+
+    /// Easing names `validate_easing_function` accepts, besides `cubic-bezier(...)`
+    pub fn supported_easings() -> Vec<&'static str> {
         vec![
             "linear",
             "ease",
@@ -696,6 +1276,9 @@ fn default_spring_damping() -> f32 {
 fn default_spring_mass() -> f32 {
     1.0
 }
+fn default_settle_epsilon() -> f32 {
+    DEFAULT_SETTLE_EPSILON
+}
 
 impl Default for AnimationConfig {
     fn default() -> Self {
@@ -710,6 +1293,565 @@ impl Default for AnimationConfig {
             properties: None,
             target_fps: 60,
             target_position: None,
+            spring: None,
+        }
+    }
+}
+
+impl AnimationConfig {
+    /// Whether window opacity follows this animation: fade types, or an explicit
+    /// `opacity` entry in `properties`
+    pub fn animates_opacity(&self) -> bool {
+        self.animation_type.contains("fade")
+            || self
+                .properties
+                .iter()
+                .flatten()
+                .any(|property| property.property == "opacity")
+    }
+
+    /// Whether frames resize the window: scale types, or `width`, `height` or
+    /// `scale` entries in `properties`
+    pub fn resizes(&self) -> bool {
+        self.animation_type.contains("scale")
+            || self
+                .properties
+                .iter()
+                .flatten()
+                .any(|property| matches!(property.property.as_str(), "width" | "height" | "scale"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(x: i32, y: i32) -> HashMap<String, PropertyValue> {
+        let mut properties = HashMap::new();
+        properties.insert("x".to_string(), PropertyValue::Pixels(x));
+        properties.insert("y".to_string(), PropertyValue::Pixels(y));
+        properties
+    }
+
+    #[test]
+    fn test_supported_animations_are_grouped_and_sorted() {
+        let groups = supported_animations();
+        let names: Vec<&str> = groups.iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            names,
+            vec!["directional", "effects", "physics", "named curves"]
+        );
+
+        for (_, entries) in &groups {
+            let mut sorted = entries.clone();
+            sorted.sort_unstable();
+            assert_eq!(entries, &sorted);
+        }
+        assert!(groups[0].1.contains(&"fromTop"));
+        assert_eq!(groups[2].1, vec!["spring"]);
+        // Every easing shows up exactly once across the groups
+        let listed: usize = groups[2..].iter().map(|(_, e)| e.len()).sum();
+        assert_eq!(listed, AnimationEngine::supported_easings().len());
+    }
+
+    #[test]
+    fn test_animates_opacity() {
+        let fade = AnimationConfig {
+            animation_type: "fade".to_string(),
+            ..Default::default()
+        };
+        assert!(fade.animates_opacity());
+
+        let slide = AnimationConfig::default();
+        assert!(!slide.animates_opacity());
+
+        let slide_with_opacity = AnimationConfig {
+            properties: Some(vec![AnimationPropertyConfig {
+                property: "opacity".to_string(),
+                from: PropertyValue::Float(0.0),
+                to: PropertyValue::Float(1.0),
+                easing: None,
+            }]),
+            ..Default::default()
+        };
+        assert!(slide_with_opacity.animates_opacity());
+    }
+
+    fn geometry(x: i32, y: i32, width: i32, height: i32) -> HashMap<String, PropertyValue> {
+        let mut properties = position(x, y);
+        properties.insert("width".to_string(), PropertyValue::Pixels(width));
+        properties.insert("height".to_string(), PropertyValue::Pixels(height));
+        properties
+    }
+
+    #[test]
+    fn test_directional_start_without_monitor_uses_fallback_size() {
+        // No set_monitor: a 1920×1080 screen at the origin is assumed
+        let engine = AnimationEngine::new();
+        let config = AnimationConfig {
+            animation_type: "fromRight".to_string(),
+            offset: "50px".to_string(),
+            ..Default::default()
+        };
+        let start = engine
+            .calculate_start_properties(&config, &geometry(400, 300, 800, 600))
+            .unwrap();
+        assert_eq!(start["x"].as_pixels(), 1920 + 50);
+    }
+
+    #[test]
+    fn test_directional_start_properties() {
+        // Monitor right of a 1920px one, 100px down; target well inside it
+        let mut engine = AnimationEngine::new();
+        engine.set_monitor(MonitorInfo {
+            x: 1920,
+            y: 100,
+            width: 1920,
+            height: 1080,
+            ..MonitorInfo::new()
+        });
+        let target = geometry(2400, 300, 800, 600);
+
+        let above = 100 - 600 - 50;
+        let below = 100 + 1080 + 50;
+        let left = 1920 - 800 - 50;
+        let right = 1920 + 1920 + 50;
+        let cases = [
+            ("fromTop", (2400, above)),
+            ("fromBottom", (2400, below)),
+            ("fromLeft", (left, 300)),
+            ("fromRight", (right, 300)),
+            ("fromTopLeft", (left, above)),
+            ("fromTopRight", (right, above)),
+            ("fromBottomLeft", (left, below)),
+            ("fromBottomRight", (right, below)),
+        ];
+
+        for (animation_type, expected) in cases {
+            let config = AnimationConfig {
+                animation_type: animation_type.to_string(),
+                offset: "50px".to_string(),
+                ..Default::default()
+            };
+            let start = engine.calculate_start_properties(&config, &target).unwrap();
+            let at = (start["x"].as_pixels(), start["y"].as_pixels());
+            assert_eq!(at, expected, "{animation_type}");
+            // Size never changes, and the start is fully off the monitor
+            assert_eq!(start["width"], target["width"], "{animation_type}");
+            assert_eq!(start["height"], target["height"], "{animation_type}");
+            let off_screen =
+                at.1 + 600 <= 100 || at.1 >= 1180 || at.0 + 800 <= 1920 || at.0 >= 3840;
+            assert!(off_screen, "{animation_type} starts on screen at {at:?}");
         }
+
+        // Signs: from the top means a smaller y, from the left a smaller x
+        let start_of = |animation_type: &str| {
+            let config = AnimationConfig {
+                animation_type: animation_type.to_string(),
+                ..Default::default()
+            };
+            engine.calculate_start_properties(&config, &target).unwrap()
+        };
+        assert!(start_of("fromTop")["y"].as_pixels() < 300);
+        assert!(start_of("fromBottom")["y"].as_pixels() > 300);
+        assert!(start_of("fromLeft")["x"].as_pixels() < 2400);
+        assert!(start_of("fromRight")["x"].as_pixels() > 2400);
+    }
+
+    #[tokio::test]
+    async fn test_scale_animation_first_frame_is_scaled_and_centered() {
+        let mut engine = AnimationEngine::new();
+        let config = AnimationConfig {
+            animation_type: "scale".to_string(),
+            scale_from: 0.5,
+            duration: 10_000,
+            ..Default::default()
+        };
+        let target = geometry(100, 200, 800, 600);
+        let start = engine.calculate_start_properties(&config, &target).unwrap();
+
+        engine
+            .start_animation("grow".to_string(), config, start, target)
+            .await
+            .unwrap();
+        let first_frame = engine.get_current_properties("grow").unwrap();
+        let pixels = |name: &str| first_frame[name].as_pixels();
+
+        assert_eq!((pixels("width"), pixels("height")), (400, 300));
+        // Center of the target (500, 500) is preserved
+        assert_eq!(pixels("x") + pixels("width") / 2, 500);
+        assert_eq!(pixels("y") + pixels("height") / 2, 500);
+    }
+
+    #[test]
+    fn test_frame_geometry_scales_about_center_without_explicit_size() {
+        let frame = |entries: &[(&str, PropertyValue)]| {
+            let properties = entries
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect();
+            frame_geometry(&properties, (100, 100), (800, 600))
+        };
+
+        assert_eq!(frame(&[]), ((100, 100), (800, 600)));
+        assert_eq!(
+            frame(&[("scale", PropertyValue::Float(0.5))]),
+            ((300, 250), (400, 300))
+        );
+        // Explicit sizes win over scale, as in the scale type's own frames
+        assert_eq!(
+            frame(&[
+                ("x", PropertyValue::Pixels(0)),
+                ("width", PropertyValue::Pixels(200)),
+                ("scale", PropertyValue::Float(0.5)),
+            ]),
+            ((0, 100), (200, 600))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stopped_animation_reports_completion() {
+        let mut engine = AnimationEngine::new();
+        let config = AnimationConfig {
+            duration: 10_000,
+            ..Default::default()
+        };
+
+        engine
+            .start_animation(
+                "slide".to_string(),
+                config,
+                position(0, 0),
+                position(100, 0),
+            )
+            .await
+            .unwrap();
+        assert!(engine.get_current_properties("slide").is_some());
+
+        // Frame loops poll get_current_properties and must end once stopped
+        engine.stop_animation("slide").unwrap();
+        assert!(engine.get_current_properties("slide").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_restarting_a_running_animation_retargets_from_current_frame() {
+        let mut engine = AnimationEngine::new();
+        let config = AnimationConfig {
+            duration: 200,
+            easing: EasingFunction::Linear,
+            ..Default::default()
+        };
+
+        let show = engine
+            .start_animation(
+                "window".to_string(),
+                config.clone(),
+                position(0, 0),
+                position(1000, 0),
+            )
+            .await
+            .unwrap();
+        sleep(Duration::from_millis(100)).await;
+
+        // Hide requested mid-show: same id, start given as the show's original start
+        let hide = engine
+            .start_animation("window".to_string(), config, position(0, 0), position(0, 0))
+            .await
+            .unwrap();
+
+        assert_eq!(show.playback(), Playback::Stopped);
+        assert_eq!(hide.playback(), Playback::Running);
+        let start_x = engine.active_animations["window"].start_properties["x"].as_pixels();
+        assert!(
+            (300..1000).contains(&start_x),
+            "hide should start from the interrupted frame, started at x={start_x}"
+        );
+
+        // Every frame moves toward the new target, and the last one lands on it
+        let mut previous = start_x as f32;
+        for _ in 0..3 {
+            sleep(Duration::from_millis(40)).await;
+            let x = engine.get_current_properties("window").unwrap()["x"].as_float();
+            assert!(
+                x < previous,
+                "frame x={x} did not move toward 0 from {previous}"
+            );
+            previous = x;
+        }
+        let end = engine.run_to_completion("window").await.unwrap();
+        assert_eq!(end["x"].as_pixels(), 0);
+    }
+
+    #[test]
+    fn test_frame_progress_follows_timeline_direction() {
+        let ping_pong = TimelineBuilder::new(Duration::from_millis(50))
+            .direction(AnimationDirection::Alternate)
+            .repeat(Some(2))
+            .build();
+        let frames = AnimationEngine::frames_per_iteration(50);
+        assert_eq!(frames, 3);
+
+        let progress: Vec<f32> = (0..ping_pong.iterations().unwrap())
+            .flat_map(|iteration| {
+                let timeline = &ping_pong;
+                (0..frames).map(move |frame| {
+                    AnimationEngine::frame_progress(timeline, iteration, frame, frames)
+                })
+            })
+            .collect();
+        assert_eq!(progress, vec![0.0, 0.5, 1.0, 1.0, 0.5, 0.0]);
+
+        // A single-frame timeline repeated "zero" times still plays its frame
+        let single = TimelineBuilder::new(Duration::from_millis(5))
+            .repeat(Some(0))
+            .build();
+        assert_eq!(AnimationEngine::frames_per_iteration(5), 1);
+        assert_eq!(single.iterations(), Some(1));
+        assert_eq!(AnimationEngine::frame_progress(&single, 0, 0, 1), 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_bounce_on_arrival_settles_exactly_on_target() {
+        let phases = bounce_on_arrival((100, -600), (100, 200), 40);
+        assert_eq!(phases.len(), 2);
+
+        // The slide passes the target downwards, the settle phase starts from there
+        assert_eq!(phases[0].from, (100, -600));
+        assert_eq!(phases[0].to, (100, 200 + ARRIVAL_BOUNCE_OVERSHOOT));
+        assert_eq!(phases[1].from, phases[0].to);
+        assert_eq!(phases[1].to, (100, 200));
+        assert_eq!(phases[1].easing, EasingFunction::EaseOutBounce);
+
+        let mut engine = AnimationEngine::new();
+        let mut last = None;
+        for (i, phase) in phases.into_iter().enumerate() {
+            let id = format!("arrival_{i}");
+            let config = AnimationConfig {
+                duration: phase.duration,
+                easing: phase.easing,
+                ..Default::default()
+            };
+            engine
+                .start_animation(
+                    id.clone(),
+                    config,
+                    position(phase.from.0, phase.from.1),
+                    position(phase.to.0, phase.to.1),
+                )
+                .await
+                .unwrap();
+            last = Some(engine.run_to_completion(&id).await.unwrap());
+        }
+        let last = last.unwrap();
+        assert_eq!((last["x"].as_pixels(), last["y"].as_pixels()), (100, 200));
+
+        // Short moves only get a proportional nudge
+        assert_eq!(bounce_on_arrival((0, 0), (50, 0), 40)[0].to, (55, 0));
+    }
+
+    #[tokio::test]
+    async fn test_run_to_completion() {
+        let mut engine = AnimationEngine::new();
+        let config = AnimationConfig {
+            duration: 50,
+            ..Default::default()
+        };
+        let handle = engine
+            .start_animation(
+                "slide".to_string(),
+                config,
+                position(0, 0),
+                position(300, 40),
+            )
+            .await
+            .unwrap();
+        assert_eq!(handle.id(), "slide");
+
+        let properties = engine.run_to_completion("slide").await.unwrap();
+        assert_eq!(properties["x"].as_pixels(), 300);
+        assert_eq!(properties["y"].as_pixels(), 40);
+        assert!(engine.get_current_properties("slide").is_none());
+
+        let missing = engine.run_to_completion("missing").await.unwrap_err();
+        assert_eq!(
+            missing.downcast_ref::<AnimationError>(),
+            Some(&AnimationError::NotFound("missing".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_to_completion_pause_and_stop() {
+        let mut engine = AnimationEngine::new();
+        let config = AnimationConfig {
+            duration: 50,
+            ..Default::default()
+        };
+
+        // Paused animations wait for resume, then finish
+        let handle = engine
+            .start_animation(
+                "paused".to_string(),
+                config.clone(),
+                position(0, 0),
+                position(100, 0),
+            )
+            .await
+            .unwrap();
+        handle.pause();
+        let resumer = handle.clone();
+        tokio::spawn(async move {
+            sleep(Duration::from_millis(100)).await;
+            resumer.resume();
+        });
+        let started = Instant::now();
+        let properties = engine.run_to_completion("paused").await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(100));
+        assert_eq!(properties["x"].as_pixels(), 100);
+
+        // Stopping mid-flight is reported as a distinct error
+        let handle = engine
+            .start_animation(
+                "stopped".to_string(),
+                AnimationConfig {
+                    duration: 10_000,
+                    ..config
+                },
+                position(0, 0),
+                position(100, 0),
+            )
+            .await
+            .unwrap();
+        tokio::spawn(async move {
+            sleep(Duration::from_millis(50)).await;
+            handle.stop();
+        });
+        let error = engine.run_to_completion("stopped").await.unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<AnimationError>(),
+            Some(&AnimationError::Stopped("stopped".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_overshooting_easing_frames_pass_target() {
+        let frames_for = |engine: &mut AnimationEngine, easing: &EasingFunction| {
+            (0..=60)
+                .map(|frame| {
+                    AnimationEngine::interpolate_properties_with_individual_easing(
+                        &mut engine.active_animations,
+                        "slide",
+                        frame as f32 / 60.0,
+                        easing,
+                    )
+                    .unwrap();
+                    engine.active_animations["slide"].properties["x"].as_pixels()
+                })
+                .collect::<Vec<i32>>()
+        };
+
+        for easing in [EasingFunction::EaseOutBack, EasingFunction::EaseOutCubic] {
+            // Fresh engine: restarting "slide" would retarget from the last frame
+            let mut engine = AnimationEngine::new();
+            let config = AnimationConfig {
+                easing: easing.clone(),
+                ..Default::default()
+            };
+            engine
+                .start_animation(
+                    "slide".to_string(),
+                    config,
+                    position(0, 0),
+                    position(500, 0),
+                )
+                .await
+                .unwrap();
+
+            let frames = frames_for(&mut engine, &easing);
+            let max = *frames.iter().max().unwrap();
+            if easing.overshoots() {
+                // Intermediate frames go past the target instead of being clamped
+                assert!(max > 500, "expected overshoot, max frame was {max}");
+            } else {
+                assert_eq!(max, 500);
+            }
+            assert_eq!(*frames.last().unwrap(), 500);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spring_animation_is_framerate_independent() {
+        let config = AnimationConfig {
+            spring: Some(SpringConfig {
+                damping: 12.0,
+                ..SpringConfig::default()
+            }),
+            ..Default::default()
+        };
+        assert!(AnimationEngine::setup_spring_animation(&AnimationConfig::default()).is_none());
+
+        let mut trajectories = Vec::new();
+        for hz in [60u32, 144] {
+            let mut engine = AnimationEngine::new();
+            engine
+                .start_animation(
+                    "spring".to_string(),
+                    config.clone(),
+                    position(0, 0),
+                    position(500, 0),
+                )
+                .await
+                .unwrap();
+
+            let frame = Duration::from_secs(1) / hz;
+            let mut frames = Vec::new();
+            while AnimationEngine::advance_spring(&mut engine.active_animations, "spring", frame)
+                .unwrap()
+            {
+                frames.push(engine.active_animations["spring"].properties["x"].as_pixels());
+                assert!(frames.len() < 5000, "spring never settled");
+            }
+            // Settled: snapped exactly onto the target
+            assert_eq!(
+                engine.active_animations["spring"].properties["x"].as_pixels(),
+                500
+            );
+            assert!(frames.iter().any(|&x| x > 500), "expected overshoot");
+            trajectories.push((hz, frames));
+        }
+
+        // 250ms in: frame 15 at 60Hz, frame 36 at 144Hz
+        let (_, at_60hz) = &trajectories[0];
+        let (_, at_144hz) = &trajectories[1];
+        assert!((at_60hz[14] - at_144hz[35]).abs() <= 1);
+    }
+
+    #[test]
+    fn test_linear_frames_round_evenly_instead_of_truncating() {
+        let monitor = MonitorInfo::default();
+        let (from, to) = (PropertyValue::Pixels(0), PropertyValue::Pixels(100));
+        let frame_count = 60;
+
+        let mut total_error = 0.0;
+        let mut steps = Vec::new();
+        let mut previous = 0;
+        for frame in 0..=frame_count {
+            let progress = frame as f32 / frame_count as f32;
+            let value = AnimationEngine::interpolate_property("x", &from, &to, progress, &monitor);
+            let exact = 100.0 * progress;
+            let pixels = value.as_pixels();
+
+            assert!((pixels as f32 - exact).abs() <= 0.5);
+            total_error += pixels as f32 - exact;
+            if frame > 0 {
+                steps.push(pixels - previous);
+            }
+            previous = pixels;
+        }
+
+        // Truncation would sit about half a pixel behind on average
+        assert!((total_error / frame_count as f32).abs() < 0.1);
+        // 100px over 60 frames: every step is 1 or 2 pixels, ending on target
+        assert!(steps.iter().all(|step| (1..=2).contains(step)));
+        assert_eq!(previous, 100);
     }
 }
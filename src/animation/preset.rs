@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::warn;
+
+/// Named, reusable animation tuning from `[animations.presets.<name>]`.
+/// Unset fields leave the referencing configuration's own value (or default) alone
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnimationPreset {
+    /// Animation type (fromTop, fromLeft, fade, scale, ...)
+    #[serde(rename = "type")]
+    pub animation_type: Option<String>,
+    /// Duration in milliseconds
+    pub duration: Option<u32>,
+    /// Easing function name
+    pub easing: Option<String>,
+    /// Delay before the animation starts (ms)
+    pub delay: Option<u32>,
+    pub spring_stiffness: Option<f32>,
+    pub spring_damping: Option<f32>,
+    pub spring_mass: Option<f32>,
+}
+
+/// Parse the `presets` table of an `[animations]` section, skipping invalid entries
+pub fn parse_presets(animations: &toml::Value) -> HashMap<String, AnimationPreset> {
+    animations
+        .get("presets")
+        .map(parse_preset_table)
+        .unwrap_or_default()
+}
+
+/// Parse a table of presets by name, skipping invalid entries
+pub fn parse_preset_table(presets: &toml::Value) -> HashMap<String, AnimationPreset> {
+    let toml::Value::Table(presets) = presets else {
+        return HashMap::new();
+    };
+
+    presets
+        .iter()
+        .filter_map(|(name, value)| match value.clone().try_into() {
+            Ok(preset) => Some((name.clone(), preset)),
+            Err(e) => {
+                warn!("⚠️ Ignoring invalid animation preset '{}': {}", name, e);
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_presets() {
+        let animations: toml::Value = toml::from_str(
+            r#"
+            [presets.snappy]
+            type = "fromTop"
+            duration = 150
+            easing = "ease-out-back"
+
+            [presets.bouncy]
+            easing = "spring"
+            spring_stiffness = 400.0
+            spring_damping = 12.0
+
+            [presets.broken]
+            duration = "fast"
+        "#,
+        )
+        .unwrap();
+
+        let presets = parse_presets(&animations);
+        assert_eq!(presets.len(), 2);
+        assert_eq!(presets["snappy"].animation_type.as_deref(), Some("fromTop"));
+        assert_eq!(presets["snappy"].duration, Some(150));
+        assert_eq!(presets["bouncy"].spring_stiffness, Some(400.0));
+        assert_eq!(presets["bouncy"].duration, None);
+        assert!(!presets.contains_key("broken"));
+    }
+}
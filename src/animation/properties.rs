@@ -1,4 +1,7 @@
 use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::ipc::MonitorInfo;
 
 /// Animatable property values with interpolation support
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -12,6 +15,82 @@ pub enum PropertyValue {
     Vector3D { x: f32, y: f32, z: f32 },
 }
 
+/// Monitor dimension a percentage is relative to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Monitor width (x, width)
+    X,
+    /// Monitor height (y, height)
+    Y,
+}
+
+impl Axis {
+    /// Axis of a positional property, None for properties without one (opacity, ...)
+    pub fn of_property(name: &str) -> Option<Axis> {
+        match name {
+            "x" | "width" => Some(Axis::X),
+            "y" | "height" => Some(Axis::Y),
+            _ => None,
+        }
+    }
+}
+
+/// An offset in pixels (`200`, `200px`) or percent of the monitor (`10%`): either one
+/// value, applied along whichever axis it is used on, or an `"x y"` pair. Animation
+/// `offset` (the distance a slide starts beyond the monitor edge) and scratchpad
+/// `offset` (from the monitor's top-left corner) both use it
+#[derive(Debug, Clone, PartialEq)]
+pub enum Offset {
+    /// One value for every axis
+    Uniform(PropertyValue),
+    /// Separate `x` and `y` values
+    Pair(PropertyValue, PropertyValue),
+}
+
+impl Offset {
+    pub fn parse(offset: &str) -> anyhow::Result<Offset> {
+        let parts: Vec<&str> = offset.split_whitespace().collect();
+        let invalid = |reason: String| {
+            anyhow::anyhow!("Invalid offset '{offset}': {reason}, expected a value like '200px' or '10%', or an 'x y' pair")
+        };
+        match parts.as_slice() {
+            [value] => Ok(Offset::Uniform(Self::parse_length(value).map_err(invalid)?)),
+            [x, y] => Ok(Offset::Pair(
+                Self::parse_length(x).map_err(invalid)?,
+                Self::parse_length(y).map_err(invalid)?,
+            )),
+            [] => Err(invalid("empty value".to_string())),
+            _ => Err(invalid(format!("{} values", parts.len()))),
+        }
+    }
+
+    /// Pixels or percentage; a bare number is pixels
+    fn parse_length(value: &str) -> Result<PropertyValue, String> {
+        let parsed = if let Some(percent) = value.strip_suffix('%') {
+            percent.parse::<f32>().ok().map(PropertyValue::Percentage)
+        } else {
+            let pixels = value.strip_suffix("px").unwrap_or(value);
+            pixels.parse::<i32>().ok().map(PropertyValue::Pixels)
+        };
+        parsed.ok_or_else(|| format!("'{value}' is not a length"))
+    }
+
+    /// Pixels along `axis`, percentages being of the monitor's size on that axis
+    pub fn along(&self, axis: Axis, monitor: &MonitorInfo) -> i32 {
+        let value = match (self, axis) {
+            (Offset::Uniform(value), _) => value,
+            (Offset::Pair(x, _), Axis::X) => x,
+            (Offset::Pair(_, y), Axis::Y) => y,
+        };
+        value.resolve(axis, monitor)
+    }
+
+    /// `(x, y)` in pixels on `monitor`
+    pub fn resolve(&self, monitor: &MonitorInfo) -> (i32, i32) {
+        (self.along(Axis::X, monitor), self.along(Axis::Y, monitor))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Color {
     pub r: f32,
@@ -83,7 +162,87 @@ impl PropertyValue {
                 z: Self::lerp_f32(*z1, *z2, progress),
             },
             // Type mismatches - return current value
-            _ => self.clone(),
+            _ => {
+                debug!(
+                    "Cannot interpolate {:?} to {:?}, holding the start value",
+                    self, target
+                );
+                self.clone()
+            }
+        }
+    }
+
+    /// Like `interpolate`, but a `Pixels` and a `Percentage` endpoint can be mixed:
+    /// the percentage is resolved against `monitor` first and the result is `Pixels`
+    pub fn interpolate_on(
+        &self,
+        target: &PropertyValue,
+        progress: f32,
+        axis: Axis,
+        monitor: &MonitorInfo,
+    ) -> PropertyValue {
+        match (self, target) {
+            (PropertyValue::Pixels(_), PropertyValue::Percentage(_))
+            | (PropertyValue::Percentage(_), PropertyValue::Pixels(_)) => {
+                PropertyValue::Pixels(Self::lerp_i32(
+                    self.resolve(axis, monitor),
+                    target.resolve(axis, monitor),
+                    progress,
+                ))
+            }
+            _ => self.interpolate(target, progress),
+        }
+    }
+
+    /// Like `interpolate_on`, but a pixel range is interpolated as a `Float` so
+    /// frames keep their sub-pixel position; `as_pixels` rounds it when applied.
+    /// A `Float` endpoint (an earlier sub-pixel frame) is taken as pixels
+    pub fn interpolate_subpixel(
+        &self,
+        target: &PropertyValue,
+        progress: f32,
+        axis: Axis,
+        monitor: &MonitorInfo,
+    ) -> PropertyValue {
+        match (self, target) {
+            (PropertyValue::Pixels(_), PropertyValue::Pixels(_) | PropertyValue::Percentage(_))
+            | (PropertyValue::Percentage(_), PropertyValue::Pixels(_)) => {
+                PropertyValue::Float(Self::lerp_f32(
+                    self.resolve(axis, monitor) as f32,
+                    target.resolve(axis, monitor) as f32,
+                    progress,
+                ))
+            }
+            (
+                PropertyValue::Float(from),
+                PropertyValue::Pixels(_) | PropertyValue::Percentage(_),
+            ) => PropertyValue::Float(Self::lerp_f32(
+                *from,
+                target.resolve(axis, monitor) as f32,
+                progress,
+            )),
+            (PropertyValue::Pixels(_) | PropertyValue::Percentage(_), PropertyValue::Float(to)) => {
+                PropertyValue::Float(Self::lerp_f32(
+                    self.resolve(axis, monitor) as f32,
+                    *to,
+                    progress,
+                ))
+            }
+            _ => self.interpolate_on(target, progress, axis, monitor),
+        }
+    }
+
+    /// Value in pixels along `axis`, percentages being relative to `monitor`'s size
+    pub fn resolve(&self, axis: Axis, monitor: &MonitorInfo) -> i32 {
+        match self {
+            PropertyValue::Percentage(percent) => {
+                let size = match axis {
+                    Axis::X => monitor.width,
+                    Axis::Y => monitor.height,
+                };
+                (size as f32 * percent / 100.0).round() as i32
+            }
+            other => other.as_pixels(),
         }
     }
 
@@ -92,17 +251,17 @@ impl PropertyValue {
         from + (to - from) * progress
     }
 
-    /// Linear interpolation for i32
+    /// Linear interpolation for i32, rounded to the nearest pixel
     fn lerp_i32(from: i32, to: i32, progress: f32) -> i32 {
-        (from as f32 + (to - from) as f32 * progress) as i32
+        (from as f32 + (to - from) as f32 * progress).round() as i32
     }
 
-    /// Get value as pixels (for positions/sizes)
+    /// Get value as pixels (for positions/sizes), rounded to the nearest pixel
     pub fn as_pixels(&self) -> i32 {
         match self {
             PropertyValue::Pixels(val) => *val,
-            PropertyValue::Percentage(val) => (*val * 1920.0) as i32, // Assume 1920 screen width
-            PropertyValue::Float(val) => *val as i32,
+            PropertyValue::Percentage(val) => (*val * 1920.0).round() as i32, // Assume 1920 screen width
+            PropertyValue::Float(val) => val.round() as i32,
             _ => 0,
         }
     }
@@ -407,6 +566,34 @@ impl AnimationProperty {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_offset_parses_single_values_and_pairs() {
+        let monitor = MonitorInfo {
+            width: 1920,
+            height: 1080,
+            ..MonitorInfo::new()
+        };
+
+        // A single value applies along whichever axis asks for it
+        let offset = Offset::parse("10%").unwrap();
+        assert_eq!(offset.along(Axis::X, &monitor), 192);
+        assert_eq!(offset.along(Axis::Y, &monitor), 108);
+        assert_eq!(
+            Offset::parse("200px").unwrap().resolve(&monitor),
+            (200, 200)
+        );
+        assert_eq!(Offset::parse("50").unwrap().resolve(&monitor), (50, 50));
+
+        let offset = Offset::parse("50px 20%").unwrap();
+        assert_eq!(offset.resolve(&monitor), (50, 216));
+        assert_eq!(Offset::parse("-10 0").unwrap().resolve(&monitor), (-10, 0));
+
+        for malformed in ["", "abc", "10px 20px 30px", "50%px", "10 wide"] {
+            let error = Offset::parse(malformed).unwrap_err().to_string();
+            assert!(error.starts_with("Invalid offset"), "{malformed}: {error}");
+        }
+    }
+
     #[test]
     fn test_property_interpolation() {
         let from = PropertyValue::Pixels(100);
@@ -416,6 +603,30 @@ mod tests {
         assert_eq!(result, PropertyValue::Pixels(150));
     }
 
+    #[test]
+    fn test_subpixel_interpolation_mixes_float_and_pixels() {
+        let monitor = MonitorInfo {
+            width: 1920,
+            height: 1080,
+            ..MonitorInfo::new()
+        };
+
+        let from = PropertyValue::Float(505.0);
+        let halfway = from.interpolate_subpixel(&PropertyValue::Pixels(0), 0.5, Axis::X, &monitor);
+        assert_eq!(halfway, PropertyValue::Float(252.5));
+        let halfway =
+            from.interpolate_subpixel(&PropertyValue::Percentage(50.0), 0.5, Axis::X, &monitor);
+        assert_eq!(halfway, PropertyValue::Float(732.5));
+
+        let back = PropertyValue::Pixels(100).interpolate_subpixel(
+            &PropertyValue::Float(200.5),
+            1.0,
+            Axis::Y,
+            &monitor,
+        );
+        assert_eq!(back, PropertyValue::Float(200.5));
+    }
+
     #[test]
     fn test_color_interpolation() {
         let red = Color::new(1.0, 0.0, 0.0, 1.0);
@@ -499,4 +710,45 @@ mod tests {
         assert_eq!(color.g, 0.0);
         assert_eq!(color.b, 0.0);
     }
+
+    #[test]
+    fn test_percentage_resolves_against_monitor() {
+        let monitor = MonitorInfo {
+            width: 2560,
+            height: 1440,
+            ..MonitorInfo::default()
+        };
+
+        let full_width = PropertyValue::from_string("100%").unwrap();
+        assert_eq!(full_width.resolve(Axis::X, &monitor), 2560);
+        assert_eq!(full_width.resolve(Axis::Y, &monitor), 1440);
+        assert_eq!(PropertyValue::Pixels(42).resolve(Axis::X, &monitor), 42);
+
+        // Slide in from one monitor width to the left of x=100
+        let from = PropertyValue::Percentage(-100.0);
+        let to = PropertyValue::Pixels(100);
+        assert_eq!(
+            from.interpolate_on(&to, 0.0, Axis::X, &monitor),
+            PropertyValue::Pixels(-2560)
+        );
+        assert_eq!(
+            from.interpolate_on(&to, 0.5, Axis::X, &monitor),
+            PropertyValue::Pixels(-1230)
+        );
+        assert_eq!(
+            to.interpolate_on(&PropertyValue::Percentage(50.0), 1.0, Axis::Y, &monitor),
+            PropertyValue::Pixels(720)
+        );
+
+        // Same-unit endpoints keep their unit
+        assert_eq!(
+            PropertyValue::Percentage(0.0).interpolate_on(
+                &PropertyValue::Percentage(50.0),
+                0.5,
+                Axis::X,
+                &monitor
+            ),
+            PropertyValue::Percentage(25.0)
+        );
+    }
 }
@@ -0,0 +1,152 @@
+use std::time::Duration;
+
+/// Displacement and velocity under which a spring counts as settled
+pub const DEFAULT_SETTLE_EPSILON: f32 = 0.001;
+
+/// Longest a spring may run before it is stopped, settled or not
+pub const MAX_SPRING_DURATION: Duration = Duration::from_secs(5);
+
+/// Integration step: frames are split into steps this long, so a spring plays
+/// out the same way whatever the monitor's refresh rate
+const STEP: Duration = Duration::from_millis(1);
+
+/// Physical parameters of a damped spring
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringParams {
+    pub stiffness: f32,
+    pub damping: f32,
+    pub mass: f32,
+}
+
+impl Default for SpringParams {
+    fn default() -> Self {
+        Self {
+            stiffness: 300.0,
+            damping: 30.0,
+            mass: 1.0,
+        }
+    }
+}
+
+/// Progress of a spring travelling from 0.0 to its rest point at 1.0
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SpringState {
+    pub position: f32,
+    pub velocity: f32,
+}
+
+impl SpringState {
+    /// Integrate the spring over `elapsed` (semi-implicit Euler in fixed steps)
+    pub fn advance(&mut self, elapsed: Duration, params: &SpringParams) {
+        let mut remaining = elapsed;
+        while !remaining.is_zero() {
+            let step = remaining.min(STEP);
+            remaining -= step;
+
+            let dt = step.as_secs_f32();
+            let displacement = self.position - 1.0;
+            let force = -params.stiffness * displacement - params.damping * self.velocity;
+            self.velocity += force / params.mass * dt;
+            self.position += self.velocity * dt;
+        }
+    }
+
+    /// Whether both the distance to rest and the velocity are below `epsilon`
+    pub fn is_settled(&self, epsilon: f32) -> bool {
+        (1.0 - self.position).abs() < epsilon && self.velocity.abs() < epsilon
+    }
+}
+
+/// A spring played frame by frame until it settles, bounded by a hard cap
+#[derive(Debug, Clone)]
+pub struct SpringAnimation {
+    pub state: SpringState,
+    params: SpringParams,
+    epsilon: f32,
+    elapsed: Duration,
+    cap: Duration,
+}
+
+impl SpringAnimation {
+    pub fn new(params: SpringParams, epsilon: f32, cap: Duration) -> Self {
+        Self {
+            state: SpringState::default(),
+            params,
+            epsilon,
+            elapsed: Duration::ZERO,
+            cap,
+        }
+    }
+
+    /// Advance by the time since the previous frame and return the progress to
+    /// render, or None once the spring has settled or the cap is reached
+    pub fn next_frame(&mut self, since_last_frame: Duration) -> Option<f32> {
+        if self.is_finished() {
+            return None;
+        }
+
+        self.elapsed += since_last_frame;
+        self.state.advance(since_last_frame, &self.params);
+        Some(self.state.position)
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.state.is_settled(self.epsilon)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.is_settled() || self.elapsed >= self.cap
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME_60HZ: Duration = Duration::from_micros(16_667);
+
+    #[test]
+    fn test_underdamped_spring_settles_within_cap() {
+        let params = SpringParams {
+            stiffness: 300.0,
+            damping: 8.0,
+            mass: 1.0,
+        };
+        let mut spring = SpringAnimation::new(params, DEFAULT_SETTLE_EPSILON, MAX_SPRING_DURATION);
+
+        let mut frames = Vec::new();
+        while let Some(position) = spring.next_frame(FRAME_60HZ) {
+            frames.push(position);
+            assert!(frames.len() < 1000, "spring loop never ended");
+        }
+
+        assert!(spring.is_settled());
+        assert!(spring.elapsed() < MAX_SPRING_DURATION);
+        // Underdamped: overshoots its rest point before settling on it
+        assert!(frames.iter().any(|&p| p > 1.0));
+        assert!((frames.last().unwrap() - 1.0).abs() < DEFAULT_SETTLE_EPSILON);
+    }
+
+    #[test]
+    fn test_spring_stops_at_cap() {
+        // Barely damped: far from settled after 100ms
+        let params = SpringParams {
+            stiffness: 300.0,
+            damping: 0.1,
+            mass: 1.0,
+        };
+        let cap = Duration::from_millis(100);
+        let mut spring = SpringAnimation::new(params, DEFAULT_SETTLE_EPSILON, cap);
+
+        let mut frames = 0;
+        while spring.next_frame(FRAME_60HZ).is_some() {
+            frames += 1;
+        }
+        assert!(!spring.is_settled());
+        assert_eq!(frames, 6);
+    }
+}
@@ -1,5 +1,7 @@
 use std::time::{Duration, Instant};
 
+use crate::animation::easing::EasingFunction;
+
 /// Precise animation timeline with keyframe support
 #[derive(Debug, Clone)]
 pub struct Timeline {
@@ -8,13 +10,14 @@ pub struct Timeline {
     loop_count: Option<u32>,
     current_loop: u32,
     direction: AnimationDirection,
+    easing: EasingFunction, // Easing of segments whose keyframe sets none
 }
 
 #[derive(Debug, Clone)]
 pub struct Keyframe {
-    pub time: f32,              // 0.0 to 1.0
-    pub value: f32,             // Animation value at this time
-    pub easing: Option<String>, // Optional easing for this segment
+    pub time: f32,                      // 0.0 to 1.0
+    pub value: f32,                     // Animation value at this time
+    pub easing: Option<EasingFunction>, // Easing of the segment leading into this keyframe
 }
 
 #[derive(Debug, Clone)]
@@ -45,6 +48,7 @@ impl Timeline {
             loop_count: Some(1),
             current_loop: 0,
             direction: AnimationDirection::Normal,
+            easing: EasingFunction::Linear,
         }
     }
 
@@ -65,11 +69,31 @@ impl Timeline {
         self.loop_count = count;
     }
 
+    /// Configured repeat count (None = infinite)
+    pub fn repeat_count(&self) -> Option<u32> {
+        self.loop_count
+    }
+
+    /// Number of iterations actually played: `Some(0)` still plays once
+    pub fn iterations(&self) -> Option<u32> {
+        self.loop_count.map(|count| count.max(1))
+    }
+
+    /// Get animation direction
+    pub fn direction(&self) -> &AnimationDirection {
+        &self.direction
+    }
+
     /// Set animation direction
     pub fn set_direction(&mut self, direction: AnimationDirection) {
         self.direction = direction;
     }
 
+    /// Set the easing of segments whose destination keyframe has none
+    pub fn set_easing(&mut self, easing: EasingFunction) {
+        self.easing = easing;
+    }
+
     /// Get progress (0.0 to 1.0) at given elapsed time
     pub fn get_progress(&mut self, elapsed: Duration) -> f32 {
         if self.duration.as_millis() == 0 {
@@ -79,9 +103,11 @@ impl Timeline {
         let total_progress = elapsed.as_millis() as f32 / self.duration.as_millis() as f32;
 
         // Handle looping
-        if let Some(loop_count) = self.loop_count {
-            if total_progress >= loop_count as f32 {
-                return 1.0; // Animation complete
+        if let Some(iterations) = self.iterations() {
+            if total_progress >= iterations as f32 {
+                // Animation complete: hold the last iteration's end state
+                self.current_loop = iterations - 1;
+                return self.directed_progress(self.current_loop, 1.0);
             }
         }
 
@@ -89,72 +115,59 @@ impl Timeline {
         let loop_progress = total_progress.fract();
         self.current_loop = total_progress.floor() as u32;
 
-        // Apply direction
-        let directed_progress = match self.direction {
-            AnimationDirection::Normal => loop_progress,
-            AnimationDirection::Reverse => 1.0 - loop_progress,
-            AnimationDirection::Alternate => {
-                if self.current_loop.is_multiple_of(2) {
-                    loop_progress
-                } else {
-                    1.0 - loop_progress
-                }
-            }
-            AnimationDirection::AlternateReverse => {
-                if self.current_loop.is_multiple_of(2) {
-                    1.0 - loop_progress
-                } else {
-                    loop_progress
-                }
-            }
-        };
-
-        directed_progress.clamp(0.0, 1.0)
+        self.directed_progress(self.current_loop, loop_progress)
     }
 
-    /// Get interpolated value at specific progress using keyframes
-    pub fn get_value_at_progress(&self, progress: f32) -> f32 {
-        if self.keyframes.is_empty() {
-            return progress;
-        }
-
-        if progress <= self.keyframes[0].time {
-            return self.keyframes[0].value;
-        }
+    /// Apply the playback direction to progress within the given iteration;
+    /// alternating directions flip on odd iterations
+    pub fn directed_progress(&self, iteration: u32, progress: f32) -> f32 {
+        let flipped = match self.direction {
+            AnimationDirection::Normal => false,
+            AnimationDirection::Reverse => true,
+            AnimationDirection::Alternate => !iteration.is_multiple_of(2),
+            AnimationDirection::AlternateReverse => iteration.is_multiple_of(2),
+        };
 
-        if let Some(last_keyframe) = self.keyframes.last() {
-            if progress >= last_keyframe.time {
-                return last_keyframe.value;
-            }
+        let progress = progress.clamp(0.0, 1.0);
+        if flipped {
+            1.0 - progress
+        } else {
+            progress
         }
+    }
 
-        // Find the two keyframes to interpolate between
-        for i in 0..self.keyframes.len() - 1 {
-            let current = &self.keyframes[i];
-            let next = &self.keyframes[i + 1];
-
-            if progress >= current.time && progress <= next.time {
-                // Calculate interpolation factor within this segment
-                let segment_progress = (progress - current.time) / (next.time - current.time);
-
-                // Apply easing to this segment if specified
-                let eased_progress = if let Some(ref easing_name) = next.easing {
-                    use crate::animation::easing::EasingFunction;
-                    EasingFunction::from_name(easing_name).apply(segment_progress)
-                } else {
-                    segment_progress
-                };
-
-                // Linear interpolation between keyframe values
-                return current.value + (next.value - current.value) * eased_progress;
-            }
+    /// Get interpolated value at specific progress using keyframes. Each segment
+    /// uses the easing of the keyframe it leads into (else the timeline's easing).
+    /// At exactly a keyframe's time the value is that keyframe's; of several
+    /// keyframes at the same time, the one added last wins
+    pub fn get_value_at_progress(&self, progress: f32) -> f32 {
+        // Keyframes are sorted stably, so the last one at a time was added last
+        let Some(current) = self.keyframes.iter().rposition(|k| k.time <= progress) else {
+            return self.keyframes.first().map_or(progress, |k| k.value);
+        };
+        let from = &self.keyframes[current];
+        let Some(next_time) = self.keyframes.get(current + 1).map(|k| k.time) else {
+            return from.value;
+        };
+        if from.time == progress {
+            return from.value;
         }
-
-        progress // Fallback
+        let to = self
+            .keyframes
+            .iter()
+            .rfind(|k| k.time == next_time)
+            .unwrap_or(from);
+
+        // Interpolation factor within this segment, eased
+        let segment_progress = (progress - from.time) / (to.time - from.time);
+        let easing = to.easing.as_ref().unwrap_or(&self.easing);
+        let eased_progress = easing.apply(segment_progress);
+
+        from.value + (to.value - from.value) * eased_progress
     }
 
     /// Add a keyframe to the timeline
-    pub fn add_keyframe(&mut self, time: f32, value: f32, easing: Option<String>) {
+    pub fn add_keyframe(&mut self, time: f32, value: f32, easing: Option<EasingFunction>) {
         let keyframe = Keyframe {
             time: time.clamp(0.0, 1.0),
             value,
@@ -187,8 +200,8 @@ impl Timeline {
 
     /// Check if animation is complete
     pub fn is_complete(&self, elapsed: Duration) -> bool {
-        if let Some(loop_count) = self.loop_count {
-            elapsed >= self.duration * loop_count
+        if let Some(iterations) = self.iterations() {
+            elapsed >= self.duration * iterations
         } else {
             false // Infinite loop never completes
         }
@@ -215,7 +228,7 @@ impl Timeline {
             Keyframe {
                 time: 1.0,
                 value: to_opacity,
-                easing: Some("ease-out".to_string()),
+                easing: Some(EasingFunction::EaseOut),
             },
         ];
         Self::with_keyframes(duration, keyframes)
@@ -233,12 +246,12 @@ impl Timeline {
             Keyframe {
                 time: 0.7,
                 value: overshoot,
-                easing: Some("ease-out".to_string()),
+                easing: Some(EasingFunction::EaseOut),
             },
             Keyframe {
                 time: 1.0,
                 value: to_scale,
-                easing: Some("ease-in".to_string()),
+                easing: Some(EasingFunction::EaseIn),
             },
         ];
         Self::with_keyframes(duration, keyframes)
@@ -255,17 +268,17 @@ impl Timeline {
             Keyframe {
                 time: 0.2,
                 value: from_pos + (to_pos - from_pos) * 0.1,
-                easing: Some("ease-in".to_string()),
+                easing: Some(EasingFunction::EaseIn),
             },
             Keyframe {
                 time: 0.8,
                 value: from_pos + (to_pos - from_pos) * 0.9,
-                easing: Some("ease-out".to_string()),
+                easing: Some(EasingFunction::EaseOut),
             },
             Keyframe {
                 time: 1.0,
                 value: to_pos,
-                easing: Some("ease-out".to_string()),
+                easing: Some(EasingFunction::EaseOut),
             },
         ];
         Self::with_keyframes(duration, keyframes)
@@ -282,27 +295,27 @@ impl Timeline {
             Keyframe {
                 time: 0.2,
                 value: 1.0,
-                easing: Some("ease-out".to_string()),
+                easing: Some(EasingFunction::EaseOut),
             },
             Keyframe {
                 time: 0.4,
                 value: 0.7,
-                easing: Some("ease-in".to_string()),
+                easing: Some(EasingFunction::EaseIn),
             },
             Keyframe {
                 time: 0.6,
                 value: 0.9,
-                easing: Some("ease-out".to_string()),
+                easing: Some(EasingFunction::EaseOut),
             },
             Keyframe {
                 time: 0.8,
                 value: 0.8,
-                easing: Some("ease-in".to_string()),
+                easing: Some(EasingFunction::EaseIn),
             },
             Keyframe {
                 time: 1.0,
                 value: 1.0,
-                easing: Some("ease-out".to_string()),
+                easing: Some(EasingFunction::EaseOut),
             },
         ];
         Self::with_keyframes(duration, keyframes)
@@ -319,27 +332,27 @@ impl Timeline {
             Keyframe {
                 time: 0.6,
                 value: 1.3,
-                easing: Some("ease-out".to_string()),
+                easing: Some(EasingFunction::EaseOut),
             },
             Keyframe {
                 time: 0.75,
                 value: 0.9,
-                easing: Some("ease-in".to_string()),
+                easing: Some(EasingFunction::EaseIn),
             },
             Keyframe {
                 time: 0.85,
                 value: 1.1,
-                easing: Some("ease-out".to_string()),
+                easing: Some(EasingFunction::EaseOut),
             },
             Keyframe {
                 time: 0.95,
                 value: 0.95,
-                easing: Some("ease-in".to_string()),
+                easing: Some(EasingFunction::EaseIn),
             },
             Keyframe {
                 time: 1.0,
                 value: 1.0,
-                easing: Some("ease-out".to_string()),
+                easing: Some(EasingFunction::EaseOut),
             },
         ];
         Self::with_keyframes(duration, keyframes)
@@ -360,7 +373,19 @@ impl TimelineBuilder {
 
     pub fn keyframe(mut self, time: f32, value: f32, easing: Option<&str>) -> Self {
         self.timeline
-            .add_keyframe(time, value, easing.map(|s| s.to_string()));
+            .add_keyframe(time, value, easing.map(EasingFunction::from_name));
+        self
+    }
+
+    /// Add a keyframe whose easing governs the segment leading into it
+    pub fn keyframe_with_easing(mut self, time: f32, value: f32, easing: EasingFunction) -> Self {
+        self.timeline.add_keyframe(time, value, Some(easing));
+        self
+    }
+
+    /// Easing of segments whose destination keyframe sets none (default: linear)
+    pub fn easing(mut self, easing: EasingFunction) -> Self {
+        self.timeline.set_easing(easing);
         self
     }
 
@@ -374,6 +399,11 @@ impl TimelineBuilder {
         self
     }
 
+    /// Number of times to play (None = infinite, Some(0) = once)
+    pub fn repeat(self, count: Option<u32>) -> Self {
+        self.loop_count(count)
+    }
+
     pub fn build(self) -> Timeline {
         self.timeline
     }
@@ -442,6 +472,47 @@ mod tests {
         assert_eq!(timeline.get_progress(Duration::from_millis(1500)), 0.5); // Reverse
     }
 
+    #[test]
+    fn test_reverse_and_alternate_reverse_directions() {
+        let mut reverse = TimelineBuilder::new(Duration::from_millis(1000))
+            .direction(AnimationDirection::Reverse)
+            .build();
+        assert_eq!(reverse.get_progress(Duration::from_millis(0)), 1.0);
+        assert_eq!(reverse.get_progress(Duration::from_millis(250)), 0.75);
+        // Finished reversed timelines rest at their start
+        assert_eq!(reverse.get_progress(Duration::from_millis(1000)), 0.0);
+
+        let mut ping_pong = TimelineBuilder::new(Duration::from_millis(1000))
+            .direction(AnimationDirection::AlternateReverse)
+            .repeat(Some(3))
+            .build();
+        assert_eq!(ping_pong.get_progress(Duration::from_millis(250)), 0.75);
+        assert_eq!(ping_pong.get_progress(Duration::from_millis(1250)), 0.25);
+        assert_eq!(ping_pong.current_loop(), 1);
+        assert_eq!(ping_pong.get_progress(Duration::from_millis(2250)), 0.75);
+        assert_eq!(ping_pong.get_progress(Duration::from_millis(3000)), 0.0);
+        assert!(ping_pong.is_complete(Duration::from_millis(3000)));
+
+        let mut endless = TimelineBuilder::new(Duration::from_millis(1000))
+            .direction(AnimationDirection::Alternate)
+            .repeat(None)
+            .build();
+        assert_eq!(endless.get_progress(Duration::from_millis(41_250)), 0.75);
+        assert!(!endless.is_complete(Duration::from_secs(3600)));
+    }
+
+    #[test]
+    fn test_zero_repeat_plays_once() {
+        let mut timeline = TimelineBuilder::new(Duration::from_millis(16))
+            .repeat(Some(0))
+            .build();
+        assert_eq!(timeline.repeat_count(), Some(0));
+        assert_eq!(timeline.iterations(), Some(1));
+        assert_eq!(timeline.get_progress(Duration::from_millis(8)), 0.5);
+        assert!(!timeline.is_complete(Duration::from_millis(8)));
+        assert!(timeline.is_complete(Duration::from_millis(16)));
+    }
+
     #[test]
     fn test_timeline_builder() {
         let timeline = TimelineBuilder::new(Duration::from_millis(1000))
@@ -455,4 +526,45 @@ mod tests {
         assert_eq!(timeline.keyframes.len(), 5); // 2 default + 3 added = 5 keyframes
         assert_eq!(timeline.loop_count, Some(3));
     }
+
+    #[test]
+    fn test_per_keyframe_easing() {
+        // Ease in up to the midpoint, ease out to the end
+        let timeline = TimelineBuilder::new(Duration::from_millis(1000))
+            .keyframe_with_easing(0.5, 0.5, EasingFunction::EaseInCubic)
+            .keyframe_with_easing(1.0, 1.0, EasingFunction::EaseOutCubic)
+            .build();
+
+        let first_half = EasingFunction::EaseInCubic.apply(0.5) * 0.5;
+        let second_half = 0.5 + EasingFunction::EaseOutCubic.apply(0.5) * 0.5;
+        assert!((timeline.get_value_at_progress(0.25) - first_half).abs() < 1e-6);
+        assert!((timeline.get_value_at_progress(0.75) - second_half).abs() < 1e-6);
+
+        // No discontinuity at keyframe times
+        assert_eq!(timeline.get_value_at_progress(0.5), 0.5);
+        assert!((timeline.get_value_at_progress(0.5 - 1e-4) - 0.5).abs() < 1e-3);
+        assert!((timeline.get_value_at_progress(0.5 + 1e-4) - 0.5).abs() < 1e-3);
+
+        // Keyframes without an easing use the timeline default
+        let default_eased = TimelineBuilder::new(Duration::from_millis(1000))
+            .easing(EasingFunction::EaseInQuad)
+            .keyframe(1.0, 2.0, None)
+            .build();
+        assert_eq!(default_eased.get_value_at_progress(0.5), 0.5);
+        assert_eq!(default_eased.get_value_at_progress(1.0), 2.0);
+    }
+
+    #[test]
+    fn test_later_keyframe_wins_at_same_time() {
+        let timeline = TimelineBuilder::new(Duration::from_millis(1000))
+            .keyframe(0.0, 0.2, None)
+            .keyframe(0.5, 1.0, None)
+            .keyframe(1.0, 0.5, None)
+            .build();
+
+        // The builder's keyframes override the default 0.0 -> 1.0 ones
+        assert_eq!(timeline.get_value_at_progress(0.0), 0.2);
+        assert_eq!(timeline.get_value_at_progress(0.75), 0.75);
+        assert_eq!(timeline.get_value_at_progress(1.0), 0.5);
+    }
 }
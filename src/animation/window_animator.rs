@@ -3,16 +3,22 @@ use hyprland::data::Monitor;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
 use tokio::time::{sleep, Duration};
-use tracing::{debug, info, warn};
+use tracing::{debug, info, instrument, warn};
 use tracing_subscriber;
 use tracing_subscriber::fmt::format;
 
-use super::{properties::PropertyValue, AnimationConfig, AnimationEngine};
+use super::{
+    frame_geometry,
+    properties::{Offset, PropertyValue},
+    scaled_about_center, AnimationConfig, AnimationEngine,
+};
 use crate::animation::easing::EasingFunction;
 use crate::ipc::{self, HyprlandClient, MonitorInfo};
 use crate::plugins::monitors;
 use hyprland::ctl::Color;
+use hyprland::dispatch::DispatchType;
 use hyprland::keyword::{Keyword, OptionValue};
 
 #[derive(Debug, Clone)]
@@ -46,6 +52,8 @@ pub struct WindowAnimator {
     pub hyprland_client: Arc<Mutex<Option<Arc<HyprlandClient>>>>,
     pub active_monitor: Arc<Mutex<MonitorInfo>>,
     active_window_animations: HashMap<String, WindowAnimationState>,
+    animation_loops: HashMap<String, JoinHandle<()>>, // Frame loop of each window's latest animation
+    batch_dispatch: bool, // Send each frame as one `hyprctl --batch` call
 }
 
 #[derive(Debug)]
@@ -71,11 +79,34 @@ impl WindowAnimator {
         Self {
             animation_engine: Arc::new(Mutex::new(AnimationEngine::new())),
             hyprland_client: Arc::new(Mutex::new(None)),
-            active_monitor: Arc::new(Mutex::new(MonitorInfo::new())),
+            active_monitor: Arc::new(Mutex::new(super::fallback_monitor())),
             active_window_animations: HashMap::new(),
+            animation_loops: HashMap::new(),
+            batch_dispatch: false,
         }
     }
 
+    /// Send each animation frame's dispatches (move, resize, opacity) as one batch
+    pub fn set_batch_dispatch(&mut self, enabled: bool) {
+        self.batch_dispatch = enabled;
+    }
+
+    pub fn batch_dispatch(&self) -> bool {
+        self.batch_dispatch
+    }
+
+    /// Frame loop of the window's latest animation, which ends after its last frame.
+    /// `None` if no loop was started for it (or it was already taken)
+    pub fn take_animation_loop(&mut self, window_address: &str) -> Option<JoinHandle<()>> {
+        self.animation_loops.remove(window_address)
+    }
+
+    /// Engine id of a window's show/hide animation. Shared by both so a new one
+    /// retargets the running one from its current frame instead of fighting it
+    fn window_animation_id(window_address: &str) -> String {
+        format!("window_{window_address}")
+    }
+
     /// Set the Hyprland client for window manipulation
     pub async fn set_hyprland_client(&self, client: Arc<HyprlandClient>) {
         let mut client_guard = self.hyprland_client.lock().await;
@@ -86,6 +117,10 @@ impl WindowAnimator {
     pub async fn set_active_monitor(&self, monitor_info: &MonitorInfo) {
         let mut monitor_guard = self.active_monitor.lock().await;
         *monitor_guard = monitor_info.clone();
+        self.animation_engine
+            .lock()
+            .await
+            .set_monitor(monitor_info.clone());
     }
 
     /// Animate a window showing with specified animation
@@ -108,7 +143,7 @@ impl WindowAnimator {
         // Get existing windows before launching to identify the new one
         let client_guard = self.hyprland_client.lock().await;
         let client = match client_guard.as_ref() {
-            Some(client) => client,
+            Some(client) => Arc::clone(client),
             None => return Ok(None),
         };
 
@@ -132,61 +167,26 @@ impl WindowAnimator {
         // Get Hyprland style for consistent appearance
         let style = self.get_hyprland_style().await;
 
-        // Create windowrulev2 rules with explicit priorities to override defaults
-        let popup_float_rule = format!(
-            "hyprctl keyword windowrulev2 'float, class:^{}$'",
-            app_class
-        );
-
-        // Use completely disabled decorations initially
-        let popup_nodeco_rule = format!(
-            "hyprctl keyword windowrulev2 'nodecoration, class:^{}$'",
-            app_class
-        );
-
-        let popup_pin_rule = format!("hyprctl keyword windowrulev2 'pin, class:^{}$'", app_class);
-
-        // Try removing all existing window rules first
-        let clear_rules_cmd = format!("hyprctl keyword windowrulev2 unset,class:^{}$", app_class);
-
         // Clear existing rules first
-        tokio::process::Command::new("sh")
-            .arg("-c")
-            .arg(&clear_rules_cmd)
-            .output()
+        client
+            .keyword("windowrulev2", &format!("unset,class:^{app_class}$"))
             .await
             .ok();
 
-        // Apply all rules with error handling
-        let rules = vec![&popup_float_rule, &popup_nodeco_rule, &popup_pin_rule];
-
+        // Float and pin the popup, with decorations completely disabled initially
+        let mut rules = vec!["float", "nodecoration", "pin"];
+        if !style.drop_shadow {
+            rules.push("noshadow");
+        }
         for rule in rules {
-            if let Err(e) = tokio::process::Command::new("sh")
-                .arg("-c")
-                .arg(rule)
-                .output()
+            if let Err(e) = client
+                .keyword("windowrulev2", &format!("{rule}, class:^{app_class}$"))
                 .await
             {
                 warn!("Failed to apply windowrule: {}", e);
             }
         }
 
-        if !style.drop_shadow {
-            let popup_shadow_rule = format!(
-                "hyprctl keyword windowrulev2 'noshadow, class:^{}$'",
-                app_class
-            );
-
-            if let Err(e) = tokio::process::Command::new("sh")
-                .arg("-c")
-                .arg(&popup_shadow_rule)
-                .output()
-                .await
-            {
-                warn!("Failed to apply shadow rule: {}", e);
-            }
-        }
-
         // Small delay to ensure rules are processed
         sleep(Duration::from_millis(50)).await;
 
@@ -231,7 +231,7 @@ impl WindowAnimator {
             //    .await?;
 
             // Create animation state
-            let animation_id = format!("show_{address}");
+            let animation_id = Self::window_animation_id(&address);
             let current_monitor = self.active_monitor.lock().await.clone();
             let state = WindowAnimationState {
                 window_address: address.to_string(),
@@ -295,14 +295,22 @@ impl WindowAnimator {
                 );
             }
 
-            // Add scale for scale animations
+            // Scale animations grow the window from `scale_from` of its size, centered
+            // on the target so only the size appears to change
             if config.animation_type.contains("scale") {
                 initial_properties
                     .insert("scale".to_string(), PropertyValue::Float(config.scale_from));
+                let ((x, y), (width, height)) =
+                    scaled_about_center(target_offset_position, target_size, config.scale_from);
+                initial_properties.insert("x".to_string(), PropertyValue::Pixels(x));
+                initial_properties.insert("y".to_string(), PropertyValue::Pixels(y));
+                initial_properties.insert("width".to_string(), PropertyValue::Pixels(width));
+                initial_properties.insert("height".to_string(), PropertyValue::Pixels(height));
             }
 
-            // Store animation type before moving config
-            let animation_type = config.animation_type.clone();
+            // Store what the frames apply before moving config
+            let resize = config.resizes();
+            let animate_opacity = config.animates_opacity();
 
             // Start the animation
             let mut engine = self.animation_engine.lock().await;
@@ -320,14 +328,16 @@ impl WindowAnimator {
                 )
                 .await?;
             debug!("✅ Animation '{}' started successfully", animation_id);
+            drop(engine);
 
             let monitor = self.active_monitor.lock().await.clone();
             // Start window update loop
             self.start_window_animation_loop(
                 address.to_string(),
                 animation_id,
-                animation_type,
-                monitor.refresh_rate,
+                resize,
+                animate_opacity,
+                target_size,
                 monitor, // ✅ PASSER LE MONITEUR
             )
             .await?;
@@ -355,6 +365,7 @@ impl WindowAnimator {
     }
 
     /// Animate a window hiding with specified animation
+    #[instrument(skip_all, fields(window = %window_address, animation_type = %config.animation_type))]
     pub async fn hide_window(
         &mut self,
         window_address: &str,
@@ -363,10 +374,7 @@ impl WindowAnimator {
         config: AnimationConfig,
         source_monitor: &MonitorInfo, // ✅ NOUVEAU PARAMÈTRE
     ) -> Result<()> {
-        debug!(
-            "🎬 Starting hide animation for window {} with type '{}'",
-            window_address, config.animation_type
-        );
+        debug!("🎬 Starting hide animation");
 
         let absolute_current_position = (
             source_monitor.x + current_position.0,
@@ -398,7 +406,7 @@ impl WindowAnimator {
         );
 
         // Create animation state
-        let animation_id = format!("hide_{window_address}");
+        let animation_id = Self::window_animation_id(window_address);
         let state = WindowAnimationState {
             window_address: window_address.to_string(),
             original_position: absolute_current_position,
@@ -432,11 +440,16 @@ impl WindowAnimator {
         target_properties.insert("y".to_string(), PropertyValue::Pixels(end_position.1));
         target_properties.insert("width".to_string(), PropertyValue::Pixels(current_size.0));
         target_properties.insert("height".to_string(), PropertyValue::Pixels(current_size.1));
-        target_properties.insert("opacity".to_string(), PropertyValue::Float(0.0));
+        // Fade hides end at `opacity_from`, mirroring where the show started
+        target_properties.insert(
+            "opacity".to_string(),
+            PropertyValue::Float(config.opacity_from),
+        );
         target_properties.insert("scale".to_string(), PropertyValue::Float(0.0));
 
-        // Store animation type before moving config
-        let animation_type = config.animation_type.clone();
+        // Store what the frames apply before moving config
+        let resize = config.resizes();
+        let animate_opacity = config.animates_opacity();
 
         // Start the animation
         let mut engine = self.animation_engine.lock().await;
@@ -448,15 +461,15 @@ impl WindowAnimator {
                 target_properties,
             )
             .await?;
-
-        let refresh_rate = source_monitor.refresh_rate;
+        drop(engine);
 
         // Start window update loop
         self.start_window_animation_loop(
             window_address.to_string(),
             animation_id,
-            animation_type,
-            refresh_rate,
+            resize,
+            animate_opacity,
+            current_size,
             source_monitor.clone(), // ✅ PASSER LE MONITEUR SOURCE
         )
         .await?;
@@ -472,37 +485,37 @@ impl WindowAnimator {
         config: &AnimationConfig,
     ) -> Result<(i32, i32)> {
         let monitor = &self.active_monitor.lock().await;
-        let offset_pixels = self.parse_offset(&config.offset, (monitor.width, monitor.height))?;
+        let (offset_x, offset_y) = self.parse_offset(&config.offset, monitor)?;
 
         // Use monitor absolute coordinates for proper multi-monitor support
         match config.animation_type.as_str() {
-            "fromTop" => Ok((target_position.0, monitor.y - target_size.1 - offset_pixels)),
+            "fromTop" => Ok((target_position.0, monitor.y - target_size.1 - offset_y)),
             "fromBottom" => Ok((
                 target_position.0,
-                monitor.y + monitor.height as i32 + offset_pixels,
+                monitor.y + monitor.height as i32 + offset_y,
             )),
-            "fromLeft" => Ok((monitor.x - target_size.0 - offset_pixels, target_position.1)),
+            "fromLeft" => Ok((monitor.x - target_size.0 - offset_x, target_position.1)),
             "fromRight" => Ok((
-                monitor.x + monitor.width as i32 + offset_pixels,
+                monitor.x + monitor.width as i32 + offset_x,
                 target_position.1,
             )),
             "fromTopLeft" => Ok((
-                monitor.x - target_size.0 - offset_pixels,
-                monitor.y - target_size.1 - offset_pixels,
+                monitor.x - target_size.0 - offset_x,
+                monitor.y - target_size.1 - offset_y,
             )),
             "fromTopRight" => Ok((
-                monitor.x + monitor.width as i32 + offset_pixels,
-                monitor.y - target_size.1 - offset_pixels,
+                monitor.x + monitor.width as i32 + offset_x,
+                monitor.y - target_size.1 - offset_y,
             )),
             "fromBottomLeft" => Ok((
-                monitor.x - target_size.0 - offset_pixels,
-                monitor.y + monitor.height as i32 + offset_pixels,
+                monitor.x - target_size.0 - offset_x,
+                monitor.y + monitor.height as i32 + offset_y,
             )),
             "fromBottomRight" => Ok((
-                monitor.x + monitor.width as i32 + offset_pixels,
-                monitor.y + monitor.height as i32 + offset_pixels,
+                monitor.x + monitor.width as i32 + offset_x,
+                monitor.y + monitor.height as i32 + offset_y,
             )),
-            "bounce" => Ok((target_position.0, monitor.y - target_size.1 - offset_pixels)), // Start above screen
+            "bounce" => Ok((target_position.0, monitor.y - target_size.1 - offset_y)), // Start above screen
             "fade" => Ok((target_position.0, target_position.1)), // No position change for fade
             "scale" => Ok((target_position.0, target_position.1)), // No position change for scale
             _ => Ok((target_position.0, target_position.1)),      // Default to target position
@@ -518,30 +531,30 @@ impl WindowAnimator {
         config: &AnimationConfig,
     ) -> Result<(i32, i32)> {
         let monitor = &self.active_monitor.lock().await;
-        let screen_size = (monitor.width, monitor.height);
-        let offset_pixels = self.parse_offset(&config.offset, screen_size)?;
+        let (offset_x, offset_y) = self.parse_offset(&config.offset, monitor)?;
 
         debug!(
             "🖥️  Monitor info: x={}, y={}, width={}, height={}",
             monitor.x, monitor.y, monitor.width, monitor.height
         );
         debug!(
-            "🎯 Position calculation: absolute_current=({}, {}), size=({}, {}), offset={}",
+            "🎯 Position calculation: absolute_current=({}, {}), size=({}, {}), offset=({}, {})",
             absolute_current_position.0,
             absolute_current_position.1,
             current_size.0,
             current_size.1,
-            offset_pixels
+            offset_x,
+            offset_y
         );
 
         // Since absolute_current_position already includes monitor offset,
         // calculate target positions relative to monitor bounds
         match config.animation_type.as_str() {
             "toTop" | "fromTop" => {
-                let target_y = monitor.y - current_size.1 - offset_pixels;
+                let target_y = monitor.y - current_size.1 - offset_y;
                 debug!(
                     "🔢 toTop calculation: monitor.y({}) - height({}) - offset({}) = {}",
-                    monitor.y, current_size.1, offset_pixels, target_y
+                    monitor.y, current_size.1, offset_y, target_y
                 );
                 debug!(
                     "🔢 toTop: keeping same X ({}) since only Y changes for toTop",
@@ -550,35 +563,35 @@ impl WindowAnimator {
                 Ok((absolute_current_position.0, target_y))
             }
             "toBottom" | "fromBottom" => {
-                let target_y = monitor.y + monitor.height as i32 + offset_pixels;
+                let target_y = monitor.y + monitor.height as i32 + offset_y;
                 Ok((absolute_current_position.0, target_y))
             }
             "toLeft" | "fromLeft" => {
-                let target_x = monitor.x - current_size.0 - offset_pixels;
+                let target_x = monitor.x - current_size.0 - offset_x;
                 Ok((target_x, absolute_current_position.1))
             }
             "toRight" | "fromRight" => {
-                let target_x = monitor.x + monitor.width as i32 + offset_pixels;
+                let target_x = monitor.x + monitor.width as i32 + offset_x;
                 Ok((target_x, absolute_current_position.1))
             }
             "toTopLeft" | "fromTopLeft" => {
-                let target_x = monitor.x - current_size.0 - offset_pixels;
-                let target_y = monitor.y - current_size.1 - offset_pixels;
+                let target_x = monitor.x - current_size.0 - offset_x;
+                let target_y = monitor.y - current_size.1 - offset_y;
                 Ok((target_x, target_y))
             }
             "toTopRight" | "fromTopRight" => {
-                let target_x = monitor.x + monitor.width as i32 + offset_pixels;
-                let target_y = monitor.y - current_size.1 - offset_pixels;
+                let target_x = monitor.x + monitor.width as i32 + offset_x;
+                let target_y = monitor.y - current_size.1 - offset_y;
                 Ok((target_x, target_y))
             }
             "toBottomLeft" | "fromBottomLeft" => {
-                let target_x = monitor.x - current_size.0 - offset_pixels;
-                let target_y = monitor.y + monitor.height as i32 + offset_pixels;
+                let target_x = monitor.x - current_size.0 - offset_x;
+                let target_y = monitor.y + monitor.height as i32 + offset_y;
                 Ok((target_x, target_y))
             }
             "toBottomRight" | "fromBottomRight" => {
-                let target_x = monitor.x + monitor.width as i32 + offset_pixels;
-                let target_y = monitor.y + monitor.height as i32 + offset_pixels;
+                let target_x = monitor.x + monitor.width as i32 + offset_x;
+                let target_y = monitor.y + monitor.height as i32 + offset_y;
                 Ok((target_x, target_y))
             }
             "fade" => Ok(absolute_current_position), // No position change for fade
@@ -596,30 +609,30 @@ impl WindowAnimator {
         config: &AnimationConfig,
         monitor: &MonitorInfo, // ✅ MONITEUR EXPLICITE
     ) -> Result<(i32, i32)> {
-        let screen_size = (monitor.width, monitor.height);
-        let offset_pixels = self.parse_offset(&config.offset, screen_size)?;
+        let (offset_x, offset_y) = self.parse_offset(&config.offset, monitor)?;
 
         debug!(
             "🖥️  Using explicit monitor: {} at x={}, y={}, width={}, height={}",
             monitor.name, monitor.x, monitor.y, monitor.width, monitor.height
         );
         debug!(
-            "🎯 Position calculation: absolute_current=({}, {}), size=({}, {}), offset={}",
+            "🎯 Position calculation: absolute_current=({}, {}), size=({}, {}), offset=({}, {})",
             absolute_current_position.0,
             absolute_current_position.1,
             current_size.0,
             current_size.1,
-            offset_pixels
+            offset_x,
+            offset_y
         );
 
         // Since absolute_current_position already includes monitor offset,
         // calculate target positions relative to monitor bounds
         match config.animation_type.as_str() {
             "toTop" | "fromTop" => {
-                let target_y = monitor.y - current_size.1 - offset_pixels;
+                let target_y = monitor.y - current_size.1 - offset_y;
                 debug!(
                     "🔢 toTop calculation: monitor.y({}) - height({}) - offset({}) = {}",
-                    monitor.y, current_size.1, offset_pixels, target_y
+                    monitor.y, current_size.1, offset_y, target_y
                 );
                 debug!(
                     "🔢 toTop: keeping same X ({}) since only Y changes for toTop",
@@ -628,26 +641,26 @@ impl WindowAnimator {
                 Ok((absolute_current_position.0, target_y))
             }
             "toBottom" | "fromBottom" => {
-                let target_y = monitor.y + monitor.height as i32 + offset_pixels;
+                let target_y = monitor.y + monitor.height as i32 + offset_y;
                 debug!(
                     "🔢 toBottom calculation: monitor.y({}) + height({}) + offset({}) = {}",
-                    monitor.y, monitor.height, offset_pixels, target_y
+                    monitor.y, monitor.height, offset_y, target_y
                 );
                 Ok((absolute_current_position.0, target_y))
             }
             "toLeft" | "fromLeft" => {
-                let target_x = monitor.x - current_size.0 - offset_pixels;
+                let target_x = monitor.x - current_size.0 - offset_x;
                 debug!(
                     "🔢 toLeft calculation: monitor.x({}) - width({}) - offset({}) = {}",
-                    monitor.x, current_size.0, offset_pixels, target_x
+                    monitor.x, current_size.0, offset_x, target_x
                 );
                 Ok((target_x, absolute_current_position.1))
             }
             "toRight" | "fromRight" => {
-                let target_x = monitor.x + monitor.width as i32 + offset_pixels;
+                let target_x = monitor.x + monitor.width as i32 + offset_x;
                 debug!(
                     "🔢 toRight calculation: monitor.x({}) + width({}) + offset({}) = {}",
-                    monitor.x, monitor.width, offset_pixels, target_x
+                    monitor.x, monitor.width, offset_x, target_x
                 );
                 debug!(
                     "🔢 toRight: keeping same Y ({}) since only X changes for toRight",
@@ -656,42 +669,42 @@ impl WindowAnimator {
                 Ok((target_x, absolute_current_position.1))
             }
             "toTopLeft" | "fromTopLeft" => {
-                let target_x = monitor.x - current_size.0 - offset_pixels;
-                let target_y = monitor.y - current_size.1 - offset_pixels;
+                let target_x = monitor.x - current_size.0 - offset_x;
+                let target_y = monitor.y - current_size.1 - offset_y;
                 debug!(
                     "🔢 toTopLeft calculation: x=monitor.x({}) - width({}) - offset({}) = {}, y=monitor.y({}) - height({}) - offset({}) = {}",
-                    monitor.x, current_size.0, offset_pixels, target_x,
-                    monitor.y, current_size.1, offset_pixels, target_y
+                    monitor.x, current_size.0, offset_x, target_x,
+                    monitor.y, current_size.1, offset_y, target_y
                 );
                 Ok((target_x, target_y))
             }
             "toTopRight" | "fromTopRight" => {
-                let target_x = monitor.x + monitor.width as i32 + offset_pixels;
-                let target_y = monitor.y - current_size.1 - offset_pixels;
+                let target_x = monitor.x + monitor.width as i32 + offset_x;
+                let target_y = monitor.y - current_size.1 - offset_y;
                 debug!(
                     "🔢 toTopRight calculation: x=monitor.x({}) + width({}) + offset({}) = {}, y=monitor.y({}) - height({}) - offset({}) = {}",
-                    monitor.x, monitor.width, offset_pixels, target_x,
-                    monitor.y, current_size.1, offset_pixels, target_y
+                    monitor.x, monitor.width, offset_x, target_x,
+                    monitor.y, current_size.1, offset_y, target_y
                 );
                 Ok((target_x, target_y))
             }
             "toBottomLeft" | "fromBottomLeft" => {
-                let target_x = monitor.x - current_size.0 - offset_pixels;
-                let target_y = monitor.y + monitor.height as i32 + offset_pixels;
+                let target_x = monitor.x - current_size.0 - offset_x;
+                let target_y = monitor.y + monitor.height as i32 + offset_y;
                 debug!(
                     "🔢 toBottomLeft calculation: x=monitor.x({}) - width({}) - offset({}) = {}, y=monitor.y({}) + height({}) + offset({}) = {}",
-                    monitor.x, current_size.0, offset_pixels, target_x,
-                    monitor.y, monitor.height, offset_pixels, target_y
+                    monitor.x, current_size.0, offset_x, target_x,
+                    monitor.y, monitor.height, offset_y, target_y
                 );
                 Ok((target_x, target_y))
             }
             "toBottomRight" | "fromBottomRight" => {
-                let target_x = monitor.x + monitor.width as i32 + offset_pixels;
-                let target_y = monitor.y + monitor.height as i32 + offset_pixels;
+                let target_x = monitor.x + monitor.width as i32 + offset_x;
+                let target_y = monitor.y + monitor.height as i32 + offset_y;
                 debug!(
                     "🔢 toBottomRight calculation: x=monitor.x({}) + width({}) + offset({}) = {}, y=monitor.y({}) + height({}) + offset({}) = {}",
-                    monitor.x, monitor.width, offset_pixels, target_x,
-                    monitor.y, monitor.height, offset_pixels, target_y
+                    monitor.x, monitor.width, offset_x, target_x,
+                    monitor.y, monitor.height, offset_y, target_y
                 );
                 Ok((target_x, target_y))
             }
@@ -717,13 +730,15 @@ impl WindowAnimator {
         Ok((center_x, center_y))
     }
 
-    /// Window animation update loop
+    /// Window animation update loop. Frames move the window, and also resize it from
+    /// `size` with `resize`
     async fn start_window_animation_loop(
-        &self,
+        &mut self,
         window_address: String,
         animation_id: String,
-        animation_type: String,
-        refresh_rate: f32,
+        resize: bool,
+        animate_opacity: bool,
+        size: (i32, i32),
         source_monitor: MonitorInfo, // ✅ NOUVEAU PARAMÈTRE
     ) -> Result<()> {
         let client_guard = self.hyprland_client.lock().await;
@@ -734,12 +749,14 @@ impl WindowAnimator {
         drop(client_guard); // Release the lock before spawning
 
         let engine = Arc::clone(&self.animation_engine);
+        let batch_dispatch = self.batch_dispatch;
         let window_address_for_unpin = window_address.clone();
 
-        tokio::spawn(async move {
+        let loop_window_address = window_address.clone();
+        let handle = tokio::spawn(async move {
             debug!("🎯 Animation loop started for window {}", window_address);
             let mut frame_count = 0;
-            let refresh_ms = (1000.0 / refresh_rate).round() as u64;
+            let refresh_ms = (1000.0 / source_monitor.refresh_rate).round() as u64;
 
             loop {
                 tokio::time::sleep(tokio::time::Duration::from_millis(refresh_ms)).await; // Adapt to refresh rate of the monitor
@@ -768,8 +785,10 @@ impl WindowAnimator {
                     &client,
                     &window_address,
                     &properties,
-                    &animation_type,
+                    resize.then_some(size),
+                    animate_opacity,
                     &source_monitor, // ✅ PASSER LE MONITEUR SOURCE
+                    batch_dispatch,
                 )
                 .await
                 {
@@ -783,15 +802,11 @@ impl WindowAnimator {
             );
 
             // Clean up window rules after animation completes
-            let unpin_cmd = format!(
-                "hyprctl keyword windowrulev2 unset pin,address:{}",
-                window_address_for_unpin
-            );
-
-            tokio::process::Command::new("sh")
-                .arg("-c")
-                .arg(&unpin_cmd)
-                .output()
+            client
+                .keyword(
+                    "windowrulev2",
+                    &format!("unset pin,address:{window_address_for_unpin}"),
+                )
                 .await
                 .ok();
 
@@ -800,31 +815,28 @@ impl WindowAnimator {
                 window_address_for_unpin
             );
         });
+        // The interrupted animation's loop would keep driving the retargeted one
+        if let Some(previous) = self.animation_loops.insert(loop_window_address, handle) {
+            previous.abort();
+        }
 
         Ok(())
     }
 
-    /// Apply animation properties to window via Hyprland commands (static version)
+    /// Apply animation properties to window via Hyprland commands (static version).
+    /// With `resize_from`, the window is also resized, from that size (see `frame_geometry`)
     async fn apply_properties_to_window_static(
         client: &HyprlandClient,
         window_address: &str,
         properties: &HashMap<String, PropertyValue>,
-        animation_type: &str,
+        resize_from: Option<(i32, i32)>,
+        animate_opacity: bool,
         source_monitor: &MonitorInfo, // ✅ NOUVEAU PARAMÈTRE
+        batch_dispatch: bool,
     ) -> Result<()> {
-        // Extract absolute position
-        let absolute_x = properties.get("x").map(|p| p.as_pixels()).unwrap_or(0);
-        let absolute_y = properties.get("y").map(|p| p.as_pixels()).unwrap_or(0);
-
-        // Extract size
-        let width = properties
-            .get("width")
-            .map(|p| p.as_pixels())
-            .unwrap_or(800);
-        let height = properties
-            .get("height")
-            .map(|p| p.as_pixels())
-            .unwrap_or(600);
+        // Extract absolute position and size
+        let ((absolute_x, absolute_y), (width, height)) =
+            frame_geometry(properties, (0, 0), resize_from.unwrap_or((800, 600)));
 
         // ✅ Plus de détection automatique - utiliser directement le moniteur passé
         let monitor = source_monitor;
@@ -848,23 +860,51 @@ impl WindowAnimator {
             window_address, relative_x, relative_y
         );
 
-        // Move window using relative coordinates (what Hyprland expects)
-        client
-            .move_window_pixel(window_address, relative_x, relative_y)
-            .await?;
+        let opacity = match properties.get("opacity") {
+            Some(PropertyValue::Float(opacity)) if animate_opacity => Some(*opacity),
+            _ => None,
+        };
 
-        debug!("✅ move_window_pixel completed");
+        // One IPC round trip for the whole frame
+        if batch_dispatch {
+            let mut commands = if resize_from.is_some() {
+                HyprlandClient::resize_and_position_commands(
+                    window_address,
+                    relative_x,
+                    relative_y,
+                    width,
+                    height,
+                )
+            } else {
+                vec![HyprlandClient::move_pixel_command(
+                    window_address,
+                    relative_x,
+                    relative_y,
+                )]
+            };
+            commands.extend(
+                opacity.map(|opacity| HyprlandClient::opacity_command(window_address, opacity)),
+            );
+            return client.dispatch_batch(commands).await;
+        }
 
-        // Resize window for scale animations
-        if animation_type.contains("scale") {
-            client.resize_window(window_address, width, height).await?;
+        // Scale animations change the size each frame, so resize and move together
+        // (relative coordinates, which is what Hyprland expects)
+        if resize_from.is_some() {
+            client
+                .resize_and_position_window(window_address, relative_x, relative_y, width, height)
+                .await?;
+        } else {
+            client
+                .move_window_pixel(window_address, relative_x, relative_y)
+                .await?;
         }
 
-        // Handle opacity changes ONLY for fade animations to prevent visual artifacts
-        if animation_type.contains("fade") {
-            if let Some(PropertyValue::Float(opacity)) = properties.get("opacity") {
-                client.set_window_opacity(window_address, *opacity).await?;
-            }
+        debug!("✅ Window frame applied");
+
+        // Handle opacity changes ONLY for opacity animations to prevent visual artifacts
+        if let Some(opacity) = opacity {
+            client.set_window_opacity(window_address, opacity).await?;
         }
 
         Ok(())
@@ -888,8 +928,10 @@ impl WindowAnimator {
             client,
             window_address,
             properties,
-            animation_type,
+            animation_type.contains("scale").then_some((800, 600)),
+            animation_type.contains("fade"),
             source_monitor,
+            self.batch_dispatch,
         )
         .await
     }
@@ -925,16 +967,72 @@ impl WindowAnimator {
         Ok(())
     }
 
-    /// Parse offset string to pixels
-    fn parse_offset(&self, offset: &str, screen_size: (u16, u16)) -> Result<i32> {
-        if offset.ends_with('%') {
-            let percent = offset.trim_end_matches('%').parse::<f32>()?;
-            Ok(((screen_size.0.max(screen_size.1) as f32) * percent / 100.0) as i32)
-        } else if offset.ends_with("px") {
-            Ok(offset.trim_end_matches("px").parse::<i32>()?)
-        } else {
-            Ok(offset.parse::<i32>()?)
+    /// Pixels of the `offset` setting on each axis of `monitor` (see `Offset`)
+    fn parse_offset(&self, offset: &str, monitor: &MonitorInfo) -> Result<(i32, i32)> {
+        Ok(Offset::parse(offset)?.resolve(monitor))
+    }
+
+    /// Animate a window's position and size together between two monitor-relative
+    /// `(position, size)` geometries. Returns once the window sits exactly on `to`
+    #[instrument(skip_all, fields(window = %window_address))]
+    pub async fn animate_geometry(
+        &self,
+        window_address: &str,
+        from: ((i32, i32), (i32, i32)),
+        to: ((i32, i32), (i32, i32)),
+        config: AnimationConfig,
+        monitor: &MonitorInfo,
+    ) -> Result<()> {
+        let client = match self.hyprland_client.lock().await.as_ref() {
+            Some(client) => Arc::clone(client),
+            None => return Ok(()),
+        };
+
+        let geometry = |((x, y), (width, height)): ((i32, i32), (i32, i32))| {
+            HashMap::from([
+                ("x".to_string(), PropertyValue::Pixels(x)),
+                ("y".to_string(), PropertyValue::Pixels(y)),
+                ("width".to_string(), PropertyValue::Pixels(width)),
+                ("height".to_string(), PropertyValue::Pixels(height)),
+            ])
+        };
+        let animation_id = format!("geometry_{window_address}");
+        self.animation_engine
+            .lock()
+            .await
+            .start_animation(animation_id.clone(), config, geometry(from), geometry(to))
+            .await?;
+
+        let refresh_ms = (1000.0 / monitor.refresh_rate.max(1.0)).round() as u64;
+        loop {
+            let properties = self
+                .animation_engine
+                .lock()
+                .await
+                .get_current_properties(&animation_id);
+            let Some(properties) = properties else {
+                break;
+            };
+
+            let value = |name: &str| properties.get(name).map(|p| p.as_pixels());
+            if let (Some(x), Some(y), Some(width), Some(height)) =
+                (value("x"), value("y"), value("width"), value("height"))
+            {
+                if let Err(e) = client.resize_window(window_address, width, height).await {
+                    debug!("Failed to apply animation properties: {}", e);
+                }
+                if let Err(e) = client.move_window_pixel(window_address, x, y).await {
+                    debug!("Failed to apply animation properties: {}", e);
+                }
+            }
+            sleep(Duration::from_millis(refresh_ms)).await;
         }
+
+        // The last frame may stop short of the target
+        let ((x, y), (width, height)) = to;
+        client.resize_window(window_address, width, height).await?;
+        client.move_window_pixel(window_address, x, y).await?;
+        Ok(())
     }
 
     /// Stop animation for a window
@@ -942,7 +1040,7 @@ impl WindowAnimator {
         if let Some(state) = self.active_window_animations.remove(window_address) {
             let mut engine = self.animation_engine.lock().await;
             engine.stop_animation(&state.animation_id)?;
-            info!("⏹️  Stopped animation for window {}", window_address);
+            info!(window = window_address, "⏹️  Stopped animation for window");
         }
         Ok(())
     }
@@ -989,7 +1087,7 @@ impl WindowAnimator {
             }
             Err(e) => {
                 debug!("❌ Even simple spawn failed: {}", e);
-                return Err(e.into());
+                return Err(e);
             }
         }
 
@@ -1284,49 +1382,19 @@ impl WindowAnimator {
         None
     }
 
-    /// Remove workspace switching prevention after animation
-    async fn allow_workspace_switching(&self, window_address: &str) -> Result<()> {
-        // Remove pin rule and restore normal border
-        let unpin_cmd = format!(
-            "hyprctl keyword windowrulev2 unset pin,address:{}",
-            window_address
-        );
-        let restore_border_cmd = format!(
-            "hyprctl keyword windowrulev2 'bordersize 1,address:{}'",
-            window_address
-        );
-
-        tokio::process::Command::new("sh")
-            .arg("-c")
-            .arg(&unpin_cmd)
-            .output()
-            .await?;
-
-        tokio::process::Command::new("sh")
-            .arg("-c")
-            .arg(&restore_border_cmd)
-            .output()
-            .await?;
-
-        debug!(
-            "📌 Unpinned window {} and restored borders after animation",
-            window_address
-        );
-        Ok(())
-    }
-
-    /// Apply popup decorations directly to a window using hyprctl commands
+    /// Apply popup decorations directly to a window
     async fn apply_popup_decorations(&self, window_address: &str, style: &HyprlandStyle) {
-        // First, try to remove ALL existing decoration rules for this window
-        let remove_decorations_cmd = format!(
-            "hyprctl keyword windowrulev2 unset,address:{}",
-            window_address
-        );
+        let Some(client) = self.hyprland_client.lock().await.clone() else {
+            return;
+        };
+        let setprop = |property: &str, value: &dyn std::fmt::Display| {
+            let args = format!("address:{window_address} {property} {value}");
+            DispatchType::Custom("setprop", Box::leak(args.into_boxed_str()))
+        };
 
-        tokio::process::Command::new("sh")
-            .arg("-c")
-            .arg(&remove_decorations_cmd)
-            .output()
+        // First, try to remove ALL existing decoration rules for this window
+        client
+            .keyword("windowrulev2", &format!("unset,address:{window_address}"))
             .await
             .ok();
 
@@ -1341,58 +1409,30 @@ impl WindowAnimator {
         // Now try direct property setting with more specific commands
         let decoration_commands = [
             // Now set the desired border size if > 0
-            format!(
-                "hyprctl setprop address:{} bordersize {}",
-                window_address, style.border_size
-            ),
+            setprop("bordersize", &style.border_size),
             // Set border colors
-            format!(
-                "hyprctl setprop address:{} activebordercolor {}",
-                window_address, style.active_border_color
-            ),
-            format!(
-                "hyprctl setprop address:{} inactivebordercolor {}",
-                window_address, style.inactive_border_color
-            ),
+            setprop("activebordercolor", &style.active_border_color),
+            setprop("inactivebordercolor", &style.inactive_border_color),
         ];
 
-        for (i, cmd) in decoration_commands.iter().enumerate() {
-            if let Err(e) = tokio::process::Command::new("sh")
-                .arg("-c")
-                .arg(cmd)
-                .output()
-                .await
-            {
-                warn!("Failed to apply decoration command '{}': {}", cmd, e);
+        for (i, command) in decoration_commands.into_iter().enumerate() {
+            if let Err(e) = client.dispatch(command).await {
+                warn!("Failed to apply decoration {}: {}", i + 1, e);
             } else {
-                debug!("✅ Applied decoration {}: {}", i + 1, cmd);
+                debug!("✅ Applied decoration {}", i + 1);
             }
 
             // Longer delay between critical commands
             sleep(Duration::from_millis(50)).await;
         }
 
-        // As a last resort, try using dispatch to focus and apply rules
-        let focus_cmd = format!("hyprctl dispatch focuswindow address:{}", window_address);
-        tokio::process::Command::new("sh")
-            .arg("-c")
-            .arg(&focus_cmd)
-            .output()
-            .await
-            .ok();
+        // As a last resort, focus the window and set the border size again
+        client.focus_window(window_address).await.ok();
 
         sleep(Duration::from_millis(50)).await;
 
-        // One final attempt to set border size
-        let final_border_cmd = format!(
-            "hyprctl setprop address:{} bordersize {}",
-            window_address, style.border_size
-        );
-
-        if let Err(e) = tokio::process::Command::new("sh")
-            .arg("-c")
-            .arg(&final_border_cmd)
-            .output()
+        if let Err(e) = client
+            .dispatch(setprop("bordersize", &style.border_size))
             .await
         {
             warn!("Final border setting failed: {}", e);
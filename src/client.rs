@@ -1,11 +1,12 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
-use tokio::net::UnixStream;
 use tracing::{error, warn};
 
 // Import the IPC protocol from the library
-use rustrland::ipc::{protocol::get_socket_path, ClientMessage, DaemonResponse};
+use rustrland::ipc::protocol::{
+    pong_line, read_response, request, send_message, SCRATCHPADS_SUBCOMMANDS,
+};
+use rustrland::ipc::{ClientMessage, DaemonResponse};
 
 #[derive(Parser)]
 #[command(name = "rustr")]
@@ -20,24 +21,29 @@ struct Cli {
 enum Commands {
     /// Toggle a scratchpad
     Toggle {
-        /// Scratchpad name
-        name: String,
+        /// Scratchpad name (defaults to `[scratchpads] default`)
+        name: Option<String>,
     },
     /// Show a scratchpad directly (without toggling)
     Show {
-        /// Scratchpad name
-        name: String,
+        /// Scratchpad name (defaults to `[scratchpads] default`)
+        name: Option<String>,
     },
     /// Hide a scratchpad directly (without toggling)
     Hide {
-        /// Scratchpad name
-        name: String,
+        /// Scratchpad name (defaults to `[scratchpads] default`)
+        name: Option<String>,
     },
     /// Toggle window attachment to scratchpad system
     Attach {
         /// Scratchpad name
         name: String,
     },
+    /// Print whether a scratchpad is visible; exits 0 if it is, 1 otherwise
+    IsVisible {
+        /// Scratchpad name
+        name: String,
+    },
     /// Show all windows (expose)
     Expose {
         /// Expose sub-command (toggle, next, prev, exit, status)
@@ -46,31 +52,42 @@ enum Commands {
     },
     /// Reload configuration
     Reload,
+    /// Print the daemon's full state (plugins included) as JSON
+    DumpState,
+    /// List the animation types and easings configs may use
+    Easings,
+    /// Stream Hyprland events as JSON lines until interrupted
+    Subscribe {
+        /// Event names to receive, e.g. WindowFocusChanged (default: all)
+        events: Vec<String>,
+    },
     /// Show daemon status
     Status,
+    /// Check that the daemon is alive, without going through Hyprland
+    Ping,
     /// List available scratchpads
     List,
     /// Workspace management
     Workspace {
-        /// Workspace command (switch, change, list, status)
+        /// Workspace command (switch, change, move_next, move_prev, drag, list, status)
         #[arg()]
         action: String,
-        /// Optional argument (workspace ID, offset, etc.)
+        /// Optional argument (workspace ID, offset, on/off/toggle for drag, etc.)
         #[arg()]
         arg: Option<String>,
     },
     /// Magnify/zoom controls
     Magnify {
-        /// Magnify command (toggle, set, in, out, reset, status)
+        /// Magnify command (toggle, zoom, set, in, out, reset, status)
         #[arg()]
         action: String,
-        /// Optional argument (zoom level, delta, etc.)
-        #[arg()]
+        /// Optional argument (zoom level, delta such as -0.5, etc.)
+        #[arg(allow_hyphen_values = true)]
         arg: Option<String>,
     },
     /// Shift workspaces between monitors
     ShiftMonitors {
-        /// Direction to shift (+1 for forward, -1 for backward)
+        /// Direction to shift (+N/-N, next/prev, or left/right)
         #[arg(default_value = "+1")]
         direction: String,
     },
@@ -85,13 +102,16 @@ enum Commands {
     },
     /// Monitor management
     Monitors {
-        /// Monitor command (relayout, list, status, test, reload)
+        /// Monitor command (relayout, list, describe, status, test, apply, current, reload)
         #[arg(default_value = "relayout")]
         command: String,
+        /// Additional arguments for the command
+        #[arg()]
+        args: Vec<String>,
     },
     /// Wallpaper management
     Wallpapers {
-        /// Wallpaper command (next, set, carousel, scan, list, status, clear, start, stop)
+        /// Wallpaper command (next, prev, set, scan, list, status, clear, start, stop, pause, resume)
         #[arg(default_value = "next")]
         command: String,
         /// Additional arguments for the command
@@ -100,7 +120,7 @@ enum Commands {
     },
     /// System notifications
     Notify {
-        /// Notification command (notify, status, list-sources, list-parsers, test-animation)
+        /// Notification command (notify, status, health, list-sources, list-parsers, test-animation)
         #[arg(default_value = "notify")]
         command: String,
         /// Additional arguments for the command
@@ -109,13 +129,48 @@ enum Commands {
     },
     /// Lost window recovery
     LostWindows {
-        /// Lost windows command (list, recover, status, enable, disable, strategy, check)
+        /// Lost windows command (list, collect, status, enable, disable, strategy, check)
         #[arg(default_value = "status")]
         command: String,
+        /// Additional arguments for the command (e.g. --include-special)
+        #[arg(allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Scratchpad management
+    Scratchpads {
+        #[arg(
+            default_value = "list",
+            help = format!("Scratchpad command ({})", SCRATCHPADS_SUBCOMMANDS.join(", "))
+        )]
+        command: String,
+        /// Additional arguments for the command
+        #[arg()]
+        args: Vec<String>,
+    },
+    /// Config profile management
+    Profile {
+        /// Profile command (current, switch <name>, clear)
+        #[arg(default_value = "current")]
+        command: String,
         /// Additional arguments for the command
         #[arg()]
         args: Vec<String>,
     },
+    /// Override a plugin config value until the next reload (not saved)
+    Set {
+        /// `<plugin>.<key> <value>` or `<plugin> <key> <value>`, e.g. `scratchpads.term.animation_duration 500`
+        #[arg(required = true, num_args = 2..=3, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Daemon debugging
+    Debug {
+        /// Debug command (last-commands, replay)
+        #[arg(default_value = "last-commands")]
+        command: String,
+        /// Arguments: [count] [--json] [--raw] for last-commands, [count] --confirm for replay
+        #[arg(allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
 }
 
 #[tokio::main]
@@ -123,13 +178,25 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     let message = match cli.command {
-        Commands::Toggle { name } => ClientMessage::Toggle { scratchpad: name },
-        Commands::Show { name } => ClientMessage::Show { scratchpad: name },
-        Commands::Hide { name } => ClientMessage::Hide { scratchpad: name },
+        // An empty name lets the daemon use the configured default scratchpad
+        Commands::Toggle { name } => ClientMessage::Toggle {
+            scratchpad: name.unwrap_or_default(),
+        },
+        Commands::Show { name } => ClientMessage::Show {
+            scratchpad: name.unwrap_or_default(),
+        },
+        Commands::Hide { name } => ClientMessage::Hide {
+            scratchpad: name.unwrap_or_default(),
+        },
         Commands::Attach { name } => ClientMessage::Attach { scratchpad: name },
+        Commands::IsVisible { name } => ClientMessage::IsVisible { scratchpad: name },
         Commands::Expose { action } => ClientMessage::ExposeAction { action },
         Commands::Reload => ClientMessage::Reload,
+        Commands::DumpState => ClientMessage::DumpState,
+        Commands::Easings => ClientMessage::ListEasings,
+        Commands::Subscribe { events } => ClientMessage::Subscribe { events },
         Commands::Status => ClientMessage::Status,
+        Commands::Ping => ClientMessage::Ping,
         Commands::List => ClientMessage::List,
         Commands::Workspace { action, arg } => ClientMessage::WorkspaceAction { action, arg },
         Commands::Magnify { action, arg } => ClientMessage::MagnifyAction { action, arg },
@@ -143,8 +210,9 @@ async fn main() -> Result<()> {
             workspace_name: Some(workspace_name),
             command,
         },
-        Commands::Monitors { command } => ClientMessage::Monitors {
+        Commands::Monitors { command, args } => ClientMessage::Monitors {
             command: Some(command),
+            args,
         },
         Commands::Wallpapers { command, args } => ClientMessage::Wallpapers {
             command: Some(command),
@@ -158,9 +226,37 @@ async fn main() -> Result<()> {
             command: Some(command),
             args,
         },
+        Commands::Scratchpads { command, args } => ClientMessage::Scratchpads {
+            command: Some(command),
+            args,
+        },
+        Commands::Profile { command, args } => ClientMessage::Profile {
+            command: Some(command),
+            args,
+        },
+        Commands::Debug { command, args } => ClientMessage::Debug {
+            command: Some(command),
+            args,
+        },
+        Commands::Set { args } => ClientMessage::from_args("set", &args)?,
     };
 
+    if matches!(message, ClientMessage::Subscribe { .. }) {
+        if let Err(e) = subscribe(&message).await {
+            eprintln!("❌ Failed to communicate with daemon: {e}");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // `is-visible` is meant for shell conditionals, so its answer is the exit code
+    let is_visible_query = matches!(message, ClientMessage::IsVisible { .. });
+
     match send_command(message).await {
+        Ok(DaemonResponse::Success { message }) if is_visible_query => {
+            println!("{message}");
+            std::process::exit(if message == "true" { 0 } else { 1 });
+        }
         Ok(response) => handle_response(response),
         Err(e) => {
             eprintln!("❌ Failed to communicate with daemon: {e}");
@@ -183,7 +279,7 @@ async fn send_command(message: ClientMessage) -> Result<DaemonResponse> {
 
     // Retry loop with exponential backoff
     for attempt in 1..=MAX_RETRIES {
-        match send_command_once(&message, IPC_TIMEOUT).await {
+        match request(&message, IPC_TIMEOUT).await {
             Ok(response) => return Ok(response),
             Err(e) => {
                 last_error = Some(e);
@@ -208,64 +304,33 @@ async fn send_command(message: ClientMessage) -> Result<DaemonResponse> {
     Err(last_error.unwrap())
 }
 
-async fn send_command_once(
-    message: &ClientMessage,
-    timeout_duration: tokio::time::Duration,
-) -> Result<DaemonResponse> {
-    use tokio::time::timeout;
-
-    let socket_path = get_socket_path();
-    let mut stream = timeout(timeout_duration, UnixStream::connect(&socket_path))
-        .await
-        .map_err(|_| anyhow::anyhow!("Connection timeout after {:?}", timeout_duration))??;
+/// Send a `Subscribe` and copy the event lines the daemon streams back to stdout
+async fn subscribe(message: &ClientMessage) -> Result<()> {
+    use tokio::io::AsyncBufReadExt;
 
-    // Serialize the message
-    let message_data = serde_json::to_vec(&message)?;
-
-    // Send message length + message with timeout
-    let msg_len = (message_data.len() as u32).to_le_bytes();
-    timeout(timeout_duration, stream.write_all(&msg_len))
-        .await
-        .map_err(|_| anyhow::anyhow!("Write timeout after {:?}", timeout_duration))??;
-    timeout(timeout_duration, stream.write_all(&message_data))
-        .await
-        .map_err(|_| anyhow::anyhow!("Write timeout after {:?}", timeout_duration))??;
-
-    // Read response length with timeout
-    let mut len_buf = [0u8; 4];
-    timeout(timeout_duration, stream.read_exact(&mut len_buf))
-        .await
-        .map_err(|_| {
-            anyhow::anyhow!(
-                "Read timeout while waiting for response length after {:?}",
-                timeout_duration
-            )
-        })??;
-    let response_len = u32::from_le_bytes(len_buf) as usize;
-
-    // Validate response length to prevent DoS
-    if response_len > 1024 * 1024 {
-        // 1MB limit
-        return Err(anyhow::anyhow!(
-            "Response too large: {} bytes",
-            response_len
-        ));
+    let mut stream = send_message(message, tokio::time::Duration::from_secs(10)).await?;
+    match read_response(&mut stream, tokio::time::Duration::from_secs(10)).await? {
+        DaemonResponse::Error { message } | DaemonResponse::Usage { message } => {
+            eprintln!("❌ Error: {message}");
+            std::process::exit(1);
+        }
+        response => eprintln!("{}", response_message(&response)),
     }
 
-    // Read response with timeout
-    let mut response_buf = vec![0u8; response_len];
-    timeout(timeout_duration, stream.read_exact(&mut response_buf))
-        .await
-        .map_err(|_| {
-            anyhow::anyhow!(
-                "Read timeout while waiting for response data after {:?}",
-                timeout_duration
-            )
-        })??;
+    let mut lines = tokio::io::BufReader::new(stream).lines();
+    while let Some(line) = lines.next_line().await? {
+        println!("{line}");
+    }
+    Ok(())
+}
 
-    // Deserialize response
-    let response: DaemonResponse = serde_json::from_slice(&response_buf)?;
-    Ok(response)
+fn response_message(response: &DaemonResponse) -> String {
+    match response {
+        DaemonResponse::Success { message }
+        | DaemonResponse::Error { message }
+        | DaemonResponse::Usage { message } => message.clone(),
+        other => format!("{other:?}"),
+    }
 }
 
 fn handle_response(response: DaemonResponse) {
@@ -277,6 +342,10 @@ fn handle_response(response: DaemonResponse) {
             eprintln!("❌ Error: {message}");
             std::process::exit(1);
         }
+        DaemonResponse::Usage { message } => {
+            eprintln!("Usage: {message}");
+            std::process::exit(2);
+        }
         DaemonResponse::Status {
             version,
             uptime_seconds,
@@ -287,6 +356,18 @@ fn handle_response(response: DaemonResponse) {
             println!("   Uptime: {uptime_seconds} seconds");
             println!("   Plugins loaded: {plugins_loaded}");
         }
+        DaemonResponse::Pong {
+            version,
+            uptime_secs,
+            plugin_count,
+        } => println!("{}", pong_line(&version, uptime_secs, plugin_count)),
+        DaemonResponse::State { state } => match serde_json::to_string_pretty(&state) {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("❌ Error: {e}");
+                std::process::exit(1);
+            }
+        },
         DaemonResponse::List { items } => {
             if items.is_empty() {
                 println!("📋 No items available");
@@ -1,9 +1,12 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use tokio::fs;
 use tracing::{debug, info};
 
+use crate::animation::{preset::parse_presets, AnimationPreset};
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Config {
     #[serde(default)]
@@ -32,25 +35,122 @@ pub struct PyprlandConfig {
     pub variables: HashMap<String, String>,
 }
 
-impl Config {
-    pub async fn load(path: &str) -> Result<Self> {
-        let expanded_path = shellexpand::tilde(path);
-        info!("📄 Reading config from: {}", expanded_path);
+/// Where the configuration comes from: the base file, plus an optional profile
+/// (`rustrland.<profile>.toml` next to it) merged over it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigSource {
+    pub path: String,
+    pub profile: Option<String>,
+}
 
-        let content = fs::read_to_string(expanded_path.as_ref())
-            .await
-            .map_err(|e| {
-                anyhow::anyhow!("Failed to read config file '{}': {}", expanded_path, e)
+impl ConfigSource {
+    pub fn new(path: &str, profile: Option<String>) -> Self {
+        Self {
+            path: path.to_string(),
+            profile,
+        }
+    }
+
+    /// Base config file, with `~` expanded
+    pub fn base_path(&self) -> PathBuf {
+        PathBuf::from(shellexpand::tilde(&self.path).as_ref())
+    }
+
+    /// Files making up the configuration: the base, then the profile if any
+    pub fn paths(&self) -> Result<Vec<PathBuf>> {
+        let base = self.base_path();
+        let mut paths = vec![base.clone()];
+        if let Some(profile) = &self.profile {
+            paths.push(profile_path(&base, profile)?);
+        }
+        Ok(paths)
+    }
+
+    /// Read the base file and merge the profile over it
+    pub async fn load_value(&self) -> Result<toml::Value> {
+        let mut merged: Option<toml::Value> = None;
+        for path in self.paths()? {
+            info!("📄 Reading config from: {}", path.display());
+            let content = fs::read_to_string(&path).await.map_err(|e| {
+                anyhow::anyhow!("Failed to read config file '{}': {}", path.display(), e)
+            })?;
+            let value: toml::Value = toml::from_str(&content).map_err(|e| {
+                anyhow::anyhow!("Failed to parse config '{}': {}", path.display(), e)
             })?;
+            match merged.as_mut() {
+                Some(base) => merge_toml(base, value),
+                None => merged = Some(value),
+            }
+        }
+        merged.ok_or_else(|| anyhow::anyhow!("No configuration file to read"))
+    }
 
-        let config: Config = toml::from_str(&content)
+    pub async fn load(&self) -> Result<Config> {
+        if let Some(profile) = &self.profile {
+            info!("👤 Using config profile '{}'", profile);
+        }
+        let config = Config::from_toml_value(self.load_value().await?)
             .map_err(|e| anyhow::anyhow!("Failed to parse config: {}", e))?;
+        config.log_sections();
+        Ok(config)
+    }
+}
+
+/// File of profile `profile` for base config `base`: `rustrland.toml` with
+/// profile `work` is `rustrland.work.toml` in the same directory
+pub fn profile_path(base: &Path, profile: &str) -> Result<PathBuf> {
+    let valid = !profile.is_empty()
+        && profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(anyhow::anyhow!(
+            "Invalid profile name '{}': use letters, digits, '-' and '_'",
+            profile
+        ));
+    }
 
-        let plugin_count = config.get_plugins().len();
+    let stem = base
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("rustrland");
+    let file_name = match base.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) => format!("{stem}.{profile}.{ext}"),
+        None => format!("{stem}.{profile}"),
+    };
+    Ok(base.with_file_name(file_name))
+}
+
+/// Merge `overlay` over `base`: tables merge key by key, anything else
+/// (values, arrays) is replaced by the overlay's
+pub fn merge_toml(base: &mut toml::Value, overlay: toml::Value) {
+    match (base, overlay) {
+        (toml::Value::Table(base), toml::Value::Table(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_toml(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+impl Config {
+    pub async fn load(path: &str) -> Result<Self> {
+        ConfigSource::new(path, None).load().await
+    }
+
+    /// Log the configuration sections found and the plugin count
+    fn log_sections(&self) {
+        let plugin_count = self.get_plugins().len();
         debug!("📋 Config loaded: {} plugins", plugin_count);
 
         // Log which configuration sections were found
-        match (&config.pyprland, &config.rustrland) {
+        match (&self.pyprland, &self.rustrland) {
             (Some(_), Some(_)) => {
                 info!("📋 Found both [pyprland] and [rustrland] configurations, merging them")
             }
@@ -58,8 +158,6 @@ impl Config {
             (None, Some(_)) => info!("📋 Found [rustrland] configuration"),
             (None, None) => info!("📋 No main configuration section found, using defaults"),
         }
-
-        Ok(config)
     }
 
     /// Get merged list of plugins from both pyprland and rustrland sections
@@ -106,6 +204,14 @@ impl Config {
         variables
     }
 
+    /// Animation presets from `[animations.presets.<name>]`, shared by all plugins
+    pub fn get_animation_presets(&self) -> HashMap<String, AnimationPreset> {
+        self.plugins
+            .get("animations")
+            .map(parse_presets)
+            .unwrap_or_default()
+    }
+
     /// Check if a configuration uses the new rustrland format
     pub fn uses_rustrland_config(&self) -> bool {
         self.rustrland.is_some()
@@ -171,3 +277,68 @@ impl super::core::hot_reload::ConfigExt for Config {
         Ok(config)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_path() {
+        let base = Path::new("/home/me/.config/hypr/rustrland.toml");
+        assert_eq!(
+            profile_path(base, "work").unwrap(),
+            PathBuf::from("/home/me/.config/hypr/rustrland.work.toml")
+        );
+        assert!(profile_path(base, "../work").is_err());
+        assert!(profile_path(base, "").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_profile_merges_over_base() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().join("rustrland.toml");
+        std::fs::write(
+            &base,
+            r#"
+            [rustrland]
+            plugins = ["scratchpads", "magnify"]
+
+            [scratchpads.term]
+            command = "foot"
+            size = "75% 60%"
+        "#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("rustrland.laptop.toml"),
+            r#"
+            [rustrland]
+            plugins = ["scratchpads"]
+
+            [scratchpads.term]
+            size = "90% 80%"
+        "#,
+        )
+        .unwrap();
+
+        let path = base.to_str().unwrap();
+        let config = ConfigSource::new(path, Some("laptop".to_string()))
+            .load()
+            .await
+            .unwrap();
+        assert_eq!(config.get_plugins(), vec!["scratchpads"]);
+        let term = &config.plugins["scratchpads"]["term"];
+        assert_eq!(term["size"].as_str(), Some("90% 80%"));
+        assert_eq!(term["command"].as_str(), Some("foot"));
+
+        // No profile: the base config as is
+        let config = Config::load(path).await.unwrap();
+        assert_eq!(config.get_plugins(), vec!["scratchpads", "magnify"]);
+
+        let missing = ConfigSource::new(path, Some("desktop".to_string()))
+            .load()
+            .await
+            .unwrap_err();
+        assert!(missing.to_string().contains("rustrland.desktop.toml"));
+    }
+}
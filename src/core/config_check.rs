@@ -0,0 +1,144 @@
+use crate::config::Config;
+use crate::core::plugin_manager::{create_plugin, plugin_init_config, reserved_keys_in_use};
+use crate::plugins::ValidationReport;
+
+/// Top-level sections that configure the daemon rather than a plugin
+const DAEMON_SECTIONS: [&str; 2] = ["animations", "hot_reload"];
+
+/// Validate a loaded configuration without starting the daemon, for
+/// `rustrland check-config`: unknown sections and plugin names, plugins listed
+/// without a section (and the reverse), then each enabled plugin's own checks
+pub fn check_config(config: &Config) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    let enabled = config.get_plugins();
+
+    let mut sections: Vec<&String> = config.plugins.keys().collect();
+    sections.sort();
+    for section in sections {
+        if DAEMON_SECTIONS.contains(&section.as_str()) {
+            continue;
+        }
+        if create_plugin(section).is_none() {
+            report.error(section.as_str(), "Unknown section: no plugin has this name");
+        } else if !enabled.contains(section) {
+            report.warning(
+                section.as_str(),
+                "Plugin is configured but not listed in `plugins`, so it is not loaded",
+            );
+        }
+    }
+
+    for plugin_name in &enabled {
+        let Some(plugin) = create_plugin(plugin_name) else {
+            report.error("plugins", format!("Unknown plugin '{plugin_name}'"));
+            continue;
+        };
+        if !config.plugins.contains_key(plugin_name) {
+            report.warning(
+                plugin_name.as_str(),
+                format!("No [{plugin_name}] section, the plugin runs with its defaults"),
+            );
+        }
+        for key in reserved_keys_in_use(plugin_name, config) {
+            report.error(
+                format!("{plugin_name}.{key}"),
+                format!("'{key}' is a reserved name, rename it"),
+            );
+        }
+        let plugin_report = plugin.validate_config(&plugin_init_config(plugin_name, config));
+        report.merge_under(plugin_name, plugin_report);
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issues(issues: &[crate::plugins::ValidationIssue]) -> Vec<(&str, &str)> {
+        issues
+            .iter()
+            .map(|issue| (issue.key.as_str(), issue.message.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_check_config_reports_issues_with_key_paths() {
+        let value: toml::Value = toml::from_str(
+            r#"
+[rustrland]
+plugins = ["scratchpads", "expose", "teleport"]
+
+[scratchpads.term]
+command = "foot"
+class = "foot"
+size = "75%"
+
+[scratchpads.files]
+command = ""
+class = "files"
+
+[magnify]
+factor = 2.0
+
+[scratchpad]
+command = "typo"
+
+[hot_reload]
+enabled = true
+        "#,
+        )
+        .unwrap();
+        let config = Config::from_toml_value(value).unwrap();
+
+        let report = check_config(&config);
+
+        assert!(report.has_errors());
+        let errors = issues(&report.errors);
+        assert!(errors.contains(&("scratchpad", "Unknown section: no plugin has this name")));
+        assert!(errors.contains(&("plugins", "Unknown plugin 'teleport'")));
+        assert!(errors.contains(&("scratchpads.files", "Command cannot be empty")));
+        assert!(errors
+            .iter()
+            .any(|(key, message)| *key == "scratchpads.term.size"
+                && message.starts_with("Invalid size format")));
+
+        let warnings = issues(&report.warnings);
+        assert!(warnings.iter().any(|(key, _)| *key == "magnify"));
+        assert!(warnings.iter().any(|(key, _)| *key == "expose"));
+        assert!(!warnings.iter().any(|(key, _)| *key == "hot_reload"));
+    }
+
+    #[test]
+    fn test_check_config_reports_scratchpads_named_like_injected_keys() {
+        let value: toml::Value = toml::from_str(
+            r#"
+[rustrland]
+plugins = ["scratchpads"]
+
+[scratchpads.variables]
+command = "foot"
+class = "foot"
+
+[scratchpads.animation_presets]
+command = "kitty"
+class = "kitty"
+        "#,
+        )
+        .unwrap();
+        let config = Config::from_toml_value(value).unwrap();
+
+        let report = check_config(&config);
+
+        let errors = issues(&report.errors);
+        assert!(errors.contains(&(
+            "scratchpads.variables",
+            "'variables' is a reserved name, rename it"
+        )));
+        assert!(errors.contains(&(
+            "scratchpads.animation_presets",
+            "'animation_presets' is a reserved name, rename it"
+        )));
+    }
+}
@@ -1,54 +1,76 @@
 use anyhow::Result;
-use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::signal;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 
-use crate::config::Config;
+use crate::config::{Config, ConfigSource};
 use crate::core::event_handler::EventHandler;
 use crate::core::hot_reload::{HotReloadConfig, HotReloadManager};
 use crate::core::plugin_manager::PluginManager;
 use crate::ipc::{server::IpcServer, HyprlandClient};
 
+/// How often expired values are swept from the shared cache
+const CACHE_SWEEP_INTERVAL: Duration = Duration::from_secs(30);
+
 pub struct Daemon {
     config: Config,
-    config_path: String,
+    config_source: Arc<RwLock<ConfigSource>>,
     hyprland_client: HyprlandClient,
     plugin_manager: Arc<RwLock<PluginManager>>,
     event_handler: EventHandler,
-    hot_reload_manager: Option<HotReloadManager>,
+    hot_reload_manager: Arc<RwLock<HotReloadManager>>,
+    /// When the daemon started, for the uptime `status` and `ping` report
+    started_at: Instant,
 }
 
 impl Daemon {
-    pub async fn new(config_path: &str) -> Result<Self> {
+    /// With `dry_run`, Hyprland is still queried but every dispatch is only logged
+    pub async fn new(config_path: &str, profile: Option<String>, dry_run: bool) -> Result<Self> {
+        let started_at = Instant::now();
         info!("📄 Loading configuration from: {}", config_path);
-        let config = Config::load(config_path).await?;
+        let config_source = ConfigSource::new(config_path, profile);
+        let config = config_source.load().await?;
+        match &config_source.profile {
+            Some(profile) => info!("👤 Active config profile: {}", profile),
+            None => info!("👤 No config profile, using the base config"),
+        }
 
         info!("🔌 Connecting to Hyprland IPC");
-        let hyprland_client = HyprlandClient::new().await?;
+        let hyprland_client = if dry_run {
+            warn!("🧪 Dry run: dispatches are logged, not sent to Hyprland");
+            HyprlandClient::dry_run()
+        } else {
+            HyprlandClient::new().await?
+        };
 
         info!("🔧 Initializing plugin manager");
         let mut plugin_manager = PluginManager::new();
         plugin_manager
             .load_plugins(&config, Arc::new(hyprland_client.clone()))
             .await?;
+        plugin_manager
+            .get_global_cache()
+            .spawn_sweeper(CACHE_SWEEP_INTERVAL);
         let plugin_manager = Arc::new(RwLock::new(plugin_manager));
 
         info!("📡 Setting up event handler");
         let event_handler = EventHandler::new();
 
         // Initialize hot reload manager
-        let hot_reload_manager = HotReloadManager::new(Arc::clone(&plugin_manager));
+        let config_source = Arc::new(RwLock::new(config_source));
+        let mut hot_reload_manager = HotReloadManager::new(Arc::clone(&plugin_manager));
+        hot_reload_manager.set_config_source(Arc::clone(&config_source));
 
         Ok(Self {
             config,
-            config_path: config_path.to_string(),
+            config_source,
             hyprland_client,
             plugin_manager,
             event_handler,
-            hot_reload_manager: Some(hot_reload_manager),
+            hot_reload_manager: Arc::new(RwLock::new(hot_reload_manager)),
+            started_at,
         })
     }
 
@@ -67,11 +89,11 @@ impl Daemon {
         // Parse hot reload configuration from config first
         let hot_reload_config = self.parse_hot_reload_config();
 
-        if let Some(ref mut hot_reload_manager) = self.hot_reload_manager {
+        {
+            let mut hot_reload_manager = self.hot_reload_manager.write().await;
             if hot_reload_config.auto_reload {
-                // Expand the tilde in config path before passing to file watcher
-                let expanded_path = shellexpand::tilde(&self.config_path);
-                let config_paths = vec![PathBuf::from(expanded_path.as_ref())];
+                // Watch the base config and the active profile
+                let config_paths = self.config_source.read().await.paths()?;
 
                 if let Err(e) = hot_reload_manager
                     .start(config_paths, hot_reload_config)
@@ -82,12 +104,19 @@ impl Daemon {
                     info!("🔥 Hot reload manager started successfully");
                 }
             } else {
+                // `rustr reload` still applies changes on demand
                 debug!("🔥 Hot reload auto_reload is disabled");
+                hot_reload_manager.set_config(hot_reload_config);
             }
         }
 
         // Start IPC server
-        let ipc_server = IpcServer::new(Arc::clone(&self.plugin_manager));
+        let ipc_server = IpcServer::new(
+            Arc::clone(&self.plugin_manager),
+            Arc::clone(&self.config_source),
+            Arc::clone(&self.hot_reload_manager),
+            self.started_at,
+        );
         tokio::spawn(async move {
             if let Err(e) = ipc_server.start().await {
                 error!("❌ IPC server error: {}", e);
@@ -133,6 +162,7 @@ impl Daemon {
         }
 
         info!("👋 Shutting down Rustrland");
+        self.hyprland_client.shutdown_event_listener().await;
         Ok(())
     }
 
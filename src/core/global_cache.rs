@@ -3,10 +3,21 @@ use crate::plugins::workspaces_follow_focus::{
     MonitorCache, MonitorInfoRef, WorkspaceCache, WorkspaceInfoRef,
 };
 use anyhow::Result;
+use serde::Serialize;
+use std::any::Any;
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
+use tracing::debug;
+
+/// A value stored with `insert_with_ttl`, dropped once `expires_at` has passed
+#[derive(Debug)]
+struct CacheEntry {
+    value: Arc<dyn Any + Send + Sync>,
+    expires_at: Instant,
+}
 
 /// Global state cache shared across all plugins for memory optimization
 /// This implements the Arc-based shared state pattern to reduce memory usage
@@ -29,6 +40,12 @@ pub struct GlobalStateCache {
 
     /// Cache validity duration (default: 2 seconds)
     cache_duration: std::time::Duration,
+
+    /// Values plugins cache for a limited time, by key
+    entries: RwLock<HashMap<String, CacheEntry>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
 }
 
 impl GlobalStateCache {
@@ -40,9 +57,100 @@ impl GlobalStateCache {
             configs: Arc::new(RwLock::new(HashMap::new())),
             variables: Arc::new(RwLock::new(HashMap::new())),
             cache_duration: std::time::Duration::from_secs(2),
+            entries: RwLock::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
         }
     }
 
+    /// Cache `value` under `key` for `ttl`, replacing any previous value
+    pub async fn insert_with_ttl<T: Any + Send + Sync>(
+        &self,
+        key: impl Into<String>,
+        value: T,
+        ttl: Duration,
+    ) {
+        let entry = CacheEntry {
+            value: Arc::new(value),
+            expires_at: Instant::now() + ttl,
+        };
+        self.entries.write().await.insert(key.into(), entry);
+    }
+
+    /// The value cached under `key`, unless it expired (which evicts it) or was
+    /// stored with another type. Counts a hit or a miss
+    pub async fn get_cached<T: Any + Send + Sync + Clone>(&self, key: &str) -> Option<T> {
+        let value = {
+            let entries = self.entries.read().await;
+            match entries.get(key) {
+                Some(entry) if entry.expires_at > Instant::now() => {
+                    entry.value.downcast_ref::<T>().cloned()
+                }
+                Some(_) => {
+                    drop(entries);
+                    self.evict_if_expired(key).await;
+                    None
+                }
+                None => None,
+            }
+        };
+
+        let counter = if value.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
+    }
+
+    /// Drop the value cached under `key`; whether there was one
+    pub async fn invalidate(&self, key: &str) -> bool {
+        self.entries.write().await.remove(key).is_some()
+    }
+
+    async fn evict_if_expired(&self, key: &str) {
+        let mut entries = self.entries.write().await;
+        if entries
+            .get(key)
+            .is_some_and(|entry| entry.expires_at <= Instant::now())
+        {
+            entries.remove(key);
+            self.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Evict every expired value; how many were evicted
+    pub async fn sweep_expired(&self) -> usize {
+        let now = Instant::now();
+        let mut entries = self.entries.write().await;
+        let before = entries.len();
+        entries.retain(|_, entry| entry.expires_at > now);
+
+        let evicted = before - entries.len();
+        self.evictions.fetch_add(evicted as u64, Ordering::Relaxed);
+        evicted
+    }
+
+    /// Sweep expired values every `interval` in the background, until the cache
+    /// is dropped
+    pub fn spawn_sweeper(self: &Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let cache: Weak<Self> = Arc::downgrade(self);
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                let Some(cache) = cache.upgrade() else {
+                    break;
+                };
+                let evicted = cache.sweep_expired().await;
+                if evicted > 0 {
+                    debug!("🧹 Evicted {} expired cache entries", evicted);
+                }
+            }
+        })
+    }
+
     /// Get monitor info with Arc sharing (no data duplication)
     pub async fn get_monitor(&self, name: &str) -> Option<MonitorInfoRef> {
         let monitors = self.monitors.read().await;
@@ -83,6 +191,10 @@ impl GlobalStateCache {
                 is_focused: monitor.is_focused,
                 active_workspace_id: monitor.active_workspace_id,
                 refresh_rate: monitor.refresh_rate,
+                reserved_top: monitor.reserved_top,
+                reserved_bottom: monitor.reserved_bottom,
+                reserved_left: monitor.reserved_left,
+                reserved_right: monitor.reserved_right,
             };
 
             let monitor_ref = Arc::new(RwLock::new(monitor_info));
@@ -143,6 +255,7 @@ impl GlobalStateCache {
         let workspaces = self.workspaces.read().await;
         let configs = self.configs.read().await;
         let vars = self.variables.read().await;
+        let entries = self.entries.read().await;
 
         MemoryStats {
             monitor_count: monitors.len(),
@@ -150,17 +263,26 @@ impl GlobalStateCache {
             config_count: configs.len(),
             variable_count: vars.len(),
             total_arc_refs: monitors.len() + workspaces.len() + configs.len() + 1, // +1 for variables
+            entry_count: entries.len(),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct MemoryStats {
     pub monitor_count: usize,
     pub workspace_count: usize,
     pub config_count: usize,
     pub variable_count: usize,
     pub total_arc_refs: usize,
+    /// Values cached with a TTL, expired ones not yet swept included
+    pub entry_count: usize,
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
 }
 
 impl Default for GlobalStateCache {
@@ -168,3 +290,64 @@ impl Default for GlobalStateCache {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_ttl_entries_expire_and_are_counted() {
+        let cache = GlobalStateCache::new();
+        cache
+            .insert_with_ttl("short", 1u32, Duration::from_millis(20))
+            .await;
+        cache
+            .insert_with_ttl("long", "kept".to_string(), Duration::from_secs(60))
+            .await;
+
+        assert_eq!(cache.get_cached::<u32>("short").await, Some(1));
+        // Wrong type and unknown keys are misses
+        assert_eq!(cache.get_cached::<String>("short").await, None);
+        assert_eq!(cache.get_cached::<u32>("missing").await, None);
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(cache.get_cached::<u32>("short").await, None);
+        assert_eq!(
+            cache.get_cached::<String>("long").await.as_deref(),
+            Some("kept")
+        );
+
+        let stats = cache.get_memory_stats().await;
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (2, 3, 1));
+        assert_eq!(stats.entry_count, 1);
+
+        assert!(cache.invalidate("long").await);
+        assert_eq!(cache.get_memory_stats().await.entry_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_sweep_evicts_only_expired_entries() {
+        let cache = Arc::new(GlobalStateCache::new());
+        cache
+            .insert_with_ttl("a", 1, Duration::from_millis(10))
+            .await;
+        cache
+            .insert_with_ttl("b", 2, Duration::from_millis(10))
+            .await;
+        cache.insert_with_ttl("c", 3, Duration::from_secs(60)).await;
+
+        let sweeper = cache.spawn_sweeper(Duration::from_millis(20));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let stats = cache.get_memory_stats().await;
+        assert_eq!((stats.entry_count, stats.evictions), (1, 2));
+        assert_eq!(cache.sweep_expired().await, 0);
+
+        // The sweeper stops once the cache is gone
+        drop(cache);
+        tokio::time::timeout(Duration::from_secs(1), sweeper)
+            .await
+            .expect("sweeper should stop")
+            .unwrap();
+    }
+}
@@ -8,24 +8,159 @@ use tokio::sync::{broadcast, RwLock};
 use tokio_stream::{wrappers::BroadcastStream, StreamExt};
 use tracing::{debug, error, info, warn};
 
-use crate::config::Config as RustrlandConfig;
-use crate::core::plugin_manager::PluginManager;
+use crate::config::{Config as RustrlandConfig, ConfigSource};
+use crate::core::config_check::check_config;
+use crate::core::plugin_manager::{plugin_init_config, PluginManager};
 
 /// Hot reload event types
 #[derive(Debug, Clone)]
 pub enum ReloadEvent {
     ConfigChanged(PathBuf),
     PluginReload(String),
+    /// A loaded plugin's configuration changed and it was re-initialized
+    PluginChanged {
+        name: String,
+    },
+    PluginAdded {
+        name: String,
+    },
+    PluginRemoved {
+        name: String,
+    },
     ValidationError(String),
     ReloadComplete,
 }
 
+/// Plugins a configuration change affects, found by comparing what each plugin's
+/// `init` receives under the old and the new configuration
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ConfigDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl ConfigDiff {
+    /// Diff for the plugins `loaded` under `old`. A plugin listed in `new` that is
+    /// not loaded (it may have failed to load) counts as added so it is retried
+    pub fn between(old: &RustrlandConfig, new: &RustrlandConfig, loaded: &[String]) -> Self {
+        let enabled = new.get_plugins();
+        let mut diff = Self {
+            added: enabled
+                .iter()
+                .filter(|name| !loaded.contains(name))
+                .cloned()
+                .collect(),
+            removed: loaded
+                .iter()
+                .filter(|name| !enabled.contains(name))
+                .cloned()
+                .collect(),
+            changed: loaded
+                .iter()
+                .filter(|name| enabled.contains(name) && Self::plugin_changed(name, old, new))
+                .cloned()
+                .collect(),
+        };
+        diff.added.sort();
+        diff.removed.sort();
+        diff.changed.sort();
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Whether `plugin_name` must be re-initialized: its section (or the presets
+    /// it is given) differ, or a variable it interpolates as `[name]` changed
+    /// even though its own section is identical
+    fn plugin_changed(plugin_name: &str, old: &RustrlandConfig, new: &RustrlandConfig) -> bool {
+        let without_variables = |config| {
+            let mut value = plugin_init_config(plugin_name, config);
+            if let Some(table) = value.as_table_mut() {
+                table.remove("variables");
+            }
+            value
+        };
+        if without_variables(old) != without_variables(new) {
+            return true;
+        }
+
+        let Some(section) = new.plugins.get(plugin_name) else {
+            return false;
+        };
+        let (old_variables, new_variables) = (old.get_variables(), new.get_variables());
+        old_variables
+            .keys()
+            .chain(new_variables.keys())
+            .filter(|name| old_variables.get(*name) != new_variables.get(*name))
+            .any(|name| references_variable(section, &format!("[{name}]")))
+    }
+}
+
+/// What a reload applied: the plugins it touched and the warnings the new
+/// configuration raised
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ReloadSummary {
+    pub plugins: ConfigDiff,
+    pub warnings: Vec<String>,
+}
+
+impl ReloadSummary {
+    /// One line per kind of change, then the warnings
+    pub fn message(&self) -> String {
+        let mut lines = Vec::new();
+        for (label, names) in [
+            ("🗑️ Removed", &self.plugins.removed),
+            ("➕ Added", &self.plugins.added),
+            ("🔄 Reloaded", &self.plugins.changed),
+        ] {
+            if !names.is_empty() {
+                lines.push(format!("{label}: {}", names.join(", ")));
+            }
+        }
+        if lines.is_empty() {
+            lines.push("✅ Configuration reloaded, no plugin changed".to_string());
+        }
+        lines.extend(self.warnings.iter().map(|w| format!("⚠️ {w}")));
+        lines.join("\n")
+    }
+}
+
+/// Whether any string in `value` contains the `[variable]` placeholder
+fn references_variable(value: &toml::Value, placeholder: &str) -> bool {
+    match value {
+        toml::Value::String(text) => text.contains(placeholder),
+        toml::Value::Array(items) => items
+            .iter()
+            .any(|item| references_variable(item, placeholder)),
+        toml::Value::Table(table) => table
+            .values()
+            .any(|item| references_variable(item, placeholder)),
+        _ => false,
+    }
+}
+
+/// Hash of the config files' contents, or None while one can't be read
+/// (e.g. between the delete and the rename of an editor's save)
+fn config_hash(paths: &[PathBuf]) -> Option<u64> {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for path in paths {
+        std::fs::read(path).ok()?.hash(&mut hasher);
+    }
+    Some(hasher.finish())
+}
+
 /// Hot reload configuration
 #[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
 pub struct HotReloadConfig {
     /// Enable automatic file watching
     pub auto_reload: bool,
-    /// Debounce duration to avoid rapid reloads
+    /// How long the config files must stay quiet before a change is reloaded
     pub debounce_ms: u64,
     /// Validate config before applying
     pub validate_before_apply: bool,
@@ -41,7 +176,7 @@ impl Default for HotReloadConfig {
     fn default() -> Self {
         Self {
             auto_reload: true,
-            debounce_ms: 500, // 500ms debounce
+            debounce_ms: 250,
             validate_before_apply: true,
             backup_on_reload: true,
             preserve_plugin_state: true,
@@ -61,6 +196,7 @@ pub struct HotReloadManager {
     last_reload: Option<Instant>,
     backup_configs: HashMap<PathBuf, String>,
     plugin_states: HashMap<String, serde_json::Value>,
+    config_source: Option<Arc<RwLock<ConfigSource>>>,
 }
 
 impl HotReloadManager {
@@ -77,9 +213,22 @@ impl HotReloadManager {
             last_reload: None,
             backup_configs: HashMap::new(),
             plugin_states: HashMap::new(),
+            config_source: None,
         }
     }
 
+    /// Reload from this source (base config merged with the active profile)
+    /// instead of the changed file alone
+    pub fn set_config_source(&mut self, config_source: Arc<RwLock<ConfigSource>>) {
+        self.config_source = Some(config_source);
+    }
+
+    /// Reload settings to use without `start`, e.g. when `auto_reload` is off and
+    /// reloads are only requested over IPC
+    pub fn set_config(&mut self, config: HotReloadConfig) {
+        self.config = config;
+    }
+
     /// Start hot reload with configuration
     pub async fn start(
         &mut self,
@@ -104,9 +253,24 @@ impl HotReloadManager {
         Ok(())
     }
 
+    /// Watch these files from now on, e.g. after a profile switch swapped the
+    /// profile file. Restarts the file watcher if one is running
+    pub async fn watch_paths(&mut self, config_paths: Vec<PathBuf>) -> Result<()> {
+        if config_paths == self.config_paths {
+            return Ok(());
+        }
+        self.config_paths = config_paths;
+
+        // Dropping the watcher closes its channel, which ends its coalescing task
+        if self.watcher.take().is_some() {
+            self.start_file_watcher().await?;
+        }
+        Ok(())
+    }
+
     /// Start file system watcher
     async fn start_file_watcher(&mut self) -> Result<()> {
-        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
 
         let mut watcher = RecommendedWatcher::new(
             move |res: Result<Event, notify::Error>| match res {
@@ -120,96 +284,106 @@ impl HotReloadManager {
             Config::default().with_poll_interval(Duration::from_millis(100)),
         )?;
 
-        // Watch all config paths
+        // Watch the directories holding the config files rather than the files:
+        // editors that save by writing a temporary file and renaming it over the
+        // config replace its inode, which silently ends a watch on the file itself
+        let mut watched_dirs: Vec<&Path> = Vec::new();
         for path in &self.config_paths {
-            if path.exists() {
-                // Try to watch the file directly first
-                match watcher.watch(path, RecursiveMode::NonRecursive) {
-                    Ok(()) => {
-                        info!("👀 Watching file directly: {:?}", path);
-                    }
-                    Err(_) => {
-                        // If watching the file directly fails, watch the parent directory
-                        if let Some(parent) = path.parent() {
-                            watcher.watch(parent, RecursiveMode::NonRecursive)?;
-                            info!("👀 Watching directory: {:?}", parent);
-                        }
-                    }
-                }
-            } else {
+            if !path.exists() {
                 error!("⚠️ Config file does not exist: {:?}", path);
             }
+            let dir = match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => Path::new("."),
+            };
+            if !watched_dirs.contains(&dir) {
+                watcher.watch(dir, RecursiveMode::NonRecursive)?;
+                info!("👀 Watching directory: {:?}", dir);
+                watched_dirs.push(dir);
+            }
         }
 
         self.watcher = Some(watcher);
 
         // Spawn background task to handle file events
-        let event_sender = self.event_sender.clone();
-        let debounce_duration = Duration::from_millis(self.config.debounce_ms);
-        let config_paths = self.config_paths.clone();
+        tokio::spawn(Self::coalesce_file_events(
+            rx,
+            self.config_paths.clone(),
+            Duration::from_millis(self.config.debounce_ms),
+            self.event_sender.clone(),
+        ));
 
-        tokio::spawn(async move {
-            let mut last_event_time: Option<Instant> = None;
+        Ok(())
+    }
 
-            loop {
-                // Use tokio async recv
-                match rx.recv().await {
-                    Some(event) => {
-                        debug!("🔍 File event received: {:?}", event);
-
-                        // Handle various event types that indicate file changes
-                        match event.kind {
-                            EventKind::Modify(_) | EventKind::Create(_) | EventKind::Access(_) => {
-                                // Check if this is one of our config files
-                                for path in event.paths {
-                                    debug!(
-                                        "🔍 Checking path: {:?} against {:?}",
-                                        path, config_paths
-                                    );
-
-                                    // Check both exact match and filename match
-                                    let is_config_file = config_paths.iter().any(|cp| {
-                                        cp == &path
-                                            || (path.file_name() == cp.file_name()
-                                                && cp.file_name().is_some())
-                                    });
-
-                                    if is_config_file {
-                                        let now = Instant::now();
-
-                                        // Debounce rapid file changes
-                                        if let Some(last_time) = last_event_time {
-                                            if now.duration_since(last_time) < debounce_duration {
-                                                debug!(
-                                                    "🔍 Event debounced, too soon after last event"
-                                                );
-                                                continue;
-                                            }
-                                        }
-
-                                        last_event_time = Some(now);
-                                        info!("📁 Config file changed: {:?}", path);
-
-                                        let _ = event_sender
-                                            .send(ReloadEvent::ConfigChanged(path.clone()));
-                                        break;
-                                    }
-                                }
+    /// Turn bursts of file events (truncate, write, rename...) into one
+    /// `ConfigChanged` once the config files have been quiet for `debounce`.
+    /// A settled change whose content hashes the same as the last one is dropped
+    async fn coalesce_file_events(
+        mut rx: tokio::sync::mpsc::UnboundedReceiver<Event>,
+        config_paths: Vec<PathBuf>,
+        debounce: Duration,
+        event_sender: broadcast::Sender<ReloadEvent>,
+    ) {
+        let mut last_hash = config_hash(&config_paths);
+        let mut pending: Option<PathBuf> = None;
+
+        loop {
+            let event = if pending.is_some() {
+                match tokio::time::timeout(debounce, rx.recv()).await {
+                    Ok(event) => event,
+                    Err(_) => {
+                        // Writes have settled
+                        let path = pending.take().unwrap_or_default();
+                        match config_hash(&config_paths) {
+                            None => debug!("🔍 Config file missing, waiting for it to reappear"),
+                            hash if hash == last_hash => {
+                                debug!("🔍 Config content unchanged, skipping reload")
                             }
-                            _ => {
-                                debug!("🔍 Ignoring event type: {:?}", event.kind);
+                            hash => {
+                                last_hash = hash;
+                                info!("📁 Config file changed: {:?}", path);
+                                let _ = event_sender.send(ReloadEvent::ConfigChanged(path));
                             }
                         }
+                        continue;
                     }
-                    None => {
-                        debug!("🔍 File watcher channel closed");
-                        break;
+                }
+            } else {
+                rx.recv().await
+            };
+
+            let Some(event) = event else {
+                debug!("🔍 File watcher channel closed");
+                break;
+            };
+            debug!("🔍 File event received: {:?}", event);
+
+            match event.kind {
+                EventKind::Modify(_)
+                | EventKind::Create(_)
+                | EventKind::Remove(_)
+                | EventKind::Access(_) => {
+                    // Check both exact match and filename match
+                    let changed = event.paths.into_iter().find(|path| {
+                        config_paths.iter().any(|cp| {
+                            cp == path
+                                || (path.file_name() == cp.file_name() && cp.file_name().is_some())
+                        })
+                    });
+                    if let Some(path) = changed {
+                        debug!(
+                            "🔍 Config event for {:?}, waiting for writes to settle",
+                            path
+                        );
+                        pending = Some(path);
                     }
                 }
+                _ => {
+                    debug!("🔍 Ignoring event type: {:?}", event.kind);
+                }
             }
-        });
-
-        Ok(())
+        }
     }
 
     /// Start event processing loop
@@ -218,6 +392,7 @@ impl HotReloadManager {
         let plugin_manager = Arc::clone(&self.plugin_manager);
         let config = self.config.clone();
         let event_sender = self.event_sender.clone();
+        let config_source = self.config_source.clone();
 
         tokio::spawn(async move {
             while let Ok(event) = receiver.recv().await {
@@ -225,12 +400,17 @@ impl HotReloadManager {
                     ReloadEvent::ConfigChanged(path) => {
                         info!("🔄 Processing config change: {:?}", path);
 
+                        let source = match &config_source {
+                            Some(source) => Some(source.read().await.clone()),
+                            None => None,
+                        };
                         info!("🚀 About to call handle_config_change...");
                         if let Err(e) = Self::handle_config_change(
                             &plugin_manager,
                             &path,
                             &config,
                             &event_sender,
+                            source.as_ref(),
                         )
                         .await
                         {
@@ -244,6 +424,15 @@ impl HotReloadManager {
                         info!("🔌 Reloading plugin: {}", plugin_name);
                         // Handle individual plugin reload
                     }
+                    ReloadEvent::PluginChanged { name } => {
+                        info!("🔄 Plugin '{}' re-initialized with its new config", name);
+                    }
+                    ReloadEvent::PluginAdded { name } => {
+                        info!("➕ Plugin '{}' added", name);
+                    }
+                    ReloadEvent::PluginRemoved { name } => {
+                        info!("🗑️ Plugin '{}' removed", name);
+                    }
                     ReloadEvent::ValidationError(error) => {
                         warn!("⚠️ Config validation error: {}", error);
                     }
@@ -255,26 +444,50 @@ impl HotReloadManager {
         });
     }
 
-    /// Handle configuration file change
+    /// Handle configuration file change. A configuration that fails to parse, or
+    /// has validation errors (with `validate_before_apply`), is rejected before any
+    /// plugin is touched, so the running configuration and plugin state stay live
     async fn handle_config_change(
         plugin_manager: &Arc<RwLock<PluginManager>>,
         config_path: &Path,
         config: &HotReloadConfig,
         event_sender: &broadcast::Sender<ReloadEvent>,
-    ) -> Result<()> {
+        config_source: Option<&ConfigSource>,
+    ) -> Result<ReloadSummary> {
         debug!("🔧 Reading config file: {:?}", config_path);
 
-        // Read and validate new configuration
-        let config_content = std::fs::read_to_string(config_path)?;
-        debug!("📄 Config content read, {} bytes", config_content.len());
+        // Read and validate new configuration; with a profile, both files make it up
+        let new_config = async {
+            let config_content = match config_source {
+                Some(source) => toml::to_string(&source.load_value().await?)?,
+                None => std::fs::read_to_string(config_path)?,
+            };
+            debug!("📄 Config content read, {} bytes", config_content.len());
 
-        debug!("🔍 Validating configuration...");
-        let new_config = Self::validate_config(&config_content).await?;
+            debug!("🔍 Validating configuration...");
+            Self::validate_config(&config_content).await
+        }
+        .await
+        .map_err(|e| anyhow::anyhow!("Configuration rejected, keeping the running one: {e}"))?;
         debug!("✅ Configuration validation completed");
 
+        let report = check_config(&new_config);
+        let describe = |issues: &[crate::plugins::ValidationIssue]| -> Vec<String> {
+            issues
+                .iter()
+                .map(|issue| format!("{}: {}", issue.key, issue.message))
+                .collect()
+        };
         if config.validate_before_apply {
+            if report.has_errors() {
+                return Err(anyhow::anyhow!(
+                    "Configuration rejected, keeping the running one:\n  {}",
+                    describe(&report.errors).join("\n  ")
+                ));
+            }
             info!("✓ Configuration validation passed");
         }
+        let warnings = describe(&report.warnings);
 
         // Create backup if enabled
         if config.backup_on_reload {
@@ -306,15 +519,35 @@ impl HotReloadManager {
                 Self::apply_partial_reload(&mut pm, &new_config, &preserved_states).await
             } else {
                 info!("🔄 Applying full reload");
-                Self::apply_full_reload(&mut pm, &new_config, &preserved_states).await
+                let loaded = pm.get_loaded_plugins();
+                Self::apply_full_reload(&mut pm, &new_config, &preserved_states)
+                    .await
+                    .map(|()| {
+                        // Every plugin restarted, so report them all as reloaded
+                        let mut diff = ConfigDiff::between(&new_config, &new_config, &loaded);
+                        diff.changed = pm.get_loaded_plugins();
+                        diff.changed.retain(|name| loaded.contains(name));
+                        diff.changed.sort();
+                        diff
+                    })
             }
         };
 
         // Handle reload results with automatic recovery
         match reload_result {
-            Ok(()) => {
+            Ok(diff) => {
                 info!("✅ Config change handled successfully");
 
+                for name in &diff.removed {
+                    let _ = event_sender.send(ReloadEvent::PluginRemoved { name: name.clone() });
+                }
+                for name in &diff.added {
+                    let _ = event_sender.send(ReloadEvent::PluginAdded { name: name.clone() });
+                }
+                for name in &diff.changed {
+                    let _ = event_sender.send(ReloadEvent::PluginChanged { name: name.clone() });
+                }
+
                 // Cleanup old backups (keep last 5)
                 if config.backup_on_reload {
                     if let Err(e) = Self::cleanup_old_backups(config_path, 5).await {
@@ -323,7 +556,10 @@ impl HotReloadManager {
                 }
 
                 let _ = event_sender.send(ReloadEvent::ReloadComplete);
-                Ok(())
+                Ok(ReloadSummary {
+                    plugins: diff,
+                    warnings,
+                })
             }
             Err(reload_error) => {
                 error!("❌ Configuration reload failed: {}", reload_error);
@@ -345,7 +581,9 @@ impl HotReloadManager {
                         Ok(()) => {
                             warn!("⚠️ Original reload failed but automatic recovery succeeded");
                             let _ = event_sender.send(ReloadEvent::ReloadComplete);
-                            Ok(())
+                            Err(anyhow::anyhow!(
+                                "Reload failed ({reload_error}), the previous configuration was restored"
+                            ))
                         }
                         Err(recovery_error) => {
                             error!("💥 Both reload and recovery failed: {}", recovery_error);
@@ -395,77 +633,64 @@ impl HotReloadManager {
         Ok(states)
     }
 
-    /// Apply partial reload (only changed plugins)
+    /// Apply partial reload: only plugins whose configuration changed are
+    /// re-initialized, the others keep running untouched
     async fn apply_partial_reload(
         plugin_manager: &mut PluginManager,
         new_config: &RustrlandConfig,
         preserved_states: &HashMap<String, serde_json::Value>,
-    ) -> Result<()> {
+    ) -> Result<ConfigDiff> {
         info!("🔄 Applying partial reload");
 
-        // Compare current and new configurations
-        let current_plugins = plugin_manager.get_loaded_plugins();
-        let new_plugins = new_config.get_plugin_names();
+        let old_config = plugin_manager.current_config().cloned().unwrap_or_default();
+        let loaded = plugin_manager.get_loaded_plugins();
+        let diff = ConfigDiff::between(&old_config, new_config, &loaded);
 
-        debug!("🔍 Current plugins: {:?}", current_plugins);
-        debug!("🔍 New plugins: {:?}", new_plugins);
-
-        // Find added, removed, and modified plugins
-        let added: Vec<_> = new_plugins
-            .iter()
-            .filter(|p| !current_plugins.contains(p))
-            .collect();
-
-        let removed: Vec<_> = current_plugins
-            .iter()
-            .filter(|p| !new_plugins.contains(p))
-            .collect();
-
-        debug!("🔍 Plugins to add: {:?}", added);
-        debug!("🔍 Plugins to remove: {:?}", removed);
-
-        let potentially_modified: Vec<_> = current_plugins
-            .iter()
-            .filter(|p| new_plugins.contains(p))
-            .collect();
+        debug!("🔍 Plugins to add: {:?}", diff.added);
+        debug!("🔍 Plugins to remove: {:?}", diff.removed);
+        debug!("🔍 Plugins changed: {:?}", diff.changed);
 
         // Remove plugins no longer needed
-        for plugin_name in removed {
+        for plugin_name in &diff.removed {
             plugin_manager.unload_plugin(plugin_name).await?;
             info!("🗑️ Removed plugin: {}", plugin_name);
         }
 
         // Add new plugins
-        for plugin_name in added {
+        for plugin_name in &diff.added {
             plugin_manager.load_plugin(plugin_name, new_config).await?;
             info!("➕ Added plugin: {}", plugin_name);
         }
 
-        // Check and reload modified plugins
-        for plugin_name in potentially_modified {
-            if Self::plugin_config_changed(plugin_manager, plugin_name, new_config).await? {
-                // Preserve state before reload
-                if let Some(state) = preserved_states.get(plugin_name) {
-                    plugin_manager
-                        .preserve_plugin_state(plugin_name, state.clone())
-                        .await?;
-                }
-
+        // Reload modified plugins
+        for plugin_name in &diff.changed {
+            // Preserve state before reload
+            if let Some(state) = preserved_states.get(plugin_name) {
                 plugin_manager
-                    .reload_plugin(plugin_name, new_config)
+                    .preserve_plugin_state(plugin_name, state.clone())
                     .await?;
-                info!("🔄 Reloaded plugin: {}", plugin_name);
+            }
 
-                // Restore state after reload
-                if let Some(state) = preserved_states.get(plugin_name) {
-                    plugin_manager
-                        .restore_plugin_state(plugin_name, state.clone())
-                        .await?;
-                }
+            plugin_manager
+                .reload_plugin(plugin_name, new_config)
+                .await?;
+            info!("🔄 Reloaded plugin: {}", plugin_name);
+
+            // Restore state after reload
+            if let Some(state) = preserved_states.get(plugin_name) {
+                plugin_manager
+                    .restore_plugin_state(plugin_name, state.clone())
+                    .await?;
             }
         }
 
-        Ok(())
+        if diff.is_empty() {
+            info!("✅ No plugin configuration changed");
+        }
+
+        // The next reload diffs against this configuration
+        plugin_manager.set_current_config(new_config.clone());
+        Ok(diff)
     }
 
     /// Apply full reload (all plugins)
@@ -495,29 +720,33 @@ impl HotReloadManager {
         Ok(())
     }
 
-    /// Check if a plugin's configuration has changed
-    async fn plugin_config_changed(
-        plugin_manager: &PluginManager,
-        plugin_name: &str,
-        new_config: &RustrlandConfig,
-    ) -> Result<bool> {
-        // Compare plugin configurations
-        let current_config = plugin_manager.get_plugin_config(plugin_name)?;
-        let new_plugin_config = new_config.get_plugin_config(plugin_name)?;
-
-        Ok(current_config != new_plugin_config)
-    }
-
-    /// Manual reload trigger
-    pub async fn reload_now(&self) -> Result<()> {
+    /// Re-read the configuration source and apply it now, as a file change would
+    pub async fn reload_now(&self) -> Result<ReloadSummary> {
         info!("🔄 Manual reload triggered");
+        let source = match &self.config_source {
+            Some(source) => source.read().await.clone(),
+            None => return Err(anyhow::anyhow!("No configuration source to reload from")),
+        };
+        self.reload_from(&source).await
+    }
 
-        if let Some(config_path) = self.config_paths.first() {
-            self.event_sender
-                .send(ReloadEvent::ConfigChanged(config_path.clone()))?;
+    /// Apply the configuration `source` loads (e.g. with another profile), with the
+    /// same validation, state preservation and recovery as a file change
+    pub async fn reload_from(&self, source: &ConfigSource) -> Result<ReloadSummary> {
+        let result = Self::handle_config_change(
+            &self.plugin_manager,
+            &source.base_path(),
+            &self.config,
+            &self.event_sender,
+            Some(source),
+        )
+        .await;
+        if let Err(e) = &result {
+            let _ = self
+                .event_sender
+                .send(ReloadEvent::ValidationError(e.to_string()));
         }
-
-        Ok(())
+        result
     }
 
     /// Stop hot reload
@@ -771,7 +1000,7 @@ pub trait HotReloadable {
         state: serde_json::Value,
     ) -> impl std::future::Future<Output = Result<()>> + Send;
     fn restore_plugin_state(
-        &self,
+        &mut self,
         plugin_name: &str,
         state: serde_json::Value,
     ) -> impl std::future::Future<Output = Result<()>> + Send;
@@ -851,7 +1080,7 @@ mod tests {
         }
 
         async fn restore_plugin_state(
-            &self,
+            &mut self,
             _plugin_name: &str,
             _state: serde_json::Value,
         ) -> Result<()> {
@@ -1008,7 +1237,7 @@ auto_reload = true
 
     #[tokio::test]
     async fn test_plugin_state_capture_and_restore() {
-        let mock_pm = MockPluginManager::new();
+        let mut mock_pm = MockPluginManager::new();
 
         // Test individual state capture
         let state = mock_pm.get_plugin_state("scratchpads").await.unwrap();
@@ -1027,12 +1256,282 @@ auto_reload = true
         assert!(result.is_ok(), "State restoration should succeed");
     }
 
+    #[tokio::test]
+    async fn test_reload_keeps_visible_scratchpad_mapping() {
+        use crate::ipc::mock::{monitor, window, MockHyprland};
+        use crate::ipc::HyprlandClient;
+
+        let config = |size: &str| {
+            let content = format!(
+                r#"
+[rustrland]
+plugins = ["scratchpads"]
+
+[scratchpads.term]
+command = "foot"
+class = "foot"
+size = "{size}"
+"#
+            );
+            RustrlandConfig::from_toml_value(toml::from_str(&content).unwrap()).unwrap()
+        };
+        let api =
+            Arc::new(
+                MockHyprland::new(vec![], vec![monitor("DP-1", (1920, 1080), true, 1)])
+                    .spawns(window("0xb", "foot", (480, 270), 1)),
+            );
+        let client = Arc::new(HyprlandClient::with_api(api));
+
+        let mut pm = PluginManager::new();
+        pm.load_plugins(&config("50% 50%"), client).await.unwrap();
+        pm.handle_command("scratchpads", "toggle", &["term"])
+            .await
+            .unwrap();
+        let plugin_manager = Arc::new(RwLock::new(pm));
+
+        // Resizing the scratchpad recreates the plugin instance
+        let states = HotReloadManager::capture_plugin_states(&plugin_manager)
+            .await
+            .unwrap();
+        let diff = {
+            let mut pm = plugin_manager.write().await;
+            HotReloadManager::apply_partial_reload(&mut pm, &config("60% 60%"), &states)
+                .await
+                .unwrap()
+        };
+        assert_eq!(diff.changed, vec!["scratchpads"]);
+
+        let state = plugin_manager
+            .read()
+            .await
+            .get_plugin_state("scratchpads")
+            .await
+            .unwrap();
+        assert_eq!(state["window_mappings"]["0xb"], "term");
+        let windows = &state["scratchpad_states"]["term"]["windows"];
+        assert_eq!(windows[0]["address"], "0xb");
+        assert_eq!(windows[0]["is_visible"], true);
+    }
+
+    #[tokio::test]
+    async fn test_reload_now_reports_changes_and_rejects_invalid_config() {
+        use crate::ipc::mock::{monitor, MockHyprland};
+        use crate::ipc::HyprlandClient;
+
+        let config = |plugins: &str, size: &str| {
+            format!(
+                r#"
+[rustrland]
+plugins = [{plugins}]
+
+[scratchpads.term]
+command = "foot"
+class = "foot"
+size = "{size}"
+"#
+            )
+        };
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("rustrland.toml");
+        fs::write(&path, config(r#""scratchpads""#, "50% 50%"))
+            .await
+            .unwrap();
+        let source = ConfigSource::new(path.to_str().unwrap(), None);
+
+        let api = Arc::new(MockHyprland::new(
+            vec![],
+            vec![monitor("DP-1", (1920, 1080), true, 1)],
+        ));
+        let mut pm = PluginManager::new();
+        pm.load_plugins(
+            &source.load().await.unwrap(),
+            Arc::new(HyprlandClient::with_api(api)),
+        )
+        .await
+        .unwrap();
+        let plugin_manager = Arc::new(RwLock::new(pm));
+        let mut manager = HotReloadManager::new(Arc::clone(&plugin_manager));
+        manager.set_config_source(Arc::new(RwLock::new(source)));
+
+        fs::write(&path, config(r#""scratchpads""#, "60% 60%"))
+            .await
+            .unwrap();
+        let summary = manager.reload_now().await.unwrap();
+        assert_eq!(summary.plugins.changed, vec!["scratchpads"]);
+        assert!(summary.message().contains("🔄 Reloaded: scratchpads"));
+
+        // An unknown plugin fails validation; the running plugins stay as they are
+        fs::write(&path, config(r#""scratchpads", "teleport""#, "60% 60%"))
+            .await
+            .unwrap();
+        let error = manager.reload_now().await.unwrap_err().to_string();
+        assert!(error.contains("rejected"), "{error}");
+        assert!(error.contains("Unknown plugin 'teleport'"), "{error}");
+        assert_eq!(
+            plugin_manager.read().await.get_loaded_plugins(),
+            vec!["scratchpads"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unparsable_config_keeps_running_plugins_until_fixed() {
+        use crate::ipc::mock::{monitor, MockHyprland};
+        use crate::ipc::HyprlandClient;
+
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("rustrland.toml");
+        fs::write(&path, "[rustrland]\nplugins = [\"scratchpads\"]\n")
+            .await
+            .unwrap();
+        let source = ConfigSource::new(path.to_str().unwrap(), None);
+
+        let api = Arc::new(MockHyprland::new(
+            vec![],
+            vec![monitor("DP-1", (1920, 1080), true, 1)],
+        ));
+        let mut pm = PluginManager::new();
+        pm.load_plugins(
+            &source.load().await.unwrap(),
+            Arc::new(HyprlandClient::with_api(api)),
+        )
+        .await
+        .unwrap();
+        let plugin_manager = Arc::new(RwLock::new(pm));
+        let mut manager = HotReloadManager::new(Arc::clone(&plugin_manager));
+        manager.set_config(HotReloadConfig {
+            backup_on_reload: false,
+            ..HotReloadConfig::default()
+        });
+        manager.set_config_source(Arc::new(RwLock::new(source)));
+
+        // A save caught halfway through an edit
+        fs::write(&path, "[rustrland\nplugins = [\"scratchpads\", \"expose\"")
+            .await
+            .unwrap();
+        let error = manager.reload_now().await.unwrap_err().to_string();
+        assert!(error.contains("rejected"), "{error}");
+        assert_eq!(
+            plugin_manager.read().await.get_loaded_plugins(),
+            vec!["scratchpads"]
+        );
+
+        fs::write(
+            &path,
+            "[rustrland]\nplugins = [\"scratchpads\", \"expose\"]\n",
+        )
+        .await
+        .unwrap();
+        let summary = manager.reload_now().await.unwrap();
+        assert_eq!(summary.plugins.added, vec!["expose"]);
+        let mut loaded = plugin_manager.read().await.get_loaded_plugins();
+        loaded.sort();
+        assert_eq!(loaded, vec!["expose", "scratchpads"]);
+    }
+
+    #[tokio::test]
+    async fn test_file_events_coalesce_into_one_reload_per_change() {
+        use notify::event::{CreateKind, DataChange, ModifyKind, RenameMode};
+
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("rustrland.toml");
+        fs::write(&path, "[rustrland]\nplugins = []\n")
+            .await
+            .unwrap();
+
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let (sender, mut receiver) = broadcast::channel(16);
+        tokio::spawn(HotReloadManager::coalesce_file_events(
+            rx,
+            vec![path.clone()],
+            Duration::from_millis(50),
+            sender,
+        ));
+        // Let it hash the current content before anything changes
+        tokio::task::yield_now().await;
+        let event = |kind: EventKind, path: &Path| Event::new(kind).add_path(path.to_path_buf());
+        let settle = || tokio::time::sleep(Duration::from_millis(200));
+
+        // An editor's save: write a temporary file, then rename it over the config
+        let swap = dir.path().join(".rustrland.toml.swp");
+        fs::write(&swap, "[rustrland]\nplugins = [\"expose\"]\n")
+            .await
+            .unwrap();
+        tx.send(event(EventKind::Create(CreateKind::File), &swap))
+            .unwrap();
+        fs::rename(&swap, &path).await.unwrap();
+        tx.send(event(
+            EventKind::Modify(ModifyKind::Name(RenameMode::To)),
+            &path,
+        ))
+        .unwrap();
+        tx.send(event(
+            EventKind::Modify(ModifyKind::Data(DataChange::Content)),
+            &path,
+        ))
+        .unwrap();
+        settle().await;
+        assert!(matches!(receiver.try_recv(), Ok(ReloadEvent::ConfigChanged(p)) if p == path));
+        assert!(receiver.try_recv().is_err(), "one event per settled change");
+
+        // Touching the file without changing it does not reload
+        tx.send(event(
+            EventKind::Modify(ModifyKind::Data(DataChange::Any)),
+            &path,
+        ))
+        .unwrap();
+        settle().await;
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_watch_paths_follows_profile_switch() {
+        let dir = tempdir().unwrap();
+        let base = dir.path().join("rustrland.toml");
+        let switched = ConfigSource::new(base.to_str().unwrap(), Some("work".to_string()));
+        let profile = switched.paths().unwrap().pop().unwrap();
+        fs::write(&base, "[rustrland]\nplugins = []\n")
+            .await
+            .unwrap();
+        fs::write(&profile, "[rustrland]\nplugins = []\n")
+            .await
+            .unwrap();
+
+        let mut manager = HotReloadManager::new(Arc::new(RwLock::new(PluginManager::new())));
+        let mut receiver = manager.subscribe();
+        let config = HotReloadConfig {
+            debounce_ms: 50,
+            ..HotReloadConfig::default()
+        };
+        manager.start(vec![base.clone()], config).await.unwrap();
+        manager
+            .watch_paths(switched.paths().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(manager.get_stats().watched_paths, 2);
+
+        // Edits to the newly active profile now trigger a reload
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        fs::write(&profile, "[rustrland]\nplugins = [\"expose\"]\n")
+            .await
+            .unwrap();
+        let changed = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Ok(ReloadEvent::ConfigChanged(path)) = receiver.recv().await {
+                    return path;
+                }
+            }
+        })
+        .await
+        .expect("a change to the profile file must be noticed");
+        assert_eq!(changed, profile);
+    }
+
     #[tokio::test]
     async fn test_hot_reload_config_default_values() {
         let config = HotReloadConfig::default();
 
         assert_eq!(config.auto_reload, true);
-        assert_eq!(config.debounce_ms, 500);
+        assert_eq!(config.debounce_ms, 250);
         assert_eq!(config.validate_before_apply, true);
         assert_eq!(config.backup_on_reload, true);
         assert_eq!(config.preserve_plugin_state, true);
@@ -1086,6 +1585,62 @@ partial_reload = true
         assert!(parsed_config.get_plugins().contains(&"expose".to_string()));
     }
 
+    #[test]
+    fn test_config_diff_follows_interpolated_variables() {
+        let config = |variables: &str, expose_columns: u32, plugins: &str| {
+            let content = format!(
+                r#"
+[rustrland]
+plugins = [{plugins}]
+variables = {{ {variables} }}
+
+[scratchpads.term]
+command = "[term_classed] main-dropterm"
+class = "main-dropterm"
+
+[expose]
+columns = {expose_columns}
+"#
+            );
+            RustrlandConfig::from_toml_value(toml::from_str(&content).unwrap()).unwrap()
+        };
+        let loaded = vec!["expose".to_string(), "scratchpads".to_string()];
+        let old = config(
+            r#"term_classed = "foot --app-id", browser = "firefox""#,
+            3,
+            r#""scratchpads", "expose""#,
+        );
+
+        // A variable the scratchpad does not use changes nothing
+        let unused = config(
+            r#"term_classed = "foot --app-id", browser = "chromium""#,
+            3,
+            r#""scratchpads", "expose""#,
+        );
+        assert!(ConfigDiff::between(&old, &unused, &loaded).is_empty());
+
+        // One it interpolates marks scratchpads, though [scratchpads] is identical
+        let used = config(
+            r#"term_classed = "kitty --class", browser = "firefox""#,
+            3,
+            r#""scratchpads", "expose""#,
+        );
+        let diff = ConfigDiff::between(&old, &used, &loaded);
+        assert_eq!(diff.changed, vec!["scratchpads"]);
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+
+        // Section edits, additions and removals
+        let reshuffled = config(
+            r#"term_classed = "foot --app-id", browser = "firefox""#,
+            4,
+            r#""expose", "magnify""#,
+        );
+        let diff = ConfigDiff::between(&old, &reshuffled, &loaded);
+        assert_eq!(diff.changed, vec!["expose"]);
+        assert_eq!(diff.added, vec!["magnify"]);
+        assert_eq!(diff.removed, vec!["scratchpads"]);
+    }
+
     #[test]
     fn test_hot_reload_stats() {
         // Test stats structure
@@ -1,3 +1,4 @@
+pub mod config_check;
 pub mod daemon;
 pub mod event_handler;
 pub mod global_cache;
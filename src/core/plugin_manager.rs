@@ -2,9 +2,11 @@ use anyhow::Result;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, Mutex, RwLock};
 use tracing::{debug, error, info, warn};
 
+use super::hot_reload::HotReloadable;
+use crate::animation::AnimationEngine;
 use crate::config::Config;
 use crate::core::global_cache::GlobalStateCache;
 use crate::ipc::{HyprlandClient, HyprlandEvent};
@@ -18,11 +20,96 @@ use crate::plugins::system_notifier::SystemNotifier;
 use crate::plugins::toggle_special::ToggleSpecialPlugin;
 use crate::plugins::wallpapers::WallpapersPlugin;
 use crate::plugins::workspaces_follow_focus::WorkspacesFollowFocusPlugin;
-use crate::plugins::{Plugin, PluginBox};
+use crate::plugins::{Plugin, PluginBox, PluginContext};
+
+/// Hyprland events buffered for context subscribers before the slowest lags
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// A fresh, uninitialized instance of the plugin called `plugin_name`
+pub fn create_plugin(plugin_name: &str) -> Option<PluginBox> {
+    let plugin: PluginBox = match plugin_name {
+        "scratchpads" => Box::new(ScratchpadsPlugin::new()),
+        "expose" => Box::new(ExposePlugin::new()),
+        "workspaces_follow_focus" => Box::new(WorkspacesFollowFocusPlugin::new()),
+        "magnify" => Box::new(MagnifyPlugin::new()),
+        "shift_monitors" => Box::new(ShiftMonitorsPlugin::new()),
+        "system_notifier" => Box::new(SystemNotifier::new()),
+        "toggle_special" => Box::new(ToggleSpecialPlugin::new()),
+        "monitors" => Box::new(MonitorsPlugin::new()),
+        "wallpapers" => Box::new(WallpapersPlugin::new()),
+        "lost_windows" => Box::new(LostWindowsPlugin::new()),
+        // Add more plugins here as they're implemented
+        _ => return None,
+    };
+    Some(plugin)
+}
+
+/// Keys `plugin_init_config` adds to a plugin's section, so none of its entries
+/// can use them
+fn injected_keys(plugin_name: &str) -> &'static [&'static str] {
+    match plugin_name {
+        "scratchpads" => &["variables", "animation_presets"],
+        _ => &[],
+    }
+}
+
+/// Entries of `plugin_name`'s section named like a key the daemon injects (e.g. a
+/// `[scratchpads.variables]` scratchpad), which the injected value replaces
+pub fn reserved_keys_in_use(plugin_name: &str, config: &Config) -> Vec<&'static str> {
+    let Some(section) = config.plugins.get(plugin_name) else {
+        return Vec::new();
+    };
+    injected_keys(plugin_name)
+        .iter()
+        .copied()
+        .filter(|key| section.get(key).is_some())
+        .collect()
+}
+
+/// The configuration a plugin's `init` receives: its own section. Scratchpads also
+/// get the merged variables and the animation presets of the `[animations]` section
+/// in it, so validation and reload diffs see them. Every plugin receives the presets
+/// through `PluginContext`
+pub fn plugin_init_config(plugin_name: &str, config: &Config) -> toml::Value {
+    let plugin_config = config
+        .plugins
+        .get(plugin_name)
+        .cloned()
+        .unwrap_or(toml::Value::Table(toml::map::Map::new()));
+    if plugin_name != "scratchpads" {
+        return plugin_config;
+    }
+    for key in reserved_keys_in_use(plugin_name, config) {
+        error!(
+            "❌ [{}.{}] is ignored: '{}' is a reserved name, rename it",
+            plugin_name, key, key
+        );
+    }
+
+    // Create a combined config with both scratchpad settings and variables
+    let mut combined_config = toml::map::Map::new();
+    if let toml::Value::Table(plugin_table) = plugin_config {
+        combined_config.extend(plugin_table);
+    }
+
+    // Add variables section (merged from both pyprland and rustrland)
+    let variables_value = toml::Value::try_from(config.get_variables())
+        .unwrap_or(toml::Value::Table(toml::map::Map::new()));
+    combined_config.insert("variables".to_string(), variables_value);
+
+    // Add animation presets shared through the [animations] section
+    let presets_value = toml::Value::try_from(config.get_animation_presets())
+        .unwrap_or(toml::Value::Table(toml::map::Map::new()));
+    combined_config.insert("animation_presets".to_string(), presets_value);
+
+    toml::Value::Table(combined_config)
+}
 
 pub struct PluginManager {
     plugins: HashMap<String, PluginBox>,
     global_cache: Arc<GlobalStateCache>,
+    animation_engine: Arc<Mutex<AnimationEngine>>,
+    events: broadcast::Sender<HyprlandEvent>,
     hyprland_client: Option<Arc<HyprlandClient>>,
     plugin_states: Arc<RwLock<HashMap<String, serde_json::Value>>>,
     current_config: Option<Config>,
@@ -39,6 +126,8 @@ impl PluginManager {
         Self {
             plugins: HashMap::new(),
             global_cache: Arc::new(GlobalStateCache::new()),
+            animation_engine: Arc::new(Mutex::new(AnimationEngine::new())),
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
             hyprland_client: None,
             plugin_states: Arc::new(RwLock::new(HashMap::new())),
             current_config: None,
@@ -78,34 +167,9 @@ impl PluginManager {
     ) -> Result<()> {
         info!("📦 Loading plugin: {}", plugin_name);
 
-        let mut plugin: PluginBox = match plugin_name {
-            "scratchpads" => {
-                let scratchpads_plugin = ScratchpadsPlugin::new();
-                scratchpads_plugin
-                    .set_hyprland_client(Arc::clone(&hyprland_client))
-                    .await;
-                Box::new(scratchpads_plugin)
-            }
-            "expose" => Box::new(ExposePlugin::new()),
-            "workspaces_follow_focus" => Box::new(WorkspacesFollowFocusPlugin::new()),
-            "magnify" => Box::new(MagnifyPlugin::new()),
-            "shift_monitors" => Box::new(ShiftMonitorsPlugin::new()),
-            "system_notifier" => Box::new(SystemNotifier::new()),
-            "toggle_special" => Box::new(ToggleSpecialPlugin::new()),
-            "monitors" => Box::new(MonitorsPlugin::new()),
-            "wallpapers" => Box::new(WallpapersPlugin::new()),
-            "lost_windows" => {
-                let lost_windows_plugin = LostWindowsPlugin::new();
-                lost_windows_plugin
-                    .set_hyprland_client(Arc::clone(&hyprland_client))
-                    .await;
-                Box::new(lost_windows_plugin)
-            }
-            // Add more plugins here as they're implemented
-            _ => {
-                warn!("⚠️  Unknown plugin: {}", plugin_name);
-                return Ok(());
-            }
+        let Some(mut plugin) = create_plugin(plugin_name) else {
+            warn!("⚠️  Unknown plugin: {}", plugin_name);
+            return Ok(());
         };
 
         // Get plugin-specific config and wrap in Arc
@@ -114,53 +178,52 @@ impl PluginManager {
             .get(plugin_name)
             .cloned()
             .unwrap_or(toml::Value::Table(toml::map::Map::new()));
-        let plugin_config_arc = Arc::new(plugin_config.clone());
 
         // Store config in global cache for sharing
         self.global_cache
-            .store_config(plugin_name.to_string(), plugin_config_arc.clone())
+            .store_config(plugin_name.to_string(), Arc::new(plugin_config))
             .await;
 
-        // For scratchpads, we need to pass both the plugin config and global variables
-        if plugin_name == "scratchpads" {
-            // Create a combined config with both scratchpad settings and variables
-            let mut combined_config = toml::map::Map::new();
-
-            // Add plugin-specific config
-            if let toml::Value::Table(plugin_table) = plugin_config {
-                for (key, value) in plugin_table {
-                    combined_config.insert(key, value);
-                }
-            }
-
-            // Add variables section (merged from both pyprland and rustrland)
-            let merged_variables = config.get_variables();
-            let variables_value = toml::Value::try_from(&merged_variables)
-                .unwrap_or(toml::Value::Table(toml::map::Map::new()));
-            combined_config.insert("variables".to_string(), variables_value);
-
-            // Store variables in global cache
-            self.global_cache.store_variables(merged_variables).await;
-
-            let combined = toml::Value::Table(combined_config);
-            let combined_arc = Arc::new(combined.clone());
+        // Shared services go in before init so plugins can use them while initializing
+        let context = PluginContext {
+            hyprland_client,
+            animation_engine: Arc::clone(&self.animation_engine),
+            events: self.events.clone(),
+            global_cache: Arc::clone(&self.global_cache),
+            variables: config.get_variables(),
+            animation_presets: config.get_animation_presets(),
+        };
+        plugin.set_context(Arc::new(context)).await;
 
-            // Store combined config in cache and initialize
+        let init_config = plugin_init_config(plugin_name, config);
+        if plugin_name == "scratchpads" {
+            // Store variables and the combined config in the global cache
             self.global_cache
-                .store_config(format!("{plugin_name}_combined"), combined_arc.clone())
+                .store_variables(config.get_variables())
+                .await;
+            self.global_cache
+                .store_config(
+                    format!("{plugin_name}_combined"),
+                    Arc::new(init_config.clone()),
+                )
                 .await;
-            plugin.init(&combined).await?;
-        } else {
-            // Initialize plugin normally
-            plugin.init(&plugin_config).await?;
         }
+        plugin.init(&init_config).await?;
         self.plugins.insert(plugin_name.to_string(), plugin);
 
         info!("✅ Plugin '{}' loaded successfully", plugin_name);
         Ok(())
     }
 
+    /// Receive every Hyprland event the daemon handles from now on
+    pub fn subscribe_events(&self) -> broadcast::Receiver<HyprlandEvent> {
+        self.events.subscribe()
+    }
+
     pub async fn handle_event(&mut self, event: &HyprlandEvent) -> Result<()> {
+        // No subscribers is fine; the send only fails then
+        let _ = self.events.send(event.clone());
+
         for (name, plugin) in &mut self.plugins {
             if let Err(e) = plugin.handle_event(event).await {
                 warn!("⚠️  Plugin '{}' error handling event: {}", name, e);
@@ -182,6 +245,23 @@ impl PluginManager {
         }
     }
 
+    /// State of every loaded plugin keyed by plugin name, for `rustr dump-state`.
+    /// A plugin that fails to report gets `{"error": ...}` instead
+    pub fn dump_state(&self) -> serde_json::Value {
+        let plugins: serde_json::Map<String, serde_json::Value> = self
+            .plugins
+            .iter()
+            .map(|(name, plugin)| {
+                let state = plugin.dump_state().unwrap_or_else(|e| {
+                    warn!("⚠️ Plugin '{}' failed to dump its state: {}", name, e);
+                    serde_json::json!({ "error": e.to_string() })
+                });
+                (name.clone(), state)
+            })
+            .collect();
+        serde_json::Value::Object(plugins)
+    }
+
     pub fn get_plugin_count(&self) -> usize {
         self.plugins.len()
     }
@@ -189,38 +269,110 @@ impl PluginManager {
     pub fn get_global_cache(&self) -> Arc<GlobalStateCache> {
         Arc::clone(&self.global_cache)
     }
-}
 
-// Implementation of HotReloadable trait for PluginManager
-impl super::hot_reload::HotReloadable for PluginManager {
-    async fn get_plugin_state(&self, plugin_name: &str) -> Result<serde_json::Value> {
-        if !self.plugins.contains_key(plugin_name) {
-            return Err(anyhow::anyhow!("Plugin '{}' not found", plugin_name));
-        }
+    /// Configuration the loaded plugins were initialized from
+    pub fn current_config(&self) -> Option<&Config> {
+        self.current_config.as_ref()
+    }
 
-        // Special handling for scratchpads plugin with detailed state capture
-        if plugin_name == "scratchpads" {
-            if let Some(_plugin) = self.plugins.get(plugin_name) {
-                // Try to downcast to ScratchpadsPlugin to access state methods
-                // For now, we'll use the basic approach but log that detailed capture is available
-                debug!("🔍 Enhanced state capture available for scratchpads plugin");
+    /// Record the configuration applied by a reload that did not go through
+    /// `load_plugins`, so the next reload diffs against it
+    pub fn set_current_config(&mut self, config: Config) {
+        self.current_config = Some(config);
+    }
+
+    /// `rustr set`: overlay one value, addressed as `<plugin>.<key>[.<key>...]`,
+    /// onto the in-memory config and re-initialize that plugin, keeping its state.
+    /// Nothing is written to the config file: the next reload drops the override
+    pub async fn override_config_value(&mut self, key_path: &str, value: &str) -> Result<String> {
+        let (plugin_name, keys) = key_path
+            .split_once('.')
+            .filter(|(plugin, keys)| !plugin.is_empty() && !keys.is_empty())
+            .ok_or_else(|| anyhow::anyhow!("Expected <plugin>.<key>, got '{}'", key_path))?;
+
+        let mut plugin_config = self.get_plugin_config(plugin_name)?;
+        set_config_value(&mut plugin_config, keys, parse_config_value(value))?;
+
+        let old_config = self.current_config.clone().unwrap_or_default();
+        let mut new_config = old_config.clone();
+        new_config
+            .plugins
+            .insert(plugin_name.to_string(), plugin_config);
+
+        // Refuse what the plugin itself would reject, before touching the instance
+        if let Some(plugin) = create_plugin(plugin_name) {
+            let report = plugin.validate_config(&plugin_init_config(plugin_name, &new_config));
+            if report.has_errors() {
+                let errors: Vec<String> = report
+                    .errors
+                    .iter()
+                    .map(|issue| format!("{}: {}", issue.key, issue.message))
+                    .collect();
+                return Err(anyhow::anyhow!(
+                    "Invalid value for {}: {}",
+                    key_path,
+                    errors.join("; ")
+                ));
             }
         }
 
-        // Basic plugin state capture (will be enhanced per-plugin in the future)
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
+        let state = self.get_plugin_state(plugin_name).await?;
+        if let Err(e) = self.reload_plugin(plugin_name, &new_config).await {
+            warn!(
+                "⚠️ Override of {} failed, restoring previous config: {}",
+                key_path, e
+            );
+            self.reload_plugin(plugin_name, &old_config).await?;
+            self.restore_plugin_state(plugin_name, state).await?;
+            return Err(e);
+        }
+        self.restore_plugin_state(plugin_name, state).await?;
+        self.current_config = Some(new_config);
+
+        info!("🎛️ Overrode {} = {} for this session", key_path, value);
+        Ok(format!(
+            "Set {key_path} = {value} (until the next reload, not saved to the config file)"
+        ))
+    }
+}
+
+/// A value typed on the command line, read as TOML (`500`, `true`, `"50% 50%"`,
+/// `["position"]`); anything that isn't valid TOML is taken as a bare string
+fn parse_config_value(value: &str) -> toml::Value {
+    toml::from_str::<toml::Table>(&format!("value = {value}"))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(value.to_string()))
+}
+
+/// Set the dotted `keys` path inside `config`, creating missing tables on the way
+fn set_config_value(config: &mut toml::Value, keys: &str, value: toml::Value) -> Result<()> {
+    let mut current = config;
+    let mut keys = keys.split('.').peekable();
+    while let Some(key) = keys.next() {
+        let toml::Value::Table(table) = current else {
+            return Err(anyhow::anyhow!("'{}' is not inside a table", key));
+        };
+        if keys.peek().is_none() {
+            table.insert(key.to_string(), value);
+            return Ok(());
+        }
+        current = table
+            .entry(key.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+    }
+    Ok(())
+}
 
-        let state = serde_json::json!({
-            "plugin_name": plugin_name,
-            "loaded": true,
-            "timestamp": timestamp,
-            "enhanced_capture": plugin_name == "scratchpads"
-        });
+// Implementation of HotReloadable trait for PluginManager
+impl HotReloadable for PluginManager {
+    async fn get_plugin_state(&self, plugin_name: &str) -> Result<serde_json::Value> {
+        let Some(plugin) = self.plugins.get(plugin_name) else {
+            return Err(anyhow::anyhow!("Plugin '{}' not found", plugin_name));
+        };
 
-        debug!("📸 Captured state for plugin '{}': {}", plugin_name, state);
+        let state = plugin.capture_state().await?;
+        debug!("📸 Captured state for plugin '{}'", plugin_name);
         Ok(state)
     }
 
@@ -241,21 +393,20 @@ impl super::hot_reload::HotReloadable for PluginManager {
     }
 
     async fn restore_plugin_state(
-        &self,
+        &mut self,
         plugin_name: &str,
         state: serde_json::Value,
     ) -> Result<()> {
-        if !self.plugins.contains_key(plugin_name) {
+        let Some(plugin) = self.plugins.get_mut(plugin_name) else {
             return Err(anyhow::anyhow!("Plugin '{}' not found", plugin_name));
-        }
-
-        // Store the state and log it for now
-        // In the future, this would call a restore_state() method on the plugin itself
-        let mut states = self.plugin_states.write().await;
-        states.insert(plugin_name.to_string(), state.clone());
-        debug!("♻️ Restored state for plugin '{}': {}", plugin_name, state);
+        };
 
-        // TODO: In the future, call plugin.restore_state(state) if the plugin supports it
+        plugin.restore_state(state.clone()).await?;
+        self.plugin_states
+            .write()
+            .await
+            .insert(plugin_name.to_string(), state);
+        debug!("♻️ Restored state for plugin '{}'", plugin_name);
         Ok(())
     }
 
@@ -370,3 +521,83 @@ impl super::hot_reload::HotReloadable for PluginManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ipc::mock::{monitor, window, MockHyprland};
+
+    #[test]
+    fn test_parse_and_set_config_value() {
+        assert_eq!(parse_config_value("500"), toml::Value::Integer(500));
+        assert_eq!(parse_config_value("true"), toml::Value::Boolean(true));
+        assert_eq!(
+            parse_config_value("50% 50%"),
+            toml::Value::String("50% 50%".to_string())
+        );
+
+        let mut config = toml::Value::Table(toml::Table::new());
+        set_config_value(
+            &mut config,
+            "term.animation_duration",
+            parse_config_value("500"),
+        )
+        .unwrap();
+        assert_eq!(config["term"]["animation_duration"].as_integer(), Some(500));
+        assert!(set_config_value(
+            &mut config,
+            "term.animation_duration.x",
+            toml::Value::Integer(1)
+        )
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_override_config_value_keeps_state() {
+        let config: Config = toml::from_str(
+            r#"
+[rustrland]
+plugins = ["scratchpads"]
+
+[scratchpads.term]
+command = "foot"
+class = "foot"
+size = "50% 50%"
+"#,
+        )
+        .unwrap();
+        let api =
+            Arc::new(
+                MockHyprland::new(vec![], vec![monitor("DP-1", (1920, 1080), true, 1)])
+                    .spawns(window("0xb", "foot", (480, 270), 1)),
+            );
+
+        let mut pm = PluginManager::new();
+        pm.load_plugins(&config, Arc::new(HyprlandClient::with_api(api)))
+            .await
+            .unwrap();
+        pm.handle_command("scratchpads", "toggle", &["term"])
+            .await
+            .unwrap();
+
+        pm.override_config_value("scratchpads.term.animation_duration", "500")
+            .await
+            .unwrap();
+        let term = &pm.current_config().unwrap().plugins["scratchpads"]["term"];
+        assert_eq!(term["animation_duration"].as_integer(), Some(500));
+        let state = pm.dump_state();
+        assert_eq!(state["scratchpads"]["window_mappings"]["0xb"], "term");
+
+        // Rejected by the plugin's validation: nothing changes
+        let err = pm
+            .override_config_value("scratchpads.term.size", "banana")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid size format"), "{err}");
+        let term = &pm.current_config().unwrap().plugins["scratchpads"]["term"];
+        assert_eq!(term["size"].as_str(), Some("50% 50%"));
+
+        assert!(pm.override_config_value("scratchpads", "1").await.is_err());
+        assert!(pm.override_config_value("nope.key", "1").await.is_err());
+    }
+}
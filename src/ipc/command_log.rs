@@ -0,0 +1,250 @@
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::ipc::protocol::{ClientMessage, DaemonResponse, NOTIFY_SUBCOMMANDS};
+
+/// Number of IPC commands kept for `rustr debug last-commands` / `replay`
+pub const COMMAND_LOG_CAPACITY: usize = 50;
+
+/// Placeholder shown instead of arguments that may carry user data
+const REDACTED: &str = "<redacted>";
+
+/// A command received over IPC, with its outcome
+#[derive(Debug, Clone, Serialize)]
+pub struct LoggedCommand {
+    /// Seconds since the Unix epoch when the command was received
+    pub timestamp: u64,
+    pub message: ClientMessage,
+    pub success: bool,
+}
+
+/// Bounded history of the most recent IPC commands, oldest first
+#[derive(Debug)]
+pub struct CommandLog {
+    entries: VecDeque<LoggedCommand>,
+    capacity: usize,
+}
+
+impl Default for CommandLog {
+    fn default() -> Self {
+        Self::new(COMMAND_LOG_CAPACITY)
+    }
+}
+
+impl CommandLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Remember a processed command, dropping the oldest one when full
+    pub fn record(&mut self, message: ClientMessage, response: &DaemonResponse) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or(0);
+        self.entries.push_back(LoggedCommand {
+            timestamp,
+            message,
+            success: !matches!(
+                response,
+                DaemonResponse::Error { .. } | DaemonResponse::Usage { .. }
+            ),
+        });
+    }
+
+    /// The last `count` commands (all when None), oldest first
+    pub fn last(&self, count: Option<usize>) -> Vec<LoggedCommand> {
+        let count = count.unwrap_or(self.entries.len()).min(self.entries.len());
+        self.entries
+            .iter()
+            .skip(self.entries.len() - count)
+            .cloned()
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl LoggedCommand {
+    /// Copy safe to print or share: free-form user text is replaced
+    pub fn redacted(&self) -> Self {
+        Self {
+            message: redact(&self.message),
+            ..self.clone()
+        }
+    }
+}
+
+/// Replace arguments that may carry user data (notification text) with a placeholder
+pub fn redact(message: &ClientMessage) -> ClientMessage {
+    match message {
+        ClientMessage::Notify { command, args } => {
+            let command = command.as_ref().map(|command| {
+                if NOTIFY_SUBCOMMANDS.contains(&command.as_str()) {
+                    command.clone()
+                } else {
+                    REDACTED.to_string()
+                }
+            });
+            ClientMessage::Notify {
+                command,
+                args: args.iter().map(|_| REDACTED.to_string()).collect(),
+            }
+        }
+        other => other.clone(),
+    }
+}
+
+/// One-line summary of a logged command, e.g. `scratchpads peek-show term`
+pub fn describe(message: &ClientMessage) -> String {
+    let with_args = |name: &str, command: &Option<String>, args: &[String]| {
+        let mut parts = vec![name.to_string()];
+        parts.extend(command.iter().cloned());
+        parts.extend(args.iter().cloned());
+        parts.join(" ")
+    };
+
+    match message {
+        ClientMessage::Toggle { scratchpad } => format!("toggle {scratchpad}"),
+        ClientMessage::Show { scratchpad } => format!("show {scratchpad}"),
+        ClientMessage::Hide { scratchpad } => format!("hide {scratchpad}"),
+        ClientMessage::Attach { scratchpad } => format!("attach {scratchpad}"),
+        ClientMessage::IsVisible { scratchpad } => format!("is-visible {scratchpad}"),
+        ClientMessage::Expose => "expose".to_string(),
+        ClientMessage::ExposeAction { action } => format!("expose {action}"),
+        ClientMessage::WorkspaceAction { action, arg } => {
+            with_args("workspace", &Some(action.clone()), arg.as_slice())
+        }
+        ClientMessage::MagnifyAction { action, arg } => {
+            with_args("magnify", &Some(action.clone()), arg.as_slice())
+        }
+        ClientMessage::ShiftMonitors { direction } => with_args("shift-monitors", direction, &[]),
+        ClientMessage::ToggleSpecial {
+            workspace_name,
+            command,
+        } => with_args("toggle-special", workspace_name, command.as_slice()),
+        ClientMessage::Monitors { command, args } => with_args("monitors", command, args),
+        ClientMessage::Wallpapers { command, args } => with_args("wallpapers", command, args),
+        ClientMessage::Notify { command, args } => with_args("notify", command, args),
+        ClientMessage::LostWindows { command, args } => with_args("lost-windows", command, args),
+        ClientMessage::Scratchpads { command, args } => with_args("scratchpads", command, args),
+        ClientMessage::Debug { command, args } => with_args("debug", command, args),
+        ClientMessage::Profile { command, args } => with_args("profile", command, args),
+        ClientMessage::Subscribe { events } => with_args("subscribe", &None, events),
+        ClientMessage::Set { key, value } => format!("set {key} {value}"),
+        ClientMessage::DumpState => "dump-state".to_string(),
+        ClientMessage::ListEasings => "easings".to_string(),
+        ClientMessage::Reload => "reload".to_string(),
+        ClientMessage::Status => "status".to_string(),
+        ClientMessage::Ping => "ping".to_string(),
+        ClientMessage::List => "list".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toggle(name: &str) -> ClientMessage {
+        ClientMessage::Toggle {
+            scratchpad: name.to_string(),
+        }
+    }
+
+    fn ok() -> DaemonResponse {
+        DaemonResponse::Success {
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_log_keeps_only_the_latest_commands() {
+        let mut log = CommandLog::new(3);
+        for name in ["a", "b", "c", "d"] {
+            log.record(toggle(name), &ok());
+        }
+        log.record(
+            toggle("e"),
+            &DaemonResponse::Error {
+                message: "Scratchpad 'e' not found".to_string(),
+            },
+        );
+
+        let names: Vec<String> = log
+            .last(None)
+            .iter()
+            .map(|c| describe(&c.message))
+            .collect();
+        assert_eq!(names, vec!["toggle c", "toggle d", "toggle e"]);
+        assert!(!log.last(None)[2].success);
+
+        let names: Vec<String> = log
+            .last(Some(2))
+            .iter()
+            .map(|c| describe(&c.message))
+            .collect();
+        assert_eq!(names, vec!["toggle d", "toggle e"]);
+        assert_eq!(log.last(Some(10)).len(), 3);
+    }
+
+    #[test]
+    fn test_notification_text_is_redacted() {
+        let mut log = CommandLog::default();
+        log.record(
+            ClientMessage::Notify {
+                command: Some("Meeting with".to_string()),
+                args: vec!["Alex".to_string()],
+            },
+            &ok(),
+        );
+        log.record(
+            ClientMessage::Notify {
+                command: Some("status".to_string()),
+                args: Vec::new(),
+            },
+            &ok(),
+        );
+        log.record(toggle("term"), &ok());
+
+        let redacted: Vec<String> = log
+            .last(None)
+            .iter()
+            .map(|c| describe(&c.redacted().message))
+            .collect();
+        assert_eq!(
+            redacted,
+            vec![
+                "notify <redacted> <redacted>",
+                "notify status",
+                "toggle term"
+            ]
+        );
+
+        // The original stays available for replay
+        assert_eq!(
+            describe(&log.last(Some(3))[0].message),
+            "notify Meeting with Alex"
+        );
+
+        let json = serde_json::to_string(&log.last(Some(1))[0].redacted()).unwrap();
+        assert!(json.contains("\"success\":true"));
+        assert!(json.contains("\"Toggle\""));
+    }
+}
@@ -5,6 +5,8 @@ use hyprland::event_listener::EventListener;
 use hyprland::shared::HyprData;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::{mpsc, Mutex, RwLock};
 use tokio::time::{sleep, Duration, Instant};
@@ -28,11 +30,81 @@ where
         .map_err(|e| anyhow::anyhow!("Hyprland API error: {}", e))
 }
 
+/// Command socket of a Hyprland instance under `base` (`<base>/<instance>/.socket.sock`)
+fn command_socket_in(base: &Path, instance: &str) -> PathBuf {
+    base.join(instance).join(".socket.sock")
+}
+
+/// Directories Hyprland creates instance sockets in, newest layout first
+fn socket_base_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Ok(runtime_dir) = std::env::var("XDG_RUNTIME_DIR") {
+        dirs.push(PathBuf::from(runtime_dir).join("hypr"));
+    }
+    dirs.push(PathBuf::from("/tmp/hypr"));
+    dirs
+}
+
+/// Whether a Hyprland process answers on `socket`. A crashed instance leaves its
+/// socket file behind, so existence alone does not mean it is running
+fn socket_is_live(socket: &Path) -> bool {
+    std::os::unix::net::UnixStream::connect(socket).is_ok()
+}
+
+/// Most recently started instance under `base` whose command socket accepts connections
+fn newest_instance_in(base: &Path) -> Option<String> {
+    std::fs::read_dir(base)
+        .ok()?
+        .flatten()
+        .filter(|entry| socket_is_live(&entry.path().join(".socket.sock")))
+        .filter_map(|entry| {
+            let modified = entry.metadata().and_then(|m| m.modified()).ok()?;
+            Some((modified, entry.file_name().to_string_lossy().into_owned()))
+        })
+        .max()
+        .map(|(_, instance)| instance)
+}
+
+/// `resolve_instance` over the given socket directories and environment instance
+fn resolve_instance_in(
+    bases: &[PathBuf],
+    env_instance: Option<String>,
+) -> Option<(String, PathBuf)> {
+    if let Some(instance) = env_instance {
+        let alive = bases
+            .iter()
+            .map(|base| command_socket_in(base, &instance))
+            .find(|socket| socket_is_live(socket));
+        if let Some(socket) = alive {
+            return Some((instance, socket));
+        }
+    }
+    bases.iter().find_map(|base| {
+        let instance = newest_instance_in(base)?;
+        let socket = command_socket_in(base, &instance);
+        Some((instance, socket))
+    })
+}
+
+/// Query the client list straight from a command socket. Unlike `Clients::get`, which
+/// caches the socket path for the process lifetime, this follows a restarted Hyprland
+fn query_clients(socket: &Path) -> Result<Vec<Client>> {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+
+    let mut stream = UnixStream::connect(socket)?;
+    stream.set_read_timeout(Some(HYPRLAND_API_TIMEOUT))?;
+    stream.write_all(b"j/clients")?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    Ok(serde_json::from_str(&response)?)
+}
+
 /// Enhanced Hyprland client with robust connection management
 pub struct EnhancedHyprlandClient {
     event_sender: Arc<Mutex<Option<mpsc::Sender<HyprlandEvent>>>>,
     connection_state: Arc<RwLock<ConnectionState>>,
-    reconnect_config: ReconnectConfig,
+    reconnect_config: RwLock<ReconnectConfig>,
     event_filters: Arc<RwLock<Vec<String>>>, // Event types to filter for
 }
 
@@ -42,11 +114,14 @@ pub struct ConnectionState {
     pub last_connection_attempt: Option<Instant>,
     pub connection_failures: u32,
     pub hyprland_instance: Option<String>,
+    pub socket_path: Option<PathBuf>,
+    pub retry_attempt: u32, // Consecutive failed attempts of the current call
+    pub current_backoff: Option<Duration>, // Delay before the next retry, while backing off
 }
 
 #[derive(Debug, Clone)]
 pub struct ReconnectConfig {
-    pub max_retries: u32,
+    pub max_retries: Option<u32>, // None retries until the call succeeds
     pub initial_delay: Duration,
     pub max_delay: Duration,
     pub backoff_multiplier: f64,
@@ -55,14 +130,31 @@ pub struct ReconnectConfig {
 impl Default for ReconnectConfig {
     fn default() -> Self {
         Self {
-            max_retries: 10,
-            initial_delay: Duration::from_millis(500),
-            max_delay: Duration::from_secs(30),
+            max_retries: Some(5),
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
             backoff_multiplier: 2.0,
         }
     }
 }
 
+impl ReconnectConfig {
+    /// Delay before retry number `attempt` (1-based): exponential, capped at `max_delay`
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let factor = self
+            .backoff_multiplier
+            .powi(attempt.saturating_sub(1).min(i32::MAX as u32) as i32);
+        self.initial_delay
+            .mul_f64(factor.min(u32::MAX as f64))
+            .min(self.max_delay)
+    }
+
+    /// Whether another retry is allowed after `attempt` failures
+    pub fn allows_retry(&self, attempt: u32) -> bool {
+        self.max_retries.is_none_or(|max| attempt <= max)
+    }
+}
+
 impl Default for EnhancedHyprlandClient {
     fn default() -> Self {
         Self::new()
@@ -74,7 +166,7 @@ impl EnhancedHyprlandClient {
         Self {
             event_sender: Arc::new(Mutex::new(None)),
             connection_state: Arc::new(RwLock::new(ConnectionState::default())),
-            reconnect_config: ReconnectConfig::default(),
+            reconnect_config: RwLock::new(ReconnectConfig::default()),
             event_filters: Arc::new(RwLock::new(vec![
                 // Filter for relevant events only
                 "workspace".to_string(),
@@ -98,6 +190,24 @@ impl EnhancedHyprlandClient {
         info!("📝 Updated event filters: {:?}", *event_filters);
     }
 
+    /// Configure how failed Hyprland calls are retried: exponential backoff from `base`,
+    /// capped at `max`, for up to `max_retries` retries (`None` for no limit)
+    pub async fn set_reconnect_policy(
+        &self,
+        base: Duration,
+        max: Duration,
+        max_retries: Option<u32>,
+    ) {
+        let mut config = self.reconnect_config.write().await;
+        config.initial_delay = base;
+        config.max_delay = max.max(base);
+        config.max_retries = max_retries;
+        info!(
+            "🔁 Reconnect policy: backoff {:?}..{:?}, max retries {:?}",
+            config.initial_delay, config.max_delay, config.max_retries
+        );
+    }
+
     /// Check current connection status
     pub async fn is_connected(&self) -> bool {
         let state = self.connection_state.read().await;
@@ -109,6 +219,98 @@ impl EnhancedHyprlandClient {
         std::env::var("HYPRLAND_INSTANCE_SIGNATURE").ok()
     }
 
+    /// Live Hyprland instance and its command socket: the environment's instance while
+    /// its socket accepts connections, otherwise the newest running one (Hyprland was
+    /// restarted)
+    fn resolve_instance() -> Option<(String, PathBuf)> {
+        resolve_instance_in(&socket_base_dirs(), Self::get_hyprland_instance())
+    }
+
+    /// Socket calls should go to, resolving it on first use
+    async fn current_socket(&self) -> Result<PathBuf> {
+        if let Some(socket) = self.connection_state.read().await.socket_path.clone() {
+            return Ok(socket);
+        }
+        let (instance, socket) = Self::resolve_instance()
+            .ok_or_else(|| anyhow::anyhow!("No running Hyprland instance found"))?;
+        let mut state = self.connection_state.write().await;
+        state.hyprland_instance = Some(instance);
+        state.socket_path = Some(socket.clone());
+        Ok(socket)
+    }
+
+    /// Re-resolve the instance after a failure. Returns true when Hyprland was restarted
+    /// under a new signature, in which case the socket path and backoff are reset
+    async fn refresh_instance(&self) -> bool {
+        let Some((instance, socket)) = Self::resolve_instance() else {
+            return false;
+        };
+        let mut state = self.connection_state.write().await;
+        if state.hyprland_instance.as_deref() == Some(instance.as_str()) {
+            return false;
+        }
+        info!(
+            "🔄 Hyprland instance changed ({:?} -> {}), switching to {}",
+            state.hyprland_instance,
+            instance,
+            socket.display()
+        );
+        state.hyprland_instance = Some(instance);
+        state.socket_path = Some(socket);
+        state.retry_attempt = 0;
+        state.current_backoff = None;
+        true
+    }
+
+    /// Run `operation` until it succeeds, backing off exponentially between failures
+    /// according to the reconnect policy. A restarted Hyprland is retried immediately
+    async fn with_reconnect<T, F, Fut>(&self, what: &str, mut operation: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let error = match operation().await {
+                Ok(value) => {
+                    let mut state = self.connection_state.write().await;
+                    state.is_connected = true;
+                    state.retry_attempt = 0;
+                    state.current_backoff = None;
+                    return Ok(value);
+                }
+                Err(e) => e,
+            };
+
+            attempt += 1;
+            let policy = self.reconnect_config.read().await.clone();
+            {
+                let mut state = self.connection_state.write().await;
+                state.is_connected = false;
+                state.connection_failures += 1;
+                state.last_connection_attempt = Some(Instant::now());
+                state.retry_attempt = attempt;
+            }
+
+            if self.refresh_instance().await {
+                continue;
+            }
+            if !policy.allows_retry(attempt) {
+                self.connection_state.write().await.current_backoff = None;
+                error!("❌ {} failed after {} attempts: {}", what, attempt, error);
+                return Err(error);
+            }
+
+            let delay = policy.delay_for(attempt);
+            self.connection_state.write().await.current_backoff = Some(delay);
+            warn!(
+                "⚠️  {} failed (attempt {}), retrying in {:?}: {}",
+                what, attempt, delay, error
+            );
+            sleep(delay).await;
+        }
+    }
+
     /// Test connection to Hyprland
     pub async fn test_connection(&self) -> Result<()> {
         debug!("🧪 Testing Hyprland connection");
@@ -282,7 +484,22 @@ impl EnhancedHyprlandClient {
         debug!("📐 Getting geometries for {} windows", addresses.len());
 
         let address_set: std::collections::HashSet<String> = addresses.iter().cloned().collect();
-        let clients = with_hyprland_timeout(Clients::get).await?;
+        // Not running under Hyprland at all is not transient, so fail right away
+        self.current_socket().await?;
+        let clients = self
+            .with_reconnect("Window geometry query", || async {
+                let socket = self.current_socket().await?;
+                tokio::time::timeout(
+                    HYPRLAND_API_TIMEOUT,
+                    tokio::task::spawn_blocking(move || query_clients(&socket)),
+                )
+                .await
+                .map_err(|_| {
+                    anyhow::anyhow!("Hyprland API call timeout after {:?}", HYPRLAND_API_TIMEOUT)
+                })?
+                .map_err(|e| anyhow::anyhow!("Failed to spawn Hyprland task: {}", e))?
+            })
+            .await?;
 
         let mut geometries = HashMap::new();
 
@@ -315,6 +532,8 @@ impl EnhancedHyprlandClient {
             connection_failures: state.connection_failures,
             last_connection_attempt: state.last_connection_attempt,
             hyprland_instance: state.hyprland_instance.clone(),
+            retry_attempt: state.retry_attempt,
+            current_backoff: state.current_backoff,
         }
     }
 }
@@ -336,6 +555,8 @@ pub struct ConnectionStats {
     pub connection_failures: u32,
     pub last_connection_attempt: Option<Instant>,
     pub hyprland_instance: Option<String>,
+    pub retry_attempt: u32,
+    pub current_backoff: Option<Duration>,
 }
 
 #[cfg(test)]
@@ -542,6 +763,8 @@ mod tests {
             connection_failures: 5,
             last_connection_attempt: Some(Instant::now()),
             hyprland_instance: Some("test_instance".to_string()),
+            retry_attempt: 0,
+            current_backoff: None,
         };
 
         assert!(stats.is_connected);
@@ -550,6 +773,124 @@ mod tests {
         assert_eq!(stats.hyprland_instance, Some("test_instance".to_string()));
     }
 
+    #[test]
+    fn test_backoff_grows_exponentially_up_to_cap() {
+        let config = ReconnectConfig {
+            max_retries: Some(3),
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(350),
+            backoff_multiplier: 2.0,
+        };
+
+        assert_eq!(config.delay_for(1), Duration::from_millis(100));
+        assert_eq!(config.delay_for(2), Duration::from_millis(200));
+        assert_eq!(config.delay_for(3), Duration::from_millis(350));
+        assert_eq!(config.delay_for(u32::MAX), Duration::from_millis(350));
+
+        assert!(config.allows_retry(3));
+        assert!(!config.allows_retry(4));
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_retries_transient_failures() {
+        let client = EnhancedHyprlandClient::new();
+        client
+            .set_reconnect_policy(Duration::from_millis(1), Duration::from_millis(2), Some(5))
+            .await;
+
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let result = client
+            .with_reconnect("test call", || async {
+                match calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) {
+                    0 | 1 => Err(anyhow::anyhow!("socket closed")),
+                    n => Ok(n),
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 2);
+
+        let stats = client.get_connection_stats().await;
+        assert_eq!(stats.connection_failures, 2);
+        assert_eq!(stats.retry_attempt, 0);
+        assert!(stats.current_backoff.is_none());
+
+        client
+            .set_reconnect_policy(Duration::from_millis(1), Duration::from_millis(1), Some(1))
+            .await;
+        let result: Result<()> = client
+            .with_reconnect("test call", || async { Err(anyhow::anyhow!("gone")) })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(client.get_connection_stats().await.retry_attempt, 2);
+    }
+
+    /// Create `instance` under `base` with a listening command socket, or only the
+    /// socket file a crashed Hyprland leaves behind
+    fn fake_instance(
+        base: &Path,
+        instance: &str,
+        live: bool,
+    ) -> Option<std::os::unix::net::UnixListener> {
+        let dir = base.join(instance);
+        std::fs::create_dir(&dir).unwrap();
+        let socket = dir.join(".socket.sock");
+        let listener = std::os::unix::net::UnixListener::bind(&socket).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        if live {
+            Some(listener)
+        } else {
+            drop(listener);
+            None
+        }
+    }
+
+    #[test]
+    fn test_newest_instance_is_picked_after_restart() {
+        let runtime = tempfile::tempdir().unwrap();
+        let _old = fake_instance(runtime.path(), "old_instance", true);
+        let _new = fake_instance(runtime.path(), "new_instance", true);
+        // A leftover directory without a socket is not a running instance
+        std::fs::create_dir(runtime.path().join("dead_instance")).unwrap();
+
+        assert_eq!(
+            newest_instance_in(runtime.path()).as_deref(),
+            Some("new_instance")
+        );
+        assert_eq!(
+            command_socket_in(runtime.path(), "new_instance"),
+            runtime.path().join("new_instance").join(".socket.sock")
+        );
+    }
+
+    #[test]
+    fn test_stale_socket_of_crashed_instance_is_skipped() {
+        let runtime = tempfile::tempdir().unwrap();
+        let bases = vec![runtime.path().to_path_buf()];
+        let _crashed = fake_instance(runtime.path(), "crashed_instance", false);
+        let _live = fake_instance(runtime.path(), "live_instance", true);
+        // A newer crash must not win over the running instance either
+        let _crashed_later = fake_instance(runtime.path(), "crashed_later", false);
+        assert!(runtime
+            .path()
+            .join("crashed_instance")
+            .join(".socket.sock")
+            .exists());
+
+        assert_eq!(
+            resolve_instance_in(&bases, Some("crashed_instance".to_string())),
+            Some((
+                "live_instance".to_string(),
+                command_socket_in(runtime.path(), "live_instance")
+            ))
+        );
+        assert_eq!(
+            resolve_instance_in(&bases, Some("live_instance".to_string()))
+                .map(|(instance, _)| instance)
+                .as_deref(),
+            Some("live_instance")
+        );
+    }
+
     #[test]
     fn test_performance_with_many_events() {
         let filters = vec!["openwindow".to_string(), "closewindow".to_string()];
@@ -0,0 +1,158 @@
+//! Scripted `HyprlandApi` for tests: serves a fixed set of windows and monitors
+//! and records every request in the order the client made it
+
+use super::{dispatch_entry, HyprlandApi};
+use anyhow::Result;
+use async_trait::async_trait;
+use hyprland::data::{Client, Monitor};
+use hyprland::dispatch::DispatchType;
+use std::sync::Mutex;
+
+#[derive(Default)]
+pub struct MockHyprland {
+    clients: Mutex<Vec<Client>>,
+    monitors: Vec<Monitor>,
+    /// Windows that appear one per `exec` dispatch, as a spawned app would map
+    pending_spawns: Mutex<Vec<Client>>,
+    calls: Mutex<Vec<String>>,
+}
+
+impl MockHyprland {
+    pub fn new(clients: Vec<Client>, monitors: Vec<Monitor>) -> Self {
+        Self {
+            clients: Mutex::new(clients),
+            monitors,
+            ..Default::default()
+        }
+    }
+
+    /// Make `window` appear when the next application is spawned
+    pub fn spawns(self, window: Client) -> Self {
+        self.pending_spawns.lock().unwrap().push(window);
+        self
+    }
+
+    /// Current state of the window at `address`
+    pub fn window(&self, address: &str) -> Option<Client> {
+        self.clients
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|c| c.address.to_string() == address)
+            .cloned()
+    }
+
+    /// Dispatches, batches and keywords so far, in `hyprctl` syntax
+    /// (`dispatch focuswindow address:0x1`, `keyword windowrulev2 float, ...`)
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// Record a call, and follow the workspace moves and floating toggles it makes
+    fn record(&self, call: String) {
+        let moved = call
+            .strip_prefix("dispatch movetoworkspacesilent ")
+            .or_else(|| call.strip_prefix("dispatch movetoworkspace "))
+            .and_then(|target| target.split_once(",address:"));
+        if let Some((workspace, address)) = moved {
+            let mut clients = self.clients.lock().unwrap();
+            if let Some(client) = clients
+                .iter_mut()
+                .find(|c| c.address.to_string() == address)
+            {
+                client.workspace.id = workspace.parse().unwrap_or(-98);
+                client.workspace.name = workspace.to_string();
+            }
+        }
+        if let Some(address) = call.strip_prefix("dispatch togglefloating address:") {
+            let mut clients = self.clients.lock().unwrap();
+            if let Some(client) = clients
+                .iter_mut()
+                .find(|c| c.address.to_string() == address)
+            {
+                client.floating = !client.floating;
+            }
+        }
+        self.calls.lock().unwrap().push(call);
+    }
+}
+
+/// Monitor as `hyprctl monitors -j` reports it
+pub fn monitor(name: &str, size: (u16, u16), focused: bool, workspace: i32) -> Monitor {
+    serde_json::from_value(serde_json::json!({
+        "id": 0,
+        "name": name,
+        "description": "",
+        "width": size.0,
+        "height": size.1,
+        "refreshRate": 60.0,
+        "x": 0,
+        "y": 0,
+        "activeWorkspace": { "id": workspace, "name": workspace.to_string() },
+        "specialWorkspace": { "id": 0, "name": "" },
+        "reserved": [0, 0, 0, 0],
+        "scale": 1.0,
+        "transform": 0,
+        "focused": focused,
+        "dpmsStatus": true,
+        "vrr": false,
+        "disabled": false
+    }))
+    .unwrap()
+}
+
+/// Show the special workspace `special:<name>` over `monitor`
+pub fn with_special(mut monitor: Monitor, name: &str) -> Monitor {
+    monitor.special_workspace.id = -98;
+    monitor.special_workspace.name = format!("special:{name}");
+    monitor
+}
+
+/// Window as `hyprctl clients -j` reports it
+pub fn window(address: &str, class: &str, at: (i16, i16), workspace: i32) -> Client {
+    serde_json::from_value(serde_json::json!({
+        "address": address, "at": [at.0, at.1], "size": [800, 600],
+        "workspace": { "id": workspace, "name": workspace.to_string() },
+        "floating": true, "fullscreen": 0, "fullscreenClient": 0, "monitor": 0,
+        "initialClass": class, "class": class, "initialTitle": "", "title": "",
+        "pid": 1, "xwayland": false, "pinned": false, "grouped": [],
+        "mapped": true, "swallowing": null, "focusHistoryID": 0,
+    }))
+    .unwrap()
+}
+
+#[async_trait]
+impl HyprlandApi for MockHyprland {
+    async fn clients(&self) -> Result<Vec<Client>> {
+        Ok(self.clients.lock().unwrap().clone())
+    }
+
+    async fn monitors(&self) -> Result<Vec<Monitor>> {
+        Ok(self.monitors.clone())
+    }
+
+    async fn dispatch(&self, command: DispatchType<'static>) -> Result<()> {
+        if let DispatchType::Exec(app) = command {
+            self.record(format!("dispatch exec {app}"));
+            let mut pending = self.pending_spawns.lock().unwrap();
+            if !pending.is_empty() {
+                self.clients.lock().unwrap().push(pending.remove(0));
+            }
+            return Ok(());
+        }
+        self.record(dispatch_entry(&command).unwrap_or_else(|| format!("dispatch {command:?}")));
+        Ok(())
+    }
+
+    async fn batch(&self, batch: String) -> Result<()> {
+        for entry in batch.split(" ; ") {
+            self.record(entry.to_string());
+        }
+        Ok(())
+    }
+
+    async fn keyword(&self, name: &str, value: &str) -> Result<()> {
+        self.record(format!("keyword {name} {value}"));
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use async_trait::async_trait;
 use hyprland::data::{Client, Clients, Monitor, Monitors};
 use hyprland::dispatch;
 use hyprland::dispatch::DispatchType::*;
@@ -9,12 +10,18 @@ use hyprland::event_listener::EventListener;
 use hyprland::shared::{HyprData, HyprDataActiveOptional, WorkspaceType};
 use std::sync::Arc;
 use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
 use tokio::time::{timeout, Duration};
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 
+pub mod command_log;
 pub mod enhanced_client;
+#[cfg(test)]
+pub mod mock;
 pub mod protocol;
 pub mod server;
+pub mod subscription;
 
 pub use enhanced_client::{ConnectionStats, EnhancedHyprlandClient, WindowGeometry};
 pub use protocol::{ClientMessage, DaemonResponse};
@@ -35,6 +42,55 @@ where
         .map_err(|e| anyhow::anyhow!("Hyprland API error: {}", e))
 }
 
+/// Most dispatches sent in one `hyprctl --batch` call; longer batches are split.
+/// 32 window commands stay far below Hyprland's socket request buffer, and a chunk
+/// is also the unit that falls back to one-by-one dispatch when the batch fails
+pub const MAX_DISPATCH_BATCH: usize = 32;
+
+/// `dispatch ...` line for the dispatchers the animation and scratchpad code
+/// sends, `None` for anything else
+fn dispatch_entry(command: &DispatchType) -> Option<String> {
+    let dispatcher = match command {
+        Custom(name, args) => format!("{name} {args}"),
+        MoveWindowPixel(position, window) => format!("movewindowpixel {position},{window}"),
+        ResizeWindowPixel(size, window) => format!("resizewindowpixel {size},{window}"),
+        MoveToWorkspace(workspace, Some(window)) => {
+            format!("movetoworkspace {workspace},{window}")
+        }
+        MoveToWorkspaceSilent(workspace, Some(window)) => {
+            format!("movetoworkspacesilent {workspace},{window}")
+        }
+        FocusWindow(window) => format!("focuswindow {window}"),
+        ToggleFloating(Some(window)) => format!("togglefloating {window}"),
+        CloseWindow(window) => format!("closewindow {window}"),
+        ToggleSpecialWorkspace(Some(name)) => format!("togglespecialworkspace {name}"),
+        ToggleSpecialWorkspace(None) => "togglespecialworkspace".to_string(),
+        _ => return None,
+    };
+    Some(format!("dispatch {dispatcher}"))
+}
+
+/// `dispatch_entry` of a command that can go into a `hyprctl --batch` request.
+/// Only idempotent dispatchers (exact moves and resizes, `setprop`) qualify: a
+/// failed batch is re-sent one command at a time, re-running whatever part of it
+/// had already been applied. `None` for anything else, dispatched on its own
+fn batch_entry(command: &DispatchType) -> Option<String> {
+    match command {
+        MoveWindowPixel(dispatch::Position::Exact(..), _)
+        | ResizeWindowPixel(dispatch::Position::Exact(..), _)
+        | Custom("setprop", _) => dispatch_entry(command),
+        _ => None,
+    }
+}
+
+/// `hyprctl` arguments that run `command`, e.g. `dispatch exec foot`
+fn dispatch_line(command: &DispatchType) -> String {
+    match command {
+        Exec(app) => format!("dispatch exec {app}"),
+        _ => dispatch_entry(command).unwrap_or_else(|| format!("dispatch {command:?}")),
+    }
+}
+
 /// Window properties for animations
 #[derive(Debug, Clone)]
 pub struct WindowProperties {
@@ -58,6 +114,11 @@ pub struct MonitorInfo {
     pub is_focused: bool,
     pub active_workspace_id: i32,
     pub refresh_rate: f32,
+    // Space reserved by bars/layer surfaces on each edge, in pixels
+    pub reserved_top: u16,
+    pub reserved_bottom: u16,
+    pub reserved_left: u16,
+    pub reserved_right: u16,
 }
 
 impl Default for MonitorInfo {
@@ -79,10 +140,41 @@ impl MonitorInfo {
             width: 0,
             x: 0,
             y: 0,
+            reserved_top: 0,
+            reserved_bottom: 0,
+            reserved_left: 0,
+            reserved_right: 0,
         }
     }
 }
 
+impl From<&Monitor> for MonitorInfo {
+    fn from(m: &Monitor) -> Self {
+        Self {
+            id: m.id,
+            name: m.name.clone(),
+            width: m.width,
+            height: m.height,
+            x: m.x,
+            y: m.y,
+            scale: m.scale,
+            is_focused: m.focused,
+            active_workspace_id: m.active_workspace.id,
+            refresh_rate: m.refresh_rate,
+            // Hyprland reports reserved space as (left, top, right, bottom)
+            reserved_top: m.reserved.1,
+            reserved_bottom: m.reserved.3,
+            reserved_left: m.reserved.0,
+            reserved_right: m.reserved.2,
+        }
+    }
+}
+
+/// The monitor holding the keyboard focus, skipping disabled outputs
+fn focused_monitor(monitors: &[Monitor]) -> Option<&Monitor> {
+    monitors.iter().find(|m| m.focused && !m.disabled)
+}
+
 /// Workspace information
 #[derive(Debug, Clone)]
 pub struct WorkspaceInfo {
@@ -94,7 +186,8 @@ pub struct WorkspaceInfo {
 }
 
 // Define a basic event type for now
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "event", content = "data")]
 pub enum HyprlandEvent {
     WorkspaceChanged { workspace: String },
     WindowOpened { window: String },
@@ -105,17 +198,206 @@ pub enum HyprlandEvent {
     Other(String),
 }
 
+impl HyprlandEvent {
+    /// Variant names, as used by IPC subscription filters
+    pub const KINDS: [&'static str; 7] = [
+        "WorkspaceChanged",
+        "WindowOpened",
+        "WindowClosed",
+        "WindowMoved",
+        "WindowFocusChanged",
+        "MonitorChanged",
+        "Other",
+    ];
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            HyprlandEvent::WorkspaceChanged { .. } => "WorkspaceChanged",
+            HyprlandEvent::WindowOpened { .. } => "WindowOpened",
+            HyprlandEvent::WindowClosed { .. } => "WindowClosed",
+            HyprlandEvent::WindowMoved { .. } => "WindowMoved",
+            HyprlandEvent::WindowFocusChanged { .. } => "WindowFocusChanged",
+            HyprlandEvent::MonitorChanged { .. } => "MonitorChanged",
+            HyprlandEvent::Other(_) => "Other",
+        }
+    }
+}
+
+/// The compositor calls `HyprlandClient` is built on. `LiveHyprland` sends them
+/// to the running Hyprland; tests substitute `mock::MockHyprland` to script
+/// windows and monitors and record what was dispatched
+#[async_trait]
+pub trait HyprlandApi: Send + Sync {
+    async fn clients(&self) -> Result<Vec<Client>>;
+    async fn monitors(&self) -> Result<Vec<Monitor>>;
+    async fn dispatch(&self, command: DispatchType<'static>) -> Result<()>;
+    /// One `hyprctl --batch` request of `;`-separated commands
+    async fn batch(&self, batch: String) -> Result<()>;
+    /// `hyprctl keyword <name> <value>`, e.g. a `windowrulev2`
+    async fn keyword(&self, name: &str, value: &str) -> Result<()>;
+    /// Whether changes are only logged (`--dry-run`), so side effects outside
+    /// Hyprland must be skipped too
+    fn is_dry_run(&self) -> bool {
+        false
+    }
+}
+
+/// `HyprlandApi` of the Hyprland instance the daemon runs under
+pub struct LiveHyprland;
+
+#[async_trait]
+impl HyprlandApi for LiveHyprland {
+    async fn clients(&self) -> Result<Vec<Client>> {
+        use hyprland::shared::HyprDataVec;
+        Ok(with_hyprland_timeout(Clients::get).await?.to_vec())
+    }
+
+    async fn monitors(&self) -> Result<Vec<Monitor>> {
+        use hyprland::shared::HyprDataVec;
+        Ok(with_hyprland_timeout(Monitors::get).await?.to_vec())
+    }
+
+    async fn dispatch(&self, command: DispatchType<'static>) -> Result<()> {
+        tokio::task::spawn_blocking(move || Dispatch::call(command)).await??;
+        Ok(())
+    }
+
+    /// hyprctl answers "ok" for each command of the batch
+    async fn batch(&self, batch: String) -> Result<()> {
+        let output = timeout(
+            HYPRLAND_API_TIMEOUT,
+            tokio::process::Command::new("hyprctl")
+                .arg("--batch")
+                .arg(&batch)
+                .output(),
+        )
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!("hyprctl --batch timeout after {:?}", HYPRLAND_API_TIMEOUT)
+        })??;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let failed: Vec<&str> = stdout
+            .split_whitespace()
+            .filter(|reply| *reply != "ok")
+            .collect();
+        if !output.status.success() || !failed.is_empty() {
+            return Err(anyhow::anyhow!(
+                "hyprctl --batch failed: {}",
+                if failed.is_empty() {
+                    String::from_utf8_lossy(&output.stderr).trim().to_string()
+                } else {
+                    failed.join(" ")
+                }
+            ));
+        }
+        Ok(())
+    }
+
+    async fn keyword(&self, name: &str, value: &str) -> Result<()> {
+        let output = timeout(
+            HYPRLAND_API_TIMEOUT,
+            tokio::process::Command::new("hyprctl")
+                .arg("keyword")
+                .arg(name)
+                .arg(value)
+                .output(),
+        )
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!("hyprctl keyword timeout after {:?}", HYPRLAND_API_TIMEOUT)
+        })??;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "hyprctl keyword {} failed: {}",
+                name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `HyprlandApi` for `--dry-run`: queries go to `inner`, while dispatches, batches
+/// and keywords are only logged as the `hyprctl` command they would run
+pub struct DryRunHyprland {
+    inner: Arc<dyn HyprlandApi>,
+}
+
+impl DryRunHyprland {
+    pub fn new(inner: Arc<dyn HyprlandApi>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl HyprlandApi for DryRunHyprland {
+    async fn clients(&self) -> Result<Vec<Client>> {
+        self.inner.clients().await
+    }
+
+    async fn monitors(&self) -> Result<Vec<Monitor>> {
+        self.inner.monitors().await
+    }
+
+    async fn dispatch(&self, command: DispatchType<'static>) -> Result<()> {
+        info!("🧪 [dry-run] hyprctl {}", dispatch_line(&command));
+        Ok(())
+    }
+
+    async fn batch(&self, batch: String) -> Result<()> {
+        info!("🧪 [dry-run] hyprctl --batch '{}'", batch);
+        Ok(())
+    }
+
+    async fn keyword(&self, name: &str, value: &str) -> Result<()> {
+        info!("🧪 [dry-run] hyprctl keyword {} '{}'", name, value);
+        Ok(())
+    }
+
+    fn is_dry_run(&self) -> bool {
+        true
+    }
+}
+
+/// Running event listener task and the token that stops it
+struct EventListenerTask {
+    shutdown: CancellationToken,
+    handle: JoinHandle<()>,
+}
+
 #[derive(Clone)]
 pub struct HyprlandClient {
     event_receiver: Arc<Mutex<Option<mpsc::Receiver<HyprlandEvent>>>>,
+    event_listener: Arc<Mutex<Option<EventListenerTask>>>,
+    api: Arc<dyn HyprlandApi>,
 }
 
 impl HyprlandClient {
     pub async fn new() -> Result<Self> {
         debug!("🔌 Creating Hyprland client");
-        Ok(Self {
+        Ok(Self::with_api(Arc::new(LiveHyprland)))
+    }
+
+    /// Client that reads the live Hyprland state but only logs what it would change
+    pub fn dry_run() -> Self {
+        debug!("🔌 Creating dry-run Hyprland client");
+        Self::with_api(Arc::new(DryRunHyprland::new(Arc::new(LiveHyprland))))
+    }
+
+    /// Whether this client only logs what it would change (`--dry-run`)
+    pub fn is_dry_run(&self) -> bool {
+        self.api.is_dry_run()
+    }
+
+    /// Client whose queries and dispatches go through `api` instead of Hyprland
+    pub fn with_api(api: Arc<dyn HyprlandApi>) -> Self {
+        Self {
             event_receiver: Arc::new(Mutex::new(None)),
-        })
+            event_listener: Arc::new(Mutex::new(None)),
+            api,
+        }
     }
 
     pub async fn test_connection(&self) -> Result<()> {
@@ -136,74 +418,102 @@ impl HyprlandClient {
         // Store the receiver
         let mut receiver_guard = self.event_receiver.lock().await;
         *receiver_guard = Some(rx);
+        drop(receiver_guard);
 
-        // Spawn background task to handle events with focus tracking
-        tokio::spawn(async move {
-            debug!("🎧 Starting focus tracking event system");
+        // A listener started earlier is replaced
+        self.shutdown_event_listener().await;
 
-            let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(500)); // Check every 500ms for responsive focus tracking
-            let mut last_focused_window: Option<String> = None;
+        let shutdown = CancellationToken::new();
+        let token = shutdown.clone();
+        let handle = tokio::spawn(async move {
+            tokio::select! {
+                _ = token.cancelled() => debug!("🛑 Event listener shut down"),
+                _ = Self::poll_events(tx) => {}
+            }
+        });
+        *self.event_listener.lock().await = Some(EventListenerTask { shutdown, handle });
 
-            loop {
-                interval.tick().await;
+        Ok(())
+    }
 
-                // Try to get the currently focused window using activewindow
-                match with_hyprland_timeout(|| {
-                    use hyprland::data::Client;
+    /// Stop the event listener task and wait for it to exit. Does nothing when
+    /// no listener is running
+    pub async fn shutdown_event_listener(&self) {
+        let Some(listener) = self.event_listener.lock().await.take() else {
+            return;
+        };
 
-                    // Get the active window directly
-                    match Client::get_active() {
-                        Ok(Some(client)) => Ok(Some(client.address.to_string())),
-                        Ok(None) => Ok(None),
-                        Err(e) => Err(e),
-                    }
-                })
-                .await
-                {
-                    Ok(current_focused) => {
-                        // Check if focus has changed
-                        if current_focused != last_focused_window {
-                            if let Some(ref current_window) = current_focused {
-                                debug!("👁️ Focus changed to: {}", current_window);
-
-                                if let Err(e) = tx
-                                    .send(HyprlandEvent::WindowFocusChanged {
-                                        window: current_window.clone(),
-                                    })
-                                    .await
-                                {
-                                    warn!("Failed to send window focus event: {}", e);
-                                }
-                            } else if last_focused_window.is_some() {
-                                debug!("👁️ Focus lost (no focused window)");
-
-                                if let Err(e) = tx
-                                    .send(HyprlandEvent::WindowFocusChanged {
-                                        window: "none".to_string(),
-                                    })
-                                    .await
-                                {
-                                    warn!("Failed to send window focus lost event: {}", e);
-                                }
-                            }
+        listener.shutdown.cancel();
+        if let Err(e) = listener.handle.await {
+            warn!("Event listener task failed: {}", e);
+        }
+    }
+
+    /// Poll Hyprland for focus changes and forward them (plus a heartbeat) until
+    /// the receiver is dropped
+    async fn poll_events(tx: mpsc::Sender<HyprlandEvent>) {
+        debug!("🎧 Starting focus tracking event system");
+
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(500)); // Check every 500ms for responsive focus tracking
+        let mut last_focused_window: Option<String> = None;
+
+        loop {
+            interval.tick().await;
 
-                            last_focused_window = current_focused;
+            // Try to get the currently focused window using activewindow
+            match with_hyprland_timeout(|| {
+                use hyprland::data::Client;
+
+                // Get the active window directly
+                match Client::get_active() {
+                    Ok(Some(client)) => Ok(Some(client.address.to_string())),
+                    Ok(None) => Ok(None),
+                    Err(e) => Err(e),
+                }
+            })
+            .await
+            {
+                Ok(current_focused) => {
+                    // Check if focus has changed
+                    if current_focused != last_focused_window {
+                        if let Some(ref current_window) = current_focused {
+                            debug!("👁️ Focus changed to: {}", current_window);
+
+                            if let Err(e) = tx
+                                .send(HyprlandEvent::WindowFocusChanged {
+                                    window: current_window.clone(),
+                                })
+                                .await
+                            {
+                                warn!("Failed to send window focus event: {}", e);
+                            }
+                        } else if last_focused_window.is_some() {
+                            debug!("👁️ Focus lost (no focused window)");
+
+                            if let Err(e) = tx
+                                .send(HyprlandEvent::WindowFocusChanged {
+                                    window: "none".to_string(),
+                                })
+                                .await
+                            {
+                                warn!("Failed to send window focus lost event: {}", e);
+                            }
                         }
-                    }
-                    Err(e) => {
-                        debug!("Failed to get focused window: {}", e);
+
+                        last_focused_window = current_focused;
                     }
                 }
-
-                // Send periodic heartbeat for other functionality
-                if let Err(_e) = tx.send(HyprlandEvent::Other("heartbeat".to_string())).await {
-                    warn!("Event receiver dropped, stopping event listener");
-                    break;
+                Err(e) => {
+                    debug!("Failed to get focused window: {}", e);
                 }
             }
-        });
 
-        Ok(())
+            // Send periodic heartbeat for other functionality
+            if let Err(_e) = tx.send(HyprlandEvent::Other("heartbeat".to_string())).await {
+                warn!("Event receiver dropped, stopping event listener");
+                break;
+            }
+        }
     }
 
     pub async fn get_next_event(&self) -> Result<HyprlandEvent> {
@@ -223,7 +533,7 @@ impl HyprlandClient {
     pub async fn find_window_by_class(&self, class: &str) -> Result<Option<Client>> {
         debug!("🔍 Looking for window with class: {}", class);
 
-        let clients = with_hyprland_timeout(Clients::get).await?;
+        let clients = self.api.clients().await?;
 
         for client in clients.iter() {
             if client.class == class {
@@ -240,20 +550,103 @@ impl HyprlandClient {
     pub async fn dispatch(&self, command: DispatchType<'static>) -> Result<()> {
         debug!("📤 Dispatching command: {:?}", command);
 
-        tokio::task::spawn_blocking(move || Dispatch::call(command)).await??;
+        self.api.dispatch(command).await?;
 
         debug!("✅ Command dispatched successfully");
         Ok(())
     }
 
+    /// Send several dispatches in one `hyprctl --batch` call (one process and one IPC
+    /// round trip per `MAX_DISPATCH_BATCH` commands). A chunk that cannot be batched
+    /// or whose batch fails is dispatched command by command instead
+    pub async fn dispatch_batch(&self, commands: Vec<DispatchType<'static>>) -> Result<()> {
+        let mut commands = commands.into_iter().peekable();
+        while commands.peek().is_some() {
+            let chunk: Vec<_> = commands.by_ref().take(MAX_DISPATCH_BATCH).collect();
+            let entries: Option<Vec<String>> = chunk.iter().map(batch_entry).collect();
+
+            let batched = match entries {
+                Some(entries) => match self.run_batch(entries.join(" ; ")).await {
+                    Ok(()) => true,
+                    Err(e) => {
+                        debug!("⚠️ Batch dispatch failed, dispatching one by one: {}", e);
+                        false
+                    }
+                },
+                None => false,
+            };
+
+            if !batched {
+                for command in chunk {
+                    self.dispatch(command).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Run one `hyprctl --batch` request
+    async fn run_batch(&self, batch: String) -> Result<()> {
+        debug!("📦 Dispatching batch: {}", batch);
+        self.api.batch(batch).await
+    }
+
+    /// Set a Hyprland keyword at runtime (`hyprctl keyword`), e.g. a `windowrulev2`
+    pub async fn keyword(&self, name: &str, value: &str) -> Result<()> {
+        debug!("🔧 Setting keyword {} {}", name, value);
+        self.api.keyword(name, value).await
+    }
+
+    fn window_identifier(address: &str) -> hyprland::dispatch::WindowIdentifier<'static> {
+        use hyprland::shared::Address;
+
+        hyprland::dispatch::WindowIdentifier::Address(Address::new(Box::leak(
+            address.to_string().into_boxed_str(),
+        )))
+    }
+
+    /// Dispatch that moves a window to a monitor-relative pixel position
+    pub fn move_pixel_command(address: &str, x: i32, y: i32) -> DispatchType<'static> {
+        DispatchType::MoveWindowPixel(
+            dispatch::Position::Exact(x as i16, y as i16),
+            Self::window_identifier(address),
+        )
+    }
+
+    /// Dispatches that resize a window and then move it (monitor-relative pixels)
+    pub fn resize_and_position_commands(
+        address: &str,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) -> Vec<DispatchType<'static>> {
+        vec![
+            DispatchType::ResizeWindowPixel(
+                dispatch::Position::Exact(width as i16, height as i16),
+                Self::window_identifier(address),
+            ),
+            Self::move_pixel_command(address, x, y),
+        ]
+    }
+
+    /// Dispatch that sets a window's opacity, clamped to 0.0..=1.0. `override` keeps
+    /// it from being multiplied with the global opacity settings
+    pub fn opacity_command(address: &str, opacity: f32) -> DispatchType<'static> {
+        let args = format!(
+            "address:{} alpha override {}",
+            address,
+            opacity.clamp(0.0, 1.0)
+        );
+        DispatchType::Custom("setprop", Box::leak(args.into_boxed_str()))
+    }
+
     /// Spawn a new application
-    pub async fn spawn_app(&self, command: &str) -> hyprland::Result<()> {
+    pub async fn spawn_app(&self, command: &str) -> Result<()> {
         info!("🚀 Spawning application: {}", command);
 
-        let str_command = command.to_string();
-        dispatch!(async; Exec, &str_command).await?;
-
-        Ok(())
+        let command: &'static str = Box::leak(command.to_string().into_boxed_str());
+        self.dispatch(Exec(command)).await
     }
 
     /// Focus a specific window
@@ -319,7 +712,7 @@ impl HyprlandClient {
         Ok(())
     }
 
-    /// Move and resize a window without changing workspace
+    /// Move and resize a window without changing workspace (monitor-relative pixels)
     pub async fn resize_and_position_window(
         &self,
         address: &str,
@@ -333,28 +726,10 @@ impl HyprlandClient {
             address, width, height, x, y
         );
 
-        use hyprland::dispatch::{DispatchType, Position, WindowIdentifier};
-        use hyprland::shared::Address;
-
-        let window_id = WindowIdentifier::Address(Address::new(Box::leak(
-            address.to_string().into_boxed_str(),
-        )));
-
-        // Resize the window using pixel dimensions
-        debug!("📏 Resizing window {} to {}x{}", address, width, height);
-        self.dispatch(DispatchType::ResizeWindowPixel(
-            Position::Exact(width as i16, height as i16),
-            window_id.clone(),
-        ))
-        .await?;
-
-        // Move the window to the specified position using pixel coordinates
-        debug!("📍 Moving window {} to position ({}, {})", address, x, y);
-        self.dispatch(DispatchType::MoveWindowPixel(
-            Position::Exact(x as i16, y as i16),
-            window_id,
-        ))
-        .await?;
+        // Resize first, then move to the pixel position
+        for command in Self::resize_and_position_commands(address, x, y, width, height) {
+            self.dispatch(command).await?;
+        }
 
         Ok(())
     }
@@ -392,24 +767,15 @@ impl HyprlandClient {
         Ok(())
     }
 
-    /// Move window to exact pixel coordinates (for animations)
+    /// Move window to exact pixel coordinates (for animations), relative to the
+    /// monitor it is on like every pixel position this client takes
     pub async fn move_window_pixel(&self, address: &str, x: i32, y: i32) -> Result<()> {
-        use hyprland::dispatch::{DispatchType, Position, WindowIdentifier};
-        use hyprland::shared::Address;
-
-        let window_id = WindowIdentifier::Address(Address::new(Box::leak(
-            address.to_string().into_boxed_str(),
-        )));
-
         debug!(
             "📍 Moving window {} to exact pixel position ({}, {})",
             address, x, y
         );
-        self.dispatch(DispatchType::MoveWindowPixel(
-            Position::Exact(x as i16, y as i16),
-            window_id,
-        ))
-        .await?;
+        self.dispatch(Self::move_pixel_command(address, x, y))
+            .await?;
 
         Ok(())
     }
@@ -440,19 +806,8 @@ impl HyprlandClient {
     pub async fn set_window_opacity(&self, address: &str, opacity: f32) -> Result<()> {
         debug!("🌟 Setting window {} opacity to {}", address, opacity);
 
-        let address = address.to_string();
-
-        // Clamp opacity between 0.0 and 1.0 to prevent issues
-        let clamped_opacity = opacity.clamp(0.0, 1.0);
-
-        // Use setprop with alpha expecting float value between 0.0 and 1.0
-        // Adding 'override' flag to prevent multiplication with global opacity settings
-        let args = format!("address:{} alpha override {}", address, clamped_opacity);
-        self.dispatch(DispatchType::Custom(
-            "setprop",
-            Box::leak(args.into_boxed_str()),
-        ))
-        .await?;
+        self.dispatch(Self::opacity_command(address, opacity))
+            .await?;
 
         Ok(())
     }
@@ -461,7 +816,7 @@ impl HyprlandClient {
     pub async fn get_window_properties(&self, address: &str) -> Result<WindowProperties> {
         debug!("🔍 Getting properties for window {}", address);
 
-        let clients = with_hyprland_timeout(Clients::get).await?;
+        let clients = self.api.clients().await?;
 
         for client in clients.iter() {
             if client.address.to_string() == address {
@@ -482,10 +837,7 @@ impl HyprlandClient {
     pub async fn get_monitors(&self) -> Result<Vec<Monitor>> {
         debug!("🖥️ Getting monitors information");
 
-        let monitors = with_hyprland_timeout(Monitors::get).await?;
-
-        use hyprland::shared::HyprDataVec;
-        Ok(monitors.to_vec())
+        self.api.monitors().await
     }
 
     /// Find windows by class name
@@ -493,7 +845,7 @@ impl HyprlandClient {
         debug!("🔍 Finding windows with class: {}", class);
 
         let target_class = class.to_string();
-        let clients = with_hyprland_timeout(Clients::get).await?;
+        let clients = self.api.clients().await?;
 
         let matching_windows: Vec<Client> = clients
             .into_iter()
@@ -513,7 +865,7 @@ impl HyprlandClient {
         debug!("🔍 Getting window info for: {}", address);
 
         let target_address = address.to_string();
-        let clients = tokio::task::spawn_blocking(Clients::get).await??;
+        let clients = self.api.clients().await?;
 
         for client in clients {
             if client.address.to_string() == target_address {
@@ -528,15 +880,35 @@ impl HyprlandClient {
     pub async fn get_windows(&self) -> Result<Vec<Client>> {
         debug!("🪟 Getting all windows");
 
-        let clients = with_hyprland_timeout(Clients::get).await?;
-        use hyprland::shared::HyprDataVec;
-        Ok(clients.to_vec())
+        self.api.clients().await
     }
 
-    /// Get current active workspace
+    /// Get the monitor the user is looking at, queried live (not cached)
+    pub async fn get_focused_monitor(&self) -> Result<MonitorInfo> {
+        let monitors = self.get_monitors().await?;
+        focused_monitor(&monitors)
+            .map(MonitorInfo::from)
+            .ok_or_else(|| anyhow::anyhow!("No focused monitor"))
+    }
+
+    /// Get the active workspace of the focused monitor
     pub async fn get_active_workspace(&self) -> Result<String> {
         debug!("🖥️ Getting active workspace");
 
+        match self.get_focused_monitor().await {
+            Ok(monitor) => {
+                debug!(
+                    "✅ Found active workspace: {} on focused monitor: {}",
+                    monitor.active_workspace_id, monitor.name
+                );
+                return Ok(monitor.active_workspace_id.to_string());
+            }
+            Err(e) => debug!(
+                "No focused monitor ({}), asking for the active workspace",
+                e
+            ),
+        }
+
         let active_workspace = with_hyprland_timeout(|| {
             use hyprland::data::Workspace;
             use hyprland::shared::HyprDataActive;
@@ -704,3 +1076,131 @@ impl HyprlandClient {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_monitor(name: &str, focused: bool, workspace: i32) -> Monitor {
+        serde_json::from_value(serde_json::json!({
+            "id": 0,
+            "name": name,
+            "description": "",
+            "width": 1920,
+            "height": 1080,
+            "refreshRate": 60.0,
+            "x": 0,
+            "y": 0,
+            "activeWorkspace": { "id": workspace, "name": workspace.to_string() },
+            "specialWorkspace": { "id": 0, "name": "" },
+            "reserved": [0, 30, 0, 0],
+            "scale": 1.0,
+            "transform": 0,
+            "focused": focused,
+            "dpmsStatus": true,
+            "vrr": false,
+            "disabled": false
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_focused_monitor_gives_its_active_workspace() {
+        let monitors = vec![
+            test_monitor("DP-1", false, 1),
+            test_monitor("HDMI-A-1", true, 4),
+        ];
+
+        let focused = MonitorInfo::from(focused_monitor(&monitors).unwrap());
+        assert_eq!(focused.name, "HDMI-A-1");
+        assert_eq!(focused.active_workspace_id, 4);
+        assert_eq!(focused.reserved_top, 30);
+
+        let unfocused = vec![test_monitor("DP-1", false, 1)];
+        assert!(focused_monitor(&unfocused).is_none());
+    }
+
+    #[test]
+    fn test_batch_entries_for_animation_frames() {
+        let mut entries: Vec<Option<String>> =
+            HyprlandClient::resize_and_position_commands("0x1", 10, -20, 800, 600)
+                .iter()
+                .map(batch_entry)
+                .collect();
+        entries.push(batch_entry(&HyprlandClient::opacity_command("0x1", 1.5)));
+
+        assert_eq!(
+            entries,
+            vec![
+                Some("dispatch resizewindowpixel exact 800 600,address:0x1".to_string()),
+                Some("dispatch movewindowpixel exact 10 -20,address:0x1".to_string()),
+                Some("dispatch setprop address:0x1 alpha override 1".to_string()),
+            ]
+        );
+
+        // Dispatchers without a batch form go out individually, as do those a
+        // one-by-one retry of a partly applied batch would run twice
+        assert_eq!(batch_entry(&DispatchType::Exit), None);
+        for command in [
+            DispatchType::ToggleFloating(Some(HyprlandClient::window_identifier("0x1"))),
+            DispatchType::CloseWindow(HyprlandClient::window_identifier("0x1")),
+            DispatchType::FocusWindow(HyprlandClient::window_identifier("0x1")),
+            DispatchType::MoveWindowPixel(
+                dispatch::Position::Delta(10, 0),
+                HyprlandClient::window_identifier("0x1"),
+            ),
+        ] {
+            assert_eq!(batch_entry(&command), None, "{command:?}");
+            assert!(dispatch_entry(&command).is_some(), "{command:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_reads_but_does_not_dispatch() {
+        use mock::{monitor, window, MockHyprland};
+
+        let api = Arc::new(MockHyprland::new(
+            vec![window("0x1", "foot", (0, 0), 1)],
+            vec![monitor("DP-1", (1920, 1080), true, 1)],
+        ));
+        let client = HyprlandClient::with_api(Arc::new(DryRunHyprland::new(api.clone())));
+
+        assert_eq!(client.get_windows().await.unwrap().len(), 1);
+        client.spawn_app("foot").await.unwrap();
+        client.focus_window("0x1").await.unwrap();
+        client
+            .dispatch_batch(HyprlandClient::resize_and_position_commands(
+                "0x1", 10, 20, 800, 600,
+            ))
+            .await
+            .unwrap();
+        assert!(api.calls().is_empty());
+
+        assert_eq!(dispatch_line(&Exec("foot")), "dispatch exec foot");
+        assert_eq!(
+            dispatch_line(&DispatchType::Custom("alterzorder", "top")),
+            "dispatch alterzorder top"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_event_listener_stops_on_shutdown() {
+        let client = HyprlandClient::new().await.unwrap();
+        client.create_event_listener().await.unwrap();
+
+        timeout(Duration::from_secs(1), client.shutdown_event_listener())
+            .await
+            .expect("listener did not stop after the shutdown signal");
+        assert!(client.event_listener.lock().await.is_none());
+
+        // The task dropped its sender: the channel drains and then closes
+        timeout(Duration::from_secs(1), async {
+            while client.get_next_event().await.is_ok() {}
+        })
+        .await
+        .expect("event channel stayed open");
+
+        // Shutting down again is a no-op
+        client.shutdown_event_listener().await;
+    }
+}
@@ -1,4 +1,9 @@
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::UnixStream;
+
+use crate::plugins::CommandOutcome;
 
 /// Messages sent from client to daemon
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,6 +16,8 @@ pub enum ClientMessage {
     Hide { scratchpad: String },
     /// Toggle window attachment to scratchpad system
     Attach { scratchpad: String },
+    /// Whether a scratchpad has a visible window ("true"/"false")
+    IsVisible { scratchpad: String },
     /// Show all windows (expose)
     Expose,
     /// Expose with action
@@ -27,7 +34,11 @@ pub enum ClientMessage {
         command: Option<String>,
     },
     /// Monitor management
-    Monitors { command: Option<String> },
+    Monitors {
+        command: Option<String>,
+        #[serde(default)]
+        args: Vec<String>,
+    },
     /// Wallpaper management
     Wallpapers {
         command: Option<String>,
@@ -43,14 +54,73 @@ pub enum ClientMessage {
         command: Option<String>,
         args: Vec<String>,
     },
+    /// Scratchpad management (peek-show, peek-hide, ...)
+    Scratchpads {
+        command: Option<String>,
+        args: Vec<String>,
+    },
+    /// Daemon debugging (last-commands, replay)
+    Debug {
+        command: Option<String>,
+        args: Vec<String>,
+    },
+    /// Config profile (current, switch <name>, clear)
+    Profile {
+        command: Option<String>,
+        args: Vec<String>,
+    },
+    /// Stream Hyprland events (all if `events` is empty) as JSON lines
+    Subscribe {
+        #[serde(default)]
+        events: Vec<String>,
+    },
+    /// Override one plugin config value for this session (`scratchpads.term.animation_duration`)
+    Set { key: String, value: String },
+    /// Full daemon and plugin state as JSON
+    DumpState,
+    /// Animation types and easings configs may use
+    ListEasings,
     /// Reload configuration
     Reload,
     /// Get daemon status
     Status,
+    /// Liveness check, answered without contacting Hyprland
+    Ping,
     /// List available scratchpads
     List,
 }
 
+/// `notify` sub-commands handled by the system notifier; anything else is message text
+pub const NOTIFY_SUBCOMMANDS: [&str; 5] = [
+    "status",
+    "health",
+    "list-sources",
+    "list-parsers",
+    "test-animation",
+];
+
+/// `scratchpads` sub-commands, in the order usage and help list them
+pub const SCRATCHPADS_SUBCOMMANDS: [&str; 18] = [
+    "toggle",
+    "show",
+    "hide",
+    "list",
+    "attach",
+    "cycle",
+    "spawn",
+    "mark",
+    "unmark",
+    "is-visible",
+    "peek-show",
+    "peek-hide",
+    "resize",
+    "geometry",
+    "timers",
+    "cancel-timers",
+    "doctor",
+    "export",
+];
+
 /// Responses sent from daemon to client
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum DaemonResponse {
@@ -58,29 +128,50 @@ pub enum DaemonResponse {
     Success { message: String },
     /// Command failed with error
     Error { message: String },
+    /// Command or arguments not understood; `message` is the usage text
+    Usage { message: String },
     /// Status information
     Status {
         version: String,
         uptime_seconds: u64,
         plugins_loaded: usize,
     },
+    /// Answer to `Ping`; `plugin_count` is `None` while a command holds the plugin manager
+    Pong {
+        version: String,
+        uptime_secs: u64,
+        plugin_count: Option<usize>,
+    },
     /// List of available items
     List { items: Vec<String> },
+    /// Structured state (`dump-state`)
+    State { state: serde_json::Value },
+}
+
+impl From<CommandOutcome> for DaemonResponse {
+    fn from(outcome: CommandOutcome) -> Self {
+        match outcome {
+            CommandOutcome::Ok(message) => DaemonResponse::Success { message },
+            CommandOutcome::Usage(message) => DaemonResponse::Usage { message },
+            CommandOutcome::Error(message) => DaemonResponse::Error { message },
+        }
+    }
 }
 
 impl ClientMessage {
     /// Parse command line arguments into a ClientMessage
     pub fn from_args(command: &str, args: &[String]) -> anyhow::Result<Self> {
         match command {
-            "toggle" => {
-                if let Some(scratchpad) = args.first() {
-                    Ok(ClientMessage::Toggle {
-                        scratchpad: scratchpad.clone(),
-                    })
-                } else {
-                    Err(anyhow::anyhow!("Toggle command requires scratchpad name"))
-                }
-            }
+            "toggle" => Ok(ClientMessage::Toggle {
+                // Empty name: the daemon falls back to the default scratchpad
+                scratchpad: args.first().cloned().unwrap_or_default(),
+            }),
+            "is_visible" | "is-visible" => Ok(ClientMessage::IsVisible {
+                scratchpad: args
+                    .first()
+                    .cloned()
+                    .ok_or_else(|| anyhow::anyhow!("is-visible requires a scratchpad name"))?,
+            }),
             "expose" => {
                 if args.is_empty() {
                     Ok(ClientMessage::Expose)
@@ -122,13 +213,46 @@ impl ClientMessage {
             }),
             "monitors" => Ok(ClientMessage::Monitors {
                 command: args.first().cloned(),
+                args: args.iter().skip(1).map(|s| s.to_string()).collect(),
             }),
             "wallpapers" | "wall" => Ok(ClientMessage::Wallpapers {
                 command: args.first().cloned(),
                 args: args.iter().skip(1).map(|s| s.to_string()).collect(),
             }),
+            "scratchpads" => Ok(ClientMessage::Scratchpads {
+                command: args.first().cloned(),
+                args: args.iter().skip(1).map(|s| s.to_string()).collect(),
+            }),
+            "debug" => Ok(ClientMessage::Debug {
+                command: args.first().cloned(),
+                args: args.iter().skip(1).map(|s| s.to_string()).collect(),
+            }),
+            "profile" => Ok(ClientMessage::Profile {
+                command: args.first().cloned(),
+                args: args.iter().skip(1).map(|s| s.to_string()).collect(),
+            }),
+            // `set scratchpads.term.size 60%` or `set scratchpads term.size 60%`
+            "set" => match args {
+                [key, value] => Ok(ClientMessage::Set {
+                    key: key.clone(),
+                    value: value.clone(),
+                }),
+                [plugin, key, value] => Ok(ClientMessage::Set {
+                    key: format!("{plugin}.{key}"),
+                    value: value.clone(),
+                }),
+                _ => Err(anyhow::anyhow!(
+                    "Usage: set <plugin>.<key> <value> or set <plugin> <key> <value>"
+                )),
+            },
             "reload" => Ok(ClientMessage::Reload),
+            "dump_state" | "dump-state" => Ok(ClientMessage::DumpState),
+            "easings" => Ok(ClientMessage::ListEasings),
+            "subscribe" => Ok(ClientMessage::Subscribe {
+                events: args.to_vec(),
+            }),
             "status" => Ok(ClientMessage::Status),
+            "ping" => Ok(ClientMessage::Ping),
             "list" => Ok(ClientMessage::List),
             _ => Err(anyhow::anyhow!("Unknown command: {}", command)),
         }
@@ -140,3 +264,89 @@ pub fn get_socket_path() -> String {
     let runtime_dir = std::env::var("XDG_RUNTIME_DIR").unwrap_or_else(|_| "/tmp".to_string());
     format!("{runtime_dir}/rustrland.sock")
 }
+
+/// Send `message` and read the daemon's answer
+pub async fn request(
+    message: &ClientMessage,
+    timeout_duration: tokio::time::Duration,
+) -> Result<DaemonResponse> {
+    let mut stream = send_message(message, timeout_duration).await?;
+    read_response(&mut stream, timeout_duration).await
+}
+
+/// Connect to the daemon and send a length-prefixed message
+pub async fn send_message(
+    message: &ClientMessage,
+    timeout_duration: tokio::time::Duration,
+) -> Result<UnixStream> {
+    use tokio::time::timeout;
+
+    let socket_path = get_socket_path();
+    let mut stream = timeout(timeout_duration, UnixStream::connect(&socket_path))
+        .await
+        .map_err(|_| anyhow::anyhow!("Connection timeout after {:?}", timeout_duration))??;
+
+    // Serialize the message
+    let message_data = serde_json::to_vec(&message)?;
+
+    // Send message length + message with timeout
+    let msg_len = (message_data.len() as u32).to_le_bytes();
+    timeout(timeout_duration, stream.write_all(&msg_len))
+        .await
+        .map_err(|_| anyhow::anyhow!("Write timeout after {:?}", timeout_duration))??;
+    timeout(timeout_duration, stream.write_all(&message_data))
+        .await
+        .map_err(|_| anyhow::anyhow!("Write timeout after {:?}", timeout_duration))??;
+
+    Ok(stream)
+}
+
+/// Read a length-prefixed response
+pub async fn read_response(
+    stream: &mut UnixStream,
+    timeout_duration: tokio::time::Duration,
+) -> Result<DaemonResponse> {
+    use tokio::time::timeout;
+
+    // Read response length with timeout
+    let mut len_buf = [0u8; 4];
+    timeout(timeout_duration, stream.read_exact(&mut len_buf))
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "Read timeout while waiting for response length after {:?}",
+                timeout_duration
+            )
+        })??;
+    let response_len = u32::from_le_bytes(len_buf) as usize;
+
+    // Validate response length to prevent DoS
+    if response_len > 1024 * 1024 {
+        // 1MB limit
+        return Err(anyhow::anyhow!(
+            "Response too large: {} bytes",
+            response_len
+        ));
+    }
+
+    // Read response with timeout
+    let mut response_buf = vec![0u8; response_len];
+    timeout(timeout_duration, stream.read_exact(&mut response_buf))
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "Read timeout while waiting for response data after {:?}",
+                timeout_duration
+            )
+        })??;
+
+    // Deserialize response
+    let response: DaemonResponse = serde_json::from_slice(&response_buf)?;
+    Ok(response)
+}
+
+/// What `ping` prints for a `Pong`
+pub fn pong_line(version: &str, uptime_secs: u64, plugin_count: Option<usize>) -> String {
+    let plugins = plugin_count.map_or_else(|| "busy".to_string(), |n| n.to_string());
+    format!("🏓 pong: v{version}, up {uptime_secs}s, plugins: {plugins}")
+}
@@ -2,23 +2,38 @@ use anyhow::Result;
 use std::sync::Arc;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::{UnixListener, UnixStream};
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 use tracing::{debug, error, info, warn};
 
-use crate::core::hot_reload::HotReloadable;
+use crate::config::{profile_path, ConfigSource};
+use crate::core::hot_reload::{HotReloadManager, HotReloadable};
 use crate::core::plugin_manager::PluginManager;
-use crate::ipc::protocol::{get_socket_path, ClientMessage, DaemonResponse};
+use crate::ipc::command_log::{self, CommandLog};
+use crate::ipc::protocol::{get_socket_path, ClientMessage, DaemonResponse, NOTIFY_SUBCOMMANDS};
+use crate::ipc::subscription::{EventFilter, EventStream};
+use crate::plugins::CommandOutcome;
 
 pub struct IpcServer {
     plugin_manager: Arc<RwLock<PluginManager>>,
     start_time: std::time::Instant,
+    command_log: Arc<Mutex<CommandLog>>,
+    config_source: Arc<RwLock<ConfigSource>>,
+    hot_reload: Arc<RwLock<HotReloadManager>>,
 }
 
 impl IpcServer {
-    pub fn new(plugin_manager: Arc<RwLock<PluginManager>>) -> Self {
+    pub fn new(
+        plugin_manager: Arc<RwLock<PluginManager>>,
+        config_source: Arc<RwLock<ConfigSource>>,
+        hot_reload: Arc<RwLock<HotReloadManager>>,
+        start_time: std::time::Instant,
+    ) -> Self {
         Self {
             plugin_manager,
-            start_time: std::time::Instant::now(),
+            start_time,
+            command_log: Arc::new(Mutex::new(CommandLog::default())),
+            config_source,
+            hot_reload,
         }
     }
 
@@ -38,10 +53,20 @@ impl IpcServer {
                 Ok((stream, _)) => {
                     let plugin_manager = Arc::clone(&self.plugin_manager);
                     let start_time = self.start_time;
+                    let command_log = Arc::clone(&self.command_log);
+                    let config_source = Arc::clone(&self.config_source);
+                    let hot_reload = Arc::clone(&self.hot_reload);
 
                     tokio::spawn(async move {
-                        if let Err(e) =
-                            Self::handle_client(stream, plugin_manager, start_time).await
+                        if let Err(e) = Self::handle_client(
+                            stream,
+                            plugin_manager,
+                            start_time,
+                            command_log,
+                            config_source,
+                            hot_reload,
+                        )
+                        .await
                         {
                             warn!("⚠️  Error handling client: {}", e);
                         }
@@ -58,6 +83,9 @@ impl IpcServer {
         mut stream: UnixStream,
         plugin_manager: Arc<RwLock<PluginManager>>,
         start_time: std::time::Instant,
+        command_log: Arc<Mutex<CommandLog>>,
+        config_source: Arc<RwLock<ConfigSource>>,
+        hot_reload: Arc<RwLock<HotReloadManager>>,
     ) -> Result<()> {
         use tokio::time::{timeout, Duration};
 
@@ -98,11 +126,48 @@ impl IpcServer {
         let message: ClientMessage = serde_json::from_slice(&msg_buf)?;
         debug!("📨 Received message: {:?}", message);
 
-        // Process the message
-        let response = Self::process_message(message, plugin_manager, start_time).await;
+        // Subscriptions keep the connection open and stream events until it closes
+        if let ClientMessage::Subscribe { events } = &message {
+            let events = events.clone();
+            return Self::stream_events(stream, message, events, plugin_manager, &command_log)
+                .await;
+        }
 
+        // Process the message; debug commands inspect the log rather than joining it
+        let response = match message {
+            ClientMessage::Debug { command, args } => {
+                Self::process_debug(
+                    command,
+                    args,
+                    plugin_manager,
+                    start_time,
+                    &command_log,
+                    &config_source,
+                    &hot_reload,
+                )
+                .await
+            }
+            message => {
+                let response = Self::process_message(
+                    message.clone(),
+                    plugin_manager,
+                    start_time,
+                    &config_source,
+                    &hot_reload,
+                )
+                .await;
+                command_log.lock().await.record(message, &response);
+                response
+            }
+        };
+
+        Self::write_response(&mut stream, &response).await
+    }
+
+    /// Send a length-prefixed response
+    async fn write_response(stream: &mut UnixStream, response: &DaemonResponse) -> Result<()> {
         // Serialize response
-        let response_data = serde_json::to_vec(&response)?;
+        let response_data = serde_json::to_vec(response)?;
 
         // Send response length + response
         let response_len = (response_data.len() as u32).to_le_bytes();
@@ -113,94 +178,139 @@ impl IpcServer {
         Ok(())
     }
 
+    /// Answer a `Subscribe` like any command, then write matching events as
+    /// newline-delimited JSON until the client disconnects. A subscriber that
+    /// cannot keep up loses the oldest events and is told how many (`Dropped`)
+    async fn stream_events(
+        mut stream: UnixStream,
+        message: ClientMessage,
+        events: Vec<String>,
+        plugin_manager: Arc<RwLock<PluginManager>>,
+        command_log: &Mutex<CommandLog>,
+    ) -> Result<()> {
+        let filter = EventFilter::new(&events);
+        let response = match &filter {
+            Ok(_) if events.is_empty() => DaemonResponse::Success {
+                message: "Subscribed to all events".to_string(),
+            },
+            Ok(_) => DaemonResponse::Success {
+                message: format!("Subscribed to {}", events.join(", ")),
+            },
+            Err(e) => DaemonResponse::Error {
+                message: e.to_string(),
+            },
+        };
+        command_log.lock().await.record(message, &response);
+
+        // Subscribe before answering so no event falls in between
+        let receiver = plugin_manager.read().await.subscribe_events();
+        Self::write_response(&mut stream, &response).await?;
+        let Ok(filter) = filter else {
+            return Ok(());
+        };
+
+        info!("📡 Event subscriber connected");
+        let mut events = EventStream::new(receiver, filter);
+        let (mut reader, mut writer) = stream.into_split();
+        let mut buf = [0u8; 64];
+        loop {
+            tokio::select! {
+                line = events.next_line() => {
+                    let Some(line) = line else { break };
+                    if writer.write_all(line.as_bytes()).await.is_err() {
+                        break;
+                    }
+                }
+                read = reader.read(&mut buf) => {
+                    if matches!(read, Ok(0) | Err(_)) {
+                        break;
+                    }
+                }
+            }
+        }
+
+        info!(
+            "📡 Event subscriber disconnected ({} events dropped)",
+            events.dropped()
+        );
+        Ok(())
+    }
+
     async fn process_message(
         message: ClientMessage,
         plugin_manager: Arc<RwLock<PluginManager>>,
         start_time: std::time::Instant,
+        config_source: &RwLock<ConfigSource>,
+        hot_reload: &RwLock<HotReloadManager>,
     ) -> DaemonResponse {
         match message {
             ClientMessage::Toggle { scratchpad } => {
                 debug!("🔄 Processing toggle for scratchpad: {}", scratchpad);
                 let mut pm = plugin_manager.write().await;
 
-                match pm
-                    .handle_command("scratchpads", "toggle", &[&scratchpad])
-                    .await
-                {
-                    Ok(result) => DaemonResponse::Success { message: result },
-                    Err(e) => DaemonResponse::Error {
-                        message: e.to_string(),
-                    },
-                }
+                CommandOutcome::from(
+                    pm.handle_command("scratchpads", "toggle", &[&scratchpad])
+                        .await,
+                )
+                .into()
             }
 
             ClientMessage::Show { scratchpad } => {
                 debug!("👁️ Processing show for scratchpad: {}", scratchpad);
                 let mut pm = plugin_manager.write().await;
 
-                match pm
-                    .handle_command("scratchpads", "show", &[&scratchpad])
-                    .await
-                {
-                    Ok(result) => DaemonResponse::Success { message: result },
-                    Err(e) => DaemonResponse::Error {
-                        message: e.to_string(),
-                    },
-                }
+                CommandOutcome::from(
+                    pm.handle_command("scratchpads", "show", &[&scratchpad])
+                        .await,
+                )
+                .into()
             }
 
             ClientMessage::Hide { scratchpad } => {
                 debug!("🙈 Processing hide for scratchpad: {}", scratchpad);
                 let mut pm = plugin_manager.write().await;
 
-                match pm
-                    .handle_command("scratchpads", "hide", &[&scratchpad])
-                    .await
-                {
-                    Ok(result) => DaemonResponse::Success { message: result },
-                    Err(e) => DaemonResponse::Error {
-                        message: e.to_string(),
-                    },
-                }
+                CommandOutcome::from(
+                    pm.handle_command("scratchpads", "hide", &[&scratchpad])
+                        .await,
+                )
+                .into()
+            }
+
+            ClientMessage::IsVisible { scratchpad } => {
+                debug!("🔎 Processing is-visible for scratchpad: {}", scratchpad);
+                let mut pm = plugin_manager.write().await;
+
+                CommandOutcome::from(
+                    pm.handle_command("scratchpads", "is-visible", &[&scratchpad])
+                        .await,
+                )
+                .into()
             }
 
             ClientMessage::Attach { scratchpad } => {
                 debug!("📌 Processing attach for scratchpad: {}", scratchpad);
                 let mut pm = plugin_manager.write().await;
 
-                match pm
-                    .handle_command("scratchpads", "attach", &[&scratchpad])
-                    .await
-                {
-                    Ok(result) => DaemonResponse::Success { message: result },
-                    Err(e) => DaemonResponse::Error {
-                        message: e.to_string(),
-                    },
-                }
+                CommandOutcome::from(
+                    pm.handle_command("scratchpads", "attach", &[&scratchpad])
+                        .await,
+                )
+                .into()
             }
 
             ClientMessage::Expose => {
                 debug!("🪟 Processing expose command");
                 let mut pm = plugin_manager.write().await;
 
-                match pm.handle_command("expose", "toggle", &[]).await {
-                    Ok(result) => DaemonResponse::Success { message: result },
-                    Err(e) => DaemonResponse::Error {
-                        message: e.to_string(),
-                    },
-                }
+                CommandOutcome::from(pm.handle_command("expose", "toggle", &[]).await).into()
             }
 
             ClientMessage::ExposeAction { action } => {
                 debug!("🪟 Processing expose action: {}", action);
                 let mut pm = plugin_manager.write().await;
 
-                match pm.handle_command("expose", &action, &[]).await {
-                    Ok(result) => DaemonResponse::Success { message: result },
-                    Err(e) => DaemonResponse::Error {
-                        message: e.to_string(),
-                    },
-                }
+                CommandOutcome::from(pm.handle_command("expose", &action, &[]).await).into()
             }
 
             ClientMessage::WorkspaceAction { action, arg } => {
@@ -208,15 +318,11 @@ impl IpcServer {
                 let mut pm = plugin_manager.write().await;
 
                 let args: Vec<&str> = arg.as_ref().map(|s| vec![s.as_str()]).unwrap_or_default();
-                match pm
-                    .handle_command("workspaces_follow_focus", &action, &args)
-                    .await
-                {
-                    Ok(result) => DaemonResponse::Success { message: result },
-                    Err(e) => DaemonResponse::Error {
-                        message: e.to_string(),
-                    },
-                }
+                CommandOutcome::from(
+                    pm.handle_command("workspaces_follow_focus", &action, &args)
+                        .await,
+                )
+                .into()
             }
 
             ClientMessage::MagnifyAction { action, arg } => {
@@ -224,12 +330,7 @@ impl IpcServer {
                 let mut pm = plugin_manager.write().await;
 
                 let args: Vec<&str> = arg.as_ref().map(|s| vec![s.as_str()]).unwrap_or_default();
-                match pm.handle_command("magnify", &action, &args).await {
-                    Ok(result) => DaemonResponse::Success { message: result },
-                    Err(e) => DaemonResponse::Error {
-                        message: e.to_string(),
-                    },
-                }
+                CommandOutcome::from(pm.handle_command("magnify", &action, &args).await).into()
             }
 
             ClientMessage::ShiftMonitors { direction } => {
@@ -237,15 +338,11 @@ impl IpcServer {
                 let mut pm = plugin_manager.write().await;
 
                 let direction_str = direction.as_deref().unwrap_or("+1");
-                match pm
-                    .handle_command("shift_monitors", direction_str, &[])
-                    .await
-                {
-                    Ok(result) => DaemonResponse::Success { message: result },
-                    Err(e) => DaemonResponse::Error {
-                        message: e.to_string(),
-                    },
-                }
+                CommandOutcome::from(
+                    pm.handle_command("shift_monitors", direction_str, &[])
+                        .await,
+                )
+                .into()
             }
 
             ClientMessage::ToggleSpecial {
@@ -258,8 +355,13 @@ impl IpcServer {
                 );
                 let mut pm = plugin_manager.write().await;
 
-                let workspace = workspace_name.as_deref().unwrap_or("special");
-                let cmd = command.as_deref().unwrap_or("");
+                let mut workspace = workspace_name.as_deref().unwrap_or("special");
+                let mut cmd = command.as_deref().unwrap_or("");
+                // `toggle-special list` and `toggle-special status` are commands
+                if cmd.is_empty() && matches!(workspace, "list" | "status") {
+                    cmd = workspace;
+                    workspace = "special";
+                }
 
                 let args: Vec<&str> = if workspace != "special" {
                     vec![workspace]
@@ -267,25 +369,16 @@ impl IpcServer {
                     vec![]
                 };
 
-                match pm.handle_command("toggle_special", cmd, &args).await {
-                    Ok(result) => DaemonResponse::Success { message: result },
-                    Err(e) => DaemonResponse::Error {
-                        message: e.to_string(),
-                    },
-                }
+                CommandOutcome::from(pm.handle_command("toggle_special", cmd, &args).await).into()
             }
 
-            ClientMessage::Monitors { command } => {
-                debug!("🖥️  Processing monitors command: {:?}", command);
+            ClientMessage::Monitors { command, args } => {
+                debug!("🖥️  Processing monitors command: {:?} {:?}", command, args);
                 let mut pm = plugin_manager.write().await;
 
                 let cmd = command.as_deref().unwrap_or("relayout");
-                match pm.handle_command("monitors", cmd, &[]).await {
-                    Ok(result) => DaemonResponse::Success { message: result },
-                    Err(e) => DaemonResponse::Error {
-                        message: e.to_string(),
-                    },
-                }
+                let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+                CommandOutcome::from(pm.handle_command("monitors", cmd, &args_refs).await).into()
             }
 
             ClientMessage::Wallpapers { command, args } => {
@@ -297,12 +390,7 @@ impl IpcServer {
 
                 let cmd = command.as_deref().unwrap_or("next");
                 let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-                match pm.handle_command("wallpapers", cmd, &args_refs).await {
-                    Ok(result) => DaemonResponse::Success { message: result },
-                    Err(e) => DaemonResponse::Error {
-                        message: e.to_string(),
-                    },
-                }
+                CommandOutcome::from(pm.handle_command("wallpapers", cmd, &args_refs).await).into()
             }
 
             ClientMessage::Notify { command, args } => {
@@ -312,9 +400,7 @@ impl IpcServer {
                 // Handle the command structure correctly
                 let (cmd, final_args): (&str, Vec<&str>) = if let Some(ref cmd_str) = command {
                     // Check if this looks like a system_notifier command
-                    if ["status", "list-sources", "list-parsers", "test-animation"]
-                        .contains(&cmd_str.as_str())
-                    {
+                    if NOTIFY_SUBCOMMANDS.contains(&cmd_str.as_str()) {
                         // This is a system_notifier subcommand
                         (cmd_str.as_str(), args.iter().map(|s| s.as_str()).collect())
                     } else {
@@ -328,12 +414,8 @@ impl IpcServer {
                     ("notify", args.iter().map(|s| s.as_str()).collect())
                 };
 
-                match pm.handle_command("system_notifier", cmd, &final_args).await {
-                    Ok(result) => DaemonResponse::Success { message: result },
-                    Err(e) => DaemonResponse::Error {
-                        message: e.to_string(),
-                    },
-                }
+                CommandOutcome::from(pm.handle_command("system_notifier", cmd, &final_args).await)
+                    .into()
             }
 
             ClientMessage::LostWindows { command, args } => {
@@ -345,19 +427,57 @@ impl IpcServer {
 
                 let cmd = command.as_deref().unwrap_or("status");
                 let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-                match pm.handle_command("lost_windows", cmd, &args_refs).await {
-                    Ok(result) => DaemonResponse::Success { message: result },
+                CommandOutcome::from(pm.handle_command("lost_windows", cmd, &args_refs).await)
+                    .into()
+            }
+
+            ClientMessage::Scratchpads { command, args } => {
+                debug!(
+                    "🪟 Processing scratchpads command: {:?} {:?}",
+                    command, args
+                );
+                let mut pm = plugin_manager.write().await;
+
+                let cmd = command.as_deref().unwrap_or("list");
+                let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+                CommandOutcome::from(pm.handle_command("scratchpads", cmd, &args_refs).await).into()
+            }
+
+            ClientMessage::Debug { .. } => DaemonResponse::Error {
+                message: "Debug commands cannot be replayed".to_string(),
+            },
+
+            ClientMessage::Subscribe { .. } => DaemonResponse::Error {
+                message: "Subscriptions cannot be replayed".to_string(),
+            },
+
+            ClientMessage::Reload => {
+                debug!("⚡ Processing reload command");
+                match hot_reload.read().await.reload_now().await {
+                    Ok(summary) => DaemonResponse::Success {
+                        message: summary.message(),
+                    },
                     Err(e) => DaemonResponse::Error {
                         message: e.to_string(),
                     },
                 }
             }
 
-            ClientMessage::Reload => {
-                debug!("⚡ Processing reload command");
+            ClientMessage::Profile { command, args } => {
+                debug!("👤 Processing profile command: {:?} {:?}", command, args);
+                match Self::handle_profile(command, args, config_source, hot_reload).await {
+                    Ok(message) => DaemonResponse::Success { message },
+                    Err(e) => DaemonResponse::Error {
+                        message: e.to_string(),
+                    },
+                }
+            }
+
+            ClientMessage::Set { key, value } => {
+                debug!("🎛️ Processing set command: {} = {}", key, value);
                 let mut pm = plugin_manager.write().await;
 
-                match Self::handle_manual_reload(&mut pm).await {
+                match pm.override_config_value(&key, &value).await {
                     Ok(message) => DaemonResponse::Success { message },
                     Err(e) => DaemonResponse::Error {
                         message: e.to_string(),
@@ -365,6 +485,32 @@ impl IpcServer {
                 }
             }
 
+            ClientMessage::DumpState => {
+                debug!("🗃️ Processing dump-state command");
+                let pm = plugin_manager.read().await;
+                let cache = pm.get_global_cache().get_memory_stats().await;
+
+                DaemonResponse::State {
+                    state: serde_json::json!({
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "uptime_seconds": start_time.elapsed().as_secs(),
+                        "cache": cache,
+                        "plugins": pm.dump_state(),
+                    }),
+                }
+            }
+
+            ClientMessage::ListEasings => {
+                debug!("🎬 Processing easings command");
+                let mut message = String::from("🎬 Animation types and easings:\n");
+                for (group, names) in crate::animation::supported_animations() {
+                    message.push_str(&format!("  {group}: {}\n", names.join(", ")));
+                }
+                message.push_str("  custom easing: cubic-bezier(x1, y1, x2, y2)");
+
+                DaemonResponse::Success { message }
+            }
+
             ClientMessage::Status => {
                 debug!("📊 Processing status command");
                 let uptime = start_time.elapsed().as_secs();
@@ -380,6 +526,22 @@ impl IpcServer {
                 }
             }
 
+            ClientMessage::Ping => {
+                debug!("🏓 Processing ping");
+                // Never wait on the plugin manager: a command stuck on an
+                // unresponsive Hyprland holds it, and ping must still answer
+                let plugin_count = plugin_manager
+                    .try_read()
+                    .ok()
+                    .map(|pm| pm.get_plugin_count());
+
+                DaemonResponse::Pong {
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                    uptime_secs: start_time.elapsed().as_secs(),
+                    plugin_count,
+                }
+            }
+
             ClientMessage::List => {
                 debug!("📋 Processing list command");
                 let pm = plugin_manager.read().await;
@@ -392,82 +554,238 @@ impl IpcServer {
         }
     }
 
-    /// Handle manual reload request
-    async fn handle_manual_reload(plugin_manager: &mut PluginManager) -> Result<String> {
-        info!("🔄 Manual reload requested");
-
-        // Find config file path (simplified - in real implementation would use the daemon's config path)
-        let config_path = std::env::var("HOME")
-            .map(|home| format!("{home}/.config/hypr/rustrland.toml"))
-            .unwrap_or_else(|_| "rustrland.toml".to_string());
-
-        // Read and parse new configuration
-        let config_content = tokio::fs::read_to_string(&config_path)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to read config file '{}': {}", config_path, e))?;
-
-        let config_value: toml::Value = toml::from_str(&config_content)
-            .map_err(|e| anyhow::anyhow!("Failed to parse config: {}", e))?;
-
-        let new_config = crate::config::Config::from_toml_value(config_value)
-            .map_err(|e| anyhow::anyhow!("Invalid configuration: {}", e))?;
+    /// Handle `debug last-commands [count] [--json] [--raw]` and
+    /// `debug replay [count] --confirm`
+    async fn process_debug(
+        command: Option<String>,
+        args: Vec<String>,
+        plugin_manager: Arc<RwLock<PluginManager>>,
+        start_time: std::time::Instant,
+        command_log: &Mutex<CommandLog>,
+        config_source: &RwLock<ConfigSource>,
+        hot_reload: &RwLock<HotReloadManager>,
+    ) -> DaemonResponse {
+        let has_flag = |flag: &str| args.iter().any(|arg| arg == flag);
+        let count = match args.iter().find(|arg| !arg.starts_with("--")) {
+            Some(count) => match count.parse::<usize>() {
+                Ok(count) => Some(count),
+                Err(_) => {
+                    return DaemonResponse::Error {
+                        message: format!("Invalid command count: {count}"),
+                    }
+                }
+            },
+            None => None,
+        };
 
-        // Get current plugins for comparison
-        let current_plugins = plugin_manager.get_loaded_plugins();
-        let new_plugins = new_config.get_plugins();
+        let commands = command_log.lock().await.last(count);
 
-        info!("🔍 Comparing configurations:");
-        info!("   Current plugins: {:?}", current_plugins);
-        info!("   New plugins: {:?}", new_plugins);
+        match command.as_deref().unwrap_or("last-commands") {
+            "last-commands" => {
+                let commands: Vec<_> = if has_flag("--raw") {
+                    commands
+                } else {
+                    commands.iter().map(|c| c.redacted()).collect()
+                };
 
-        // Perform smart reload
-        let mut reloaded = Vec::new();
-        let mut added = Vec::new();
-        let mut removed = Vec::new();
+                if has_flag("--json") {
+                    return match serde_json::to_string_pretty(&commands) {
+                        Ok(message) => DaemonResponse::Success { message },
+                        Err(e) => DaemonResponse::Error {
+                            message: e.to_string(),
+                        },
+                    };
+                }
+                if commands.is_empty() {
+                    return DaemonResponse::Success {
+                        message: "No commands recorded".to_string(),
+                    };
+                }
 
-        // Find removed plugins
-        for plugin in &current_plugins {
-            if !new_plugins.contains(plugin) {
-                plugin_manager.unload_plugin(plugin).await?;
-                removed.push(plugin.clone());
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|elapsed| elapsed.as_secs())
+                    .unwrap_or(0);
+                let lines: Vec<String> = commands
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        format!(
+                            "{:>3}. {:>5}s ago  {} {}",
+                            i + 1,
+                            now.saturating_sub(c.timestamp),
+                            if c.success { "✅" } else { "❌" },
+                            command_log::describe(&c.message)
+                        )
+                    })
+                    .collect();
+                DaemonResponse::Success {
+                    message: lines.join("\n"),
+                }
             }
-        }
+            "replay" => {
+                // Replaying has side effects: show what would run unless confirmed
+                if !has_flag("--confirm") {
+                    let lines: Vec<String> = commands
+                        .iter()
+                        .map(|c| format!("  {}", command_log::describe(&c.redacted().message)))
+                        .collect();
+                    return DaemonResponse::Error {
+                        message: format!(
+                            "Replay would re-run {} command(s), add --confirm to proceed:\n{}",
+                            commands.len(),
+                            lines.join("\n")
+                        ),
+                    };
+                }
 
-        // Find added plugins
-        for plugin in &new_plugins {
-            if !current_plugins.contains(plugin) {
-                plugin_manager.load_plugin(plugin, &new_config).await?;
-                added.push(plugin.clone());
+                warn!("🔁 Replaying {} logged command(s)", commands.len());
+                let mut lines = Vec::new();
+                for logged in commands {
+                    let description = command_log::describe(&logged.redacted().message);
+                    let response = Self::process_message(
+                        logged.message,
+                        Arc::clone(&plugin_manager),
+                        start_time,
+                        config_source,
+                        hot_reload,
+                    )
+                    .await;
+                    let outcome = match response {
+                        DaemonResponse::Error { message } | DaemonResponse::Usage { message } => {
+                            format!("❌ {message}")
+                        }
+                        _ => "✅".to_string(),
+                    };
+                    lines.push(format!("{description}: {outcome}"));
+                }
+                DaemonResponse::Success {
+                    message: format!("Replayed {} command(s):\n{}", lines.len(), lines.join("\n")),
+                }
             }
+            other => DaemonResponse::Error {
+                message: format!(
+                    "Unknown debug command: {other} (expected last-commands or replay)"
+                ),
+            },
         }
+    }
 
-        // Reload existing plugins (simplified - doesn't check if config actually changed)
-        for plugin in &new_plugins {
-            if current_plugins.contains(plugin) {
-                plugin_manager.reload_plugin(plugin, &new_config).await?;
-                reloaded.push(plugin.clone());
+    /// `profile current|switch <name>|clear`: switching reloads with the new
+    /// profile, and keeps the previous one if that reload fails
+    async fn handle_profile(
+        command: Option<String>,
+        args: Vec<String>,
+        config_source: &RwLock<ConfigSource>,
+        hot_reload: &RwLock<HotReloadManager>,
+    ) -> Result<String> {
+        let profile = match command.as_deref().unwrap_or("current") {
+            "current" => {
+                return Ok(match &config_source.read().await.profile {
+                    Some(profile) => format!("Active profile: {profile}"),
+                    None => "No profile active, using the base config".to_string(),
+                })
             }
+            "switch" => {
+                let name = args
+                    .first()
+                    .ok_or_else(|| anyhow::anyhow!("Usage: profile switch <name>"))?;
+                profile_path(&config_source.read().await.base_path(), name)?;
+                Some(name.clone())
+            }
+            "clear" => None,
+            other => {
+                return Err(anyhow::anyhow!(
+                    "Unknown profile command: {other} (expected current, switch or clear)"
+                ))
+            }
+        };
+
+        let source = ConfigSource {
+            profile: profile.clone(),
+            ..config_source.read().await.clone()
+        };
+        let message = hot_reload
+            .read()
+            .await
+            .reload_from(&source)
+            .await?
+            .message();
+        // Watch the new profile's file rather than the old one's
+        match source.paths() {
+            Ok(paths) => {
+                if let Err(e) = hot_reload.write().await.watch_paths(paths).await {
+                    warn!("⚠️ Failed to watch the new profile's config files: {}", e);
+                }
+            }
+            Err(e) => warn!("⚠️ Failed to resolve the new profile's config files: {}", e),
         }
+        *config_source.write().await = source;
 
-        // Build result message
-        let mut messages = Vec::new();
-
-        if !removed.is_empty() {
-            messages.push(format!("🗑️ Removed: {}", removed.join(", ")));
-        }
-
-        if !added.is_empty() {
-            messages.push(format!("➕ Added: {}", added.join(", ")));
-        }
-
-        if !reloaded.is_empty() {
-            messages.push(format!("🔄 Reloaded: {}", reloaded.join(", ")));
+        match profile {
+            Some(profile) => {
+                info!("👤 Switched to config profile '{}'", profile);
+                Ok(format!("Switched to profile '{profile}'. {message}"))
+            }
+            None => {
+                info!("👤 Cleared config profile, using the base config");
+                Ok(format!("Using the base config. {message}"))
+            }
         }
+    }
+}
 
-        if messages.is_empty() {
-            Ok("✅ Configuration up-to-date, no changes needed".to_string())
-        } else {
-            Ok(format!("✅ Reload complete: {}", messages.join("; ")))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_ping_answers_while_plugin_manager_is_busy() {
+        let plugin_manager = Arc::new(RwLock::new(PluginManager::new()));
+        let config_source = RwLock::new(ConfigSource::new("/nonexistent/rustrland.toml", None));
+        let hot_reload = RwLock::new(HotReloadManager::new(Arc::clone(&plugin_manager)));
+        let start_time = std::time::Instant::now();
+
+        let pong = IpcServer::process_message(
+            ClientMessage::Ping,
+            Arc::clone(&plugin_manager),
+            start_time,
+            &config_source,
+            &hot_reload,
+        )
+        .await;
+        assert!(matches!(
+            pong,
+            DaemonResponse::Pong {
+                plugin_count: Some(0),
+                ..
+            }
+        ));
+
+        // A command waiting on Hyprland holds the write lock; ping must not wait for it
+        let _busy = plugin_manager.write().await;
+        let pong = tokio::time::timeout(
+            std::time::Duration::from_millis(500),
+            IpcServer::process_message(
+                ClientMessage::Ping,
+                Arc::clone(&plugin_manager),
+                start_time,
+                &config_source,
+                &hot_reload,
+            ),
+        )
+        .await
+        .expect("ping blocked on the plugin manager");
+        match pong {
+            DaemonResponse::Pong {
+                version,
+                plugin_count,
+                ..
+            } => {
+                assert_eq!(version, env!("CARGO_PKG_VERSION"));
+                assert_eq!(plugin_count, None);
+            }
+            other => panic!("expected Pong, got {other:?}"),
         }
     }
 }
@@ -0,0 +1,137 @@
+use anyhow::Result;
+use std::collections::HashSet;
+use tokio::sync::broadcast;
+
+use crate::ipc::HyprlandEvent;
+
+/// Events a `Subscribe` client asked for, by variant name; empty means all
+#[derive(Debug, Default)]
+pub struct EventFilter {
+    kinds: HashSet<&'static str>,
+}
+
+impl EventFilter {
+    /// Build a filter from event names (case-insensitive), rejecting unknown ones
+    pub fn new(names: &[String]) -> Result<Self> {
+        let mut kinds = HashSet::new();
+        for name in names {
+            let kind = HyprlandEvent::KINDS
+                .iter()
+                .find(|kind| kind.eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Unknown event '{}', expected one of: {}",
+                        name,
+                        HyprlandEvent::KINDS.join(", ")
+                    )
+                })?;
+            kinds.insert(*kind);
+        }
+        Ok(Self { kinds })
+    }
+
+    pub fn matches(&self, event: &HyprlandEvent) -> bool {
+        self.kinds.is_empty() || self.kinds.contains(event.kind())
+    }
+}
+
+/// Reads a subscriber's events off the daemon's event channel as JSON lines.
+///
+/// Slow consumers are never waited for: once a subscriber falls more than the
+/// channel capacity behind, the oldest events are dropped and the next line is a
+/// `{"event":"Dropped","data":{"count":..,"total":..}}` record instead.
+pub struct EventStream {
+    receiver: broadcast::Receiver<HyprlandEvent>,
+    filter: EventFilter,
+    dropped: u64,
+}
+
+impl EventStream {
+    pub fn new(receiver: broadcast::Receiver<HyprlandEvent>, filter: EventFilter) -> Self {
+        Self {
+            receiver,
+            filter,
+            dropped: 0,
+        }
+    }
+
+    /// Events dropped so far because this subscriber lagged
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Next line to send, newline included, or `None` once the daemon shuts down
+    pub async fn next_line(&mut self) -> Option<String> {
+        loop {
+            let record = match self.receiver.recv().await {
+                Ok(event) if self.filter.matches(&event) => serde_json::to_value(&event).ok()?,
+                Ok(_) => continue,
+                Err(broadcast::error::RecvError::Lagged(count)) => {
+                    self.dropped += count;
+                    serde_json::json!({
+                        "event": "Dropped",
+                        "data": { "count": count, "total": self.dropped },
+                    })
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            };
+            return Some(format!("{record}\n"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn focus(window: &str) -> HyprlandEvent {
+        HyprlandEvent::WindowFocusChanged {
+            window: window.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_filter_matches_requested_kinds_only() {
+        let filter = EventFilter::new(&["windowfocuschanged".to_string()]).unwrap();
+        assert!(filter.matches(&focus("0x1")));
+        assert!(!filter.matches(&HyprlandEvent::WindowOpened {
+            window: "0x1".to_string()
+        }));
+
+        assert!(EventFilter::new(&[]).unwrap().matches(&focus("0x1")));
+        assert!(EventFilter::new(&["WindowFocus".to_string()]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_lagging_subscriber_gets_dropped_count() {
+        let (sender, receiver) = broadcast::channel(2);
+        let filter = EventFilter::new(&["WindowFocusChanged".to_string()]).unwrap();
+        let mut stream = EventStream::new(receiver, filter);
+
+        for i in 0..5 {
+            sender.send(focus(&format!("0x{i}"))).unwrap();
+        }
+
+        assert_eq!(
+            stream.next_line().await.unwrap(),
+            "{\"data\":{\"count\":3,\"total\":3},\"event\":\"Dropped\"}\n"
+        );
+        assert_eq!(
+            stream.next_line().await.unwrap(),
+            "{\"data\":{\"window\":\"0x3\"},\"event\":\"WindowFocusChanged\"}\n"
+        );
+        assert_eq!(stream.dropped(), 3);
+        assert!(stream.next_line().await.unwrap().contains("0x4"));
+
+        // Filtered out events are skipped, not reported as dropped
+        sender
+            .send(HyprlandEvent::Other("heartbeat".to_string()))
+            .unwrap();
+        sender.send(focus("0x5")).unwrap();
+        assert!(stream.next_line().await.unwrap().contains("0x5"));
+        assert_eq!(stream.dropped(), 3);
+
+        drop(sender);
+        assert!(stream.next_line().await.is_none());
+    }
+}
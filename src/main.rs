@@ -1,7 +1,8 @@
 #![allow(dead_code, unused_imports)]
 
 use anyhow::Result;
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::time::Duration;
 use tracing::{error, info};
 
 mod animation;
@@ -11,7 +12,10 @@ mod core;
 mod ipc;
 mod plugins;
 
+use crate::config::ConfigSource;
+use crate::core::config_check::check_config;
 use crate::core::daemon::Daemon;
+use crate::ipc::protocol::{pong_line, request, ClientMessage, DaemonResponse};
 
 #[derive(Parser)]
 #[command(name = "rustrland")]
@@ -22,6 +26,10 @@ struct Cli {
     #[arg(short, long, default_value = "~/.config/hypr/rustrland.toml")]
     config: String,
 
+    /// Config profile: merge `rustrland.<PROFILE>.toml`, next to the config file, over it
+    #[arg(short, long)]
+    profile: Option<String>,
+
     /// Enable debug logging
     #[arg(short, long)]
     debug: bool,
@@ -33,6 +41,94 @@ struct Cli {
     /// Run in foreground (don't daemonize)
     #[arg(short, long)]
     foreground: bool,
+
+    /// Log the hyprctl commands the daemon would run instead of running them
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Log output format
+    #[arg(long, value_enum, default_value_t = LogFormat::Text)]
+    log_format: LogFormat,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// How log lines are written to stderr
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum LogFormat {
+    /// Human-readable lines
+    Text,
+    /// One JSON object per event, with its fields and current span, for journald/loki
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Validate the configuration and exit, non-zero if it has errors
+    CheckConfig,
+    /// Ask the running daemon for its version, uptime and plugin count; non-zero if it does not answer
+    Ping,
+}
+
+/// How long `rustrland ping` waits for the daemon before reporting it down
+const PING_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Print the daemon's pong; false when it is unreachable or answers something else
+async fn run_ping() -> bool {
+    match tokio::time::timeout(PING_TIMEOUT, request(&ClientMessage::Ping, PING_TIMEOUT)).await {
+        Ok(Ok(DaemonResponse::Pong {
+            version,
+            uptime_secs,
+            plugin_count,
+        })) => {
+            println!("{}", pong_line(&version, uptime_secs, plugin_count));
+            true
+        }
+        Ok(Ok(other)) => {
+            println!("❌ Unexpected response: {other:?}");
+            false
+        }
+        Ok(Err(e)) => {
+            println!("❌ Daemon not reachable: {e}");
+            false
+        }
+        Err(_) => {
+            println!("❌ Daemon did not answer within {PING_TIMEOUT:?}");
+            false
+        }
+    }
+}
+
+/// Print every problem found in the configuration; true when there is no error
+async fn run_check_config(source: &ConfigSource) -> bool {
+    let config = match source.load().await {
+        Ok(config) => config,
+        Err(e) => {
+            println!("error: {e}");
+            return false;
+        }
+    };
+
+    let report = check_config(&config);
+    for issue in &report.errors {
+        println!("error: {}: {}", issue.key, issue.message);
+    }
+    for issue in &report.warnings {
+        println!("warning: {}: {}", issue.key, issue.message);
+    }
+
+    let summary = format!(
+        "{} error(s), {} warning(s)",
+        report.errors.len(),
+        report.warnings.len()
+    );
+    if report.has_errors() {
+        println!("❌ {summary}");
+    } else {
+        println!("✅ Configuration is valid: {summary}");
+    }
+    !report.has_errors()
 }
 
 #[tokio::main]
@@ -42,16 +138,34 @@ async fn main() -> Result<()> {
     // Setup logging
     let log_level = if cli.debug {
         "debug"
-    } else if cli.verbose {
+    } else if cli.verbose || cli.dry_run {
         "info"
     } else {
         "warn"
     };
 
-    tracing_subscriber::fmt()
+    let subscriber = tracing_subscriber::fmt()
         .with_env_filter(format!("rustrland={log_level}"))
-        .with_target(false)
-        .init();
+        .with_target(false);
+    match cli.log_format {
+        LogFormat::Text => subscriber.init(),
+        LogFormat::Json => subscriber.json().with_current_span(true).init(),
+    }
+
+    if let Some(Command::Ping) = cli.command {
+        if !run_ping().await {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if let Some(Command::CheckConfig) = cli.command {
+        let source = ConfigSource::new(&cli.config, cli.profile);
+        if !run_check_config(&source).await {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
     info!("🦀 Starting Rustrland v{}", env!("CARGO_PKG_VERSION"));
 
@@ -62,7 +176,7 @@ async fn main() -> Result<()> {
     }
 
     // Create and run daemon
-    match Daemon::new(&cli.config).await {
+    match Daemon::new(&cli.config, cli.profile, cli.dry_run).await {
         Ok(mut daemon) => {
             if let Err(e) = daemon.run().await {
                 error!("❌ Daemon error: {}", e);
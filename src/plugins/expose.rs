@@ -6,14 +6,17 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 use tracing::{debug, info, warn};
 
+use crate::animation::{AnimationConfig, EasingFunction, WindowAnimator};
 use crate::core::GlobalStateCache;
-use crate::ipc::{HyprlandClient, HyprlandEvent};
-use crate::plugins::Plugin;
+use crate::ipc::{HyprlandClient, HyprlandEvent, MonitorInfo};
+use crate::plugins::{Plugin, PluginContext};
 
 use hyprland::data::{Client, Clients, Workspaces};
+use hyprland::dispatch::{DispatchType, WindowIdentifier, WorkspaceIdentifierWithSpecial};
+use hyprland::shared::Address;
 use hyprland::shared::{HyprData, HyprDataVec};
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ExposeConfig {
     /// Include windows from special workspaces (default: false)
     #[serde(default)]
@@ -26,6 +29,181 @@ pub struct ExposeConfig {
     /// Enable debug logging (default: false)
     #[serde(default)]
     pub debug_logging: bool,
+
+    /// Grid layout: "auto" picks a near-square grid from the window count,
+    /// "columns" uses `columns` (default: `columns` if set, otherwise auto)
+    #[serde(default)]
+    pub layout: Option<String>,
+
+    /// Number of grid columns
+    #[serde(default)]
+    pub columns: Option<u32>,
+
+    /// Space between grid cells and around the grid, in pixels (default: 20)
+    #[serde(default = "default_gap")]
+    pub gap: u32,
+
+    /// Tiling animation duration in milliseconds (default: 250)
+    #[serde(default = "default_animation_duration")]
+    pub animation_duration: u32,
+
+    /// Tiling animation easing (default: "ease-out-cubic")
+    #[serde(default = "default_animation_easing")]
+    pub animation_easing: String,
+}
+
+fn default_gap() -> u32 {
+    20
+}
+fn default_animation_duration() -> u32 {
+    250
+}
+fn default_animation_easing() -> String {
+    "ease-out-cubic".to_string()
+}
+
+impl Default for ExposeConfig {
+    fn default() -> Self {
+        Self {
+            include_special: false,
+            target_monitor: None,
+            debug_logging: false,
+            layout: None,
+            columns: None,
+            gap: default_gap(),
+            animation_duration: default_animation_duration(),
+            animation_easing: default_animation_easing(),
+        }
+    }
+}
+
+impl ExposeConfig {
+    /// Fixed number of grid columns, or None for the near-square auto layout
+    pub fn grid_columns(&self) -> Result<Option<u32>> {
+        match self.layout.as_deref() {
+            None => Ok(self.columns.filter(|columns| *columns > 0)),
+            Some("auto") => Ok(None),
+            Some("columns") => match self.columns {
+                Some(columns) if columns > 0 => Ok(Some(columns)),
+                _ => Err(anyhow::anyhow!(
+                    "layout = \"columns\" requires columns of at least 1"
+                )),
+            },
+            Some(other) => Err(anyhow::anyhow!(
+                "Unknown layout '{}' (expected \"auto\" or \"columns\")",
+                other
+            )),
+        }
+    }
+}
+
+/// Rectangle in monitor-relative pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridCell {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl GridCell {
+    /// `(position, size)` as taken by `WindowAnimator::animate_geometry`
+    fn geometry(&self) -> ((i32, i32), (i32, i32)) {
+        ((self.x, self.y), (self.width, self.height))
+    }
+
+    /// Part of a monitor not reserved by bars and other layer surfaces
+    fn usable_area(monitor: &MonitorInfo) -> Self {
+        let (left, right) = (monitor.reserved_left as i32, monitor.reserved_right as i32);
+        let (top, bottom) = (monitor.reserved_top as i32, monitor.reserved_bottom as i32);
+        Self {
+            x: left,
+            y: top,
+            width: monitor.width as i32 - left - right,
+            height: monitor.height as i32 - top - bottom,
+        }
+    }
+}
+
+/// Grid `(columns, rows)` for `count` windows: `columns` when set, otherwise
+/// the most square grid that fits them
+pub fn grid_dimensions(count: usize, columns: Option<u32>) -> (usize, usize) {
+    if count == 0 {
+        return (0, 0);
+    }
+    let columns = match columns {
+        Some(columns) if columns > 0 => (columns as usize).min(count),
+        _ => (count as f64).sqrt().ceil() as usize,
+    };
+    (columns, count.div_ceil(columns))
+}
+
+/// Cells for windows of the given sizes inside `area`, in reading order, with a
+/// short last row centered. A single window keeps its size (shrunk to fit if
+/// needed) and is centered rather than stretched over the whole area
+pub fn layout_cells(
+    sizes: &[(i32, i32)],
+    columns: Option<u32>,
+    area: GridCell,
+    gap: i32,
+) -> Vec<GridCell> {
+    if let [(width, height)] = sizes {
+        let width = (*width).min(area.width - 2 * gap).max(1);
+        let height = (*height).min(area.height - 2 * gap).max(1);
+        return vec![GridCell {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        }];
+    }
+
+    let (columns, rows) = grid_dimensions(sizes.len(), columns);
+    if columns == 0 {
+        return Vec::new();
+    }
+    let cell_width = ((area.width - gap * (columns as i32 + 1)) / columns as i32).max(1);
+    let cell_height = ((area.height - gap * (rows as i32 + 1)) / rows as i32).max(1);
+
+    (0..sizes.len())
+        .map(|index| {
+            let (row, column) = (index / columns, index % columns);
+            let in_row = (sizes.len() - row * columns).min(columns);
+            let row_offset = (columns - in_row) as i32 * (cell_width + gap) / 2;
+            GridCell {
+                x: area.x + gap + row_offset + column as i32 * (cell_width + gap),
+                y: area.y + gap + row as i32 * (cell_height + gap),
+                width: cell_width,
+                height: cell_height,
+            }
+        })
+        .collect()
+}
+
+/// Grid cell of each window, given as `(monitor id, size)`: every monitor gets
+/// its own grid, filled in the windows' order. None for windows whose monitor
+/// is unknown
+pub fn plan_grid(
+    windows: &[(i128, (i32, i32))],
+    monitors: &[MonitorInfo],
+    columns: Option<u32>,
+    gap: i32,
+) -> Vec<Option<GridCell>> {
+    let mut cells = vec![None; windows.len()];
+    for monitor in monitors {
+        let members: Vec<usize> = (0..windows.len())
+            .filter(|&index| windows[index].0 == monitor.id)
+            .collect();
+        let sizes: Vec<(i32, i32)> = members.iter().map(|&index| windows[index].1).collect();
+        let area = GridCell::usable_area(monitor);
+        for (index, cell) in members
+            .into_iter()
+            .zip(layout_cells(&sizes, columns, area, gap))
+        {
+            cells[index] = Some(cell);
+        }
+    }
+    cells
 }
 
 #[derive(Debug, Clone)]
@@ -34,6 +212,24 @@ pub struct ExposeState {
     pub original_workspace: i32,
     pub original_windows: Vec<WindowState>,
     pub target_monitor: Option<String>,
+    pub tiles: Vec<ExposeTile>,
+    pub selected: Option<usize>,
+}
+
+/// A window laid out in the expose grid, with what is needed to put it back
+#[derive(Debug, Clone)]
+pub struct ExposeTile {
+    pub address: String,
+    pub title: String,
+    /// Monitor whose grid the window joined
+    pub monitor: MonitorInfo,
+    /// Workspace to send the window back to on dismiss
+    pub workspace: String,
+    /// Real geometry before expose, relative to `monitor`
+    pub original_position: (i32, i32),
+    pub original_size: (i32, i32),
+    pub was_floating: bool,
+    pub cell: GridCell,
 }
 
 #[derive(Debug, Clone)]
@@ -50,6 +246,8 @@ impl Default for ExposeState {
             original_workspace: 1,
             original_windows: Vec::new(),
             target_monitor: None,
+            tiles: Vec::new(),
+            selected: None,
         }
     }
 }
@@ -59,6 +257,7 @@ pub struct ExposePlugin {
     state: ExposeState,
     hyprland_client: Arc<Mutex<Option<Arc<HyprlandClient>>>>,
     global_cache: Arc<GlobalStateCache>,
+    window_animator: WindowAnimator,
 }
 
 impl ExposePlugin {
@@ -68,9 +267,26 @@ impl ExposePlugin {
             state: ExposeState::default(),
             hyprland_client: Arc::new(Mutex::new(None)),
             global_cache: Arc::new(GlobalStateCache::new()),
+            window_animator: WindowAnimator::new(),
+        }
+    }
+
+    async fn get_hyprland_client(&self) -> Result<Arc<HyprlandClient>> {
+        match self.hyprland_client.lock().await.as_ref() {
+            Some(client) => Ok(Arc::clone(client)),
+            None => Err(anyhow::anyhow!("Hyprland client not available")),
         }
     }
 
+    async fn get_monitors(client: &HyprlandClient) -> Result<Vec<MonitorInfo>> {
+        Ok(client
+            .get_monitors()
+            .await?
+            .iter()
+            .map(MonitorInfo::from)
+            .collect())
+    }
+
     /// Get current workspace on target monitor
     async fn get_current_workspace(&self) -> Result<i32> {
         let workspaces = tokio::task::spawn_blocking(Workspaces::get).await??;
@@ -153,13 +369,16 @@ impl ExposePlugin {
         Ok(filtered_windows)
     }
 
-    /// Enter expose mode using Pyprland's special workspace approach
+    /// Enter expose mode: gather windows onto the active workspace of their
+    /// monitor (or `target_monitor`) and animate them into a grid per monitor
     async fn enter_expose(&mut self) -> Result<String> {
         if self.state.is_active {
             return Ok("Expose already active".to_string());
         }
 
-        info!("🎯 Entering expose mode (Pyprland-compatible)");
+        info!("🎯 Entering expose mode");
+        let client = self.get_hyprland_client().await?;
+        let columns = self.config.grid_columns()?;
 
         // Store current workspace
         self.state.original_workspace = self.get_current_workspace().await?;
@@ -173,124 +392,185 @@ impl ExposePlugin {
             return Ok("No windows to expose".to_string());
         }
 
-        // Store original window states
+        let monitors = Self::get_monitors(&client).await?;
+        let target_monitor = self
+            .config
+            .target_monitor
+            .as_ref()
+            .and_then(|name| monitors.iter().find(|m| m.name == *name));
+        let placement: Vec<(i128, (i32, i32))> = windows
+            .iter()
+            .map(|window| {
+                let monitor = target_monitor.map_or(window.monitor, |m| m.id);
+                (monitor, (window.size.0 as i32, window.size.1 as i32))
+            })
+            .collect();
+        let cells = plan_grid(&placement, &monitors, columns, self.config.gap as i32);
+
         self.state.original_windows.clear();
-        for window in &windows {
+        self.state.tiles.clear();
+        for ((window, cell), (monitor_id, size)) in windows.iter().zip(cells).zip(&placement) {
+            let (Some(cell), Some(monitor)) = (cell, monitors.iter().find(|m| m.id == *monitor_id))
+            else {
+                debug!("Skipping window on unknown monitor: {}", window.title);
+                continue;
+            };
+            let address = window.address.to_string();
+
+            if window.workspace.id != monitor.active_workspace_id {
+                let workspace = monitor.active_workspace_id.to_string();
+                if let Err(e) = client.move_window_to_workspace(&address, &workspace).await {
+                    warn!("Failed to gather window '{}': {}", window.title, e);
+                    continue;
+                }
+            }
+            if !window.floating {
+                if let Err(e) = client.toggle_floating(&address).await {
+                    warn!("Failed to float window '{}': {}", window.title, e);
+                }
+            }
+
             self.state.original_windows.push(WindowState {
-                address: window.address.to_string(),
+                address: address.clone(),
                 original_workspace: window.workspace.id,
                 title: window.title.clone(),
             });
+            self.state.tiles.push(ExposeTile {
+                address,
+                title: window.title.clone(),
+                monitor: monitor.clone(),
+                workspace: if window.workspace.name.starts_with("special:") {
+                    window.workspace.name.clone()
+                } else {
+                    window.workspace.id.to_string()
+                },
+                original_position: (
+                    window.at.0 as i32 - monitor.x,
+                    window.at.1 as i32 - monitor.y,
+                ),
+                original_size: *size,
+                was_floating: window.floating,
+                cell,
+            });
         }
 
-        // First, show the special workspace (this creates it and makes it active)
-        let show_cmd = "hyprctl dispatch togglespecialworkspace exposed";
-        if let Err(e) = tokio::process::Command::new("sh")
-            .arg("-c")
-            .arg(show_cmd)
-            .output()
-            .await
-        {
-            warn!("Failed to activate special:exposed workspace: {}", e);
-        } else if self.config.debug_logging {
-            debug!("Activated special:exposed workspace");
-        }
-
-        // Small delay to ensure workspace is ready
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        self.state.is_active = true;
+        self.state.selected = (!self.state.tiles.is_empty()).then_some(0);
+        self.animate_tiles(true).await;
 
-        // Move all windows to special:exposed workspace (now that it's visible)
-        for window in &windows {
-            let move_cmd = format!(
-                "hyprctl dispatch movetoworkspace special:exposed,address:{}",
-                window.address
-            );
-            if let Err(e) = tokio::process::Command::new("sh")
-                .arg("-c")
-                .arg(&move_cmd)
-                .output()
-                .await
-            {
-                warn!(
-                    "Failed to move window '{}' to exposed workspace: {}",
-                    window.title, e
-                );
-            } else if self.config.debug_logging {
-                debug!("Moved window '{}' to special:exposed", window.title);
+        if let Some(tile) = self.state.selected.and_then(|i| self.state.tiles.get(i)) {
+            if let Err(e) = client.focus_window(&tile.address).await {
+                debug!("Failed to focus selected window: {}", e);
             }
         }
 
-        self.state.is_active = true;
-
         Ok(format!(
             "Expose mode activated with {} windows",
             self.state.original_windows.len()
         ))
     }
 
-    /// Exit expose mode and restore windows
+    /// Exit expose mode: animate windows back to their real geometry, restore
+    /// their workspace and tiling, and focus the selected one
     async fn exit_expose(&mut self) -> Result<String> {
         if !self.state.is_active {
             return Ok("Expose not active".to_string());
         }
 
         info!("🚪 Exiting expose mode");
+        self.animate_tiles(false).await;
+
+        if let Ok(client) = self.get_hyprland_client().await {
+            for tile in &self.state.tiles {
+                if tile.workspace != tile.monitor.active_workspace_id.to_string() {
+                    if let Err(e) = client
+                        .move_window_to_workspace(&tile.address, &tile.workspace)
+                        .await
+                    {
+                        warn!("Failed to restore window '{}': {}", tile.title, e);
+                    }
+                }
+                if !tile.was_floating {
+                    if let Err(e) = client.toggle_floating(&tile.address).await {
+                        warn!("Failed to re-tile window '{}': {}", tile.title, e);
+                    }
+                }
+                if self.config.debug_logging {
+                    debug!(
+                        "Restored window '{}' to workspace {}",
+                        tile.title, tile.workspace
+                    );
+                }
+            }
 
-        // Hide the special:exposed workspace
-        let hide_cmd = "hyprctl dispatch togglespecialworkspace exposed";
-        if let Err(e) = tokio::process::Command::new("sh")
-            .arg("-c")
-            .arg(hide_cmd)
-            .output()
-            .await
-        {
-            warn!("Failed to hide special:exposed workspace: {}", e);
-        } else if self.config.debug_logging {
-            debug!("Hidden special:exposed workspace");
+            if let Some(tile) = self.state.selected.and_then(|i| self.state.tiles.get(i)) {
+                if let Err(e) = client.focus_window(&tile.address).await {
+                    debug!("Failed to focus selected window: {}", e);
+                }
+            }
         }
 
-        // Restore windows to their original workspaces
-        for window_state in &self.state.original_windows {
-            let restore_cmd = format!(
-                "hyprctl dispatch movetoworkspacesilent {},address:{}",
-                window_state.original_workspace, window_state.address
-            );
-            if let Err(e) = tokio::process::Command::new("sh")
-                .arg("-c")
-                .arg(&restore_cmd)
-                .output()
-                .await
-            {
-                warn!("Failed to restore window '{}': {}", window_state.title, e);
-            } else if self.config.debug_logging {
-                debug!(
-                    "Restored window '{}' to workspace {}",
-                    window_state.title, window_state.original_workspace
-                );
+        // Reset state
+        self.state = ExposeState::default();
+
+        Ok("Expose mode deactivated".to_string())
+    }
+
+    /// Animate every exposed window between its real geometry and its grid cell
+    async fn animate_tiles(&self, entering: bool) {
+        let config = AnimationConfig {
+            animation_type: "expose".to_string(),
+            duration: self.config.animation_duration,
+            easing: EasingFunction::from_name(&self.config.animation_easing),
+            ..AnimationConfig::default()
+        };
+
+        let animations = self.state.tiles.iter().map(|tile| {
+            let original = (tile.original_position, tile.original_size);
+            let (from, to) = if entering {
+                (original, tile.cell.geometry())
+            } else {
+                (tile.cell.geometry(), original)
+            };
+            self.window_animator.animate_geometry(
+                &tile.address,
+                from,
+                to,
+                config.clone(),
+                &tile.monitor,
+            )
+        });
+
+        let results = futures::future::join_all(animations).await;
+        for (tile, result) in self.state.tiles.iter().zip(results) {
+            if let Err(e) = result {
+                warn!("Failed to animate window '{}': {}", tile.title, e);
             }
         }
+    }
 
-        // Return to original workspace
-        let original_workspace = self.state.original_workspace;
-        let workspace_cmd = format!("hyprctl dispatch workspace {}", original_workspace);
-        if let Err(e) = tokio::process::Command::new("sh")
-            .arg("-c")
-            .arg(&workspace_cmd)
-            .output()
-            .await
-        {
-            warn!(
-                "Failed to return to original workspace {}: {}",
-                original_workspace, e
-            );
-        } else if self.config.debug_logging {
-            debug!("Returned to original workspace {}", original_workspace);
+    /// Move the selection `step` windows through the grid (wrapping) and focus it
+    async fn move_selection(&mut self, step: isize) -> Result<String> {
+        if !self.state.is_active || self.state.tiles.is_empty() {
+            return Ok("Expose not active".to_string());
         }
 
-        // Reset state
-        self.state = ExposeState::default();
+        let count = self.state.tiles.len() as isize;
+        let current = self.state.selected.unwrap_or(0) as isize;
+        let selected = (current + step).rem_euclid(count) as usize;
+        self.state.selected = Some(selected);
 
-        Ok("Expose mode deactivated".to_string())
+        let tile = &self.state.tiles[selected];
+        if let Ok(client) = self.get_hyprland_client().await {
+            client.focus_window(&tile.address).await?;
+        }
+
+        Ok(format!(
+            "Selected window {}/{}: {}",
+            selected + 1,
+            count,
+            tile.title
+        ))
     }
 
     /// Toggle expose mode
@@ -308,10 +588,15 @@ impl ExposePlugin {
             return Ok("Expose: Inactive".to_string());
         }
 
+        let selected = match self.state.selected {
+            Some(index) => format!(" | Selected: {}", index + 1),
+            None => String::new(),
+        };
         Ok(format!(
-            "Expose: Active | Windows: {} | Original Workspace: {}",
+            "Expose: Active | Windows: {} | Original Workspace: {}{}",
             self.state.original_windows.len(),
-            self.state.original_workspace
+            self.state.original_workspace,
+            selected
         ))
     }
 
@@ -322,9 +607,10 @@ impl ExposePlugin {
         }
 
         // Check if special:exposed workspace exists and has windows
-        let clients = tokio::task::spawn_blocking(Clients::get).await??;
-        let exposed_windows: Vec<_> = clients
-            .to_vec()
+        let client = self.get_hyprland_client().await?;
+        let exposed_windows: Vec<_> = client
+            .get_windows()
+            .await?
             .into_iter()
             .filter(|c| c.workspace.name == "special:exposed")
             .collect();
@@ -336,11 +622,10 @@ impl ExposePlugin {
             );
 
             // Hide the special:exposed workspace first
-            let hide_cmd = "hyprctl dispatch togglespecialworkspace exposed";
-            if let Err(e) = tokio::process::Command::new("sh")
-                .arg("-c")
-                .arg(hide_cmd)
-                .output()
+            if let Err(e) = client
+                .dispatch(DispatchType::ToggleSpecialWorkspace(Some(
+                    "exposed".to_string(),
+                )))
                 .await
             {
                 warn!("Failed to hide orphaned special:exposed workspace: {}", e);
@@ -348,14 +633,12 @@ impl ExposePlugin {
 
             // Move all windows back to workspace 1 (default)
             for window in exposed_windows {
-                let restore_cmd = format!(
-                    "hyprctl dispatch movetoworkspace 1,address:{}",
-                    window.address
-                );
-                if let Err(e) = tokio::process::Command::new("sh")
-                    .arg("-c")
-                    .arg(&restore_cmd)
-                    .output()
+                let address = Address::new(Box::leak(window.address.to_string().into_boxed_str()));
+                if let Err(e) = client
+                    .dispatch(DispatchType::MoveToWorkspace(
+                        WorkspaceIdentifierWithSpecial::Id(1),
+                        Some(WindowIdentifier::Address(address)),
+                    ))
                     .await
                 {
                     warn!(
@@ -388,6 +671,13 @@ impl Plugin for ExposePlugin {
         "expose"
     }
 
+    async fn set_context(&mut self, context: Arc<PluginContext>) {
+        self.global_cache = Arc::clone(&context.global_cache);
+        let client = Arc::clone(&context.hyprland_client);
+        *self.hyprland_client.lock().await = Some(Arc::clone(&client));
+        self.window_animator.set_hyprland_client(client).await;
+    }
+
     async fn init(&mut self, config: &toml::Value) -> Result<()> {
         info!("🎯 Initializing expose plugin (Pyprland-compatible)");
 
@@ -397,6 +687,12 @@ impl Plugin for ExposePlugin {
                 Err(e) => return Err(anyhow::anyhow!("Invalid expose configuration: {}", e)),
             }
         }
+        if let Err(e) = self.config.grid_columns() {
+            return Err(anyhow::anyhow!("Invalid expose configuration: {}", e));
+        }
+        if self.config.layout.as_deref() == Some("auto") && self.config.columns.is_some() {
+            warn!("⚠️ expose: columns is ignored with layout = \"auto\"");
+        }
 
         // Check if special:exposed workspace exists and clean it up (skip in test environment)
         #[cfg(not(test))]
@@ -421,8 +717,14 @@ impl Plugin for ExposePlugin {
                     self.exit_expose().await?;
                 }
             }
-            HyprlandEvent::WorkspaceChanged { .. } => {
-                if self.state.is_active {
+            HyprlandEvent::WorkspaceChanged { workspace } => {
+                // Focusing a window of the grid on another monitor is not leaving expose
+                let in_grid = self
+                    .state
+                    .tiles
+                    .iter()
+                    .any(|tile| tile.monitor.active_workspace_id.to_string() == *workspace);
+                if self.state.is_active && !in_grid {
                     if self.config.debug_logging {
                         debug!("Workspace changed during expose - exiting");
                     }
@@ -443,9 +745,11 @@ impl Plugin for ExposePlugin {
         match command {
             "toggle" | "show" | "enter" => self.toggle_expose().await,
             "hide" | "exit" => self.exit_expose().await,
+            "next" => self.move_selection(1).await,
+            "prev" => self.move_selection(-1).await,
             "status" => self.get_status().await,
             _ => Ok(format!(
-                "Unknown expose command: {}. Available: toggle, show, enter, hide, exit, status",
+                "Unknown expose command: {}. Available: toggle, show, enter, hide, exit, next, prev, status",
                 command
             )),
         }
@@ -1004,4 +1308,178 @@ mod tests {
             );
         }
     }
+
+    fn monitor(id: i128, x: i32, width: u16, height: u16) -> MonitorInfo {
+        MonitorInfo {
+            id,
+            name: format!("DP-{id}"),
+            width,
+            height,
+            x,
+            active_workspace_id: id as i32 + 1,
+            refresh_rate: 60.0,
+            ..MonitorInfo::new()
+        }
+    }
+
+    #[test]
+    fn test_grid_dimensions() {
+        assert_eq!(grid_dimensions(0, None), (0, 0));
+        assert_eq!(grid_dimensions(2, None), (2, 1));
+        assert_eq!(grid_dimensions(5, None), (3, 2));
+        assert_eq!(grid_dimensions(9, None), (3, 3));
+        assert_eq!(grid_dimensions(10, None), (4, 3));
+        assert_eq!(grid_dimensions(5, Some(2)), (2, 3));
+        // Never more columns than windows
+        assert_eq!(grid_dimensions(2, Some(4)), (2, 1));
+    }
+
+    #[test]
+    fn test_layout_cells_grid_and_single_window() {
+        let area = GridCell {
+            x: 0,
+            y: 40,
+            width: 1000,
+            height: 600,
+        };
+
+        let cells = layout_cells(&[(800, 600); 3], None, area, 20);
+        // 2x2 grid: (1000 - 3*20) / 2 = 470 wide, (600 - 3*20) / 2 = 270 high
+        assert_eq!(
+            cells[0],
+            GridCell {
+                x: 20,
+                y: 60,
+                width: 470,
+                height: 270
+            }
+        );
+        assert_eq!(cells[1].x, 510);
+        // The short last row is centered
+        assert_eq!((cells[2].x, cells[2].y), (265, 350));
+
+        // A single window is centered at its own size, not stretched
+        let single = layout_cells(&[(400, 300)], None, area, 20);
+        assert_eq!(
+            single,
+            vec![GridCell {
+                x: 300,
+                y: 190,
+                width: 400,
+                height: 300
+            }]
+        );
+        // ... and shrunk when larger than the area
+        let single = layout_cells(&[(3000, 300)], None, area, 20);
+        assert_eq!((single[0].x, single[0].width), (20, 960));
+    }
+
+    #[test]
+    fn test_plan_grid_per_monitor() {
+        let monitors = [monitor(0, 0, 1920, 1080), monitor(1, 1920, 1280, 1024)];
+        let windows = [
+            (0, (800, 600)),
+            (1, (640, 480)),
+            (0, (800, 600)),
+            (7, (800, 600)),
+        ];
+
+        let cells = plan_grid(&windows, &monitors, None, 10);
+        // Two windows side by side on the first monitor
+        let (left, right) = (cells[0].unwrap(), cells[2].unwrap());
+        assert_eq!((left.x, left.y, left.width), (10, 10, 945));
+        assert_eq!(right.x, 965);
+        // Alone on the second monitor: centered in monitor-relative coordinates
+        assert_eq!(
+            cells[1],
+            Some(GridCell {
+                x: 320,
+                y: 272,
+                width: 640,
+                height: 480
+            })
+        );
+        assert_eq!(cells[3], None);
+    }
+
+    #[test]
+    fn test_grid_layout_config() {
+        let parse = |toml: &str| toml::from_str::<ExposeConfig>(toml).unwrap().grid_columns();
+        assert_eq!(parse("").unwrap(), None);
+        assert_eq!(parse("columns = 3").unwrap(), Some(3));
+        assert_eq!(parse("layout = \"auto\"\ncolumns = 3").unwrap(), None);
+        assert_eq!(parse("layout = \"columns\"\ncolumns = 4").unwrap(), Some(4));
+        assert!(parse("layout = \"columns\"").is_err());
+        assert!(parse("layout = \"spiral\"").is_err());
+        assert_eq!(ExposeConfig::default().gap, 20);
+    }
+
+    #[tokio::test]
+    async fn test_orphaned_exposed_windows_are_restored_through_the_client() {
+        use crate::ipc::mock::{window, MockHyprland};
+        use crate::ipc::DryRunHyprland;
+
+        let mut orphan = window("0x1", "kitty", (0, 0), -99);
+        orphan.workspace.name = "special:exposed".to_string();
+        let api = Arc::new(MockHyprland::new(vec![orphan], vec![]));
+        let mut plugin = ExposePlugin::new();
+
+        // Under --dry-run the live session is left alone
+        *plugin.hyprland_client.lock().await = Some(Arc::new(HyprlandClient::with_api(Arc::new(
+            DryRunHyprland::new(api.clone()),
+        ))));
+        plugin.cleanup_orphaned_exposed_workspace().await.unwrap();
+        assert!(api.calls().is_empty());
+
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+        plugin.cleanup_orphaned_exposed_workspace().await.unwrap();
+        assert_eq!(
+            api.calls(),
+            vec![
+                "dispatch togglespecialworkspace exposed",
+                "dispatch movetoworkspace 1,address:0x1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_next_prev_wrap_through_grid() {
+        let mut plugin = ExposePlugin::new();
+        assert_eq!(
+            plugin.handle_command("next", &[]).await.unwrap(),
+            "Expose not active"
+        );
+
+        plugin.state.is_active = true;
+        plugin.state.selected = Some(0);
+        for (address, title) in [("0x1", "Firefox"), ("0x2", "Terminal"), ("0x3", "Mail")] {
+            plugin.state.tiles.push(ExposeTile {
+                address: address.to_string(),
+                title: title.to_string(),
+                monitor: monitor(0, 0, 1920, 1080),
+                workspace: "1".to_string(),
+                original_position: (0, 0),
+                original_size: (800, 600),
+                was_floating: false,
+                cell: GridCell {
+                    x: 0,
+                    y: 0,
+                    width: 100,
+                    height: 100,
+                },
+            });
+        }
+
+        assert_eq!(
+            plugin.handle_command("next", &[]).await.unwrap(),
+            "Selected window 2/3: Terminal"
+        );
+        plugin.handle_command("prev", &[]).await.unwrap();
+        assert_eq!(
+            plugin.handle_command("prev", &[]).await.unwrap(),
+            "Selected window 3/3: Mail"
+        );
+        assert!(plugin.get_status().await.unwrap().ends_with("Selected: 3"));
+    }
 }
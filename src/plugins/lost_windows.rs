@@ -8,7 +8,7 @@ use tokio::sync::Mutex;
 use tracing::{debug, error, info, warn};
 
 use crate::ipc::{HyprlandClient, HyprlandEvent, MonitorInfo};
-use crate::plugins::Plugin;
+use crate::plugins::{Plugin, PluginContext};
 
 // ============================================================================
 // CONFIGURATION STRUCTURES
@@ -382,11 +382,6 @@ impl LostWindowsPlugin {
         }
     }
 
-    pub async fn set_hyprland_client(&self, client: Arc<HyprlandClient>) {
-        let mut client_guard = self.hyprland_client.lock().await;
-        *client_guard = Some(client);
-    }
-
     /// Get current monitors
     async fn get_monitors(&self) -> Result<Vec<MonitorInfo>> {
         let client_guard = self.hyprland_client.lock().await;
@@ -397,21 +392,7 @@ impl LostWindowsPlugin {
         drop(client_guard);
 
         let monitors = client.get_monitors().await?;
-        let monitor_infos: Vec<MonitorInfo> = monitors
-            .iter()
-            .map(|m| MonitorInfo {
-                id: m.id,
-                name: m.name.clone(),
-                width: m.width,
-                height: m.height,
-                x: m.x,
-                y: m.y,
-                scale: m.scale,
-                is_focused: m.focused,
-                active_workspace_id: m.active_workspace.id,
-                refresh_rate: m.refresh_rate,
-            })
-            .collect();
+        let monitor_infos: Vec<MonitorInfo> = monitors.iter().map(MonitorInfo::from).collect();
 
         Ok(monitor_infos)
     }
@@ -448,29 +429,32 @@ impl LostWindowsPlugin {
         Ok(window_infos)
     }
 
-    /// Check if a window is contained within any monitor
+    /// Check if a window overlaps any monitor. Only windows lying entirely
+    /// outside every monitor are lost
     fn is_window_contained(window: &WindowInfo, monitors: &[MonitorInfo]) -> bool {
         let (win_x, win_y) = window.position;
         let (win_width, win_height) = window.size;
 
-        for monitor in monitors {
-            // Check if window overlaps with monitor bounds
+        monitors.iter().any(|monitor| {
             let overlap_x =
                 (win_x + win_width).min(monitor.x + monitor.width as i32) - win_x.max(monitor.x);
             let overlap_y =
                 (win_y + win_height).min(monitor.y + monitor.height as i32) - win_y.max(monitor.y);
+            overlap_x > 0 && overlap_y > 0
+        })
+    }
 
-            // Consider window contained if there's significant overlap
-            if overlap_x > win_width / 4 && overlap_y > win_height / 4 {
-                return true;
-            }
-        }
-
-        false
+    /// Size a recovered window gets: its own, shrunk to fit `monitor` within `margin`
+    fn fit_size(size: (i32, i32), monitor: &MonitorInfo, margin: i32) -> (i32, i32) {
+        (
+            size.0.min(monitor.width as i32 - margin * 2).max(1),
+            size.1.min(monitor.height as i32 - margin * 2).max(1),
+        )
     }
 
-    /// Find lost windows
-    async fn find_lost_windows(&self) -> Result<Vec<WindowInfo>> {
+    /// Find lost windows. Windows on special workspaces are skipped unless
+    /// `include_special`, as they are hidden on purpose
+    async fn find_lost_windows(&self, include_special: bool) -> Result<Vec<WindowInfo>> {
         let monitors = self.get_monitors().await?;
         let mut windows = self.get_windows().await?;
 
@@ -482,6 +466,10 @@ impl LostWindowsPlugin {
                 continue;
             }
 
+            if !include_special && window.workspace.starts_with("special") {
+                continue;
+            }
+
             // Skip if excluded class
             if self.config.exclude_classes.contains(&window.class) {
                 continue;
@@ -532,9 +520,17 @@ impl LostWindowsPlugin {
             self.get_focused_monitor().await?
         };
 
+        // Place windows at the size they will be given
+        let fitted: Vec<WindowInfo> = lost_windows
+            .iter()
+            .map(|window| WindowInfo {
+                size: Self::fit_size(window.size, &target_monitor, self.config.margin),
+                ..window.clone()
+            })
+            .collect();
         let positions = WindowPositioner::calculate_positions(
             &self.config.rescue_strategy,
-            &lost_windows,
+            &fitted,
             &target_monitor,
             self.config.margin,
         );
@@ -576,15 +572,25 @@ impl LostWindowsPlugin {
             recovered_count, session.strategy
         );
 
+        let workspace = session.target_monitor.active_workspace_id.to_string();
         for (window, &(new_x, new_y)) in session.lost_windows.iter().zip(session.positions.iter()) {
-            // Move window to current workspace
+            // Move window to the target monitor's workspace
             if let Err(e) = client
-                .move_window_to_workspace(&window.address, &session.target_monitor.name)
+                .move_window_to_workspace(&window.address, &workspace)
                 .await
             {
                 warn!("Failed to move window to workspace: {}", e);
             }
 
+            // Shrink windows too large for the monitor
+            let (width, height) =
+                Self::fit_size(window.size, &session.target_monitor, self.config.margin);
+            if (width, height) != window.size {
+                if let Err(e) = client.resize_window(&window.address, width, height).await {
+                    warn!("Failed to resize window: {}", e);
+                }
+            }
+
             // Set window position
             if let Err(e) = client.move_window(&window.address, new_x, new_y).await {
                 warn!("Failed to move window to position: {}", e);
@@ -625,7 +631,7 @@ impl LostWindowsPlugin {
 
         self.last_check = Some(now);
 
-        let lost_windows = self.find_lost_windows().await?;
+        let lost_windows = self.find_lost_windows(false).await?;
         if !lost_windows.is_empty() {
             info!("🔍 Auto-recovery found {} lost windows", lost_windows.len());
             self.create_recovery_session(lost_windows).await?;
@@ -638,9 +644,20 @@ impl LostWindowsPlugin {
         Ok(())
     }
 
+    /// Recover lost windows onto the focused monitor
+    async fn recover_lost_windows(&mut self, include_special: bool) -> Result<String> {
+        let lost_windows = self.find_lost_windows(include_special).await?;
+        if lost_windows.is_empty() {
+            Ok("✅ No lost windows found".to_string())
+        } else {
+            self.create_recovery_session(lost_windows).await?;
+            self.execute_recovery().await
+        }
+    }
+
     /// List lost windows
-    async fn list_lost_windows(&self) -> Result<String> {
-        let lost_windows = self.find_lost_windows().await?;
+    async fn list_lost_windows(&self, include_special: bool) -> Result<String> {
+        let lost_windows = self.find_lost_windows(include_special).await?;
 
         if lost_windows.is_empty() {
             return Ok("✅ No lost windows found".to_string());
@@ -650,11 +667,12 @@ impl LostWindowsPlugin {
 
         for (i, window) in lost_windows.iter().enumerate() {
             output.push_str(&format!(
-                "[{}] {} ({})\n    Class: {} | Position: ({}, {}) | Size: {}x{}\n",
+                "[{}] {} ({})\n    Class: {} | Workspace: {} | Position: ({}, {}) | Size: {}x{}\n",
                 i + 1,
                 window.title,
                 window.address,
                 window.class,
+                window.workspace,
                 window.position.0,
                 window.position.1,
                 window.size.0,
@@ -662,7 +680,7 @@ impl LostWindowsPlugin {
             ));
         }
 
-        output.push_str("\nUse 'lost_windows recover' to rescue these windows\n");
+        output.push_str("\nUse 'lost_windows collect' to rescue these windows\n");
 
         Ok(output)
     }
@@ -671,7 +689,7 @@ impl LostWindowsPlugin {
     async fn get_status(&self) -> Result<String> {
         let monitors = self.get_monitors().await?;
         let windows = self.get_windows().await?;
-        let lost_windows = self.find_lost_windows().await?;
+        let lost_windows = self.find_lost_windows(false).await?;
 
         let floating_count = windows.iter().filter(|w| w.is_floating).count();
 
@@ -729,6 +747,10 @@ impl Plugin for LostWindowsPlugin {
         "lost_windows"
     }
 
+    async fn set_context(&mut self, context: Arc<PluginContext>) {
+        *self.hyprland_client.lock().await = Some(Arc::clone(&context.hyprland_client));
+    }
+
     async fn init(&mut self, config: &toml::Value) -> Result<()> {
         info!("🔍 Initializing lost_windows plugin");
 
@@ -768,18 +790,12 @@ impl Plugin for LostWindowsPlugin {
     }
 
     async fn handle_command(&mut self, command: &str, args: &[&str]) -> Result<String> {
+        let include_special = args.contains(&"--include-special");
+
         match command {
-            "list" => self.list_lost_windows().await,
+            "list" => self.list_lost_windows(include_special).await,
 
-            "recover" | "rescue" => {
-                let lost_windows = self.find_lost_windows().await?;
-                if lost_windows.is_empty() {
-                    Ok("✅ No lost windows found".to_string())
-                } else {
-                    self.create_recovery_session(lost_windows).await?;
-                    self.execute_recovery().await
-                }
-            }
+            "collect" | "recover" | "rescue" => self.recover_lost_windows(include_special).await,
 
             "status" => self.get_status().await,
 
@@ -811,12 +827,12 @@ impl Plugin for LostWindowsPlugin {
             }
 
             "check" => {
-                let lost_windows = self.find_lost_windows().await?;
+                let lost_windows = self.find_lost_windows(include_special).await?;
                 Ok(format!("🔍 Found {} lost windows", lost_windows.len()))
             }
 
             _ => Err(anyhow::anyhow!(
-                "Unknown lost_windows command: {}. Available: list, recover, status, enable, disable, strategy, check",
+                "Unknown lost_windows command: {}. Available: list, collect, status, enable, disable, strategy, check",
                 command
             )),
         }
@@ -868,6 +884,10 @@ mod tests {
             is_focused: true,
             active_workspace_id: 1,
             refresh_rate: 60.0,
+            reserved_top: 0,
+            reserved_bottom: 0,
+            reserved_left: 0,
+            reserved_right: 0,
         };
 
         let windows = vec![
@@ -936,11 +956,15 @@ mod tests {
             is_focused: true,
             active_workspace_id: 1,
             refresh_rate: 60.0,
+            reserved_top: 0,
+            reserved_bottom: 0,
+            reserved_left: 0,
+            reserved_right: 0,
         };
 
         let monitors = vec![monitor];
 
-        // Window partially outside should still be considered contained if enough overlap
+        // Window partially outside is still contained
         assert!(LostWindowsPlugin::is_window_contained(&window, &monitors));
 
         // Completely outside window
@@ -954,4 +978,66 @@ mod tests {
             &monitors
         ));
     }
+
+    #[test]
+    fn test_fit_size() {
+        let monitor = MonitorInfo {
+            width: 1920,
+            height: 1080,
+            ..MonitorInfo::new()
+        };
+
+        assert_eq!(
+            LostWindowsPlugin::fit_size((800, 600), &monitor, 50),
+            (800, 600)
+        );
+        assert_eq!(
+            LostWindowsPlugin::fit_size((2560, 1440), &monitor, 50),
+            (1820, 980)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_collect_skips_special_workspaces_by_default() {
+        use crate::ipc::mock::{monitor, window, MockHyprland};
+
+        let mut plugin = LostWindowsPlugin::new();
+        plugin.config.enable_animations = false;
+
+        let mut hidden = window("0x2", "notes", (-5000, 0), -98);
+        hidden.workspace.name = "special:notes".to_string();
+        let api = Arc::new(MockHyprland::new(
+            vec![
+                window("0x1", "term", (-5000, -5000), 1),
+                hidden,
+                window("0x3", "editor", (100, 100), 1),
+            ],
+            vec![monitor("DP-1", (1920, 1080), true, 3)],
+        ));
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+
+        let listing = plugin.handle_command("list", &[]).await.unwrap();
+        assert!(listing.contains("Found 1 lost windows"), "{listing}");
+        assert!(listing.contains("Position: (-5000, -5000)"), "{listing}");
+
+        let result = plugin.handle_command("collect", &[]).await.unwrap();
+        assert_eq!(result, "Recovered 1 lost windows");
+        let moved: Vec<String> = api
+            .calls()
+            .into_iter()
+            .filter(|call| call.starts_with("dispatch movetoworkspacesilent"))
+            .collect();
+        assert_eq!(moved, vec!["dispatch movetoworkspacesilent 3,address:0x1"]);
+
+        let result = plugin
+            .handle_command("collect", &["--include-special"])
+            .await
+            .unwrap();
+        // The mock leaves 0x1 where it was, so it is collected again
+        assert_eq!(result, "Recovered 2 lost windows");
+        assert!(api
+            .calls()
+            .contains(&"dispatch movetoworkspacesilent 3,address:0x2".to_string()));
+    }
 }
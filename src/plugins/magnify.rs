@@ -1,23 +1,37 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::process::Command;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{watch, Mutex};
+use tokio::task::JoinHandle;
 use tracing::{debug, error, info, warn};
 
-use crate::ipc::HyprlandEvent;
-use crate::plugins::Plugin;
+use crate::animation::{
+    AnimationConfig, AnimationEngine, AnimationHandle, EasingFunction, Playback, PropertyValue,
+};
+use crate::ipc::{HyprlandClient, HyprlandEvent};
+use crate::plugins::{Plugin, PluginContext};
+
+/// Engine id of the zoom transition; only one runs at a time
+const ZOOM_ANIMATION_ID: &str = "magnify-zoom";
+
+/// Animated property holding the cursor zoom factor
+const ZOOM_PROPERTY: &str = "zoom_factor";
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MagnifyConfig {
     /// Default zoom factor when toggling (default: 2.0)
-    #[serde(default = "default_factor")]
+    #[serde(default = "default_factor", alias = "default_factor")]
     pub factor: f32,
 
     /// Animation duration in milliseconds (default: 300)
     #[serde(default = "default_duration")]
     pub duration: u32,
 
-    /// Number of animation steps (default: 30)
+    /// Number of frames a zoom transition is rendered in (default: 30)
     #[serde(default = "default_steps")]
     pub steps: u32,
 
@@ -92,7 +106,6 @@ pub struct MagnifyState {
     pub current_zoom: f32,
     pub is_zoomed: bool,
     pub target_zoom: f32,
-    pub animating: bool,
 }
 
 impl Default for MagnifyState {
@@ -101,26 +114,47 @@ impl Default for MagnifyState {
             current_zoom: 1.0,
             is_zoomed: false,
             target_zoom: 1.0,
-            animating: false,
         }
     }
 }
 
+/// Zoom transition in flight: the engine animation and the task rendering it
+struct ZoomAnimation {
+    handle: AnimationHandle,
+    task: JoinHandle<()>,
+}
+
 pub struct MagnifyPlugin {
     config: MagnifyConfig,
     state: MagnifyState,
     external_tool_available: bool,
+    animation_engine: Arc<Mutex<AnimationEngine>>,
+    /// Zoom factor last applied to Hyprland, updated on every animation frame
+    live_zoom: Arc<watch::Sender<f32>>,
+    zoom_animation: Option<ZoomAnimation>,
+    hyprland_client: Option<Arc<HyprlandClient>>,
 }
 
 impl MagnifyPlugin {
     pub fn new() -> Self {
+        let (live_zoom, _) = watch::channel(1.0);
         Self {
             config: MagnifyConfig::default(),
             state: MagnifyState::default(),
             external_tool_available: false,
+            animation_engine: Arc::new(Mutex::new(AnimationEngine::new())),
+            live_zoom: Arc::new(live_zoom),
+            zoom_animation: None,
+            hyprland_client: None,
         }
     }
 
+    fn hyprland_client(&self) -> Result<Arc<HyprlandClient>> {
+        self.hyprland_client
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Hyprland client not available"))
+    }
+
     /// Check if external zoom tools are available
     async fn check_external_tools(&mut self) -> bool {
         debug!("🔍 Checking for external zoom tools...");
@@ -187,6 +221,8 @@ impl MagnifyPlugin {
 
         if self.config.use_external_tool && self.external_tool_available {
             self.set_zoom_external(clamped_zoom).await
+        } else if self.config.smooth_animation && self.config.duration > 0 {
+            self.animate_zoom_hyprctl(clamped_zoom).await
         } else {
             self.set_zoom_hyprctl(clamped_zoom).await
         }
@@ -276,45 +312,143 @@ impl MagnifyPlugin {
         // Note: This only affects cursor size, not screen magnification
         // For real screen zoom, external tools are needed
 
-        let result = tokio::task::spawn_blocking(move || {
-            Command::new("hyprctl")
-                .args(["keyword", "cursor:zoom_factor", &target_zoom.to_string()])
-                .output()
-        })
-        .await??;
-
-        if result.status.success() {
-            self.state.current_zoom = target_zoom;
-            self.state.target_zoom = target_zoom;
-            self.state.is_zoomed = target_zoom > 1.0;
-            info!(
-                "✅ Cursor zoom set to {:.1}x (note: this only affects cursor size)",
-                target_zoom
-            );
-            Ok(())
-        } else {
-            let error_msg = String::from_utf8_lossy(&result.stderr);
-            Err(anyhow::anyhow!("Failed to set cursor zoom: {}", error_msg))
+        self.cancel_zoom_animation();
+        Self::apply_cursor_zoom(&*self.hyprland_client()?, target_zoom).await?;
+        self.live_zoom.send_replace(target_zoom);
+
+        self.state.current_zoom = target_zoom;
+        self.state.target_zoom = target_zoom;
+        self.state.is_zoomed = target_zoom > 1.0;
+        info!(
+            "✅ Cursor zoom set to {:.1}x (note: this only affects cursor size)",
+            target_zoom
+        );
+        Ok(())
+    }
+
+    /// Run `hyprctl keyword cursor:zoom_factor` (only logged under `--dry-run`)
+    async fn apply_cursor_zoom(client: &HyprlandClient, zoom: f32) -> Result<()> {
+        client
+            .keyword("cursor:zoom_factor", &zoom.to_string())
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to set cursor zoom: {}", e))
+    }
+
+    /// Animate the cursor zoom towards `target_zoom` through the animation engine.
+    /// A transition already in flight is cancelled and the new one starts from the
+    /// factor it had reached, so rapid commands retarget instead of stacking
+    async fn animate_zoom_hyprctl(&mut self, target_zoom: f32) -> Result<()> {
+        self.cancel_zoom_animation();
+        let from_zoom = *self.live_zoom.borrow();
+
+        self.state.current_zoom = from_zoom;
+        self.state.target_zoom = target_zoom;
+        self.state.is_zoomed = target_zoom > 1.0;
+        if (from_zoom - target_zoom).abs() < f32::EPSILON {
+            return Ok(());
         }
+
+        debug!(
+            "🔍 Animating cursor zoom {:.2}x -> {:.2}x over {}ms",
+            from_zoom, target_zoom, self.config.duration
+        );
+
+        let config = AnimationConfig {
+            animation_type: "zoom".to_string(),
+            duration: self.config.duration,
+            easing: EasingFunction::from_name(&self.config.easing),
+            ..AnimationConfig::default()
+        };
+        let handle = self
+            .animation_engine
+            .lock()
+            .await
+            .start_animation(
+                ZOOM_ANIMATION_ID.to_string(),
+                config,
+                HashMap::from([(ZOOM_PROPERTY.to_string(), PropertyValue::Float(from_zoom))]),
+                HashMap::from([(ZOOM_PROPERTY.to_string(), PropertyValue::Float(target_zoom))]),
+            )
+            .await?;
+
+        let task = tokio::spawn(Self::run_zoom_animation(
+            self.hyprland_client()?,
+            Arc::clone(&self.animation_engine),
+            handle.clone(),
+            Arc::clone(&self.live_zoom),
+            target_zoom,
+            self.frame_interval(),
+        ));
+        self.zoom_animation = Some(ZoomAnimation { handle, task });
+
+        Ok(())
     }
 
-    /// Apply easing function to animation progress
-    fn apply_easing(&self, progress: f32) -> f32 {
-        match self.config.easing.as_str() {
-            "linear" => progress,
-            "ease-in" => progress * progress,
-            "ease-out" => 1.0 - (1.0 - progress).powi(2),
-            "ease-in-out" => {
-                if progress < 0.5 {
-                    2.0 * progress * progress
-                } else {
-                    1.0 - 2.0 * (1.0 - progress).powi(2)
-                }
+    /// Time between two rendered frames: the duration split into `steps` frames
+    fn frame_interval(&self) -> Duration {
+        let frame = Duration::from_millis(self.config.duration as u64) / self.config.steps.max(1);
+        frame.max(Duration::from_millis(1))
+    }
+
+    /// Feed each interpolated zoom factor to Hyprland until the animation ends.
+    /// Lands exactly on the target unless the animation was stopped (retargeted)
+    async fn run_zoom_animation(
+        client: Arc<HyprlandClient>,
+        engine: Arc<Mutex<AnimationEngine>>,
+        handle: AnimationHandle,
+        live_zoom: Arc<watch::Sender<f32>>,
+        target_zoom: f32,
+        frame_interval: Duration,
+    ) {
+        loop {
+            let zoom = engine
+                .lock()
+                .await
+                .get_current_properties(handle.id())
+                .and_then(|properties| properties.get(ZOOM_PROPERTY).map(|v| v.as_float()));
+            let Some(zoom) = zoom else {
+                break;
+            };
+
+            if let Err(e) = Self::apply_cursor_zoom(&client, zoom).await {
+                warn!("⚠️  Zoom animation aborted: {}", e);
+                return;
             }
-            _ => progress, // Default to linear
+            live_zoom.send_replace(zoom);
+            tokio::time::sleep(frame_interval).await;
+        }
+
+        if handle.playback() == Playback::Stopped {
+            return;
+        }
+        match Self::apply_cursor_zoom(&client, target_zoom).await {
+            Ok(()) => {
+                live_zoom.send_replace(target_zoom);
+                info!("✅ Cursor zoom animated to {:.1}x", target_zoom);
+            }
+            Err(e) => warn!("⚠️  Failed to finish zoom animation: {}", e),
+        }
+    }
+
+    /// Stop the zoom transition in flight, leaving the zoom where it got to
+    fn cancel_zoom_animation(&mut self) {
+        if let Some(animation) = self.zoom_animation.take() {
+            animation.handle.stop();
+            animation.task.abort();
         }
     }
 
+    /// Zoom factor currently applied, mid-transition included
+    fn current_zoom(&self) -> f32 {
+        *self.live_zoom.borrow()
+    }
+
+    fn is_animating(&self) -> bool {
+        self.zoom_animation
+            .as_ref()
+            .is_some_and(|animation| !animation.task.is_finished())
+    }
+
     /// Toggle zoom (zoom in if not zoomed, zoom out if zoomed)
     async fn toggle_zoom(&mut self) -> Result<String> {
         let target_zoom = if self.state.is_zoomed {
@@ -325,7 +459,8 @@ impl MagnifyPlugin {
 
         info!(
             "🔍 Toggling zoom from {:.1}x to {:.1}x",
-            self.state.current_zoom, target_zoom
+            self.current_zoom(),
+            target_zoom
         );
 
         self.set_zoom_level(target_zoom).await?;
@@ -352,13 +487,15 @@ impl MagnifyPlugin {
         Ok(format!("Zoom set to {zoom:.1}x"))
     }
 
-    /// Change zoom relatively (+ or -)
+    /// Change zoom relatively (+ or -), from the level being zoomed to if a
+    /// transition is in flight
     async fn change_zoom(&mut self, delta: f32) -> Result<String> {
-        let target_zoom = self.state.current_zoom + delta;
+        let base_zoom = self.state.target_zoom;
+        let target_zoom = base_zoom + delta;
 
         info!(
             "🔍 Changing zoom by {:.1}x (from {:.1}x to {:.1}x)",
-            delta, self.state.current_zoom, target_zoom
+            delta, base_zoom, target_zoom
         );
 
         if target_zoom < self.config.min_zoom {
@@ -388,6 +525,20 @@ impl MagnifyPlugin {
         ))
     }
 
+    /// `zoom <factor>` sets an absolute level, `zoom +0.5` / `zoom -0.5` a relative one
+    async fn zoom(&mut self, arg: &str) -> Result<String> {
+        let arg = arg.trim();
+        let value: f32 = arg
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid zoom factor: {}", arg))?;
+
+        if arg.starts_with(['+', '-']) {
+            self.change_zoom(value).await
+        } else {
+            self.set_zoom(value).await
+        }
+    }
+
     /// Zoom in by increment
     async fn zoom_in(&mut self) -> Result<String> {
         self.change_zoom(self.config.increment).await
@@ -419,16 +570,16 @@ impl MagnifyPlugin {
         } else {
             "hyprctl"
         };
-        let animation_status = if self.state.animating {
-            " (animating)"
+        let animation_status = if self.is_animating() {
+            format!(" (animating to {:.1}x)", self.state.target_zoom)
         } else {
-            ""
+            String::new()
         };
 
         Ok(format!(
             "Magnify: {} | Current: {:.1}x | Method: {}{}\nRange: {:.1}x - {:.1}x | Increment: {:.1}x",
             status,
-            self.state.current_zoom,
+            self.current_zoom(),
             tool_status,
             animation_status,
             self.config.min_zoom,
@@ -450,6 +601,10 @@ impl Plugin for MagnifyPlugin {
         "magnify"
     }
 
+    async fn set_context(&mut self, context: Arc<PluginContext>) {
+        self.hyprland_client = Some(Arc::clone(&context.hyprland_client));
+    }
+
     async fn init(&mut self, config: &toml::Value) -> Result<()> {
         info!("🔍 Initializing magnify plugin");
 
@@ -512,6 +667,15 @@ impl Plugin for MagnifyPlugin {
                     Err(anyhow::anyhow!("Change command requires delta value"))
                 }
             }
+            "zoom" => {
+                if let Some(zoom_str) = args.first() {
+                    self.zoom(zoom_str).await
+                } else {
+                    Err(anyhow::anyhow!(
+                        "Zoom command requires a factor (e.g. 2.0 or +0.5)"
+                    ))
+                }
+            }
             "in" => self.zoom_in().await,
             "out" => self.zoom_out().await,
             "reset" => self.reset_zoom().await,
@@ -520,3 +684,36 @@ impl Plugin for MagnifyPlugin {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_factor_alias() {
+        let config: MagnifyConfig = toml::from_str("default_factor = 3.0").unwrap();
+        assert_eq!(config.factor, 3.0);
+    }
+
+    #[test]
+    fn test_frame_interval_splits_duration_into_steps() {
+        let mut plugin = MagnifyPlugin::new();
+        assert_eq!(plugin.frame_interval(), Duration::from_millis(10));
+
+        plugin.config.steps = 0;
+        plugin.config.duration = 200;
+        assert_eq!(plugin.frame_interval(), Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn test_zoom_rejects_invalid_factors() {
+        let mut plugin = MagnifyPlugin::new();
+        assert!(plugin.handle_command("zoom", &[]).await.is_err());
+        assert!(plugin.handle_command("zoom", &["big"]).await.is_err());
+        assert!(plugin.handle_command("zoom", &["9.0"]).await.is_err());
+        // Relative to the pending target (1.0), below min_zoom
+        assert!(plugin.handle_command("zoom", &["-0.5"]).await.is_err());
+        assert_eq!(plugin.current_zoom(), 1.0);
+        assert!(!plugin.is_animating());
+    }
+}
@@ -1,7 +1,12 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{broadcast, Mutex};
 
-use crate::ipc::HyprlandEvent;
+use crate::animation::{AnimationEngine, AnimationPreset};
+use crate::core::GlobalStateCache;
+use crate::ipc::{HyprlandClient, HyprlandEvent};
 
 pub mod expose;
 pub mod lost_windows;
@@ -14,20 +19,154 @@ pub mod toggle_special;
 pub mod wallpapers;
 pub mod workspaces_follow_focus;
 
+/// Services the daemon shares with every plugin, the single place new shared
+/// services are added.
+///
+/// Lifecycle: the plugin manager builds one context per load and hands it to a
+/// plugin through `set_context` once, right after the plugin is constructed and
+/// before `init`. A reload constructs the plugin again and sets a fresh context
+pub struct PluginContext {
+    pub hyprland_client: Arc<HyprlandClient>,
+    /// Animation engine shared by all plugins
+    pub animation_engine: Arc<Mutex<AnimationEngine>>,
+    /// Every Hyprland event the daemon receives, for plugins that `subscribe()`
+    pub events: broadcast::Sender<HyprlandEvent>,
+    pub global_cache: Arc<GlobalStateCache>,
+    /// Merged `[variables]` of the configuration
+    pub variables: HashMap<String, String>,
+    /// `[animations.presets]`, for any plugin to resolve presets by name
+    pub animation_presets: HashMap<String, AnimationPreset>,
+}
+
+/// A problem found in a configuration, at a dotted key path
+/// (`term.size` within a plugin section, `scratchpads.term.size` overall)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    pub key: String,
+    pub message: String,
+}
+
+/// Errors and warnings found by `Plugin::validate_config`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    pub errors: Vec<ValidationIssue>,
+    pub warnings: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    pub fn error(&mut self, key: impl Into<String>, message: impl Into<String>) {
+        self.errors.push(ValidationIssue {
+            key: key.into(),
+            message: message.into(),
+        });
+    }
+
+    pub fn warning(&mut self, key: impl Into<String>, message: impl Into<String>) {
+        self.warnings.push(ValidationIssue {
+            key: key.into(),
+            message: message.into(),
+        });
+    }
+
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    /// Add `other`'s issues with their keys nested under `prefix`
+    pub fn merge_under(&mut self, prefix: &str, other: ValidationReport) {
+        let nest = |issue: ValidationIssue| ValidationIssue {
+            key: if issue.key.is_empty() {
+                prefix.to_string()
+            } else {
+                format!("{prefix}.{}", issue.key)
+            },
+            message: issue.message,
+        };
+        self.errors.extend(other.errors.into_iter().map(nest));
+        self.warnings.extend(other.warnings.into_iter().map(nest));
+    }
+}
+
+/// Error a plugin returns from `handle_command` when the call itself is wrong
+/// (unknown subcommand, missing argument). Carries the usage text to show
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageError(pub String);
+
+impl std::fmt::Display for UsageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for UsageError {}
+
+/// `Err` for `handle_command` asking the client to print `text` as usage
+pub fn usage(text: impl Into<String>) -> anyhow::Error {
+    UsageError(text.into()).into()
+}
+
+/// What a plugin command came to, as reported back to the client
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandOutcome {
+    Ok(String),
+    /// The command was not understood; the text lists what is
+    Usage(String),
+    Error(String),
+}
+
+impl From<Result<String>> for CommandOutcome {
+    fn from(result: Result<String>) -> Self {
+        match result {
+            Ok(message) => CommandOutcome::Ok(message),
+            Err(e) => match e.downcast::<UsageError>() {
+                Ok(UsageError(text)) => CommandOutcome::Usage(text),
+                Err(e) => CommandOutcome::Error(e.to_string()),
+            },
+        }
+    }
+}
+
 #[async_trait]
 pub trait Plugin: Send + Sync {
     /// Plugin name
     fn name(&self) -> &str;
 
+    /// Receive the shared services. Called by the plugin manager right before `init`
+    async fn set_context(&mut self, _context: Arc<PluginContext>) {}
+
     /// Initialize plugin with configuration
     async fn init(&mut self, config: &toml::Value) -> Result<()>;
 
     /// Handle Hyprland events
     async fn handle_event(&mut self, event: &HyprlandEvent) -> Result<()>;
 
-    /// Handle commands from client
+    /// Handle commands from client. Return `Err(usage(...))` for a command or
+    /// arguments the plugin doesn't understand, so the client shows usage
     async fn handle_command(&mut self, command: &str, args: &[&str]) -> Result<String>;
 
+    /// Current state for `rustr dump-state`. Plugins without state to report
+    /// return an empty object
+    fn dump_state(&self) -> Result<serde_json::Value> {
+        Ok(serde_json::json!({}))
+    }
+
+    /// Check a configuration, as `init` would receive it, without applying it or
+    /// talking to Hyprland. Used by `rustrland check-config`
+    fn validate_config(&self, _config: &toml::Value) -> ValidationReport {
+        ValidationReport::default()
+    }
+
+    /// State to carry over a hot reload, which replaces the plugin instance.
+    /// Plugins with nothing worth keeping return `null`
+    async fn capture_state(&self) -> Result<serde_json::Value> {
+        Ok(serde_json::Value::Null)
+    }
+
+    /// Take back what `capture_state` returned, after the reloaded instance's `init`
+    async fn restore_state(&mut self, _state: serde_json::Value) -> Result<()> {
+        Ok(())
+    }
+
     /// Cleanup plugin resources (background tasks, timers, etc.)
     async fn cleanup(&mut self) -> Result<()> {
         // Default implementation does nothing
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::process::Command;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -10,9 +10,10 @@ use tokio::time::sleep;
 use tracing::{debug, error, info, warn};
 
 use crate::ipc::{HyprlandClient, HyprlandEvent};
-use crate::plugins::Plugin;
+use crate::plugins::{Plugin, PluginContext};
 
-use hyprland::data::Monitors;
+use hyprland::data::{Monitor, Monitors};
+use hyprland::dispatch::DispatchType;
 use hyprland::shared::{HyprData, HyprDataVec};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -27,6 +28,79 @@ pub struct MonitorSettings {
     pub transform: Option<u32>,
 }
 
+/// One output of a monitor profile, applied as a `monitor` keyword
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ProfileMonitor {
+    /// Mode as "WIDTHxHEIGHT" (default: "preferred")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<String>,
+    /// Refresh rate in Hz, used with `resolution`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate: Option<f64>,
+    /// Position as "XxY" (default: "auto")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<String>,
+    /// Scale factor (default: "auto")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<f64>,
+    /// Transform/rotation (0-7), as in `settings`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transform: Option<u32>,
+    /// Disable the output instead (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+impl ProfileMonitor {
+    /// Value of the `monitor` keyword applying this to output `name`
+    pub fn keyword(&self, name: &str) -> String {
+        if !self.enabled {
+            return format!("{name},disable");
+        }
+
+        let mode = match (&self.resolution, self.rate) {
+            (Some(resolution), Some(rate)) => format!("{resolution}@{rate}"),
+            (Some(resolution), None) => resolution.clone(),
+            (None, _) => "preferred".to_string(),
+        };
+        let position = self.position.as_deref().unwrap_or("auto");
+        let scale = self
+            .scale
+            .map_or_else(|| "auto".to_string(), |scale| scale.to_string());
+
+        let mut keyword = format!("{name},{mode},{position},{scale}");
+        if let Some(transform) = self.transform {
+            keyword.push_str(&format!(",transform,{transform}"));
+        }
+        keyword
+    }
+
+    /// The live state of `monitor`, as a profile would set it
+    fn from_live(monitor: &Monitor) -> Self {
+        Self {
+            resolution: Some(format!("{}x{}", monitor.width, monitor.height)),
+            rate: Some((monitor.refresh_rate as f64 * 100.0).round() / 100.0),
+            position: Some(format!("{}x{}", monitor.x, monitor.y)),
+            scale: Some((monitor.scale as f64 * 100.0).round() / 100.0),
+            transform: Some(monitor.transform as u32),
+            enabled: !monitor.disabled,
+        }
+    }
+}
+
+/// Named monitor layout: output name -> settings
+pub type MonitorProfile = BTreeMap<String, ProfileMonitor>;
+
+/// Whether `profile` is meant for the `connected` outputs: it covers all of
+/// them, and every output it enables is connected
+pub fn profile_matches(profile: &MonitorProfile, connected: &BTreeSet<String>) -> bool {
+    connected.iter().all(|name| profile.contains_key(name))
+        && profile
+            .iter()
+            .filter(|(_, monitor)| monitor.enabled)
+            .all(|(name, _)| connected.contains(name))
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct PlacementRule {
     /// Target monitor to place relative to
@@ -87,6 +161,14 @@ pub struct MonitorsConfig {
     /// Case insensitive monitor name matching (default: true)
     #[serde(default = "default_true")]
     pub case_insensitive: bool,
+
+    /// Named layouts for `monitors apply <profile>` (profile -> output -> settings)
+    #[serde(default)]
+    pub profiles: BTreeMap<String, MonitorProfile>,
+
+    /// Apply the profile matching the connected outputs when they change (default: false)
+    #[serde(default)]
+    pub auto_apply_profiles: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -142,6 +224,8 @@ impl Default for MonitorsConfig {
             settings: HashMap::new(),
             debug_logging: false,
             case_insensitive: true,
+            profiles: BTreeMap::new(),
+            auto_apply_profiles: false,
         }
     }
 }
@@ -165,6 +249,39 @@ pub struct MonitorInfo {
     pub scale: f64,
     pub transform: u32,
     pub disabled: bool,
+    pub reserved_top: u16,
+    pub reserved_bottom: u16,
+    pub reserved_left: u16,
+    pub reserved_right: u16,
+}
+
+impl From<&Monitor> for MonitorInfo {
+    fn from(monitor: &Monitor) -> Self {
+        Self {
+            id: monitor.id,
+            name: monitor.name.clone(),
+            description: monitor.description.clone(),
+            make: String::new(),   // Not available in hyprland crate
+            model: String::new(),  // Not available in hyprland crate
+            serial: String::new(), // Not available in hyprland crate
+            active_workspace_id: monitor.active_workspace.id,
+            active_workspace_name: monitor.active_workspace.name.clone(),
+            focused: monitor.focused,
+            width: monitor.width,
+            height: monitor.height,
+            refresh_rate: monitor.refresh_rate,
+            x: monitor.x,
+            y: monitor.y,
+            scale: monitor.scale as f64,
+            transform: monitor.transform as u32,
+            disabled: monitor.disabled,
+            // Hyprland reports reserved space as (left, top, right, bottom)
+            reserved_top: monitor.reserved.1,
+            reserved_bottom: monitor.reserved.3,
+            reserved_left: monitor.reserved.0,
+            reserved_right: monitor.reserved.2,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -188,6 +305,7 @@ pub struct MonitorsPlugin {
     hyprland_client: Arc<Mutex<Option<Arc<HyprlandClient>>>>,
     last_layout_time: Option<Instant>,
     pending_layout_apply: bool,
+    connected_outputs: BTreeSet<String>, // Outputs seen by the last auto-apply check
 }
 
 impl MonitorsPlugin {
@@ -198,6 +316,7 @@ impl MonitorsPlugin {
             hyprland_client: Arc::new(Mutex::new(None)),
             last_layout_time: None,
             pending_layout_apply: false,
+            connected_outputs: BTreeSet::new(),
         }
     }
 
@@ -209,25 +328,7 @@ impl MonitorsPlugin {
         let mut monitor_map = HashMap::new();
 
         for monitor in monitor_vec {
-            let monitor_info = MonitorInfo {
-                id: monitor.id,
-                name: monitor.name.clone(),
-                description: monitor.description.clone(),
-                make: String::new(),   // Not available in hyprland crate
-                model: String::new(),  // Not available in hyprland crate
-                serial: String::new(), // Not available in hyprland crate
-                active_workspace_id: monitor.active_workspace.id,
-                active_workspace_name: monitor.active_workspace.name.clone(),
-                focused: monitor.focused,
-                width: monitor.width,
-                height: monitor.height,
-                refresh_rate: monitor.refresh_rate,
-                x: monitor.x,
-                y: monitor.y,
-                scale: monitor.scale as f64,
-                transform: monitor.transform as u32,
-                disabled: monitor.disabled,
-            };
+            let monitor_info = MonitorInfo::from(&monitor);
 
             monitor_map.insert(monitor.name, monitor_info);
         }
@@ -569,23 +670,11 @@ impl MonitorsPlugin {
             monitor_spec.push_str(&transform.to_string());
         }
 
-        // Execute hyprctl command
-        let output = tokio::task::spawn_blocking(move || {
-            Command::new("hyprctl")
-                .args(["keyword", "monitor", &monitor_spec])
-                .output()
-        })
-        .await??;
-
-        if !output.status.success() {
-            let error_msg = String::from_utf8_lossy(&output.stderr);
-            return Err(anyhow::anyhow!(
-                "hyprctl monitor command failed: {}",
-                error_msg
-            ));
-        }
-
-        Ok(())
+        // Through the client, so `--dry-run` only logs it
+        self.get_hyprland_client()
+            .await?
+            .keyword("monitor", &monitor_spec)
+            .await
     }
 
     /// Apply a placement rule
@@ -611,23 +700,10 @@ impl MonitorsPlugin {
             source_monitor.name, source_monitor.width, source_monitor.height, new_x, new_y
         );
 
-        // Execute hyprctl command
-        let output = tokio::task::spawn_blocking(move || {
-            Command::new("hyprctl")
-                .args(["keyword", "monitor", &position_spec])
-                .output()
-        })
-        .await??;
-
-        if !output.status.success() {
-            let error_msg = String::from_utf8_lossy(&output.stderr);
-            return Err(anyhow::anyhow!(
-                "hyprctl position command failed: {}",
-                error_msg
-            ));
-        }
-
-        Ok(())
+        self.get_hyprland_client()
+            .await?
+            .keyword("monitor", &position_spec)
+            .await
     }
 
     /// Calculate new position for monitor based on placement rule
@@ -729,6 +805,164 @@ impl MonitorsPlugin {
         Ok(())
     }
 
+    async fn get_hyprland_client(&self) -> Result<Arc<HyprlandClient>> {
+        self.hyprland_client
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Hyprland client not available"))
+    }
+
+    /// Apply a configured profile. Enabled outputs are set up first; an output
+    /// being disabled has its workspace moved to one of them before it goes away.
+    /// Outputs are only disabled when a live one can take their workspace
+    async fn apply_profile(&mut self, name: &str) -> Result<String> {
+        let profile = self.config.profiles.get(name).cloned().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unknown monitor profile '{}'. Available: {}",
+                name,
+                self.config
+                    .profiles
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })?;
+
+        let client = self.get_hyprland_client().await?;
+
+        for (output, monitor) in profile.iter().filter(|(_, m)| m.enabled) {
+            client.keyword("monitor", &monitor.keyword(output)).await?;
+        }
+
+        // Queried after enabling, so outputs the profile just turned on can be targets.
+        // Workspaces go to the focused output if it stays, else the first kept one
+        let monitors = client.get_monitors().await?;
+        let kept = |monitor: &&Monitor| {
+            !monitor.disabled && profile.get(&monitor.name).is_some_and(|m| m.enabled)
+        };
+        let target = monitors
+            .iter()
+            .filter(kept)
+            .find(|m| m.focused)
+            .or_else(|| monitors.iter().find(kept));
+
+        if target.is_none() {
+            let stranded: Vec<&str> = profile
+                .iter()
+                .filter(|(output, m)| {
+                    !m.enabled && monitors.iter().any(|l| &l.name == *output && !l.disabled)
+                })
+                .map(|(output, _)| output.as_str())
+                .collect();
+            if !stranded.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "Not disabling {} for profile '{}': no output it enables is live to take the workspaces",
+                    stranded.join(", "),
+                    name
+                ));
+            }
+        }
+
+        let mut migrated = Vec::new();
+        for (output, monitor) in profile.iter().filter(|(_, m)| !m.enabled) {
+            let live = monitors.iter().find(|m| &m.name == output && !m.disabled);
+            if let (Some(live), Some(target)) = (live, target) {
+                let args = format!("{} {}", live.active_workspace.id, target.name);
+                client
+                    .dispatch(DispatchType::Custom(
+                        "moveworkspacetomonitor",
+                        Box::leak(args.into_boxed_str()),
+                    ))
+                    .await?;
+                migrated.push(format!(
+                    "workspace {} {} → {}",
+                    live.active_workspace.name, output, target.name
+                ));
+            }
+            client.keyword("monitor", &monitor.keyword(output)).await?;
+        }
+
+        self.last_layout_time = Some(Instant::now());
+        info!("🖥️  Applied monitor profile '{}'", name);
+
+        let mut result = format!(
+            "Applied monitor profile '{}' ({} outputs)",
+            name,
+            profile.len()
+        );
+        if !migrated.is_empty() {
+            result.push_str(&format!(", moved {}", migrated.join(", ")));
+        }
+        Ok(result)
+    }
+
+    /// The live layout as a `[monitors.profiles.current]` section, to paste into
+    /// the configuration
+    async fn current_profile(&self) -> Result<String> {
+        let client = self.get_hyprland_client().await?;
+        let profile: MonitorProfile = client
+            .get_monitors()
+            .await?
+            .iter()
+            .map(|monitor| (monitor.name.clone(), ProfileMonitor::from_live(monitor)))
+            .collect();
+
+        let snapshot = BTreeMap::from([(
+            "monitors",
+            BTreeMap::from([("profiles", BTreeMap::from([("current", profile)]))]),
+        )]);
+        Ok(toml::to_string(&snapshot)?)
+    }
+
+    /// With `auto_apply_profiles`, apply the first profile matching the connected
+    /// outputs once they differ from the last check
+    async fn auto_apply_profile(&mut self) -> Result<()> {
+        if !self.config.auto_apply_profiles || self.config.profiles.is_empty() {
+            return Ok(());
+        }
+
+        let client = self.get_hyprland_client().await?;
+        let connected: BTreeSet<String> = client
+            .get_monitors()
+            .await?
+            .into_iter()
+            .map(|monitor| monitor.name)
+            .collect();
+        if connected == self.connected_outputs {
+            return Ok(());
+        }
+
+        let matching = self
+            .config
+            .profiles
+            .iter()
+            .find(|(_, profile)| profile_matches(profile, &connected))
+            .map(|(name, _)| name.clone());
+        self.connected_outputs = connected;
+
+        let Some(name) = matching else {
+            if self.config.debug_logging {
+                debug!(
+                    "🖥️  No monitor profile matches outputs {:?}",
+                    self.connected_outputs
+                );
+            }
+            return Ok(());
+        };
+        self.apply_profile(&name).await?;
+
+        // Disabled outputs drop out of the list: don't take that for a change
+        self.connected_outputs = client
+            .get_monitors()
+            .await?
+            .into_iter()
+            .map(|monitor| monitor.name)
+            .collect();
+        Ok(())
+    }
+
     /// List all monitors with their configurations
     async fn list_monitors(&mut self) -> Result<String> {
         self.update_monitors().await?;
@@ -760,6 +994,11 @@ impl MonitorsPlugin {
                 output.push_str(&format!("    Description: {}\n", monitor.description));
             }
 
+            output.push_str(&format!(
+                "    Reserved: {}\n",
+                Self::format_reserved(monitor)
+            ));
+
             // Show configured settings
             if let Some(settings) = self.config.settings.get(name) {
                 output.push_str("    Configured settings:");
@@ -794,9 +1033,87 @@ impl MonitorsPlugin {
             }
         }
 
+        // Show profiles, marking the ones meant for the connected outputs
+        if !self.config.profiles.is_empty() {
+            let connected: BTreeSet<String> = layout.monitors.keys().cloned().collect();
+            output.push_str("\n🗂️  Profiles:\n");
+            for (name, profile) in &self.config.profiles {
+                let matching = if profile_matches(profile, &connected) {
+                    " ✅ matches connected outputs"
+                } else {
+                    ""
+                };
+                output.push_str(&format!(
+                    "  {}: {}{}\n",
+                    name,
+                    profile.keys().cloned().collect::<Vec<_>>().join(", "),
+                    matching
+                ));
+            }
+        }
+
         Ok(output)
     }
 
+    /// Describe one monitor (or all) with every detected property
+    async fn describe_monitors(&mut self, name: Option<&str>) -> Result<String> {
+        self.update_monitors().await?;
+
+        let layout = match &self.current_layout {
+            Some(layout) => layout,
+            None => return Ok("No monitor layout available".to_string()),
+        };
+
+        let mut monitors: Vec<&MonitorInfo> = match name {
+            Some(name) => {
+                let monitor = self
+                    .find_monitor(name, &layout.monitors)
+                    .ok_or_else(|| anyhow::anyhow!("Monitor '{}' not found", name))?;
+                vec![monitor]
+            }
+            None => layout.monitors.values().collect(),
+        };
+        monitors.sort_by_key(|m| m.id);
+
+        Ok(monitors
+            .into_iter()
+            .map(Self::describe_monitor)
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    /// Full description of a single monitor
+    fn describe_monitor(monitor: &MonitorInfo) -> String {
+        format!(
+            "🖥️  {} (id {})\n  Description: {}\n  Resolution: {}x{}@{:.2}Hz\n  Position: ({}, {})\n  Scale: {:.2}x, Transform: {}\n  Workspace: {} ({})\n  Focused: {}, Disabled: {}\n  Reserved: {}\n",
+            monitor.name,
+            monitor.id,
+            monitor.description,
+            monitor.width,
+            monitor.height,
+            monitor.refresh_rate,
+            monitor.x,
+            monitor.y,
+            monitor.scale,
+            monitor.transform,
+            monitor.active_workspace_name,
+            monitor.active_workspace_id,
+            monitor.focused,
+            monitor.disabled,
+            Self::format_reserved(monitor)
+        )
+    }
+
+    fn format_reserved(monitor: &MonitorInfo) -> String {
+        format!(
+            "top {}, bottom {}, left {}, right {}",
+            monitor.reserved_top,
+            monitor.reserved_bottom,
+            monitor.reserved_left,
+            monitor.reserved_right
+        )
+    }
+
     /// Test monitor layout without applying
     async fn test_layout(&mut self) -> Result<String> {
         self.update_monitors().await?;
@@ -972,6 +1289,10 @@ impl Plugin for MonitorsPlugin {
         "monitors"
     }
 
+    async fn set_context(&mut self, context: Arc<PluginContext>) {
+        *self.hyprland_client.lock().await = Some(Arc::clone(&context.hyprland_client));
+    }
+
     async fn init(&mut self, config: &toml::Value) -> Result<()> {
         info!("🖥️  Initializing monitors plugin");
 
@@ -994,6 +1315,10 @@ impl Plugin for MonitorsPlugin {
             }
         }
 
+        if let Err(e) = self.auto_apply_profile().await {
+            warn!("Failed to apply monitor profile: {}", e);
+        }
+
         info!(
             "✅ Monitors plugin initialized with {} monitors",
             self.current_layout
@@ -1031,6 +1356,10 @@ impl Plugin for MonitorsPlugin {
                     if let Err(e) = self.apply_monitor_layout().await {
                         warn!("Failed to apply layout after monitor connection: {}", e);
                     }
+
+                    if let Err(e) = self.auto_apply_profile().await {
+                        warn!("Failed to apply monitor profile: {}", e);
+                    }
                 } else if event_data.starts_with("monitorremoved>>") {
                     let monitor_name = event_data
                         .strip_prefix("monitorremoved>>")
@@ -1043,6 +1372,16 @@ impl Plugin for MonitorsPlugin {
 
                     // Update monitor state
                     self.update_monitors().await?;
+
+                    if let Err(e) = self.auto_apply_profile().await {
+                        warn!("Failed to apply monitor profile: {}", e);
+                    }
+                }
+            }
+
+            HyprlandEvent::MonitorChanged { .. } => {
+                if let Err(e) = self.auto_apply_profile().await {
+                    warn!("Failed to apply monitor profile: {}", e);
                 }
             }
 
@@ -1071,8 +1410,15 @@ impl Plugin for MonitorsPlugin {
             }
 
             "list" => self.list_monitors().await,
+            "describe" => self.describe_monitors(args.first().copied()).await,
             "status" => self.get_status().await,
             "test" => self.test_layout().await,
+            "current" => self.current_profile().await,
+
+            "apply" => match args.first() {
+                Some(profile) => self.apply_profile(profile).await,
+                None => Err(anyhow::anyhow!("Usage: monitors apply <profile>")),
+            },
 
             "reload" => {
                 // Force reload of monitor configuration
@@ -1081,7 +1427,7 @@ impl Plugin for MonitorsPlugin {
             }
 
             _ => Ok(format!(
-                "Unknown monitors command: {command}. Available: relayout, list, status, test, reload"  
+                "Unknown monitors command: {command}. Available: relayout, list, describe, status, test, apply, current, reload"
             )),
         }
     }
@@ -1123,6 +1469,10 @@ mod tests {
             scale: 1.0,
             transform: 0,
             disabled: false,
+            reserved_top: 0,
+            reserved_bottom: 0,
+            reserved_left: 0,
+            reserved_right: 0,
         }
     }
 
@@ -1372,4 +1722,194 @@ mod tests {
         assert!(matches!(center, PlacementAlignment::Center));
         assert!(matches!(middle, PlacementAlignment::Middle));
     }
+
+    #[test]
+    fn test_describe_monitor_includes_reserved_area() {
+        let monitor = MonitorInfo {
+            reserved_top: 32,
+            reserved_left: 48,
+            ..create_test_monitor("DP-1", 0, 0, 2560, 1440)
+        };
+
+        let description = MonitorsPlugin::describe_monitor(&monitor);
+        assert!(description.contains("DP-1"));
+        assert!(description.contains("Resolution: 2560x1440"));
+        assert!(description.contains("Reserved: top 32, bottom 0, left 48, right 0"));
+    }
+
+    fn docked_profile() -> MonitorProfile {
+        toml::from_str(
+            r#"
+            [eDP-1]
+            enabled = false
+
+            [DP-1]
+            resolution = "2560x1440"
+            rate = 144
+            position = "0x0"
+            scale = 1.25
+            transform = 1
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_profile_monitor_keyword() {
+        let profile = docked_profile();
+        assert_eq!(
+            profile["DP-1"].keyword("DP-1"),
+            "DP-1,2560x1440@144,0x0,1.25,transform,1"
+        );
+        assert_eq!(profile["eDP-1"].keyword("eDP-1"), "eDP-1,disable");
+
+        let defaults: ProfileMonitor = toml::from_str("").unwrap();
+        assert_eq!(defaults.keyword("HDMI-A-1"), "HDMI-A-1,preferred,auto,auto");
+    }
+
+    #[test]
+    fn test_profile_matches_connected_outputs() {
+        let profile = docked_profile();
+        let outputs = |names: &[&str]| names.iter().map(|n| n.to_string()).collect();
+
+        assert!(profile_matches(&profile, &outputs(&["eDP-1", "DP-1"])));
+        // A disabled output may already be gone
+        assert!(profile_matches(&profile, &outputs(&["DP-1"])));
+        assert!(!profile_matches(&profile, &outputs(&["eDP-1"])));
+        assert!(!profile_matches(
+            &profile,
+            &outputs(&["eDP-1", "DP-1", "HDMI-A-1"])
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_apply_profile_migrates_workspace_before_disabling() {
+        use crate::ipc::mock::{monitor, MockHyprland};
+
+        let mut plugin = create_test_plugin();
+        plugin
+            .config
+            .profiles
+            .insert("docked".to_string(), docked_profile());
+
+        let api = Arc::new(MockHyprland::new(
+            vec![],
+            vec![
+                monitor("eDP-1", (1920, 1080), true, 1),
+                monitor("DP-1", (2560, 1440), false, 2),
+            ],
+        ));
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+
+        let result = plugin.handle_command("apply", &["docked"]).await.unwrap();
+        assert!(
+            result.contains("moved workspace 1 eDP-1 → DP-1"),
+            "{result}"
+        );
+        assert_eq!(
+            api.calls(),
+            vec![
+                "keyword monitor DP-1,2560x1440@144,0x0,1.25,transform,1",
+                "dispatch moveworkspacetomonitor 1 DP-1",
+                "keyword monitor eDP-1,disable",
+            ]
+        );
+
+        assert!(plugin.handle_command("apply", &["missing"]).await.is_err());
+        assert!(plugin.handle_command("apply", &[]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_apply_profile_keeps_output_when_no_live_target() {
+        use crate::ipc::mock::{monitor, MockHyprland};
+
+        let mut plugin = create_test_plugin();
+        plugin
+            .config
+            .profiles
+            .insert("docked".to_string(), docked_profile());
+
+        // DP-1 is known to Hyprland but did not come up
+        let mut dp1 = monitor("DP-1", (2560, 1440), false, 2);
+        dp1.disabled = true;
+        let api = Arc::new(MockHyprland::new(
+            vec![],
+            vec![monitor("eDP-1", (1920, 1080), true, 1), dp1],
+        ));
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+
+        let error = plugin
+            .handle_command("apply", &["docked"])
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("Not disabling eDP-1"), "{error}");
+        assert_eq!(
+            api.calls(),
+            vec!["keyword monitor DP-1,2560x1440@144,0x0,1.25,transform,1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_monitor_settings_go_through_the_client() {
+        use crate::ipc::mock::{monitor, MockHyprland};
+        use crate::ipc::DryRunHyprland;
+
+        let plugin = create_test_plugin();
+        let api = Arc::new(MockHyprland::new(
+            vec![],
+            vec![monitor("DP-1", (2560, 1440), true, 1)],
+        ));
+        let settings = MonitorSettings {
+            resolution: None,
+            rate: None,
+            scale: Some(1.5),
+            transform: None,
+        };
+        let dp1 = create_test_monitor("DP-1", 0, 0, 2560, 1440);
+
+        // Under --dry-run the live session is left alone
+        *plugin.hyprland_client.lock().await = Some(Arc::new(HyprlandClient::with_api(Arc::new(
+            DryRunHyprland::new(api.clone()),
+        ))));
+        plugin
+            .apply_monitor_settings(&dp1, &settings)
+            .await
+            .unwrap();
+        assert!(api.calls().is_empty());
+
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+        plugin
+            .apply_monitor_settings(&dp1, &settings)
+            .await
+            .unwrap();
+        assert_eq!(api.calls(), vec!["keyword monitor DP-1,1.5"]);
+    }
+
+    #[tokio::test]
+    async fn test_current_profile_round_trips() {
+        use crate::ipc::mock::{monitor, MockHyprland};
+
+        let plugin = create_test_plugin();
+        let api = Arc::new(MockHyprland::new(
+            vec![],
+            vec![monitor("DP-1", (2560, 1440), true, 1)],
+        ));
+        *plugin.hyprland_client.lock().await = Some(Arc::new(HyprlandClient::with_api(api)));
+
+        let snapshot = plugin.current_profile().await.unwrap();
+        assert!(
+            snapshot.contains("[monitors.profiles.current.DP-1]"),
+            "{snapshot}"
+        );
+
+        let config: toml::Value = toml::from_str(&snapshot).unwrap();
+        let config: MonitorsConfig = config["monitors"].clone().try_into().unwrap();
+        assert_eq!(
+            config.profiles["current"]["DP-1"].keyword("DP-1"),
+            "DP-1,2560x1440@60,0x0,1,transform,0"
+        );
+    }
 }
@@ -4,21 +4,24 @@ use hyprland::dispatch::{Dispatch, DispatchType, WorkspaceIdentifier};
 use hyprland::shared::HyprData;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, Mutex, RwLock};
 use tokio::task::JoinHandle;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, instrument, warn};
 
 // Arc-optimized configuration types
 pub type ScratchpadConfigRef = Arc<ScratchpadConfig>;
 pub type ValidatedConfigRef = Arc<ValidatedConfig>;
 
-use crate::animation::{AnimationConfig, EasingFunction, WindowAnimator};
+use crate::animation::preset::parse_preset_table;
+use crate::animation::{AnimationConfig, AnimationPreset, EasingFunction, Offset, WindowAnimator};
+use crate::ipc::protocol::SCRATCHPADS_SUBCOMMANDS;
 use crate::ipc::{
     EnhancedHyprlandClient, HyprlandClient, HyprlandEvent, MonitorInfo, WindowGeometry,
 };
-use crate::plugins::Plugin;
+use crate::plugins::{usage, Plugin, PluginContext, ValidationReport};
 
 // Import pour la position du curseur
 use hyprland::data::CursorPosition;
@@ -40,12 +43,72 @@ pub struct AnimationPropertyConfig {
     pub easing: Option<String>,
 }
 
+impl AnimationPropertyConfig {
+    /// Engine property for a show, or for a hide (played backwards). Percentages of
+    /// `x`/`width` and `y`/`height` are of the monitor's width and height, `x`/`y` being
+    /// relative to its top-left corner; other percentages are fractions (`50%` is 0.5)
+    pub fn to_engine(
+        &self,
+        showing: bool,
+        monitor: &MonitorInfo,
+    ) -> Result<crate::animation::AnimationPropertyConfig> {
+        let from = self.parse_value(&self.from, monitor)?;
+        let to = self.parse_value(&self.to, monitor)?;
+        let (from, to) = if showing { (from, to) } else { (to, from) };
+        Ok(crate::animation::AnimationPropertyConfig {
+            property: self.property.clone(),
+            from,
+            to,
+            easing: self.easing.as_deref().map(EasingFunction::from_name),
+        })
+    }
+
+    fn parse_value(
+        &self,
+        value: &str,
+        monitor: &MonitorInfo,
+    ) -> Result<crate::animation::PropertyValue> {
+        use crate::animation::{Axis, PropertyValue};
+
+        let invalid = || {
+            anyhow::anyhow!(
+                "Animation property '{}' has invalid value '{}', expected a number, pixels or a percentage",
+                self.property,
+                value
+            )
+        };
+        let parsed = PropertyValue::from_string(value).map_err(|_| invalid())?;
+        match (Axis::of_property(&self.property), parsed) {
+            (
+                Some(axis),
+                parsed @ (PropertyValue::Pixels(_)
+                | PropertyValue::Percentage(_)
+                | PropertyValue::Float(_)),
+            ) => {
+                let origin = match (self.property.as_str(), axis) {
+                    ("x", _) => monitor.x,
+                    ("y", _) => monitor.y,
+                    _ => 0,
+                };
+                Ok(PropertyValue::Pixels(
+                    origin + parsed.resolve(axis, monitor),
+                ))
+            }
+            (None, PropertyValue::Percentage(percent)) => Ok(PropertyValue::Float(percent / 100.0)),
+            (None, PropertyValue::Float(number)) => Ok(PropertyValue::Float(number)),
+            (None, PropertyValue::Pixels(pixels)) => Ok(PropertyValue::Float(pixels as f32)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ScratchpadConfig {
     // Basic config
     pub command: String,
     pub class: Option<String>,
+    pub class_regex: Option<String>, // Matches variable classes (e.g. "^chrome-.*"); exact class wins
     pub size: String,
 
     // Animation config
@@ -56,11 +119,14 @@ pub struct ScratchpadConfig {
     pub animation_scale_from: Option<f32>,   // Scale animation start value
     pub animation_opacity_from: Option<f32>, // Fade animation start value
     pub animation_properties: Option<Vec<AnimationPropertyConfig>>, // Multi-property animations
+    pub animate: Option<Vec<String>>, // Animated aspects: "position", "opacity" (None: per type)
+    pub arrival_effect: Option<String>, // Played on reaching the shown position: "bounce" or "none"
 
     // Physics-based animation parameters
     pub spring_stiffness: Option<f32>, // Spring stiffness (default: 300.0)
     pub spring_damping: Option<f32>,   // Spring damping (default: 30.0)
     pub spring_mass: Option<f32>,      // Spring mass (default: 1.0)
+    pub spring_settle_epsilon: Option<f32>, // Settled below this displacement/velocity (default: 0.001)
 
     // Cubic bezier animation parameters
     pub cubic_bezier_x1: Option<f32>, // Bezier control point 1 X
@@ -76,6 +142,7 @@ pub struct ScratchpadConfig {
     pub lazy: bool,
     pub pinned: bool,
     pub excludes: Vec<String>,
+    pub group: Option<String>, // Scratchpads sharing a group exclude each other
     pub restore_excluded: bool,
     pub preserve_aspect: bool,
     pub force_monitor: Option<String>,
@@ -83,12 +150,18 @@ pub struct ScratchpadConfig {
     pub allow_special_workspaces: bool,
     pub smart_focus: bool,
     pub close_on_hide: bool,
-    pub unfocus: Option<String>, // "hide" option
+    pub kill_process_group: bool, // Terminate the window's process group on close
+    pub hide_mode: String,        // "special" (default), "offscreen" or "minimize"
+    pub unfocus: Option<String>,  // "hide" option
     pub max_size: Option<String>,
-    pub r#use: Option<String>, // Template inheritance
+    pub per_monitor: HashMap<String, String>, // monitor name -> size override
+    pub r#use: Option<String>,                // Template inheritance
+    pub animation_preset: Option<String>,     // Named [animations.presets] entry
 
     // Position and focus control
     pub position: Option<String>, // Manual window positioning
+    pub floating: bool,           // Float with geometry (true) or tile into the layout (false)
+    pub respect_reserved: bool,   // Keep clear of bars/reserved areas (default: true)
     pub hysteresis: Option<f32>,  // Unfocus reactivity control (default: 0.4)
     pub restore_focus: bool,      // Restore focused state when hiding (default: true)
     pub multi: bool,              // Pyprland compatibility alias for multi_window
@@ -96,6 +169,10 @@ pub struct ScratchpadConfig {
     // Multi-window support
     pub multi_window: bool,
     pub max_instances: Option<u32>,
+
+    // Rapid toggle handling
+    pub debounce_ms: Option<u32>, // Ignore toggles closer than this to the previous one
+    pub interrupt: String,        // Toggle during an animation: "cancel" (default) or "ignore"
 }
 
 impl Default for ScratchpadConfig {
@@ -103,6 +180,7 @@ impl Default for ScratchpadConfig {
         Self {
             command: String::new(),
             class: None,
+            class_regex: None,
             size: "50% 50%".to_string(),
             animation: None,
             animation_duration: None,
@@ -111,9 +189,12 @@ impl Default for ScratchpadConfig {
             animation_scale_from: None,
             animation_opacity_from: None,
             animation_properties: None,
+            animate: None,
+            arrival_effect: None,
             spring_stiffness: None,
             spring_damping: None,
             spring_mass: None,
+            spring_settle_epsilon: None,
             cubic_bezier_x1: None,
             cubic_bezier_y1: None,
             cubic_bezier_x2: None,
@@ -124,6 +205,7 @@ impl Default for ScratchpadConfig {
             lazy: false,
             pinned: true,
             excludes: Vec::new(),
+            group: None,
             restore_excluded: false,
             preserve_aspect: false,
             force_monitor: None,
@@ -131,15 +213,23 @@ impl Default for ScratchpadConfig {
             allow_special_workspaces: false,
             smart_focus: true,
             close_on_hide: false,
+            hide_mode: "special".to_string(),
+            kill_process_group: false,
             unfocus: None,
             max_size: None,
+            per_monitor: HashMap::new(),
             r#use: None,
+            animation_preset: None,
             position: None,
+            floating: true,
+            respect_reserved: true,
             hysteresis: Some(0.4),
             restore_focus: true,
             multi: false,
             multi_window: false,
             max_instances: Some(1),
+            debounce_ms: None,
+            interrupt: "cancel".to_string(),
         }
     }
 }
@@ -149,6 +239,7 @@ pub struct ValidatedConfig {
     // All fields from ScratchpadConfig
     pub command: String,
     pub class: String,
+    pub class_regex: Option<String>,
     pub size: String,
     pub animation: Option<String>,
     pub animation_duration: Option<u32>,     // Duration in ms
@@ -157,11 +248,14 @@ pub struct ValidatedConfig {
     pub animation_scale_from: Option<f32>,   // Scale animation start value
     pub animation_opacity_from: Option<f32>, // Fade animation start value
     pub animation_properties: Option<Vec<AnimationPropertyConfig>>, // Multi-property animations
+    pub animate: Option<Vec<String>>, // Animated aspects: "position", "opacity" (None: per type)
+    pub arrival_effect: Option<String>, // Played on reaching the shown position: "bounce" or "none"
 
     // Physics-based animation parameters
     pub spring_stiffness: Option<f32>, // Spring stiffness (default: 300.0)
     pub spring_damping: Option<f32>,   // Spring damping (default: 30.0)
     pub spring_mass: Option<f32>,      // Spring mass (default: 1.0)
+    pub spring_settle_epsilon: Option<f32>, // Settled below this displacement/velocity (default: 0.001)
 
     // Cubic bezier animation parameters
     pub cubic_bezier_x1: Option<f32>, // Bezier control point 1 X
@@ -175,6 +269,7 @@ pub struct ValidatedConfig {
     pub lazy: bool,
     pub pinned: bool,
     pub excludes: Vec<String>,
+    pub group: Option<String>, // Scratchpads sharing a group exclude each other
     pub restore_excluded: bool,
     pub preserve_aspect: bool,
     pub force_monitor: Option<String>,
@@ -182,28 +277,143 @@ pub struct ValidatedConfig {
     pub allow_special_workspaces: bool,
     pub smart_focus: bool,
     pub close_on_hide: bool,
+    pub kill_process_group: bool,
+    pub hide_mode: String,
     pub unfocus: Option<String>,
     pub max_size: Option<String>,
+    pub per_monitor: HashMap<String, String>,
     pub r#use: Option<String>,
+    pub animation_preset: Option<String>,
     pub position: Option<String>,
+    pub floating: bool,
+    pub respect_reserved: bool,
     pub hysteresis: Option<f32>,
     pub restore_focus: bool,
     pub multi: bool,
     pub multi_window: bool,
     pub max_instances: Option<u32>,
+    pub debounce_ms: Option<u32>,
+    pub interrupt: String,
 
     // Validation metadata
     pub validation_errors: Vec<String>,
     pub validation_warnings: Vec<String>,
 
+    /// `class_regex`, compiled once during validation
+    #[serde(skip)]
+    pub compiled_class_regex: Option<regex::Regex>,
+
     // Pre-calculated values for performance
     pub parsed_size: Option<(i32, i32)>, // width, height (cached for default monitor)
     pub parsed_offset: Option<(i32, i32)>, // x, y offset
     pub parsed_max_size: Option<(i32, i32)>, // max width, height
     pub parsed_position: Option<(i32, i32)>, // parsed x, y position
+    pub parsed_anchor: Option<PositionAnchor>, // named `position` anchor, resolved per monitor
+    pub parsed_monitor_sizes: HashMap<String, (i32, i32)>, // per_monitor sizes on known monitors
+
+    /// Window size before any resize, learned on first spawn for `preserve_aspect`
+    pub native_size: Option<(i32, i32)>,
 }
 
 impl ValidatedConfig {
+    /// Whether the window's class is exactly the configured `class`
+    pub fn matches_exact_class(&self, client: &hyprland::data::Client) -> bool {
+        !self.class.is_empty() && self.class != "unknown" && client.class == self.class
+    }
+
+    /// Whether the window belongs to this scratchpad: its exact `class`, or else
+    /// the compiled `class_regex`
+    pub fn matches_class(&self, client: &hyprland::data::Client) -> bool {
+        self.matches_exact_class(client)
+            || self
+                .compiled_class_regex
+                .as_ref()
+                .is_some_and(|regex| regex.is_match(&client.class))
+    }
+
+    /// Size string for a monitor: its `per_monitor` override, else the base `size`
+    pub fn size_for_monitor(&self, monitor_name: &str) -> &str {
+        self.per_monitor
+            .get(monitor_name)
+            .map(String::as_str)
+            .unwrap_or(&self.size)
+    }
+
+    /// Whether `aspect` ("position" or "opacity") is animated, or None when `animate`
+    /// is unset and the animation type decides
+    pub fn animates(&self, aspect: &str) -> Option<bool> {
+        self.animate
+            .as_ref()
+            .map(|aspects| aspects.iter().any(|a| a == aspect))
+    }
+
+    /// Engine properties for an explicit `animate` selection or `animation_properties`,
+    /// moving from `from` to `to` on `monitor`; None when both are unset. Position is
+    /// always included so that without "position" the window stays where it is visible
+    /// (`to` on show, `from` on hide). `animation_properties` entries replace the
+    /// computed property of the same name, and are played backwards on hide
+    pub fn animation_properties_for(
+        &self,
+        from: (i32, i32),
+        to: (i32, i32),
+        showing: bool,
+        monitor: &MonitorInfo,
+    ) -> Option<Vec<crate::animation::AnimationPropertyConfig>> {
+        use crate::animation::{AnimationPropertyConfig, PropertyValue};
+
+        if self.animate.is_none() && self.animation_properties.is_none() {
+            return None;
+        }
+
+        let (from, to) = match self.animates("position") {
+            Some(true) => (from, to),
+            _ if showing => (to, to),
+            _ => (from, from),
+        };
+        let mut properties = vec![
+            AnimationPropertyConfig {
+                property: "x".to_string(),
+                from: PropertyValue::Pixels(from.0),
+                to: PropertyValue::Pixels(to.0),
+                easing: None,
+            },
+            AnimationPropertyConfig {
+                property: "y".to_string(),
+                from: PropertyValue::Pixels(from.1),
+                to: PropertyValue::Pixels(to.1),
+                easing: None,
+            },
+        ];
+
+        if self.animates("opacity") == Some(true) {
+            let hidden = self.animation_opacity_from.unwrap_or(0.0);
+            let (from, to) = if showing {
+                (hidden, 1.0)
+            } else {
+                (1.0, hidden)
+            };
+            properties.push(AnimationPropertyConfig {
+                property: "opacity".to_string(),
+                from: PropertyValue::Float(from),
+                to: PropertyValue::Float(to),
+                easing: None,
+            });
+        }
+
+        for custom in self.animation_properties.iter().flatten() {
+            match custom.to_engine(showing, monitor) {
+                Ok(property) => {
+                    properties.retain(|p| p.property != property.property);
+                    properties.push(property);
+                }
+                // Reported by validation; the rest of the animation still plays
+                Err(e) => warn!("⚠️ Skipping animation property: {}", e),
+            }
+        }
+
+        Some(properties)
+    }
+
     /// Convert configuration to appropriate EasingFunction based on animation_easing and physics parameters
     pub fn to_easing_function(&self) -> crate::animation::EasingFunction {
         use crate::animation::EasingFunction;
@@ -236,11 +446,76 @@ impl ValidatedConfig {
         }
     }
 
+    /// Spring driving physics-based animations, settling below `spring_settle_epsilon`
+    pub fn spring_animation(&self) -> crate::animation::SpringAnimation {
+        let spring = self.spring_settings();
+        crate::animation::SpringAnimation::new(
+            crate::animation::SpringParams {
+                stiffness: spring.stiffness,
+                damping: spring.damping,
+                mass: spring.mass,
+            },
+            spring.settle_epsilon,
+            crate::animation::spring::MAX_SPRING_DURATION,
+        )
+    }
+
+    /// The same spring as `spring_animation`, for animations the engine drives
+    /// (hide). None unless the easing is `spring`
+    pub fn spring_config(&self) -> Option<crate::animation::SpringConfig> {
+        self.uses_physics_animation()
+            .then(|| self.spring_settings())
+    }
+
+    /// How long the spring takes to settle (or reach its cap), played at 60 fps
+    pub fn spring_settle_time(&self) -> Duration {
+        let mut spring = self.spring_animation();
+        while spring.next_frame(Duration::from_micros(16_667)).is_some() {}
+        spring.elapsed()
+    }
+
+    fn spring_settings(&self) -> crate::animation::SpringConfig {
+        let defaults = crate::animation::SpringParams::default();
+        crate::animation::SpringConfig {
+            stiffness: self.spring_stiffness.unwrap_or(defaults.stiffness),
+            damping: self.spring_damping.unwrap_or(defaults.damping),
+            mass: self.spring_mass.unwrap_or(defaults.mass),
+            initial_velocity: 0.0,
+            settle_epsilon: self
+                .spring_settle_epsilon
+                .unwrap_or(crate::animation::DEFAULT_SETTLE_EPSILON),
+        }
+    }
+
+    /// Whether hiding parks the window off-screen on its workspace instead of moving
+    /// it to the special workspace. Windows pinned by Hyprland always are: moving them
+    /// to a special workspace would drop the pin
+    pub fn parks_offscreen(&self, window_pinned: bool) -> bool {
+        self.floating && (self.hide_mode == "offscreen" || (self.pinned && window_pinned))
+    }
+
+    /// Whether show and hide fade the window: an explicit `animate` selection
+    /// decides, otherwise a fade animation type does
+    pub fn fades(&self) -> bool {
+        self.animates("opacity")
+            .unwrap_or_else(|| self.animation.as_ref().is_some_and(|a| a.contains("fade")))
+            || self
+                .animation_properties
+                .iter()
+                .flatten()
+                .any(|property| property.property == "opacity")
+    }
+
     /// Check if this configuration uses physics-based animations
     pub fn uses_physics_animation(&self) -> bool {
         matches!(self.animation_easing.as_deref(), Some("spring"))
     }
 
+    /// Whether shows end with a short overshoot-and-settle (`arrival_effect = "bounce"`)
+    pub fn bounces_on_arrival(&self) -> bool {
+        self.arrival_effect.as_deref() == Some("bounce")
+    }
+
     /// Check if this configuration uses custom bezier curves
     pub fn uses_custom_bezier(&self) -> bool {
         self.cubic_bezier_x1.is_some()
@@ -255,6 +530,7 @@ impl Default for ValidatedConfig {
         Self {
             command: String::new(),
             class: String::new(),
+            class_regex: None,
             size: "50% 50%".to_string(),
             animation: None,
             animation_duration: None,
@@ -263,9 +539,12 @@ impl Default for ValidatedConfig {
             animation_scale_from: None,
             animation_opacity_from: None,
             animation_properties: None,
+            animate: None,
+            arrival_effect: None,
             spring_stiffness: None,
             spring_damping: None,
             spring_mass: None,
+            spring_settle_epsilon: None,
             cubic_bezier_x1: None,
             cubic_bezier_y1: None,
             cubic_bezier_x2: None,
@@ -276,6 +555,7 @@ impl Default for ValidatedConfig {
             lazy: false,
             pinned: true,
             excludes: Vec::new(),
+            group: None,
             restore_excluded: false,
             preserve_aspect: false,
             force_monitor: None,
@@ -283,21 +563,33 @@ impl Default for ValidatedConfig {
             allow_special_workspaces: false,
             smart_focus: true,
             close_on_hide: false,
+            hide_mode: "special".to_string(),
+            kill_process_group: false,
             unfocus: None,
             max_size: None,
+            per_monitor: HashMap::new(),
             r#use: None,
+            animation_preset: None,
             position: None,
+            floating: true,
+            respect_reserved: true,
             hysteresis: Some(0.4),
             restore_focus: true,
             multi: false,
             multi_window: false,
             max_instances: Some(1),
+            debounce_ms: None,
+            interrupt: "cancel".to_string(),
             validation_errors: Vec::new(),
             validation_warnings: Vec::new(),
+            compiled_class_regex: None,
             parsed_size: None,
             parsed_offset: None,
             parsed_max_size: None,
             parsed_position: None,
+            parsed_anchor: None,
+            parsed_monitor_sizes: HashMap::new(),
+            native_size: None,
         }
     }
 }
@@ -372,34 +664,164 @@ impl ScratchpadState {
 
 pub struct GeometryCalculator;
 
+/// Named `position` such as `"top-center"`, placed against the usable area of the
+/// target monitor with `margin` as inset from the anchored edge(s)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PositionAnchor {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    CenterLeft,
+    Center,
+    CenterRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+impl PositionAnchor {
+    /// Parse an anchor keyword (`top-left`, `center`, `bottom-right`, ...)
+    pub fn parse(anchor: &str) -> Option<Self> {
+        let anchor = anchor.trim().to_lowercase().replace('_', "-");
+        Some(match anchor.as_str() {
+            "top-left" => Self::TopLeft,
+            "top-center" | "top" => Self::TopCenter,
+            "top-right" => Self::TopRight,
+            "center-left" | "left" => Self::CenterLeft,
+            "center" => Self::Center,
+            "center-right" | "right" => Self::CenterRight,
+            "bottom-left" => Self::BottomLeft,
+            "bottom-center" | "bottom" => Self::BottomCenter,
+            "bottom-right" => Self::BottomRight,
+            _ => return None,
+        })
+    }
+
+    /// Top-left corner, relative to an area of `area` size, of a window of `size`
+    pub fn resolve(self, area: (i32, i32), size: (i32, i32), margin: i32) -> (i32, i32) {
+        let place = |edge: i8, area: i32, size: i32| match edge {
+            -1 => margin,
+            0 => (area - size) / 2,
+            _ => area - size - margin,
+        };
+        let (horizontal, vertical) = match self {
+            Self::TopLeft => (-1, -1),
+            Self::TopCenter => (0, -1),
+            Self::TopRight => (1, -1),
+            Self::CenterLeft => (-1, 0),
+            Self::Center => (0, 0),
+            Self::CenterRight => (1, 0),
+            Self::BottomLeft => (-1, 1),
+            Self::BottomCenter => (0, 1),
+            Self::BottomRight => (1, 1),
+        };
+        (
+            place(horizontal, area.0, size.0),
+            place(vertical, area.1, size.1),
+        )
+    }
+}
+
+/// Monitor a `force_monitor` value designates: a name (`"DP-1"`), an index matching
+/// the Hyprland monitor id (`"1"`, or `1` in TOML), or `"current"` for the focused one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MonitorSelector {
+    Name(String),
+    Index(i128),
+    Current,
+}
+
+impl MonitorSelector {
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("current") {
+            Self::Current
+        } else if let Ok(index) = value.parse() {
+            Self::Index(index)
+        } else {
+            Self::Name(value.to_string())
+        }
+    }
+
+    /// The selected monitor among `monitors`, if connected
+    pub fn find<'a>(&self, monitors: &'a [MonitorInfo]) -> Option<&'a MonitorInfo> {
+        monitors.iter().find(|m| match self {
+            Self::Name(name) => m.name == *name,
+            Self::Index(index) => m.id == *index,
+            Self::Current => m.is_focused,
+        })
+    }
+}
+
 impl GeometryCalculator {
     /// Calculate window geometry with monitor-aware positioning
     pub fn calculate_geometry(
         config: &ValidatedConfig,
         monitor: &MonitorInfo,
     ) -> Result<WindowGeometry> {
-        let (width, height) = Self::parse_size(&config.size, monitor, config.max_size.as_deref())?;
+        Self::calculate_geometry_with_clamping(config, monitor).map(|(geometry, _)| geometry)
+    }
+
+    /// `calculate_geometry`, also telling whether the position had to be pulled
+    /// back inside the monitor's usable area
+    pub fn calculate_geometry_with_clamping(
+        config: &ValidatedConfig,
+        monitor: &MonitorInfo,
+    ) -> Result<(WindowGeometry, bool)> {
+        // `per_monitor` overrides are pre-calculated for the monitors known at validation
+        let (width, height) = match config.parsed_monitor_sizes.get(&monitor.name) {
+            Some(&size) => size,
+            None => Self::parse_size(
+                config.size_for_monitor(&monitor.name),
+                monitor,
+                config.max_size.as_deref(),
+            )?,
+        };
+        let (width, height) = match config.native_size {
+            Some(native) if config.preserve_aspect => Self::fit_aspect((width, height), native),
+            _ => (width, height),
+        };
         let (offset_x, offset_y) = Self::parse_offset(config.offset.as_deref(), monitor)?;
         let margin = config.margin.unwrap_or(0);
+        let (area_x, area_y, area_width, area_height) = Self::usable_area(config, monitor);
 
         // Calculate position with monitor-aware positioning
-        let (x, y) = if let Some((pos_x, pos_y)) = config.parsed_position {
+        let (x, y) = if let Some(anchor) = config.parsed_anchor {
+            // Anchors depend on the monitor, so resolve them on the target one
+            let (pos_x, pos_y) = anchor.resolve((area_width, area_height), (width, height), margin);
+            (area_x + pos_x, area_y + pos_y)
+        } else if let Some((pos_x, pos_y)) = config.parsed_position {
             // Use explicit position when provided
-            (monitor.x + pos_x, monitor.y + pos_y)
+            (area_x + pos_x, area_y + pos_y)
+        } else if let Some(position) = &config.position {
+            // Position set but not pre-parsed (no monitor at load time, or invalid)
+            match Self::parse_offset(Some(position), monitor) {
+                Ok((pos_x, pos_y)) => (area_x + pos_x, area_y + pos_y),
+                Err(e) => {
+                    warn!(
+                        "⚠️ Invalid position '{}' ({}), centering on monitor {}",
+                        position, e, monitor.name
+                    );
+                    (
+                        area_x + (area_width - width) / 2,
+                        area_y + (area_height - height) / 2,
+                    )
+                }
+            }
         } else {
             // Use offset and margin-based positioning
-            let base_x = monitor.x + offset_x + margin;
-            let base_y = monitor.y + offset_y + margin;
+            let base_x = area_x + offset_x + margin;
+            let base_y = area_y + offset_y + margin;
 
             // Center the window if no specific positioning
             let x = if offset_x == 0 && config.offset.is_none() {
-                monitor.x + (monitor.width as i32 - width) / 2
+                area_x + (area_width - width) / 2
             } else {
                 base_x
             };
 
             let y = if offset_y == 0 && config.offset.is_none() {
-                monitor.y + (monitor.height as i32 - height) / 2
+                area_y + (area_height - height) / 2
             } else {
                 base_y
             };
@@ -407,23 +829,54 @@ impl GeometryCalculator {
             (x, y)
         };
 
-        // Ensure window stays within monitor bounds
-        let final_x = x
-            .max(monitor.x)
-            .min(monitor.x + (monitor.width as i32) - width);
-        let final_y = y
-            .max(monitor.y)
-            .min(monitor.y + (monitor.height as i32) - height);
+        // Ensure window stays within bounds, keeping its top-left corner visible
+        let final_x = x.min(area_x + area_width - width).max(area_x);
+        let final_y = y.min(area_y + area_height - height).max(area_y);
 
-        Ok(WindowGeometry {
+        let geometry = WindowGeometry {
             x: final_x,
             y: final_y,
             width,
             height,
             workspace: "e+0".to_string(), // Default workspace
             monitor: 0,                   // Will be updated based on actual monitor
-            floating: true,               // Scratchpads are typically floating
-        })
+            floating: config.floating,
+        };
+        Ok((geometry, (final_x, final_y) != (x, y)))
+    }
+
+    /// Area available for placement as (x, y, width, height): the whole monitor, or
+    /// the monitor minus Hyprland's reserved edges (bars) with `respect_reserved`
+    pub fn usable_area(config: &ValidatedConfig, monitor: &MonitorInfo) -> (i32, i32, i32, i32) {
+        let (top, bottom, left, right) = if config.respect_reserved {
+            (
+                monitor.reserved_top as i32,
+                monitor.reserved_bottom as i32,
+                monitor.reserved_left as i32,
+                monitor.reserved_right as i32,
+            )
+        } else {
+            (0, 0, 0, 0)
+        };
+
+        (
+            monitor.x + left,
+            monitor.y + top,
+            (monitor.width as i32 - left - right).max(0),
+            (monitor.height as i32 - top - bottom).max(0),
+        )
+    }
+
+    /// Geometry to apply when showing, or `None` for tiled scratchpads where the
+    /// layout places the window
+    pub fn show_geometry(
+        config: &ValidatedConfig,
+        monitor: &MonitorInfo,
+    ) -> Result<Option<WindowGeometry>> {
+        if !config.floating {
+            return Ok(None);
+        }
+        Self::calculate_geometry(config, monitor).map(Some)
     }
 
     /// Parse size string with monitor-aware dimensions
@@ -456,25 +909,90 @@ impl GeometryCalculator {
         Ok((width, height))
     }
 
-    /// Parse offset string like "50px 100px" or "10% 20%"
-    pub fn parse_offset(offset_str: Option<&str>, monitor: &MonitorInfo) -> Result<(i32, i32)> {
-        let offset_str = match offset_str {
-            Some(s) => s,
-            None => return Ok((0, 0)),
-        };
+    /// Largest size of `native`'s aspect ratio inside `size`: the longer dimension
+    /// (relative to that ratio) is shrunk, the other kept
+    pub fn fit_aspect(size: (i32, i32), native: (i32, i32)) -> (i32, i32) {
+        let (width, height) = size;
+        let (native_width, native_height) = native;
+        if native_width <= 0 || native_height <= 0 {
+            return size;
+        }
+
+        // Compare width / height against native_width / native_height without division
+        let (width, height) = (width as i64, height as i64);
+        let (native_width, native_height) = (native_width as i64, native_height as i64);
+        if width * native_height > height * native_width {
+            (
+                (height * native_width / native_height) as i32,
+                height as i32,
+            )
+        } else {
+            (width as i32, (width * native_height / native_width) as i32)
+        }
+    }
 
-        let parts: Vec<&str> = offset_str.split_whitespace().collect();
-        if parts.len() != 2 {
-            return Err(anyhow::anyhow!(
-                "Invalid offset format '{}', expected 'x y'",
-                offset_str
-            ));
+    /// Parse offset string like "50px 100px", "10% 20%" or "20px" (both axes)
+    pub fn parse_offset(offset_str: Option<&str>, monitor: &MonitorInfo) -> Result<(i32, i32)> {
+        match offset_str {
+            Some(offset) => Ok(Offset::parse(offset)?.resolve(monitor)),
+            None => Ok((0, 0)),
         }
+    }
 
-        let x = Self::parse_dimension(parts[0], monitor.width as i32)?;
-        let y = Self::parse_dimension(parts[1], monitor.height as i32)?;
+    /// Geometry after a `resize` request on a window at `current` (x, y, width, height).
+    /// Each dimension is absolute (`800px`, `50%`) or a delta on the current size
+    /// (`+100`, `-50px`, `+10%`), percentages being of the monitor. The size is clamped
+    /// to `max_size` and the usable area, and the window moved back on-screen if needed
+    pub fn resize_geometry(
+        config: &ValidatedConfig,
+        monitor: &MonitorInfo,
+        current: (i32, i32, i32, i32),
+        request: (&str, &str),
+    ) -> Result<(i32, i32, i32, i32)> {
+        let (x, y, current_width, current_height) = current;
+        let mut width = Self::resize_dimension(request.0, current_width, monitor.width as i32)
+            .map_err(|e| anyhow::anyhow!("Invalid width '{}': {}", request.0, e))?;
+        let mut height = Self::resize_dimension(request.1, current_height, monitor.height as i32)
+            .map_err(|e| anyhow::anyhow!("Invalid height '{}': {}", request.1, e))?;
+
+        if let Some(max_size) = &config.max_size {
+            let (max_width, max_height) = Self::parse_size(max_size, monitor, None)?;
+            width = width.min(max_width);
+            height = height.min(max_height);
+        }
+        let (area_x, area_y, area_width, area_height) = Self::usable_area(config, monitor);
+        width = width.min(area_width);
+        height = height.min(area_height);
+
+        Ok((
+            x.min(area_x + area_width - width).max(area_x),
+            y.min(area_y + area_height - height).max(area_y),
+            width,
+            height,
+        ))
+    }
 
-        Ok((x, y))
+    /// One dimension of a `resize` request, see `resize_geometry`
+    fn resize_dimension(spec: &str, current: i32, monitor_size: i32) -> Result<i32> {
+        let value = match spec.chars().next() {
+            Some(sign @ ('+' | '-')) => {
+                let amount = &spec[1..];
+                if !amount.starts_with(|c: char| c.is_ascii_digit()) {
+                    return Err(anyhow::anyhow!("expected a number after '{}'", sign));
+                }
+                let delta = Self::parse_dimension(amount, monitor_size)?;
+                if sign == '+' {
+                    current + delta
+                } else {
+                    current - delta
+                }
+            }
+            _ => Self::parse_dimension(spec, monitor_size)?,
+        };
+        if value <= 0 {
+            return Err(anyhow::anyhow!("the size would be {}px", value));
+        }
+        Ok(value)
     }
 
     /// Parse individual dimension (supports %, px, or raw numbers)
@@ -512,6 +1030,17 @@ impl ConfigValidator {
         configs: &HashMap<String, ScratchpadConfigRef>,
         monitors: &[MonitorInfo],
         variables: &HashMap<String, String>,
+    ) -> HashMap<String, ValidatedConfigRef> {
+        Self::validate_configs_with_presets(configs, monitors, variables, &HashMap::new())
+    }
+
+    /// Validate and preprocess scratchpad configurations, resolving `animation_preset`
+    /// references against `presets`
+    pub fn validate_configs_with_presets(
+        configs: &HashMap<String, ScratchpadConfigRef>,
+        monitors: &[MonitorInfo],
+        variables: &HashMap<String, String>,
+        presets: &HashMap<String, AnimationPreset>,
     ) -> HashMap<String, ValidatedConfigRef> {
         let mut validated_temp = HashMap::new();
 
@@ -535,9 +1064,23 @@ impl ConfigValidator {
                 }
             }
 
+            // Resolve animation preset: explicit (or inherited) fields win
+            if let Some(preset_name) = validated_config.animation_preset.clone() {
+                if let Some(preset) = presets.get(&preset_name) {
+                    Self::apply_animation_preset(&mut validated_config, preset);
+                } else {
+                    validated_config
+                        .validation_errors
+                        .push(format!("Animation preset '{preset_name}' not found"));
+                }
+            }
+
             validated_temp.insert(name.clone(), validated_config);
         }
 
+        Self::apply_groups(&mut validated_temp);
+        Self::detect_rule_conflicts(&mut validated_temp);
+
         // Second pass: cross-validation and advanced checks
         let validated_clone = validated_temp.clone();
         for (name, config) in &mut validated_temp {
@@ -553,6 +1096,78 @@ impl ConfigValidator {
         validated
     }
 
+    /// Turn `group` membership into mutual `excludes` between group-mates
+    fn apply_groups(configs: &mut HashMap<String, ValidatedConfig>) {
+        let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+        for (name, config) in configs.iter_mut() {
+            match config.group.as_deref().map(str::trim) {
+                Some("") => config
+                    .validation_errors
+                    .push("Group name cannot be empty".to_string()),
+                Some(group) => groups
+                    .entry(group.to_string())
+                    .or_default()
+                    .push(name.clone()),
+                None => {}
+            }
+        }
+
+        for members in groups.values() {
+            for name in members {
+                let Some(config) = configs.get_mut(name) else {
+                    continue;
+                };
+                for mate in members {
+                    if mate != name && !config.excludes.contains(mate) {
+                        config.excludes.push(mate.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Warn about scratchpads whose window rules would target the same windows.
+    /// Windows are claimed (and their `windowrulev2` rules applied) by exact class
+    /// match, so scratchpads sharing a class steal each other's windows. Hidden
+    /// windows all share `special:hidden` by design, so that is not reported
+    fn detect_rule_conflicts(configs: &mut HashMap<String, ValidatedConfig>) {
+        let mut by_class: HashMap<String, Vec<String>> = HashMap::new();
+        for (name, config) in configs.iter() {
+            if config.class != "unknown" && !config.class.is_empty() {
+                by_class
+                    .entry(config.class.clone())
+                    .or_default()
+                    .push(name.clone());
+            }
+        }
+
+        for (class, mut names) in by_class {
+            if names.len() < 2 {
+                continue;
+            }
+            names.sort();
+            warn!(
+                "⚠️ Scratchpads {} share class '{}': their windows and window rules will get mixed up",
+                names.join(", "),
+                class
+            );
+            for name in &names {
+                let others: Vec<&str> = names
+                    .iter()
+                    .filter(|other| *other != name)
+                    .map(String::as_str)
+                    .collect();
+                if let Some(config) = configs.get_mut(name) {
+                    config.validation_warnings.push(format!(
+                        "Class '{}' is also used by scratchpad(s) {}; windows may be claimed by the wrong scratchpad",
+                        class,
+                        others.join(", ")
+                    ));
+                }
+            }
+        }
+    }
+
     fn convert_to_validated(config: &ScratchpadConfig) -> ValidatedConfig {
         debug!("🔍 CONVERT_TO_VALIDATED for command '{}': animation_duration={:?}, animation_delay={:?}, animation_easing={:?}", 
                config.command, config.animation_duration, config.animation_delay, config.animation_easing);
@@ -566,6 +1181,7 @@ impl ConfigValidator {
         ValidatedConfig {
             command: config.command.clone(),
             class,
+            class_regex: config.class_regex.clone(),
             size: config.size.clone(),
             animation: config.animation.clone(),
             animation_duration: config.animation_duration,
@@ -574,9 +1190,12 @@ impl ConfigValidator {
             animation_scale_from: config.animation_scale_from,
             animation_opacity_from: config.animation_opacity_from,
             animation_properties: config.animation_properties.clone(),
+            animate: config.animate.clone(),
+            arrival_effect: config.arrival_effect.clone(),
             spring_stiffness: config.spring_stiffness,
             spring_damping: config.spring_damping,
             spring_mass: config.spring_mass,
+            spring_settle_epsilon: config.spring_settle_epsilon,
             cubic_bezier_x1: config.cubic_bezier_x1,
             cubic_bezier_y1: config.cubic_bezier_y1,
             cubic_bezier_x2: config.cubic_bezier_x2,
@@ -587,6 +1206,7 @@ impl ConfigValidator {
             lazy: config.lazy,
             pinned: config.pinned,
             excludes: config.excludes.clone(),
+            group: config.group.clone(),
             restore_excluded: config.restore_excluded,
             preserve_aspect: config.preserve_aspect,
             force_monitor: config.force_monitor.clone(),
@@ -594,21 +1214,33 @@ impl ConfigValidator {
             allow_special_workspaces: config.allow_special_workspaces,
             smart_focus: config.smart_focus,
             close_on_hide: config.close_on_hide,
+            hide_mode: config.hide_mode.clone(),
+            kill_process_group: config.kill_process_group,
             unfocus: config.unfocus.clone(),
             max_size: config.max_size.clone(),
+            per_monitor: config.per_monitor.clone(),
             r#use: config.r#use.clone(),
+            animation_preset: config.animation_preset.clone(),
             position: config.position.clone(),
+            floating: config.floating,
+            respect_reserved: config.respect_reserved,
             hysteresis: config.hysteresis,
             restore_focus: config.restore_focus,
             multi: config.multi,
             multi_window: config.multi_window || config.multi, // Support both
             max_instances: config.max_instances,
+            debounce_ms: config.debounce_ms,
+            interrupt: config.interrupt.clone(),
             validation_errors: Vec::new(),
             validation_warnings: Vec::new(),
+            compiled_class_regex: None,
             parsed_size: None,
             parsed_offset: None,
             parsed_max_size: None,
             parsed_position: None,
+            parsed_anchor: None,
+            parsed_monitor_sizes: HashMap::new(),
+            native_size: None,
         }
     }
 
@@ -627,59 +1259,63 @@ impl ConfigValidator {
 
         // Note: Class validation - class is now required for documentation
 
-        // Validate size format and pre-calculate for default monitor
-        if let Some(default_monitor) = monitors.first() {
-            match GeometryCalculator::parse_size(
-                &config.size,
-                default_monitor,
-                config.max_size.as_deref(),
-            ) {
-                Ok((width, height)) => {
-                    config.parsed_size = Some((width, height));
-                }
-                Err(e) => {
-                    config
-                        .validation_errors
-                        .push(format!("Invalid size format: {e}"));
-                }
-            }
-
-            // Pre-calculate offset
-            if let Ok((x, y)) =
-                GeometryCalculator::parse_offset(config.offset.as_deref(), default_monitor)
-            {
-                config.parsed_offset = Some((x, y));
+        if let Some(pattern) = &config.class_regex {
+            match regex::Regex::new(pattern) {
+                Ok(regex) => config.compiled_class_regex = Some(regex),
+                Err(e) => config
+                    .validation_errors
+                    .push(format!("Invalid class_regex '{pattern}': {e}")),
             }
+        }
 
-            // Pre-calculate max_size
-            if let Some(max_size) = &config.max_size {
-                if let Ok((max_w, max_h)) =
-                    GeometryCalculator::parse_size(max_size, default_monitor, None)
-                {
-                    config.parsed_max_size = Some((max_w, max_h));
+        // Validate position format (doesn't depend on monitor dimensions). A single
+        // word is an anchor and must be a known one rather than silently centering
+        if let Some(position_str) = &config.position {
+            if !position_str.trim().contains(char::is_whitespace) {
+                match PositionAnchor::parse(position_str) {
+                    Some(anchor) => config.parsed_anchor = Some(anchor),
+                    None => config.validation_errors.push(format!(
+                        "Invalid position format: unknown anchor '{position_str}'"
+                    )),
                 }
+            } else if let Err(e) =
+                GeometryCalculator::parse_offset(Some(position_str), &MonitorInfo::default())
+            {
+                config
+                    .validation_errors
+                    .push(format!("Invalid position format: {e}"));
             }
+        }
 
-            // Pre-calculate position
-            if let Some(position_str) = &config.position {
-                if let Ok((x, y)) =
-                    GeometryCalculator::parse_offset(Some(position_str), default_monitor)
-                {
-                    config.parsed_position = Some((x, y));
-                } else {
-                    config
-                        .validation_errors
-                        .push(format!("Invalid position format: {}", position_str));
-                }
+        if let Some(offset) = &config.offset {
+            if let Err(e) = Offset::parse(offset) {
+                config.validation_errors.push(e.to_string());
             }
         }
 
+        let (errors, warnings) = Self::precompute_geometry(config, monitors);
+        config.validation_errors.extend(errors);
+        config.validation_warnings.extend(warnings);
+
         // Validate monitor reference
-        if let Some(monitor_name) = &config.force_monitor {
-            if !monitors.iter().any(|m| m.name == *monitor_name) {
-                config.validation_warnings.push(format!(
-                    "Monitor '{monitor_name}' not found, will use focused monitor"
-                ));
+        if let Some(forced) = &config.force_monitor {
+            match MonitorSelector::parse(forced) {
+                MonitorSelector::Current => {}
+                selector @ MonitorSelector::Index(index) => {
+                    if selector.find(monitors).is_none() {
+                        config.validation_warnings.push(format!(
+                            "Monitor index {index} is out of range ({} monitor(s) connected), will use focused monitor",
+                            monitors.len()
+                        ));
+                    }
+                }
+                selector @ MonitorSelector::Name(_) => {
+                    if selector.find(monitors).is_none() {
+                        config.validation_warnings.push(format!(
+                            "Monitor '{forced}' not found, will use focused monitor"
+                        ));
+                    }
+                }
             }
         }
 
@@ -707,6 +1343,40 @@ impl ConfigValidator {
             }
         }
 
+        // Validate hide mode
+        match config.hide_mode.as_str() {
+            "special" | "offscreen" => {}
+            "minimize" => config.validation_warnings.push(
+                "hide_mode 'minimize' is not supported by Hyprland, using 'special'".to_string(),
+            ),
+            other => config.validation_errors.push(format!(
+                "Invalid hide_mode '{other}', expected 'special', 'offscreen' or 'minimize'"
+            )),
+        }
+        if config.hide_mode == "offscreen" && !config.floating {
+            config.validation_warnings.push(
+                "hide_mode 'offscreen' needs a floating window, tiled windows use 'special'"
+                    .to_string(),
+            );
+        }
+
+        // Validate arrival effect
+        if let Some(effect) = &config.arrival_effect {
+            if !matches!(effect.as_str(), "bounce" | "none") {
+                config.validation_errors.push(format!(
+                    "Invalid arrival_effect '{effect}', expected 'bounce' or 'none'"
+                ));
+            }
+        }
+
+        // Validate interrupt policy
+        if !matches!(config.interrupt.as_str(), "cancel" | "ignore") {
+            config.validation_errors.push(format!(
+                "Invalid interrupt policy '{}', expected 'cancel' or 'ignore'",
+                config.interrupt
+            ));
+        }
+
         // Validate hide_delay
         if let Some(delay) = config.hide_delay {
             if delay > 10000 {
@@ -729,6 +1399,16 @@ impl ConfigValidator {
             }
         }
 
+        // hide_delay only delays hide commands; unfocus hides wait for hysteresis
+        if config.hide_delay.is_some_and(|delay| delay > 0)
+            && config.unfocus.as_deref() == Some("hide")
+        {
+            config.validation_warnings.push(format!(
+                "hide_delay applies to hide commands only, unfocus hides use hysteresis ({:.1}s)",
+                config.hysteresis.unwrap_or(0.4)
+            ));
+        }
+
         // Validate animation configuration
         Self::validate_animation_config(config);
 
@@ -750,26 +1430,152 @@ impl ConfigValidator {
         }
     }
 
-    fn merge_with_template(
-        mut config: ValidatedConfig,
-        template: &ScratchpadConfig,
-    ) -> ValidatedConfig {
-        // Only use template values if current config doesn't have them set
-        if config.command.is_empty() && !template.command.is_empty() {
-            config.command = template.command.clone();
-        }
-        // Inherit class from template if not specified or is "unknown"
-        if config.class == "unknown" {
-            if let Some(template_class) = &template.class {
-                config.class = template_class.clone();
+    /// Pre-calculate the geometry fields (size, offset, max size and position on
+    /// the first monitor, per-monitor sizes on the others) for `monitors`, replacing
+    /// earlier values. Returns the errors and warnings found along the way
+    pub fn precompute_geometry(
+        config: &mut ValidatedConfig,
+        monitors: &[MonitorInfo],
+    ) -> (Vec<String>, Vec<String>) {
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+        config.parsed_size = None;
+        config.parsed_offset = None;
+        config.parsed_max_size = None;
+        config.parsed_position = None;
+        config.parsed_monitor_sizes.clear();
+
+        // Validate size format and pre-calculate for default monitor
+        if let Some(default_monitor) = monitors.first() {
+            match GeometryCalculator::parse_size(
+                &config.size,
+                default_monitor,
+                config.max_size.as_deref(),
+            ) {
+                Ok((width, height)) => {
+                    config.parsed_size = Some((width, height));
+                }
+                Err(e) => {
+                    errors.push(format!("Invalid size format: {e}"));
+                }
+            }
+
+            // Pre-calculate offset
+            if let Ok((x, y)) =
+                GeometryCalculator::parse_offset(config.offset.as_deref(), default_monitor)
+            {
+                config.parsed_offset = Some((x, y));
+            }
+
+            // Pre-calculate max_size
+            if let Some(max_size) = &config.max_size {
+                if let Ok((max_w, max_h)) =
+                    GeometryCalculator::parse_size(max_size, default_monitor, None)
+                {
+                    config.parsed_max_size = Some((max_w, max_h));
+                }
+            }
+
+            // Pre-calculate position
+            if let (Some(anchor), Some(size)) = (config.parsed_anchor, config.parsed_size) {
+                let (_, _, area_width, area_height) =
+                    GeometryCalculator::usable_area(config, default_monitor);
+                config.parsed_position = Some(anchor.resolve(
+                    (area_width, area_height),
+                    size,
+                    config.margin.unwrap_or(0),
+                ));
+            } else if let Some(position_str) = &config.position {
+                if let Ok((x, y)) =
+                    GeometryCalculator::parse_offset(Some(position_str), default_monitor)
+                {
+                    config.parsed_position = Some((x, y));
+                }
+            }
+        }
+
+        // Validate per-monitor size overrides, pre-calculating them on known monitors
+        let mut overrides: Vec<(&String, &String)> = config.per_monitor.iter().collect();
+        overrides.sort();
+        for (monitor_name, size) in overrides {
+            let monitor = monitors.iter().find(|m| m.name == *monitor_name);
+            let reference = monitor.cloned().unwrap_or_default();
+            match GeometryCalculator::parse_size(size, &reference, config.max_size.as_deref()) {
+                Ok(parsed) if monitor.is_some() => {
+                    config
+                        .parsed_monitor_sizes
+                        .insert(monitor_name.clone(), parsed);
+                }
+                Ok(_) => {
+                    if !monitors.is_empty() {
+                        warnings.push(format!(
+                            "Size override for monitor '{monitor_name}' does not match any connected monitor"
+                        ));
+                    }
+                }
+                Err(e) => errors.push(format!(
+                    "Invalid size override for monitor '{monitor_name}': {e}"
+                )),
+            }
+        }
+
+        (errors, warnings)
+    }
+
+    /// Fill animation settings left unset by the scratchpad from a preset
+    fn apply_animation_preset(config: &mut ValidatedConfig, preset: &AnimationPreset) {
+        if config.animation.is_none() {
+            config.animation = preset.animation_type.clone();
+        }
+        if config.animation_duration.is_none() {
+            config.animation_duration = preset.duration;
+        }
+        if config.animation_easing.is_none() {
+            config.animation_easing = preset.easing.clone();
+        }
+        if config.animation_delay.is_none() {
+            config.animation_delay = preset.delay;
+        }
+        if config.spring_stiffness.is_none() {
+            config.spring_stiffness = preset.spring_stiffness;
+        }
+        if config.spring_damping.is_none() {
+            config.spring_damping = preset.spring_damping;
+        }
+        if config.spring_mass.is_none() {
+            config.spring_mass = preset.spring_mass;
+        }
+    }
+
+    fn merge_with_template(
+        mut config: ValidatedConfig,
+        template: &ScratchpadConfig,
+    ) -> ValidatedConfig {
+        // Only use template values if current config doesn't have them set
+        if config.command.is_empty() && !template.command.is_empty() {
+            config.command = template.command.clone();
+        }
+        // Inherit class from template if not specified or is "unknown"
+        if config.class == "unknown" {
+            if let Some(template_class) = &template.class {
+                config.class = template_class.clone();
             }
         }
+        if config.class_regex.is_none() {
+            config.class_regex = template.class_regex.clone();
+        }
         if config.size == "50% 50%" && template.size != "50% 50%" {
             config.size = template.size.clone();
         }
+        if config.per_monitor.is_empty() {
+            config.per_monitor = template.per_monitor.clone();
+        }
         if config.animation.is_none() {
             config.animation = template.animation.clone();
         }
+        if config.animation_preset.is_none() {
+            config.animation_preset = template.animation_preset.clone();
+        }
         if config.margin.is_none() {
             config.margin = template.margin;
         }
@@ -782,6 +1588,9 @@ impl ConfigValidator {
         if config.position.is_none() {
             config.position = template.position.clone();
         }
+        if config.arrival_effect.is_none() {
+            config.arrival_effect = template.arrival_effect.clone();
+        }
         if config.hysteresis.is_none() {
             config.hysteresis = template.hysteresis;
         }
@@ -957,6 +1766,17 @@ impl ConfigValidator {
                     config
                         .validation_errors
                         .push(format!("Animation property {} has empty property name", i));
+                } else if !ANIMATION_PROPERTY_NAMES.contains(&prop.property.as_str()) {
+                    config.validation_errors.push(format!(
+                        "Animation property {} '{}' is not supported, expected one of: {}",
+                        i,
+                        prop.property,
+                        ANIMATION_PROPERTY_NAMES.join(", ")
+                    ));
+                } else if !prop.from.is_empty() && !prop.to.is_empty() {
+                    if let Err(e) = prop.to_engine(true, &MonitorInfo::default()) {
+                        config.validation_errors.push(e.to_string());
+                    }
                 }
                 if prop.from.is_empty() {
                     config
@@ -971,6 +1791,25 @@ impl ConfigValidator {
             }
         }
 
+        // Validate animated aspects
+        if let Some(aspects) = &config.animate {
+            for aspect in aspects {
+                if !ANIMATABLE_ASPECTS.contains(&aspect.as_str()) {
+                    config.validation_errors.push(format!(
+                        "Unknown animate entry '{}', expected one of: {}",
+                        aspect,
+                        ANIMATABLE_ASPECTS.join(", ")
+                    ));
+                }
+            }
+            if aspects.is_empty() {
+                config.validation_warnings.push(
+                    "Empty animate list: the window appears and disappears without animation"
+                        .to_string(),
+                );
+            }
+        }
+
         // Validate spring physics parameters
         if let Some(stiffness) = config.spring_stiffness {
             if stiffness < 10.0 {
@@ -1011,6 +1850,16 @@ impl ConfigValidator {
             }
         }
 
+        if let Some(epsilon) = config.spring_settle_epsilon {
+            if epsilon <= 0.0 || epsilon >= 1.0 {
+                config.validation_warnings.push(format!(
+                    "Spring settle epsilon must be between 0 and 1, using {}",
+                    crate::animation::spring::DEFAULT_SETTLE_EPSILON
+                ));
+                config.spring_settle_epsilon = None;
+            }
+        }
+
         // Validate cubic bezier parameters
         let bezier_params = [
             ("cubic_bezier_x1", config.cubic_bezier_x1),
@@ -1069,6 +1918,109 @@ impl ConfigValidator {
 #[derive(Debug, Clone)]
 pub enum InternalCommand {
     SimpleHide { scratchpad_name: String },
+    SyncGeometry { window_address: String },
+}
+
+/// Quiet period after the last move event before a window's geometry is re-read
+const GEOMETRY_SYNC_DEBOUNCE_MS: u64 = 100;
+
+/// Key of the monitor list in the shared cache
+const MONITORS_CACHE_KEY: &str = "scratchpads.monitors";
+
+/// Extra time an animation loop may run past its delay + duration
+const ANIMATION_LOOP_SLACK_MS: u64 = 250;
+
+/// Dispatches for one show frame: move (and resize) to `position` if the frame
+/// has one, then set `opacity` if it is being animated
+fn show_frame_commands(
+    window_address: &str,
+    position: Option<(i32, i32)>,
+    geometry: &crate::ipc::WindowGeometry,
+    opacity: Option<f32>,
+) -> Vec<hyprland::dispatch::DispatchType<'static>> {
+    let mut commands = match position {
+        Some((x, y)) => HyprlandClient::resize_and_position_commands(
+            window_address,
+            x,
+            y,
+            geometry.width,
+            geometry.height,
+        ),
+        None => Vec::new(),
+    };
+    commands
+        .extend(opacity.map(|opacity| HyprlandClient::opacity_command(window_address, opacity)));
+    commands
+}
+
+/// Window aspects the `animate` option can select
+const ANIMATABLE_ASPECTS: [&str; 2] = ["position", "opacity"];
+
+/// Properties `animation_properties` entries can animate
+const ANIMATION_PROPERTY_NAMES: [&str; 6] = ["x", "y", "width", "height", "opacity", "scale"];
+
+/// Distance past the monitor edge for windows hidden with `hide_mode = "offscreen"`
+const OFFSCREEN_HIDE_MARGIN: i32 = 200;
+
+/// A delayed action together with the moment it fires
+#[derive(Debug)]
+pub struct PendingTimer {
+    pub handle: JoinHandle<()>,
+    pub deadline: Instant,
+}
+
+/// A background show/hide animation still running for a scratchpad
+#[derive(Debug, Clone)]
+pub struct AnimationInProgress {
+    pub window_address: String,
+    pub ends_at: Instant,
+    pub target: (i32, i32, i32, i32), // x, y, width, height
+}
+
+/// A window turned into a scratchpad by `mark`, and what `unmark` needs to give
+/// it back as an ordinary window
+#[derive(Debug, Clone)]
+pub struct MarkedWindow {
+    pub address: String,
+    pub was_floating: bool,
+    pub ad_hoc: bool, // Scratchpad created by `mark` (no config), dropped on `unmark`
+}
+
+/// The parts of a monitor the pre-calculated scratchpad geometry depends on.
+/// Focus and active workspace are left out: they change on every `focusedmon`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorLayout {
+    pub name: String,
+    pub size: (u16, u16),
+    pub scale: f32,
+    pub reserved: [u16; 4], // top, bottom, left, right
+}
+
+impl MonitorLayout {
+    pub fn of(monitors: &[MonitorInfo]) -> Vec<MonitorLayout> {
+        monitors
+            .iter()
+            .map(|monitor| MonitorLayout {
+                name: monitor.name.clone(),
+                size: (monitor.width, monitor.height),
+                scale: monitor.scale,
+                reserved: [
+                    monitor.reserved_top,
+                    monitor.reserved_bottom,
+                    monitor.reserved_left,
+                    monitor.reserved_right,
+                ],
+            })
+            .collect()
+    }
+}
+
+/// What to do with a toggle, given the scratchpad's debounce/interrupt policy
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToggleDecision {
+    Proceed,
+    Ignore,
+    CancelAndProceed,
 }
 
 // ============================================================================
@@ -1082,9 +2034,8 @@ pub struct ScratchpadsPlugin {
     pub enhanced_client: Arc<EnhancedHyprlandClient>, // Enhanced client for better reliability
     pub variables: Arc<tokio::sync::RwLock<HashMap<String, String>>>,
 
-    // Performance optimizations
-    pub monitors_cache: Arc<RwLock<Vec<MonitorInfo>>>,
-    pub cache_valid_until: Arc<RwLock<Instant>>,
+    // Performance optimizations: monitors are cached in the shared cache for `cache_duration`
+    pub global_cache: Arc<crate::core::GlobalStateCache>,
     pub cache_duration: Duration,
 
     // Multi-window tracking
@@ -1095,8 +2046,11 @@ pub struct ScratchpadsPlugin {
     // Template inheritance cache removed - was unused
 
     // Animation and delay management
-    pub hide_tasks: HashMap<String, JoinHandle<()>>,
-    pub hysteresis_tasks: HashMap<String, JoinHandle<()>>, // For hysteresis delays
+    pub hide_tasks: HashMap<String, PendingTimer>, // window_address -> delayed hide
+    pub stash_tasks: HashMap<String, PendingTimer>, // window_address -> move away after hide animation
+    pub parked_windows: HashSet<String>, // window addresses hidden off-screen on their workspace
+    pub faded_windows: HashSet<String>,  // window addresses whose hide faded them out
+    pub hysteresis_tasks: HashMap<String, PendingTimer>, // For hysteresis delays
     pub window_animator: Arc<Mutex<WindowAnimator>>,
 
     // Internal command channel for hysteresis and other delayed actions
@@ -1105,10 +2059,22 @@ pub struct ScratchpadsPlugin {
 
     // Validated configurations (Arc-optimized)
     pub validated_configs: HashMap<String, ValidatedConfigRef>,
+    pub default_scratchpad: Option<String>, // Used by toggle/show/hide without a name
+    pub animation_presets: HashMap<String, AnimationPreset>, // From [animations.presets]
 
     // Geometry synchronization
     pub geometry_cache: Arc<RwLock<HashMap<String, WindowGeometry>>>, // window_address -> geometry
     pub sync_tasks: HashMap<String, JoinHandle<()>>,                  // window_address -> sync task
+
+    // Rapid toggle handling
+    pub animations_in_progress: HashMap<String, AnimationInProgress>, // scratchpad_name -> animation
+    pub last_toggles: HashMap<String, Instant>, // scratchpad_name -> last toggle
+
+    // Windows turned into scratchpads at runtime
+    pub marked: HashMap<String, MarkedWindow>, // scratchpad_name -> marked window
+
+    // Monitors the validated configs' geometry was pre-calculated for
+    pub geometry_layout: Vec<MonitorLayout>,
 }
 
 impl ScratchpadsPlugin {
@@ -1121,45 +2087,63 @@ impl ScratchpadsPlugin {
             hyprland_client: Arc::new(Mutex::new(None)),
             enhanced_client: Arc::new(EnhancedHyprlandClient::new()),
             variables: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
-            monitors_cache: Arc::new(RwLock::new(Vec::new())),
-            cache_valid_until: Arc::new(RwLock::new(Instant::now())),
+            global_cache: Arc::new(crate::core::GlobalStateCache::new()),
             cache_duration: Duration::from_secs(2), // Cache monitors for 2 seconds
             window_to_scratchpad: HashMap::new(),
             focused_window: None,
             previous_focused_window: None,
             hide_tasks: HashMap::new(),
+            stash_tasks: HashMap::new(),
+            parked_windows: HashSet::new(),
+            faded_windows: HashSet::new(),
             hysteresis_tasks: HashMap::new(),
             window_animator: Arc::new(Mutex::new(WindowAnimator::new())),
             internal_sender: Some(internal_sender),
             internal_receiver: Some(internal_receiver),
             validated_configs: HashMap::new(),
+            default_scratchpad: None,
+            animation_presets: HashMap::new(),
             geometry_cache: Arc::new(RwLock::new(HashMap::new())),
             sync_tasks: HashMap::new(),
+            animations_in_progress: HashMap::new(),
+            last_toggles: HashMap::new(),
+            marked: HashMap::new(),
+            geometry_layout: Vec::new(),
         }
     }
 
-    pub async fn set_hyprland_client(&self, client: Arc<HyprlandClient>) {
-        let mut client_guard = self.hyprland_client.lock().await;
-        *client_guard = Some(client.clone());
+    /// Pre-calculate the scratchpads' geometry again if the monitors changed
+    /// (output added or removed, resolution, scale or reserved space) since it was
+    /// last done. Issues were already reported when the config was validated
+    pub async fn refresh_monitor_geometry(&mut self) {
+        let monitors = match self.get_monitors().await {
+            Ok(monitors) => monitors,
+            Err(e) => {
+                debug!("Monitors unavailable, keeping scratchpad geometry: {}", e);
+                return;
+            }
+        };
+        let layout = MonitorLayout::of(&monitors);
+        if layout == self.geometry_layout {
+            return;
+        }
 
-        // Set the client for the WindowAnimator as well
-        let animator = self.window_animator.lock().await;
-        animator.set_hyprland_client(client).await;
+        info!("🖥️ Monitor layout changed, recalculating scratchpad geometry");
+        for config in self.validated_configs.values_mut() {
+            ConfigValidator::precompute_geometry(Arc::make_mut(config), &monitors);
+        }
+        self.geometry_layout = layout;
     }
 
     /// Get current monitors with caching for performance
     pub async fn get_monitors(&self) -> Result<Vec<MonitorInfo>> {
-        let now = Instant::now();
-
-        // Check cache validity
+        if let Some(monitors) = self
+            .global_cache
+            .get_cached::<Vec<MonitorInfo>>(MONITORS_CACHE_KEY)
+            .await
+            .filter(|monitors| !monitors.is_empty())
         {
-            let cache_valid = self.cache_valid_until.read().await;
-            if now < *cache_valid {
-                let monitors = self.monitors_cache.read().await;
-                if !monitors.is_empty() {
-                    return Ok(monitors.clone());
-                }
-            }
+            return Ok(monitors);
         }
 
         // Cache expired or empty, refresh monitors
@@ -1171,31 +2155,15 @@ impl ScratchpadsPlugin {
         drop(client_guard);
 
         let monitors = client.get_monitors().await?;
-        let monitor_infos: Vec<MonitorInfo> = monitors
-            .iter()
-            .map(|m| MonitorInfo {
-                id: m.id,
-                name: m.name.clone(),
-                width: m.width,
-                height: m.height,
-                x: m.x,
-                y: m.y,
-                scale: m.scale,
-                is_focused: m.focused,
-                active_workspace_id: m.active_workspace.id,
-                refresh_rate: m.refresh_rate,
-            })
-            .collect();
+        let monitor_infos: Vec<MonitorInfo> = monitors.iter().map(MonitorInfo::from).collect();
 
-        // Update cache
-        {
-            let mut cache = self.monitors_cache.write().await;
-            *cache = monitor_infos.clone();
-        }
-        {
-            let mut cache_valid = self.cache_valid_until.write().await;
-            *cache_valid = now + self.cache_duration;
-        }
+        self.global_cache
+            .insert_with_ttl(
+                MONITORS_CACHE_KEY,
+                monitor_infos.clone(),
+                self.cache_duration,
+            )
+            .await;
 
         Ok(monitor_infos)
     }
@@ -1204,18 +2172,27 @@ impl ScratchpadsPlugin {
     pub async fn get_target_monitor(&self, config: &ValidatedConfig) -> Result<MonitorInfo> {
         let monitors = self.get_monitors().await?;
 
-        // Force specific monitor if configured
+        // Force specific monitor if configured; "current" is the focused one below
         if let Some(forced_monitor) = &config.force_monitor {
-            if let Some(monitor) = monitors.iter().find(|m| m.name == *forced_monitor) {
-                return Ok(monitor.clone());
+            let selector = MonitorSelector::parse(forced_monitor);
+            if selector != MonitorSelector::Current {
+                if let Some(monitor) = selector.find(&monitors) {
+                    return Ok(monitor.clone());
+                }
+                warn!(
+                    "Forced monitor '{}' not found, using focused monitor",
+                    forced_monitor
+                );
             }
-            warn!(
-                "Forced monitor '{}' not found, using focused monitor",
-                forced_monitor
-            );
         }
 
-        // Use focused monitor
+        // Use the focused monitor, asked live: the cached list may predate a focus change
+        if let Ok(client) = self.get_hyprland_client().await {
+            match client.get_focused_monitor().await {
+                Ok(monitor) => return Ok(monitor),
+                Err(e) => debug!("Focused monitor query failed ({}), using cache", e),
+            }
+        }
         monitors
             .iter()
             .find(|m| m.is_focused)
@@ -1264,6 +2241,77 @@ impl ScratchpadsPlugin {
         result
     }
 
+    /// Binary a command runs: its first word after any `VAR=value` assignments
+    fn command_binary(command: &str) -> Option<String> {
+        command
+            .split_whitespace()
+            .map(|word| word.trim_matches(|c| c == '"' || c == '\''))
+            .find(|word| !word.is_empty() && !word.contains('='))
+            .map(|word| shellexpand::tilde(word).into_owned())
+    }
+
+    /// Locate an executable like the shell would: paths are used as-is, bare names
+    /// are looked up in `search_path`
+    fn resolve_executable(binary: &str, search_path: &std::ffi::OsStr) -> Option<PathBuf> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let is_executable = |path: &Path| {
+            path.metadata()
+                .map(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+                .unwrap_or(false)
+        };
+
+        if binary.contains('/') {
+            let path = PathBuf::from(binary);
+            return is_executable(&path).then_some(path);
+        }
+
+        std::env::split_paths(search_path)
+            .map(|dir| dir.join(binary))
+            .find(|path| is_executable(path))
+    }
+
+    /// Check that every scratchpad's expanded command resolves to an executable.
+    /// Returns (name, resolved path or the missing binary), sorted by name
+    fn check_commands(
+        &self,
+        variables: &HashMap<String, String>,
+        search_path: &std::ffi::OsStr,
+    ) -> Vec<(String, Result<PathBuf, String>)> {
+        let mut report: Vec<_> = self
+            .scratchpads
+            .iter()
+            .map(|(name, config)| {
+                let command = self.expand_command(&config.command, variables);
+                let health = match Self::command_binary(&command) {
+                    Some(binary) => Self::resolve_executable(&binary, search_path).ok_or(binary),
+                    None => Err(String::new()),
+                };
+                (name.clone(), health)
+            })
+            .collect();
+        report.sort_by(|a, b| a.0.cmp(&b.0));
+        report
+    }
+
+    async fn command_health_report(&self) -> Vec<(String, Result<PathBuf, String>)> {
+        let variables = self.variables.read().await.clone();
+        let search_path = std::env::var_os("PATH").unwrap_or_default();
+        self.check_commands(&variables, &search_path)
+    }
+
+    fn format_command_health(report: &[(String, Result<PathBuf, String>)]) -> String {
+        report
+            .iter()
+            .map(|(name, health)| match health {
+                Ok(path) => format!("{name}: OK ({})", path.display()),
+                Err(binary) if binary.is_empty() => format!("{name}: missing (empty command)"),
+                Err(binary) => format!("{name}: missing '{binary}' (not found on PATH)"),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Bulk update geometries for all tracked windows
     pub async fn sync_all_geometries(&mut self) {
         let window_addresses: Vec<String> = self.window_to_scratchpad.keys().cloned().collect();
@@ -1304,9 +2352,17 @@ impl ScratchpadsPlugin {
         }
     }
 
-    /// Get the reverse animation type for hiding (fromLeft -> toLeft, etc.)
-    fn get_reverse_animation_type(&self, show_animation: &str) -> String {
-        match show_animation.to_lowercase().as_str() {
+    /// Hide animation matching a show animation; the single source for this mapping.
+    ///
+    /// - Built-in directions match case-insensitively, in camelCase or snake_case,
+    ///   and always return the camelCase `to*` name (`FROM_TOP_LEFT` -> `toTopLeft`)
+    /// - Symmetric types (`fade`, `scale`, `spring`) return their lowercase name
+    /// - Other names with a `from`/`from_` prefix (any casing) swap it for `to`/`to_`
+    ///   and keep the rest verbatim (`fromCorner` -> `toCorner`)
+    /// - Anything else is logged and returned unchanged
+    fn hide_animation_type(show_animation: &str) -> String {
+        let lowered = show_animation.to_lowercase();
+        match lowered.as_str() {
             "fromleft" | "from_left" => "toLeft".to_string(),
             "fromright" | "from_right" => "toRight".to_string(),
             "fromtop" | "from_top" => "toTop".to_string(),
@@ -1315,15 +2371,26 @@ impl ScratchpadsPlugin {
             "fromtopright" | "from_top_right" => "toTopRight".to_string(),
             "frombottomleft" | "from_bottom_left" => "toBottomLeft".to_string(),
             "frombottomright" | "from_bottom_right" => "toBottomRight".to_string(),
-            "fade" => "fade".to_string(),     // Fade is symmetric
-            "scale" => "scale".to_string(),   // Scale is symmetric
-            "spring" => "spring".to_string(), // Spring can be symmetric
+            "fade" | "scale" | "spring" => lowered,
             _ => {
-                // For unknown animations, try to infer reverse
-                if show_animation.starts_with("from") {
-                    show_animation.replacen("from", "to", 1)
+                // The prefix is ASCII, so byte offsets in `lowered` match the original
+                let (prefix, prefix_len) = if lowered.starts_with("from_") {
+                    ("to_", 5)
+                } else if lowered.starts_with("from") {
+                    ("to", 4)
+                } else {
+                    ("", 0)
+                };
+                let rest = &show_animation[prefix_len..];
+
+                if prefix_len > 0 && !rest.is_empty() {
+                    format!("{prefix}{rest}")
                 } else {
-                    show_animation.to_string() // Fallback to same animation
+                    warn!(
+                        "⚠️  Cannot infer hide animation for '{}', reusing it",
+                        show_animation
+                    );
+                    show_animation.to_string()
                 }
             }
         }
@@ -1422,56 +2489,20 @@ impl ScratchpadsPlugin {
             workspace
         );
 
+        // 1. Créer le workspace spécial s'il n'existe pas
+        let client = self.get_hyprland_client().await?;
         if !self.workspace_exists(workspace).await? {
-            Dispatch::call(DispatchType::ToggleSpecialWorkspace(Some(
-                workspace.to_string(),
-            )))?
+            client
+                .dispatch(DispatchType::ToggleSpecialWorkspace(Some(
+                    workspace.to_string(),
+                )))
+                .await?;
         }
-        // 1. Créer le workspace spécial s'il n'existe pas
 
-        let rules = vec![
-            format!(
-                "hyprctl keyword windowrulev2 'float, workspace:{}'",
-                workspace
-            ),
-            format!(
-                "hyprctl keyword windowrulev2 'noanim, workspace:{}'",
-                workspace
-            ),
-            format!(
-                "hyprctl keyword windowrulev2 'nodecoration, workspace:{}'",
-                workspace
-            ),
-            format!(
-                "hyprctl keyword windowrulev2 'noshadow, workspace:{}'",
-                workspace
-            ),
-        ];
+        let rules = ["float", "noanim", "nodecoration", "noshadow"]
+            .map(|rule| format!("{rule}, workspace:{workspace}"));
 
-        for rule in rules {
-            debug!("🔧 Executing rule: {}", rule);
-            match tokio::process::Command::new("sh")
-                .arg("-c")
-                .arg(&rule)
-                .output()
-                .await
-            {
-                Ok(output) => {
-                    if !output.status.success() {
-                        warn!(
-                            "❌ Rule failed: {} - stderr: {}",
-                            rule,
-                            String::from_utf8_lossy(&output.stderr)
-                        );
-                    } else {
-                        debug!("✅ Rule applied successfully: {}", rule);
-                    }
-                }
-                Err(e) => {
-                    warn!("❌ Failed to execute rule: {} - error: {}", rule, e);
-                }
-            }
-        }
+        Self::apply_window_rules(&client, &rules).await;
 
         debug!("🎨 Applied windowrules for workspace: {}", workspace);
         Ok(())
@@ -1517,63 +2548,39 @@ impl ScratchpadsPlugin {
     }
 
     /// Apply specific windowrules to an identified scratchpad window
-    async fn apply_scratchpad_window_rules(&self, window_address: &str) -> Result<()> {
-        let rules = vec![
-            //format!("hyprctl dispatch togglefloating address:{}", window_address),
-            format!(
-                "hyprctl keyword windowrulev2 'float, address:{}'",
-                window_address
-            ),
-            format!(
-                "hyprctl keyword windowrulev2 'noanim, address:{}'",
-                window_address
-            ),
-            format!(
-                "hyprctl keyword windowrulev2 'nodecoration, address:{}'",
-                window_address
-            ),
-            format!(
-                "hyprctl keyword windowrulev2 'noshadow, address:{}'",
-                window_address
-            ),
-            format!(
-                "hyprctl keyword windowrulev2 'immediate, address:{}'",
-                window_address
-            ),
-            //format!("hyprctl dispatch togglefloating address:{}", window_address),
-        ];
+    async fn apply_scratchpad_window_rules(
+        &self,
+        client: &HyprlandClient,
+        window_address: &str,
+        floating: bool,
+    ) -> Result<()> {
+        let floating_rule = floating.then_some("float");
+        let rules: Vec<String> = floating_rule
+            .into_iter()
+            .chain(["noanim", "nodecoration", "noshadow", "immediate"])
+            .map(|rule| format!("{rule}, address:{window_address}"))
+            .collect();
+
+        Self::apply_window_rules(client, &rules).await;
+
+        debug!("🎨 Applied specific rules to window: {}", window_address);
+        Ok(())
+    }
 
+    /// Add `windowrulev2` rules; a rule Hyprland rejects is logged and skipped
+    async fn apply_window_rules(client: &HyprlandClient, rules: &[String]) {
         for rule in rules {
             debug!("🔧 Executing rule: {}", rule);
-            match tokio::process::Command::new("sh")
-                .arg("-c")
-                .arg(&rule)
-                .output()
-                .await
-            {
-                Ok(output) => {
-                    if !output.status.success() {
-                        warn!(
-                            "❌ Rule failed: {} - stderr: {}",
-                            rule,
-                            String::from_utf8_lossy(&output.stderr)
-                        );
-                    } else {
-                        debug!("✅ Rule applied successfully: {}", rule);
-                    }
-                }
-                Err(e) => {
-                    warn!("❌ Failed to execute rule: {} - error: {}", rule, e);
-                }
+            match client.keyword("windowrulev2", rule).await {
+                Ok(()) => debug!("✅ Rule applied successfully: {}", rule),
+                Err(e) => warn!("❌ Rule failed: {} - error: {}", rule, e),
             }
         }
-
-        debug!("🎨 Applied specific rules to window: {}", window_address);
-        Ok(())
     }
 
     /// Animate window from any position to target position
     #[allow(clippy::too_many_arguments)]
+    #[instrument(skip_all, fields(scratchpad = %name, window = %window.address, animation_type = %animation_type))]
     async fn animate_window_to_position(
         &self,
         client: &crate::ipc::HyprlandClient,
@@ -1586,6 +2593,27 @@ impl ScratchpadsPlugin {
     ) -> Result<()> {
         let window_address = window.address.to_string();
 
+        // An explicit `animate` selection replaces the type's implicit behavior
+        let monitor = self.get_target_monitor(config).await?;
+        let properties = config.animation_properties_for(
+            start_position,
+            (geometry.x, geometry.y),
+            true,
+            &monitor,
+        );
+        let start_position = if config.animates("position") == Some(false) {
+            (geometry.x, geometry.y)
+        } else {
+            start_position
+        };
+        let animate_opacity = config.fades();
+        let opacity_from = config.animation_opacity_from.unwrap_or(0.0);
+        if animate_opacity {
+            client
+                .set_window_opacity(&window_address, opacity_from)
+                .await?;
+        }
+
         info!("🎬 TRACE: animate_window_to_position - Setting window {} to start position ({}, {}) before animation",
               window_address, start_position.0, start_position.1);
 
@@ -1615,126 +2643,272 @@ impl ScratchpadsPlugin {
             );
         }
 
-        // Create and run animation
-        let animation_config = crate::animation::AnimationConfig {
-            animation_type: animation_type.to_string(),
-            duration: config.animation_duration.unwrap_or(300),
-            easing: config.to_easing_function(),
-            offset: "50px".to_string(), // Use reasonable fixed offset
-            opacity_from: config.animation_opacity_from.unwrap_or(1.0),
-            scale_from: config.animation_scale_from.unwrap_or(1.0),
-            delay: config.animation_delay.unwrap_or(0),
-            properties: None,
-            target_fps: 60,
-            target_position: None,
-        };
+        // Whatever ends the frames, a fade-in must not leave a see-through window
+        let animated = self
+            .play_show_frames(
+                client,
+                &window_address,
+                config,
+                geometry,
+                animation_type,
+                name,
+                start_position,
+                properties,
+            )
+            .await;
 
-        let monitor = self.get_target_monitor(config).await?;
-        let animator = self.window_animator.lock().await;
-        animator.set_active_monitor(&monitor).await;
+        // Final position
+        client
+            .resize_and_position_window(
+                &window_address,
+                geometry.x,
+                geometry.y,
+                geometry.width,
+                geometry.height,
+            )
+            .await?;
+        if animate_opacity {
+            client.set_window_opacity(&window_address, 1.0).await?;
+        }
+        animated?;
 
-        let mut engine = animator.animation_engine.lock().await;
-        let animation_id = format!("scratchpad_{}_special_show", name);
+        debug!("✨ Animation completed for scratchpad '{}'", name);
+        Ok(())
+    }
 
-        engine
-            .start_animation(
-                animation_id.clone(),
-                animation_config.clone(),
-                vec![
-                    (
-                        "x".to_string(),
-                        crate::animation::PropertyValue::Pixels(start_position.0),
-                    ),
-                    (
-                        "y".to_string(),
-                        crate::animation::PropertyValue::Pixels(start_position.1),
-                    ),
+    /// Move (and fade, with `fades()`) a window from `start_position` to `geometry`
+    /// frame by frame, without the final snap into place
+    #[allow(clippy::too_many_arguments)]
+    async fn play_show_frames(
+        &self,
+        client: &crate::ipc::HyprlandClient,
+        window_address: &str,
+        config: &ValidatedConfig,
+        geometry: &crate::ipc::WindowGeometry,
+        animation_type: &str,
+        name: &str,
+        start_position: (i32, i32),
+        properties: Option<Vec<crate::animation::AnimationPropertyConfig>>,
+    ) -> Result<()> {
+        use crate::animation::PropertyValue;
+
+        let animate_opacity = config.fades();
+        let opacity_from = config.animation_opacity_from.unwrap_or(0.0);
+
+        if config.uses_physics_animation() {
+            Self::spring_window_to_position(
+                client,
+                window_address,
+                config,
+                geometry,
+                start_position,
+                animate_opacity,
+            )
+            .await?;
+        } else {
+            // The slide is one phase, or a slide and a short settle with arrival_effect
+            let duration = config.animation_duration.unwrap_or(300);
+            let phases = if config.bounces_on_arrival() {
+                crate::animation::bounce_on_arrival(
+                    start_position,
+                    (geometry.x, geometry.y),
+                    duration,
+                )
+            } else {
+                vec![crate::animation::AnimationPhase {
+                    from: start_position,
+                    to: (geometry.x, geometry.y),
+                    duration,
+                    easing: config.to_easing_function(),
+                }]
+            };
+
+            let monitor = self.get_target_monitor(config).await?;
+            let animator = self.window_animator.lock().await;
+            animator.set_active_monitor(&monitor).await;
+
+            let batch = animator.batch_dispatch();
+            let mut properties = properties;
+            for (index, phase) in phases.into_iter().enumerate() {
+                // Delay and extra properties (opacity) belong to the first phase
+                let animation_config = crate::animation::AnimationConfig {
+                    animation_type: animation_type.to_string(),
+                    duration: phase.duration,
+                    easing: phase.easing,
+                    offset: "50px".to_string(), // Use reasonable fixed offset
+                    opacity_from: config.animation_opacity_from.unwrap_or(1.0),
+                    scale_from: config.animation_scale_from.unwrap_or(1.0),
+                    delay: if index == 0 {
+                        config.animation_delay.unwrap_or(0)
+                    } else {
+                        0
+                    },
+                    properties: properties.take(),
+                    target_fps: 60,
+                    target_position: None,
+                    spring: None,
+                };
+                let animation_id = format!("scratchpad_{}_special_show", name);
+
+                let mut start: HashMap<String, PropertyValue> = [
+                    ("x".to_string(), PropertyValue::Pixels(phase.from.0)),
+                    ("y".to_string(), PropertyValue::Pixels(phase.from.1)),
                 ]
                 .into_iter()
-                .collect(),
-                vec![
-                    (
-                        "x".to_string(),
-                        crate::animation::PropertyValue::Pixels(geometry.x),
-                    ),
-                    (
-                        "y".to_string(),
-                        crate::animation::PropertyValue::Pixels(geometry.y),
-                    ),
+                .collect();
+                let mut end: HashMap<String, PropertyValue> = [
+                    ("x".to_string(), PropertyValue::Pixels(phase.to.0)),
+                    ("y".to_string(), PropertyValue::Pixels(phase.to.1)),
                 ]
                 .into_iter()
-                .collect(),
-            )
-            .await?;
+                .collect();
+                // A fade type without `animate` fades in over the first phase
+                if animate_opacity && animation_config.properties.is_none() {
+                    let from = if index == 0 { opacity_from } else { 1.0 };
+                    start.insert("opacity".to_string(), PropertyValue::Float(from));
+                    end.insert("opacity".to_string(), PropertyValue::Float(1.0));
+                }
 
-        drop(engine);
+                let mut engine = animator.animation_engine.lock().await;
+                engine
+                    .start_animation(animation_id.clone(), animation_config.clone(), start, end)
+                    .await?;
+                drop(engine);
+
+                // Animation loop: run until the engine reports completion so back/elastic
+                // easings play out their overshoot instead of being cut short and snapped.
+                // Frames are applied unclamped; the cap only guards against a stuck engine
+                let loop_cap = tokio::time::Duration::from_millis(
+                    animation_config.delay as u64
+                        + animation_config.duration as u64
+                        + ANIMATION_LOOP_SLACK_MS,
+                );
+                let start_time = tokio::time::Instant::now();
+
+                while start_time.elapsed() < loop_cap {
+                    let Some(properties) = ({
+                        let mut engine = animator.animation_engine.lock().await;
+                        engine.get_current_properties(&animation_id)
+                    }) else {
+                        break;
+                    };
 
-        // Animation loop
-        let duration_ms = animation_config.duration as u64;
-        let start_time = tokio::time::Instant::now();
+                    // Frames are sub-pixel; round only when moving the window
+                    let ((x, y), (width, height)) = crate::animation::frame_geometry(
+                        &properties,
+                        (geometry.x, geometry.y),
+                        (geometry.width, geometry.height),
+                    );
+                    let frame = crate::ipc::WindowGeometry {
+                        x,
+                        y,
+                        width,
+                        height,
+                        ..geometry.clone()
+                    };
+                    let opacity = match properties.get("opacity") {
+                        Some(PropertyValue::Float(opacity)) if animate_opacity => Some(*opacity),
+                        _ => None,
+                    };
+                    let commands =
+                        show_frame_commands(window_address, Some((x, y)), &frame, opacity);
 
-        while tokio::time::Instant::now()
-            .duration_since(start_time)
-            .as_millis()
-            < (duration_ms as u128)
-        {
-            if let Some(properties) = {
-                let mut engine = animator.animation_engine.lock().await;
-                engine.get_current_properties(&animation_id)
-            } {
-                if let (Some(x_prop), Some(y_prop)) = (properties.get("x"), properties.get("y")) {
-                    if let (
-                        crate::animation::PropertyValue::Pixels(x),
-                        crate::animation::PropertyValue::Pixels(y),
-                    ) = (x_prop, y_prop)
-                    {
-                        client
-                            .resize_and_position_window(
-                                &window_address,
-                                *x,
-                                *y,
-                                geometry.width,
-                                geometry.height,
-                            )
-                            .await?;
+                    // One IPC round trip per frame when batching, as the animator does
+                    if batch {
+                        client.dispatch_batch(commands).await?;
+                    } else {
+                        for command in commands {
+                            client.dispatch(command).await?;
+                        }
                     }
+                    tokio::time::sleep(tokio::time::Duration::from_millis(16)).await;
                 }
-            } else {
-                break;
             }
-            tokio::time::sleep(tokio::time::Duration::from_millis(16)).await;
         }
 
-        // Final position
-        client
-            .resize_and_position_window(
-                &window_address,
-                geometry.x,
-                geometry.y,
-                geometry.width,
-                geometry.height,
-            )
-            .await?;
+        Ok(())
+    }
 
-        debug!("✨ Animation completed for scratchpad '{}'", name);
+    /// Drive a show animation with spring physics until the spring settles, rather
+    /// than for a fixed duration (bounded by `MAX_SPRING_DURATION`)
+    async fn spring_window_to_position(
+        client: &crate::ipc::HyprlandClient,
+        window_address: &str,
+        config: &ValidatedConfig,
+        geometry: &crate::ipc::WindowGeometry,
+        start_position: (i32, i32),
+        animate_opacity: bool,
+    ) -> Result<()> {
+        let lerp = |from: i32, to: i32, progress: f32| {
+            from + ((to - from) as f32 * progress).round() as i32
+        };
+        let opacity_from = config.animation_opacity_from.unwrap_or(0.0);
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(
+            config.animation_delay.unwrap_or(0) as u64,
+        ))
+        .await;
+
+        let mut spring = config.spring_animation();
+        let mut last_frame = tokio::time::Instant::now();
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_millis(16)).await;
+            let now = tokio::time::Instant::now();
+            let Some(progress) = spring.next_frame(now - last_frame) else {
+                break;
+            };
+            last_frame = now;
+
+            client
+                .resize_and_position_window(
+                    window_address,
+                    lerp(start_position.0, geometry.x, progress),
+                    lerp(start_position.1, geometry.y, progress),
+                    geometry.width,
+                    geometry.height,
+                )
+                .await?;
+            if animate_opacity {
+                let opacity = opacity_from + (1.0 - opacity_from) * progress;
+                client
+                    .set_window_opacity(window_address, opacity.clamp(0.0, 1.0))
+                    .await?;
+            }
+        }
+
+        debug!(
+            "🌀 Spring animation for {} {} after {}ms",
+            window_address,
+            if spring.is_settled() {
+                "settled"
+            } else {
+                "hit the time cap"
+            },
+            spring.elapsed().as_millis()
+        );
         Ok(())
     }
 
     /// Finalize scratchpad setup and tracking
     async fn finalize_scratchpad_setup(
         &mut self,
+        client: &HyprlandClient,
         window: &hyprland::data::Client,
         name: &str,
     ) -> Result<()> {
         let window_address = window.address.to_string();
 
-        // Récupérer la géométrie de la fenêtre via l'enhanced client
-        let geometry = match self
-            .enhanced_client
-            .get_window_geometry(&window_address)
-            .await
-        {
-            Ok(geo) => geo,
+        // Géométrie actuelle : les règles appliquées ont pu déplacer la fenêtre
+        let geometry = match client.get_window_info(&window_address).await {
+            Ok(info) => crate::ipc::WindowGeometry {
+                x: info.at.0 as i32,
+                y: info.at.1 as i32,
+                width: info.size.0 as i32,
+                height: info.size.1 as i32,
+                workspace: info.workspace.name.clone(),
+                monitor: info.monitor as i32,
+                floating: info.floating,
+            },
             Err(e) => {
                 warn!(
                     "Failed to get geometry for window {}: {}",
@@ -1811,10 +2985,21 @@ impl ScratchpadsPlugin {
     }
 
     /// Main toggle logic for scratchpads
+    #[instrument(skip_all, fields(scratchpad = %name))]
     async fn toggle_scratchpad(&mut self, name: &str) -> Result<String> {
-        info!("🔄 Toggling scratchpad: {}", name);
+        info!("🔄 Toggling scratchpad");
 
         let validated_config = self.get_validated_config(name)?;
+
+        match self.check_toggle(name, Instant::now()) {
+            ToggleDecision::Ignore => {
+                debug!("⏳ Ignoring toggle for '{}' (debounce/animation)", name);
+                return Ok(format!("Scratchpad '{name}' toggle ignored"));
+            }
+            ToggleDecision::CancelAndProceed => self.snap_animation_to_target(name).await,
+            ToggleDecision::Proceed => {}
+        }
+
         debug!(
             "📋 Using config for '{}': class='{}', command='{}'",
             name, validated_config.class, validated_config.command
@@ -1844,11 +3029,37 @@ impl ScratchpadsPlugin {
                     );
                 }
 
-                // Check if any window is visible on current workspace
-                let visible_window = state.windows.iter().find(|w| w.is_visible);
+                // A window flagged visible that sits on a closed special workspace
+                // (the hidden one, or a special workspace toggled off since) is not
+                let hypr_windows = client.get_windows().await?;
+                let open_special = Self::open_special_workspaces(&client).await;
+                let visible_window = state.windows.iter().find(|w| {
+                    w.is_visible
+                        && hypr_windows
+                            .iter()
+                            .find(|hw| hw.address.to_string() == w.address)
+                            .is_none_or(|hw| {
+                                Self::counts_as_visible(
+                                    &hw.workspace.name,
+                                    validated_config.allow_special_workspaces,
+                                    &open_special,
+                                )
+                            })
+                });
                 debug!("🔍 Found visible window: {}", visible_window.is_some());
 
                 if let Some(window_state) = visible_window {
+                    // With alt_toggle, a scratchpad covered by another one's focus is
+                    // raised instead of hidden
+                    if validated_config.alt_toggle && self.other_scratchpad_focused(name) {
+                        info!(
+                            "⬆️ Scratchpad '{}' visible but another one has focus, raising it",
+                            name
+                        );
+                        let address = window_state.address.clone();
+                        return self.raise_scratchpad_window(&client, &address, name).await;
+                    }
+
                     // Window is visible - hide it
                     info!("👁️ Scratchpad '{}' visible, hiding it", name);
 
@@ -1858,8 +3069,13 @@ impl ScratchpadsPlugin {
                         .iter()
                         .find(|w| w.address.to_string() == window_state.address)
                     {
-                        self.hide_scratchpad_window(&client, hypr_window, name)
-                            .await
+                        let hidden = self
+                            .hide_scratchpad_window(&client, hypr_window, name)
+                            .await;
+                        if hidden.is_ok() {
+                            self.restore_excluded(name).await;
+                        }
+                        hidden
                     } else {
                         // Window no longer exists, clean up state and spawn new
                         info!("🧹 Window no longer exists, cleaning state and spawning new");
@@ -1920,6 +3136,183 @@ impl ScratchpadsPlugin {
         }
     }
 
+    /// Command spawning a scratchpad's app on `workspace`. A floating window opens
+    /// at `at` (monitor-relative) with the scratchpad's size, or at its own size
+    /// while `preserve_aspect` still has to learn it; a tiled one goes where the
+    /// layout puts it
+    fn spawn_command(
+        config: &ValidatedConfig,
+        workspace: &str,
+        geometry: &WindowGeometry,
+        at: (i32, i32),
+        command: &str,
+    ) -> String {
+        if !config.floating {
+            format!("[workspace {workspace}] {command}")
+        } else if Self::awaits_native_size(config) {
+            format!(
+                "[workspace {workspace};float;move {} {}] {command}",
+                at.0, at.1
+            )
+        } else {
+            format!(
+                "[workspace {workspace};float;size {} {};move {} {}] {command}",
+                geometry.width, geometry.height, at.0, at.1
+            )
+        }
+    }
+
+    /// Whether `preserve_aspect` still needs the window's native size
+    fn awaits_native_size(config: &ValidatedConfig) -> bool {
+        config.preserve_aspect && config.native_size.is_none()
+    }
+
+    /// Record the window's current size as the native size of `name`, once, for
+    /// `preserve_aspect`. Returns the updated config, or `None` when nothing was
+    /// learned: geometry then falls back to the configured size until the next show
+    async fn learn_native_size(
+        &mut self,
+        client: &HyprlandClient,
+        name: &str,
+        window_address: &str,
+    ) -> Option<ValidatedConfigRef> {
+        let config = self.validated_configs.get_mut(name)?;
+        if !Self::awaits_native_size(config) {
+            return None;
+        }
+
+        match client.get_window_properties(window_address).await {
+            Ok(properties) if properties.width > 0 && properties.height > 0 => {
+                info!(
+                    "📐 Native size of '{}' is {}x{}, preserving its aspect",
+                    name, properties.width, properties.height
+                );
+                Arc::make_mut(config).native_size = Some((properties.width, properties.height));
+                Some(config.clone())
+            }
+            Ok(_) => {
+                debug!(
+                    "📐 Window {} not mapped yet, native size unknown",
+                    window_address
+                );
+                None
+            }
+            Err(e) => {
+                debug!("📐 Native size of {} unavailable: {}", window_address, e);
+                None
+            }
+        }
+    }
+
+    /// Whether the focused window belongs to a scratchpad other than `name`
+    fn other_scratchpad_focused(&self, name: &str) -> bool {
+        self.focused_window
+            .as_ref()
+            .and_then(|address| self.window_to_scratchpad.get(address))
+            .is_some_and(|focused| focused != name)
+    }
+
+    /// Bring a visible scratchpad window forward: focus it and put it on top
+    async fn raise_scratchpad_window(
+        &self,
+        client: &HyprlandClient,
+        window_address: &str,
+        name: &str,
+    ) -> Result<String> {
+        client.focus_window(window_address).await?;
+        client
+            .dispatch(hyprland::dispatch::DispatchType::Custom(
+                "alterzorder",
+                Box::leak(format!("top,address:{window_address}").into_boxed_str()),
+            ))
+            .await?;
+        Ok(format!("Scratchpad '{name}' raised"))
+    }
+
+    /// Scratchpad named in `args`, falling back to the configured default
+    fn resolve_scratchpad_name(&self, args: &[&str]) -> Option<String> {
+        args.first()
+            .filter(|name| !name.is_empty())
+            .map(|name| name.to_string())
+            .or_else(|| self.default_scratchpad.clone())
+    }
+
+    /// Apply the scratchpad's debounce and interrupt policy to a toggle at `now`
+    fn check_toggle(&mut self, name: &str, now: Instant) -> ToggleDecision {
+        let (debounce_ms, interrupt) = match self.validated_configs.get(name) {
+            Some(config) => (config.debounce_ms, config.interrupt.clone()),
+            None => return ToggleDecision::Proceed,
+        };
+
+        if let (Some(debounce_ms), Some(last)) = (debounce_ms, self.last_toggles.get(name)) {
+            if now.saturating_duration_since(*last) < Duration::from_millis(debounce_ms as u64) {
+                return ToggleDecision::Ignore;
+            }
+        }
+
+        let animating = self
+            .animations_in_progress
+            .get(name)
+            .is_some_and(|animation| animation.ends_at > now);
+
+        let decision = if !animating {
+            self.animations_in_progress.remove(name);
+            ToggleDecision::Proceed
+        } else if interrupt == "ignore" {
+            return ToggleDecision::Ignore;
+        } else {
+            ToggleDecision::CancelAndProceed
+        };
+
+        self.last_toggles.insert(name.to_string(), now);
+        decision
+    }
+
+    /// Remember a background animation so later toggles can respect it
+    fn track_animation(
+        &mut self,
+        name: &str,
+        window_address: &str,
+        duration: Duration,
+        target: (i32, i32, i32, i32),
+    ) {
+        self.animations_in_progress.insert(
+            name.to_string(),
+            AnimationInProgress {
+                window_address: window_address.to_string(),
+                ends_at: Instant::now() + duration,
+                target,
+            },
+        );
+    }
+
+    /// Stop a running animation and jump the window to where it was heading
+    async fn snap_animation_to_target(&mut self, name: &str) {
+        let Some(animation) = self.animations_in_progress.remove(name) else {
+            return;
+        };
+
+        {
+            let mut animator = self.window_animator.lock().await;
+            if let Err(e) = animator.stop_animation(&animation.window_address).await {
+                debug!("Failed to stop animation for '{}': {}", name, e);
+            }
+        }
+
+        let (x, y, width, height) = animation.target;
+        match self.get_hyprland_client().await {
+            Ok(client) => {
+                if let Err(e) = client
+                    .resize_and_position_window(&animation.window_address, x, y, width, height)
+                    .await
+                {
+                    warn!("⚠️ Failed to snap '{}' to animation target: {}", name, e);
+                }
+            }
+            Err(e) => debug!("No client to snap '{}': {}", name, e),
+        }
+    }
+
     /// Show a scratchpad directly (without toggling)
     async fn show_scratchpad_direct(&mut self, name: &str) -> Result<String> {
         info!("👁️  Showing scratchpad directly: {}", name);
@@ -1971,6 +3364,95 @@ impl ScratchpadsPlugin {
         }
     }
 
+    /// Visible scratchpads that showing `name` must hide: its `excludes` (all
+    /// others for "*"), which include its group-mates
+    fn scratchpads_to_exclude(&self, name: &str, config: &ValidatedConfig) -> Vec<String> {
+        let exclude_all = config.excludes.iter().any(|exclude| exclude == "*");
+        let mut excluded: Vec<String> = self
+            .states
+            .iter()
+            .filter(|(other, state)| {
+                *other != name
+                    && (exclude_all || config.excludes.contains(other))
+                    && state.windows.iter().any(|w| w.is_visible)
+            })
+            .map(|(other, _)| other.clone())
+            .collect();
+        excluded.sort();
+        excluded
+    }
+
+    /// Hide the scratchpads excluded by `name` before it is shown
+    async fn hide_excluded(&mut self, name: &str, config: &ValidatedConfig) {
+        for other in self.scratchpads_to_exclude(name, config) {
+            info!("🚫 Hiding '{}', excluded by '{}'", other, name);
+            match self.hide_scratchpad_direct(&other).await {
+                Ok(_) => {
+                    if let Some(state) = self.states.get_mut(&other) {
+                        state.excluded_by.insert(name.to_string());
+                    }
+                }
+                Err(e) => warn!("⚠️ Failed to hide excluded scratchpad '{}': {}", other, e),
+            }
+        }
+    }
+
+    /// Forget that `name` excluded other scratchpads and, with `restore_excluded`,
+    /// return the ones to show again: those it hid while they were visible, that
+    /// nothing else still excludes and that still have a hidden window (closed
+    /// ones are not resurrected)
+    fn take_excluded_to_restore(&mut self, name: &str) -> Vec<String> {
+        let restore = self
+            .validated_configs
+            .get(name)
+            .is_some_and(|config| config.restore_excluded);
+
+        let mut to_restore: Vec<String> = self
+            .states
+            .iter_mut()
+            .filter_map(|(other, state)| state.excluded_by.remove(name).then_some((other, state)))
+            .filter(|(_, state)| {
+                restore
+                    && state.excluded_by.is_empty()
+                    && state.is_spawned
+                    && !state.windows.is_empty()
+                    && !state.windows.iter().any(|w| w.is_visible)
+            })
+            .map(|(other, _)| other.clone())
+            .collect();
+        to_restore.sort();
+        to_restore
+    }
+
+    /// Show again the scratchpads `name` hid through its excludes, once it is hidden
+    async fn restore_excluded(&mut self, name: &str) {
+        for other in self.take_excluded_to_restore(name) {
+            info!(
+                "♻️ Restoring '{}', hidden while '{}' was shown",
+                other, name
+            );
+            if let Err(e) = self.show_scratchpad_direct(&other).await {
+                warn!(
+                    "⚠️ Failed to restore excluded scratchpad '{}': {}",
+                    other, e
+                );
+            }
+        }
+    }
+
+    /// Hide a scratchpad as the user asked for it, restoring what it excluded.
+    /// Whichever of the hysteresis and hide_delay timers gets here first wins: the
+    /// other one is cancelled so the scratchpad is not hidden twice
+    async fn hide_scratchpad_and_restore(&mut self, name: &str) -> Result<String> {
+        self.cancel_pending_hides(name);
+        if self.states.contains_key(name) && !self.is_scratchpad_visible(name) {
+            return Ok(format!("Scratchpad '{name}' already hidden"));
+        }
+        let message = self.hide_scratchpad_direct(name).await?;
+        self.restore_excluded(name).await;
+        Ok(message)
+    }
+
     /// Hide a scratchpad directly (without toggling)
     async fn hide_scratchpad_direct(&mut self, name: &str) -> Result<String> {
         info!("🙈 Hiding scratchpad directly: {}", name);
@@ -2064,26 +3546,180 @@ impl ScratchpadsPlugin {
         }
     }
 
-    /// Get current workspace information
-    async fn get_current_workspace(&self, client: &HyprlandClient) -> Result<String> {
-        client.get_active_workspace().await
-    }
+    /// Turn the focused window into scratchpad `name`, so toggles hide and show it.
+    /// A configured `name` adopts the window in place of its own; any other name
+    /// gets a scratchpad of its own, sized and placed where the window is now.
+    /// A window already owned by another scratchpad is moved over, not shared
+    async fn mark_focused_window(&mut self, name: &str) -> Result<String> {
+        let client = self.get_hyprland_client().await?;
+        let windows = client.get_windows().await?;
+        let window = match &self.focused_window {
+            Some(address) => windows.iter().find(|w| w.address.to_string() == *address),
+            None => windows.iter().find(|w| w.focus_history_id == 0),
+        }
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("No focused window to mark"))?;
+        let address = window.address.to_string();
 
-    /// Spawn a scratchpad window if it doesn't exist, return the window
-    async fn spawn_scratchpad(
-        &mut self,
-        name: &str,
-        config: &ValidatedConfig,
-    ) -> Result<hyprland::data::Client> {
-        info!("🚀 Spawning scratchpad '{}' if needed", name);
+        let owner = self.window_to_scratchpad.get(&address).cloned();
+        if owner.as_deref() == Some(name) {
+            return Ok(format!("Window {address} is already scratchpad '{name}'"));
+        }
 
-        let client = self.get_hyprland_client().await?;
+        // Re-assign: the window leaves its current scratchpad, keeping how it was
+        let mut was_floating = window.floating;
+        if let Some(owner) = owner {
+            info!(
+                "📌 Moving window {} from '{}' to '{}'",
+                address, owner, name
+            );
+            if let Some(previous) = self.marked.get(&owner).filter(|m| m.address == address) {
+                was_floating = previous.was_floating;
+            }
+            self.forget_marked_window(&owner, &address);
+        }
+        // The window replaces whatever `name` marked before
+        if self.marked.contains_key(name) {
+            self.unmark_window(name).await?;
+        }
 
-        // Step 1: Check if scratchpad already exists using internal tracking system
-        let should_spawn_new = if let Some(state) = self.states.get(name) {
-            if state.is_spawned && !state.windows.is_empty() {
-                // Verify the tracked window still exists in Hyprland
-                if let Some(window_state) = state.windows.first() {
+        let ad_hoc = !self.scratchpads.contains_key(name);
+        if ad_hoc {
+            let monitors = self.get_monitors().await.unwrap_or_default();
+            let geometry = (
+                window.at.0 as i32,
+                window.at.1 as i32,
+                window.size.0 as i32,
+                window.size.1 as i32,
+            );
+            let mut config = ScratchpadConfig {
+                command: String::new(),
+                class: Some(window.class.clone()),
+                size: format!("{}px {}px", geometry.2, geometry.3),
+                ..Default::default()
+            };
+            let mut validated = ConfigValidator::convert_to_validated(&config);
+            if let Some(monitor) = Self::monitor_at(&monitors, geometry) {
+                let (area_x, area_y, _, _) = GeometryCalculator::usable_area(&validated, monitor);
+                let position = format!("{}px {}px", geometry.0 - area_x, geometry.1 - area_y);
+                config.position = Some(position.clone());
+                validated.position = Some(position);
+            }
+            self.scratchpads.insert(name.to_string(), Arc::new(config));
+            self.validated_configs
+                .insert(name.to_string(), Arc::new(validated));
+        } else if let Some(state) = self.states.get(name) {
+            // A configured scratchpad's own windows are left alone, just untracked
+            for tracked in &state.windows {
+                self.window_to_scratchpad.remove(&tracked.address);
+            }
+        }
+
+        let config = self.get_validated_config(name)?;
+        if config.floating && !window.floating {
+            client.toggle_floating(&address).await?;
+        }
+
+        let state = self.states.entry(name.to_string()).or_default();
+        state.windows = vec![WindowState {
+            address: address.clone(),
+            is_visible: true,
+            last_position: None,
+            monitor: None,
+            workspace: Some(window.workspace.name.clone()),
+            last_focus: Some(Instant::now()),
+        }];
+        state.is_spawned = true;
+        state.original_workspace = Some(window.workspace.name.clone());
+        self.window_to_scratchpad
+            .insert(address.clone(), name.to_string());
+        self.marked.insert(
+            name.to_string(),
+            MarkedWindow {
+                address: address.clone(),
+                was_floating,
+                ad_hoc,
+            },
+        );
+
+        info!("📌 Window {} marked as scratchpad '{}'", address, name);
+        Ok(format!("Window {address} marked as scratchpad '{name}'"))
+    }
+
+    /// Give the window marked as `name` back: shown if hidden, tiled again if it
+    /// was, and no longer tracked
+    async fn unmark_window(&mut self, name: &str) -> Result<String> {
+        let marked = self
+            .marked
+            .get(name)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Scratchpad '{}' has no marked window", name))?;
+        let client = self.get_hyprland_client().await?;
+        let address = marked.address.clone();
+
+        let window = client
+            .get_windows()
+            .await?
+            .into_iter()
+            .find(|w| w.address.to_string() == address);
+        if let Some(window) = window {
+            // Hidden: bring it back the way a show would, wherever it was stashed
+            if !self.is_scratchpad_visible(name) {
+                self.show_scratchpad_direct(name).await?;
+            }
+            if window.floating && !marked.was_floating {
+                client.toggle_floating(&address).await?;
+            }
+        }
+
+        self.forget_marked_window(name, &address);
+        info!("📌 Window {} unmarked from scratchpad '{}'", address, name);
+        Ok(format!("Window {address} is no longer scratchpad '{name}'"))
+    }
+
+    /// Stop tracking `address` as a window of `name`; a scratchpad that only
+    /// existed for its marked window goes away with it
+    fn forget_marked_window(&mut self, name: &str, address: &str) {
+        self.window_to_scratchpad.remove(address);
+        if let Some(state) = self.states.get_mut(name) {
+            state.windows.retain(|w| w.address != address);
+            if state.windows.is_empty() {
+                state.is_spawned = false;
+            }
+        }
+
+        if self.marked.get(name).is_some_and(|m| m.address == address) {
+            if let Some(marked) = self.marked.remove(name) {
+                if marked.ad_hoc {
+                    self.scratchpads.remove(name);
+                    self.validated_configs.remove(name);
+                    self.states.remove(name);
+                }
+            }
+        }
+    }
+
+    /// Get current workspace information
+    async fn get_current_workspace(&self, client: &HyprlandClient) -> Result<String> {
+        client.get_active_workspace().await
+    }
+
+    /// Spawn a scratchpad window if it doesn't exist, return the window
+    #[instrument(skip_all, fields(scratchpad = %name))]
+    async fn spawn_scratchpad(
+        &mut self,
+        name: &str,
+        config: &ValidatedConfig,
+    ) -> Result<hyprland::data::Client> {
+        info!("🚀 Spawning scratchpad if needed");
+
+        let client = self.get_hyprland_client().await?;
+
+        // Step 1: Check if scratchpad already exists using internal tracking system
+        let should_spawn_new = if let Some(state) = self.states.get(name) {
+            if state.is_spawned && !state.windows.is_empty() {
+                // Verify the tracked window still exists in Hyprland
+                if let Some(window_state) = state.windows.first() {
                     let current_windows = client.get_windows().await?;
                     if let Some(existing_window) = current_windows
                         .iter()
@@ -2123,6 +3759,14 @@ impl ScratchpadsPlugin {
             self.states.remove(name);
         }
 
+        // Marked scratchpads only have the window they were made from
+        if config.command.trim().is_empty() {
+            return Err(anyhow::anyhow!(
+                "Scratchpad '{}' has no command to spawn a window with",
+                name
+            ));
+        }
+
         // Step 2: Capture ORIGINAL active workspace BEFORE any spawn operations
         let original_active_workspace = client.get_active_workspace().await?;
         debug!(
@@ -2159,7 +3803,8 @@ impl ScratchpadsPlugin {
         }
 
         // Calculer et stocker les positions pour cohérence future
-        let (spawn_x, spawn_y) = if let Some(animation_type) = &config.animation {
+        let animation = config.animation.as_ref().filter(|_| config.floating);
+        let (spawn_x, spawn_y) = if let Some(animation_type) = animation {
             // Utiliser la nouvelle fonction unifiée pour garantir la cohérence
             let positions = Self::calculate_unified_animation_positions(
                 animation_type,
@@ -2206,14 +3851,12 @@ impl ScratchpadsPlugin {
             self.expand_command(&config.command, &variables)
         }; // variables guard is dropped here
 
-        let spawn_command = format!(
-            "[workspace {};float;size {} {};move {} {}] {}",
-            original_active_workspace,
-            geometry.width,
-            geometry.height,
-            hyprland_relative_x,
-            hyprland_relative_y,
-            command
+        let spawn_command = Self::spawn_command(
+            config,
+            &original_active_workspace,
+            &geometry,
+            (hyprland_relative_x, hyprland_relative_y),
+            &command,
         );
 
         info!("🚀 TRACE: Spawning with command: {}", spawn_command);
@@ -2248,14 +3891,17 @@ impl ScratchpadsPlugin {
         );
 
         // Step 7: Apply specific windowrules to the identified window
-        self.apply_scratchpad_window_rules(&window_address).await?;
+        self.apply_scratchpad_window_rules(&client, &window_address, config.floating)
+            .await?;
         debug!(
             "📋 Window class '{}' for scratchpad '{}'",
             new_window.class, name
         );
 
         // Step 8: Final setup and tracking (without positioning/animation)
-        self.finalize_scratchpad_setup(&new_window, name).await?;
+        self.finalize_scratchpad_setup(&client, &new_window, name)
+            .await?;
+        self.learn_native_size(&client, name, &window_address).await;
 
         Ok(new_window)
     }
@@ -2273,6 +3919,9 @@ impl ScratchpadsPlugin {
 
         // Step 1: Spawn the scratchpad (handles both new creation and existing detection)
         let window = self.spawn_scratchpad(name, config).await?;
+        // Spawning may have learned the native size for preserve_aspect
+        let learned = self.get_validated_config(name).ok();
+        let config = learned.as_deref().unwrap_or(config);
 
         // Step 2: Show the scratchpad (handles positioning, animation, and focus)
         let client = self.get_hyprland_client().await?;
@@ -2280,13 +3929,14 @@ impl ScratchpadsPlugin {
     }
 
     /// Hide a scratchpad window with animation, then move to special workspace
+    #[instrument(skip_all, fields(scratchpad = %name, window = %window.address))]
     async fn hide_scratchpad_window(
         &mut self,
         client: &HyprlandClient,
         window: &hyprland::data::Client,
         name: &str,
     ) -> Result<String> {
-        info!("🙈 Hiding scratchpad window: {}", window.address);
+        info!("🙈 Hiding scratchpad window");
 
         // Get config for restore_focus setting and animation
         let config = self.get_validated_config(name)?;
@@ -2295,8 +3945,12 @@ impl ScratchpadsPlugin {
         // Store current focus for potential restoration
         let should_restore_focus = config.restore_focus;
 
+        // Hidden windows are only moved away once the hide animation has played
+        let mut stash_after = Duration::ZERO;
+        let mut faded = false;
+
         // Handle hide animations using unified position calculation
-        if let Some(animation_type) = &config.animation {
+        if let Some(animation_type) = config.animation.as_ref().filter(|_| config.floating) {
             // Get current window geometry
             let windows = client.get_windows().await?;
             let current_geometry = windows
@@ -2372,14 +4026,30 @@ impl ScratchpadsPlugin {
                 duration: config.animation_duration.unwrap_or(300),
                 easing: config.to_easing_function(),
                 offset: "50px".to_string(), // Même offset mais sera ignoré car target_position est défini
-                opacity_from: 1.0,
+                opacity_from: config.animation_opacity_from.unwrap_or(0.0),
                 scale_from: 1.0,
                 delay: config.animation_delay.unwrap_or(0),
-                properties: None,
+                // Same monitor-relative start as the animator's own hide properties
+                properties: config.animation_properties_for(
+                    (
+                        source_monitor.x + current_geometry.x,
+                        source_monitor.y + current_geometry.y,
+                    ),
+                    hide_target_position,
+                    false,
+                    &source_monitor,
+                ),
                 target_fps: 60,
                 target_position: Some(hide_target_position), // ✅ POSITION PRÉ-CALCULÉE
+                spring: config.spring_config(),
             };
 
+            // The slide and the fade run as one multi-property animation
+            faded = hide_config.animates_opacity();
+            if faded {
+                self.faded_windows.insert(window_address.clone());
+            }
+
             // 4. Use WindowAnimator with pre-calculated position
             let mut animator = self.window_animator.lock().await;
             animator.set_active_monitor(&source_monitor).await;
@@ -2393,10 +4063,79 @@ impl ScratchpadsPlugin {
                     &source_monitor,
                 )
                 .await?;
+            drop(animator);
+
+            // The hide animation runs in the background; show animations are awaited.
+            // A spring runs until it settles rather than for `animation_duration`
+            let motion = if config.uses_physics_animation() {
+                config.spring_settle_time()
+            } else {
+                Duration::from_millis(config.animation_duration.unwrap_or(300) as u64)
+            };
+            let duration =
+                Duration::from_millis(config.animation_delay.unwrap_or(0) as u64) + motion;
+            stash_after = duration;
+            self.track_animation(
+                name,
+                &window_address,
+                duration,
+                (
+                    hide_target_position.0,
+                    hide_target_position.1,
+                    current_geometry.width,
+                    current_geometry.height,
+                ),
+            );
         }
 
-        // Update visibility state to reflect that window is now hidden
-        self.mark_window_hidden(name, &window_address);
+        if config.close_on_hide {
+            // Let the hide animation play out so the slide-out is seen, then close
+            // the window for good instead of keeping it around hidden
+            let animation_loop = self
+                .window_animator
+                .lock()
+                .await
+                .take_animation_loop(&window_address);
+            if let Some(animation_loop) = animation_loop {
+                let grace = stash_after + Duration::from_millis(ANIMATION_LOOP_SLACK_MS);
+                if tokio::time::timeout(grace, animation_loop).await.is_err() {
+                    debug!(
+                        "⏱️ Hide animation of {} still running after {:?}, closing anyway",
+                        window_address, grace
+                    );
+                }
+            }
+            self.faded_windows.remove(&window_address);
+            self.close_scratchpad_window(client, window, name, config.kill_process_group)
+                .await?;
+        } else {
+            // Offscreen windows stay on their workspace so the app keeps rendering
+            let offscreen = if config.parks_offscreen(window.pinned) {
+                if config.hide_mode != "offscreen" {
+                    debug!(
+                        "📌 '{}' is pinned, parking it off-screen to keep the pin",
+                        name
+                    );
+                }
+                let monitor = self.get_spawn_monitor_or_current(name, &config).await?;
+                Some(Self::offscreen_hide_position(
+                    config.animation.as_deref(),
+                    (window.at.0 as i32, window.at.1 as i32),
+                    (window.size.0 as i32, window.size.1 as i32),
+                    &monitor,
+                ))
+            } else {
+                None
+            };
+            if offscreen.is_some() {
+                self.parked_windows.insert(window_address.clone());
+            }
+            self.stash_hidden_window(client, &window_address, offscreen, stash_after, faded)
+                .await?;
+
+            // Update visibility state to reflect that window is now hidden
+            self.mark_window_hidden(name, &window_address);
+        }
 
         // Restore focus to previously focused window if enabled
         if should_restore_focus {
@@ -2408,7 +4147,133 @@ impl ScratchpadsPlugin {
         Ok(format!("Scratchpad '{name}' hidden with animation"))
     }
 
+    /// Close a scratchpad window and forget it, so the next toggle spawns a fresh one
+    async fn close_scratchpad_window(
+        &mut self,
+        client: &HyprlandClient,
+        window: &hyprland::data::Client,
+        name: &str,
+        kill_process_group: bool,
+    ) -> Result<()> {
+        let window_address = window.address.to_string();
+        info!(
+            "🗑️ Closing scratchpad '{}' window: {}",
+            name, window_address
+        );
+
+        // Resolve the group before closing: the leader may exit with its window
+        let process_group = if kill_process_group {
+            match Self::process_group_of(window.pid).await {
+                Ok(pgid) => Some(pgid),
+                Err(e) => {
+                    warn!(
+                        "⚠️ Not killing process group of scratchpad '{}' (pid {}): {}",
+                        name, window.pid, e
+                    );
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        if let Err(e) = client.close_window(&window_address).await {
+            // Closed externally (e.g. during the hide animation): nothing left to close
+            let still_open = client
+                .get_windows()
+                .await
+                .map(|windows| {
+                    windows
+                        .iter()
+                        .any(|w| w.address.to_string() == window_address)
+                })
+                .unwrap_or(true);
+            if still_open {
+                return Err(e);
+            }
+            debug!("🗑️ Window {} was already closed", window_address);
+        }
+
+        if let Some(pgid) = process_group {
+            if let Err(e) = Self::terminate_process_group(client, pgid).await {
+                debug!("⚠️ Failed to terminate process group {}: {}", pgid, e);
+            } else {
+                info!("💀 Sent SIGTERM to process group {} of '{}'", pgid, name);
+            }
+        }
+
+        self.window_to_scratchpad.remove(&window_address);
+        if let Some(state) = self.states.get_mut(name) {
+            state.windows.retain(|w| w.address != window_address);
+            if state.windows.is_empty() {
+                state.is_spawned = false;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Find the process group of the process that owns a window.
+    ///
+    /// The pid is the one Hyprland reports for the window, i.e. the process that
+    /// created it. Children that moved to their own group (setsid, daemons) are
+    /// not reached.
+    async fn process_group_of(pid: i32) -> Result<i32> {
+        if pid <= 1 {
+            return Err(anyhow::anyhow!("no usable pid for window"));
+        }
+
+        let stat = tokio::fs::read_to_string(format!("/proc/{pid}/stat")).await?;
+        let pgid = Self::parse_process_group(&stat)
+            .ok_or_else(|| anyhow::anyhow!("could not parse process group of pid {}", pid))?;
+
+        // Never signal our own group (or init's)
+        let own_stat = tokio::fs::read_to_string("/proc/self/stat").await?;
+        if pgid <= 1 || Some(pgid) == Self::parse_process_group(&own_stat) {
+            return Err(anyhow::anyhow!(
+                "process group {} is shared with rustrland",
+                pgid
+            ));
+        }
+
+        Ok(pgid)
+    }
+
+    /// Send SIGTERM to every process in a process group. Under `--dry-run` the
+    /// `kill` is only logged, like the dispatches before it
+    async fn terminate_process_group(client: &HyprlandClient, pgid: i32) -> Result<()> {
+        if client.is_dry_run() {
+            info!("🧪 [dry-run] kill -TERM -- -{}", pgid);
+            return Ok(());
+        }
+
+        let output = tokio::process::Command::new("kill")
+            .arg("-TERM")
+            .arg("--")
+            .arg(format!("-{pgid}"))
+            .output()
+            .await?;
+
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "kill failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Extract the process group id from the contents of `/proc/<pid>/stat`
+    fn parse_process_group(stat: &str) -> Option<i32> {
+        // The command name is wrapped in parentheses and may contain spaces,
+        // so start after the last ')': state, ppid, pgrp, ...
+        let rest = &stat[stat.rfind(')')? + 1..];
+        rest.split_whitespace().nth(2)?.parse().ok()
+    }
+
     /// Show a scratchpad window on current workspace
+    #[instrument(skip_all, fields(scratchpad = %name, window = %window.address))]
     async fn show_scratchpad(
         &mut self,
         client: &HyprlandClient,
@@ -2416,15 +4281,74 @@ impl ScratchpadsPlugin {
         config: &ValidatedConfig,
         name: &str,
     ) -> Result<String> {
-        info!("👁️ Showing scratchpad window: {}", window.address);
+        info!("👁️ Showing scratchpad window");
         debug!("🔍 Current window workspace: {}", window.workspace.name);
 
         let window_address = window.address.to_string();
 
+        // A hide that is still waiting to move the window away must not run now
+        self.cancel_stash(&window_address);
+        self.parked_windows.remove(&window_address);
+
+        // A fade-out may have been cut short before its stash restored the opacity
+        if self.faded_windows.remove(&window_address) {
+            Self::restore_opacity(client, &self.window_animator, &window_address).await;
+        }
+
+        // Native size still unknown (the window was not mapped at spawn): learn it now
+        let learned = if Self::awaits_native_size(config) {
+            self.learn_native_size(client, name, &window_address).await
+        } else {
+            None
+        };
+        let config = learned.as_deref().unwrap_or(config);
+
+        self.hide_excluded(name, config).await;
+        // Shown again (by the user or a restore): no longer waiting on an excluder
+        if let Some(state) = self.states.get_mut(name) {
+            state.excluded_by.clear();
+        }
+
         // Get target monitor and its active workspace
-        let _target_monitor = self.get_target_monitor(config).await?;
-        //let target_workspace = _target_monitor.active_workspace_id.to_string();
-        //debug!("🔍 Target workspace: {}", target_workspace);
+        let target_monitor = self.get_target_monitor(config).await?;
+
+        // Tiled scratchpads get no geometry: the layout places them
+        if GeometryCalculator::show_geometry(config, &target_monitor)?.is_none() {
+            return self
+                .show_tiled_scratchpad(client, window, config, name)
+                .await;
+        }
+
+        // Show on the workspace the user is on now, not the one it was spawned from;
+        // with allow_special_workspaces, on a special workspace open over it
+        let open_special = if config.allow_special_workspaces {
+            Self::open_special_workspaces(client)
+                .await
+                .remove(&target_monitor.name)
+        } else {
+            None
+        };
+        let target_workspace =
+            open_special.unwrap_or_else(|| target_monitor.active_workspace_id.to_string());
+        debug!("🔍 Target workspace: {}", target_workspace);
+        self.states
+            .entry(name.to_string())
+            .or_default()
+            .original_workspace = Some(target_workspace);
+
+        // Bring stashed windows back first so the show animation is visible;
+        // offscreen-hidden windows are still on a regular workspace
+        if window.workspace.name.starts_with("special:") {
+            let original_workspace = self
+                .states
+                .get(name)
+                .and_then(|state| state.original_workspace.clone());
+            if let Some(workspace) = original_workspace {
+                client
+                    .move_window_to_workspace(&window_address, &workspace)
+                    .await?;
+            }
+        }
 
         // Apply geometry and focus using proper animation system
         if let Ok(monitor) = self.get_target_monitor(config).await {
@@ -2559,79 +4483,284 @@ impl ScratchpadsPlugin {
         Ok(format!("Scratchpad '{name}' shown"))
     }
 
-    fn mark_window_visible(&mut self, scratchpad_name: &str, window_address: &str) {
-        let state = self.states.entry(scratchpad_name.to_string()).or_default();
-        state.last_used = Some(Instant::now());
+    /// Show a tiled scratchpad on the active workspace without touching its geometry
+    async fn show_tiled_scratchpad(
+        &mut self,
+        client: &HyprlandClient,
+        window: &hyprland::data::Client,
+        config: &ValidatedConfig,
+        name: &str,
+    ) -> Result<String> {
+        let window_address = window.address.to_string();
 
-        // Find or create window state
-        if let Some(window_state) = state
-            .windows
-            .iter_mut()
-            .find(|w| w.address == *window_address)
-        {
-            window_state.is_visible = true;
-            window_state.last_focus = Some(Instant::now());
-        } else {
-            state.windows.push(WindowState {
-                address: window_address.to_string(),
-                is_visible: true,
-                last_position: None,
-                monitor: None,
-                workspace: None,
-                last_focus: Some(Instant::now()),
-            });
+        let active_workspace = client.get_active_workspace().await?;
+        client
+            .move_window_to_workspace(&window_address, &active_workspace)
+            .await?;
+
+        // The window may still be floating from an earlier floating config
+        if window.floating {
+            client.toggle_floating(&window_address).await?;
         }
 
-        self.window_to_scratchpad
-            .insert(window_address.to_string(), scratchpad_name.to_string());
-    }
+        if config.smart_focus {
+            client.focus_window(&window_address).await?;
+        }
 
-    fn mark_window_hidden(&mut self, scratchpad_name: &str, window_address: &str) {
-        let state = self.states.entry(scratchpad_name.to_string()).or_default();
-        state.last_used = Some(Instant::now());
+        self.mark_window_visible(name, &window_address);
 
-        // Find window state and mark as hidden
-        if let Some(window_state) = state
+        Ok(format!("Scratchpad '{name}' shown (tiled)"))
+    }
+
+    /// Drop tracked instances of `name` whose window is gone from Hyprland
+    fn prune_closed_instances(&mut self, name: &str, live: &HashSet<String>) {
+        let Some(state) = self.states.get_mut(name) else {
+            return;
+        };
+        let (kept, closed): (Vec<_>, Vec<_>) = state
             .windows
-            .iter_mut()
-            .find(|w| w.address == *window_address)
-        {
-            window_state.is_visible = false;
+            .drain(..)
+            .partition(|w| live.contains(&w.address));
+        state.windows = kept;
+        if state.windows.is_empty() {
+            state.is_spawned = false;
+        }
+        for window in closed {
             debug!(
-                "🔍 Marked window {} as hidden for scratchpad '{}'",
-                window_address, scratchpad_name
-            );
-        } else {
-            warn!(
-                "⚠️  Attempted to mark non-existent window {} as hidden for scratchpad '{}'",
-                window_address, scratchpad_name
+                "🧹 Pruning closed instance {} of scratchpad '{}'",
+                window.address, name
             );
+            self.window_to_scratchpad.remove(&window.address);
         }
     }
 
-    /// Calculate start position for animation based on type and target (Fixed geometry)
-    /// Multi-monitor aware: ensures offscreen positions are always truly offscreen
-    fn calculate_spawn_position_offscreen(
-        animation_type: &str,
-        target_position: (i32, i32),
-        target_size: (i32, i32),
-        monitor: &MonitorInfo,
-        offset_pixels: i32,
-    ) -> (i32, i32) {
-        // Limit offset to reasonable values (max 200px off-screen)
-        let safe_offset = offset_pixels.clamp(10, 200);
+    /// Pick the instance after the most recently focused one, wrapping around,
+    /// and mark it as focused. Closed instances are pruned first.
+    fn next_instance(&mut self, name: &str, live: &HashSet<String>) -> Option<String> {
+        self.prune_closed_instances(name, live);
+        let state = self.states.get_mut(name)?;
+        if state.windows.is_empty() {
+            return None;
+        }
 
-        // Calculate monitor bounds for proper offscreen positioning
-        let monitor_top = monitor.y;
-        let monitor_bottom = monitor.y + monitor.height as i32;
-        let monitor_left = monitor.x;
-        let monitor_right = monitor.x + monitor.width as i32;
+        let current = state
+            .windows
+            .iter()
+            .enumerate()
+            .filter_map(|(i, w)| w.last_focus.map(|at| (i, at)))
+            .max_by_key(|(_, at)| *at)
+            .map(|(i, _)| i);
+        let next = current.map_or(0, |i| (i + 1) % state.windows.len());
+
+        let window = &mut state.windows[next];
+        window.last_focus = Some(Instant::now());
+        Some(window.address.clone())
+    }
 
-        let calculated_position = match animation_type {
-            "fromTop" => {
-                // Always position above the monitor's top edge, regardless of monitor offset
-                let offscreen_y = monitor_top - target_size.1 - safe_offset;
-                info!("🔍 TRACE: fromTop calculation - monitor_top={}, target_size.1={}, safe_offset={}, result_y={}",
+    /// Focus the next instance of a multi-window scratchpad
+    async fn cycle_instances(&mut self, name: &str) -> Result<String> {
+        let client = self.get_hyprland_client().await?;
+        let live: HashSet<String> = client
+            .get_windows()
+            .await?
+            .iter()
+            .map(|w| w.address.to_string())
+            .collect();
+
+        let address = self
+            .next_instance(name, &live)
+            .ok_or_else(|| anyhow::anyhow!("Scratchpad '{}' has no open instance", name))?;
+        client.focus_window(&address).await?;
+
+        let count = self.states.get(name).map_or(0, |s| s.windows.len());
+        Ok(format!(
+            "Focused instance {address} of '{name}' ({count} open)"
+        ))
+    }
+
+    /// Explicitly open one more instance of a multi-window scratchpad, up to
+    /// `max_instances`, on the active workspace
+    async fn spawn_instance(&mut self, name: &str) -> Result<String> {
+        let config = self
+            .validated_configs
+            .get(name)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Scratchpad '{}' not found", name))?;
+        if !config.multi_window {
+            return Err(anyhow::anyhow!(
+                "Scratchpad '{}' is not configured with multi_window",
+                name
+            ));
+        }
+
+        let client = self.get_hyprland_client().await?;
+        let before_snapshot = client.get_windows().await?;
+        let before_addresses: HashSet<String> = before_snapshot
+            .iter()
+            .map(|w| w.address.to_string())
+            .collect();
+        self.prune_closed_instances(name, &before_addresses);
+
+        let max_instances = config.max_instances.unwrap_or(1) as usize;
+        let open = self.states.get(name).map_or(0, |s| s.windows.len());
+        if open >= max_instances {
+            return Err(anyhow::anyhow!(
+                "Scratchpad '{}' already has {} of {} instances",
+                name,
+                open,
+                max_instances
+            ));
+        }
+
+        let active_workspace = client.get_active_workspace().await?;
+        let monitor = self.get_target_monitor(&config).await?;
+        let geometry = GeometryCalculator::calculate_geometry(&config, &monitor)?;
+        let command = {
+            let variables = self.variables.read().await;
+            self.expand_command(&config.command, &variables)
+        };
+        let spawn_command = Self::spawn_command(
+            &config,
+            &active_workspace,
+            &geometry,
+            (geometry.x - monitor.x, geometry.y - monitor.y),
+            &command,
+        );
+
+        info!("🚀 Spawning instance {} of '{}'", open + 1, name);
+        client.spawn_app(&spawn_command).await?;
+
+        let new_window = self
+            .find_new_window_by_comparison(&client, &before_addresses, 5000)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Failed to find newly spawned window"))?;
+        let window_address = new_window.address.to_string();
+
+        self.apply_scratchpad_window_rules(&client, &window_address, config.floating)
+            .await?;
+        self.finalize_scratchpad_setup(&client, &new_window, name)
+            .await?;
+        self.states
+            .entry(name.to_string())
+            .or_default()
+            .original_workspace
+            .get_or_insert(active_workspace);
+        self.mark_window_visible(name, &window_address);
+        client.focus_window(&window_address).await?;
+
+        Ok(format!(
+            "Spawned instance {window_address} of '{name}' ({}/{max_instances})",
+            open + 1
+        ))
+    }
+
+    /// Whether any window of the scratchpad is currently shown
+    pub fn is_scratchpad_visible(&self, name: &str) -> bool {
+        self.states
+            .get(name)
+            .is_some_and(|state| state.windows.iter().any(|w| w.is_visible))
+    }
+
+    fn mark_window_visible(&mut self, scratchpad_name: &str, window_address: &str) {
+        let state = self.states.entry(scratchpad_name.to_string()).or_default();
+        state.last_used = Some(Instant::now());
+
+        // Find or create window state
+        if let Some(window_state) = state
+            .windows
+            .iter_mut()
+            .find(|w| w.address == *window_address)
+        {
+            window_state.is_visible = true;
+            window_state.last_focus = Some(Instant::now());
+        } else {
+            state.windows.push(WindowState {
+                address: window_address.to_string(),
+                is_visible: true,
+                last_position: None,
+                monitor: None,
+                workspace: None,
+                last_focus: Some(Instant::now()),
+            });
+        }
+
+        self.window_to_scratchpad
+            .insert(window_address.to_string(), scratchpad_name.to_string());
+    }
+
+    /// Special workspace shown over each monitor (`special:name`), by monitor name
+    async fn open_special_workspaces(client: &HyprlandClient) -> HashMap<String, String> {
+        match client.get_monitors().await {
+            Ok(monitors) => monitors
+                .into_iter()
+                .filter(|m| m.special_workspace.name.starts_with("special:"))
+                .map(|m| (m.name, m.special_workspace.name))
+                .collect(),
+            Err(e) => {
+                debug!("Could not list open special workspaces: {}", e);
+                HashMap::new()
+            }
+        }
+    }
+
+    /// Whether a window on `workspace` can be seen. Special workspaces hide their
+    /// windows, except for `allow_special_workspaces` scratchpads shown on a special
+    /// workspace that is open on some monitor
+    fn counts_as_visible(
+        workspace: &str,
+        allow_special_workspaces: bool,
+        open_special: &HashMap<String, String>,
+    ) -> bool {
+        !workspace.starts_with("special:")
+            || (allow_special_workspaces && open_special.values().any(|open| open == workspace))
+    }
+
+    fn mark_window_hidden(&mut self, scratchpad_name: &str, window_address: &str) {
+        let state = self.states.entry(scratchpad_name.to_string()).or_default();
+        state.last_used = Some(Instant::now());
+
+        // Find window state and mark as hidden
+        if let Some(window_state) = state
+            .windows
+            .iter_mut()
+            .find(|w| w.address == *window_address)
+        {
+            window_state.is_visible = false;
+            debug!(
+                "🔍 Marked window {} as hidden for scratchpad '{}'",
+                window_address, scratchpad_name
+            );
+        } else {
+            warn!(
+                "⚠️  Attempted to mark non-existent window {} as hidden for scratchpad '{}'",
+                window_address, scratchpad_name
+            );
+        }
+    }
+
+    /// Calculate start position for animation based on type and target (Fixed geometry)
+    /// Multi-monitor aware: ensures offscreen positions are always truly offscreen
+    fn calculate_spawn_position_offscreen(
+        animation_type: &str,
+        target_position: (i32, i32),
+        target_size: (i32, i32),
+        monitor: &MonitorInfo,
+        offset_pixels: i32,
+    ) -> (i32, i32) {
+        // Limit offset to reasonable values (max 200px off-screen)
+        let safe_offset = offset_pixels.clamp(10, 200);
+
+        // Calculate monitor bounds for proper offscreen positioning
+        let monitor_top = monitor.y;
+        let monitor_bottom = monitor.y + monitor.height as i32;
+        let monitor_left = monitor.x;
+        let monitor_right = monitor.x + monitor.width as i32;
+
+        let calculated_position = match animation_type {
+            "fromTop" => {
+                // Always position above the monitor's top edge, regardless of monitor offset
+                let offscreen_y = monitor_top - target_size.1 - safe_offset;
+                info!("🔍 TRACE: fromTop calculation - monitor_top={}, target_size.1={}, safe_offset={}, result_y={}",
                        monitor_top, target_size.1, safe_offset, offscreen_y);
                 info!(
                     "🔍 TRACE: fromTop - target_position=({}, {}), final_position=({}, {})",
@@ -2866,6 +4995,21 @@ impl ScratchpadsPlugin {
         Ok(())
     }
 
+    /// Scratchpad a window belongs to by class: exact `class` matches first, then
+    /// `class_regex` (in name order, so the choice is stable)
+    fn find_scratchpad_for_window(&self, window: &hyprland::data::Client) -> Option<String> {
+        let mut names: Vec<&String> = self.validated_configs.keys().collect();
+        names.sort();
+        let find = |matches: &dyn Fn(&ValidatedConfig) -> bool| {
+            names
+                .iter()
+                .find(|name| matches(&self.validated_configs[name.as_str()]))
+                .map(|name| name.to_string())
+        };
+        find(&|config| config.matches_exact_class(window))
+            .or_else(|| find(&|config| config.matches_class(window)))
+    }
+
     async fn handle_window_opened(&mut self, window_address: &str) {
         debug!("🪟 Window opened: {}", window_address);
 
@@ -2891,13 +5035,13 @@ impl ScratchpadsPlugin {
         let opened_window = windows
             .into_iter()
             .find(|w| w.address.to_string() == window_address);
-        let window_class = match opened_window {
+        let window = match opened_window {
             Some(window) => {
                 debug!(
                     "🔍 Found opened window - class: '{}', title: '{}'",
                     window.class, window.title
                 );
-                window.class
+                window
             }
             None => {
                 debug!(
@@ -2908,9 +5052,12 @@ impl ScratchpadsPlugin {
             }
         };
 
-        // Find scratchpad that matches this window class
+        // Find scratchpad that matches this window class; an exact `class` beats a
+        // `class_regex` match of another scratchpad
+        let matched = self.find_scratchpad_for_window(&window);
+        let window_class = window.class;
         for (scratchpad_name, config) in &self.scratchpads {
-            if config.class.as_ref() == Some(&window_class) {
+            if matched.as_ref() == Some(scratchpad_name) {
                 debug!(
                     "📋 Detected scratchpad window: {} for '{}' (class: '{}')",
                     window_address, scratchpad_name, window_class
@@ -2957,7 +5104,72 @@ impl ScratchpadsPlugin {
     async fn handle_window_moved(&mut self, window_address: &str) {
         debug!("📍 Window moved: {}", window_address);
 
-        // Window moved - geometry updated
+        if self.window_to_scratchpad.contains_key(window_address) {
+            self.schedule_geometry_sync(window_address);
+        }
+    }
+
+    /// Debounced geometry sync: every move restarts the window's timer so a
+    /// drag only triggers one geometry query once it settles
+    fn schedule_geometry_sync(&mut self, window_address: &str) {
+        if let Some(handle) = self.sync_tasks.remove(window_address) {
+            handle.abort();
+        }
+
+        let sender = self.internal_sender.clone();
+        let address = window_address.to_string();
+
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(GEOMETRY_SYNC_DEBOUNCE_MS)).await;
+
+            if let Some(sender) = sender {
+                let _ = sender.send(InternalCommand::SyncGeometry {
+                    window_address: address,
+                });
+            }
+        });
+
+        self.sync_tasks.insert(window_address.to_string(), handle);
+    }
+
+    /// Refresh cached geometry and last known position for a scratchpad window
+    async fn sync_window_geometry(&mut self, window_address: &str) {
+        self.sync_tasks.remove(window_address);
+
+        let geometry = match self
+            .enhanced_client
+            .get_window_geometry(window_address)
+            .await
+        {
+            Ok(geometry) => geometry,
+            Err(e) => {
+                debug!("⚠️  Failed to sync geometry for {}: {}", window_address, e);
+                return;
+            }
+        };
+
+        self.record_window_geometry(window_address, geometry).await;
+    }
+
+    async fn record_window_geometry(&mut self, window_address: &str, geometry: WindowGeometry) {
+        if let Some(name) = self.window_to_scratchpad.get(window_address) {
+            if let Some(state) = self.states.get_mut(name) {
+                if let Some(window) = state
+                    .windows
+                    .iter_mut()
+                    .find(|w| w.address == window_address)
+                {
+                    window.last_position =
+                        Some((geometry.x, geometry.y, geometry.width, geometry.height));
+                }
+            }
+        }
+
+        self.geometry_cache
+            .write()
+            .await
+            .insert(window_address.to_string(), geometry);
+        debug!("📐 Synced geometry for {}", window_address);
     }
 
     async fn handle_workspace_changed(&mut self, workspace: &str) {
@@ -2971,11 +5183,18 @@ impl ScratchpadsPlugin {
         }
 
         // Update visibility status for scratchpad windows
-        // Special workspaces (like special:scratchpad) typically hide windows
-        let _is_special_workspace = workspace.starts_with("special:");
+        let open_special = match self.get_hyprland_client().await {
+            Ok(client) => Self::open_special_workspaces(&client).await,
+            Err(_) => HashMap::new(),
+        };
 
         // Update window visibility status based on workspace
         for (window_address, scratchpad_name) in &self.window_to_scratchpad {
+            // Windows parked off-screen never leave their workspace (pinned ones follow
+            // every workspace), so it says nothing about their visibility
+            if self.parked_windows.contains(window_address) {
+                continue;
+            }
             if let Some(state) = self.states.get_mut(scratchpad_name) {
                 if let Some(window_state) = state
                     .windows
@@ -2988,7 +5207,15 @@ impl ScratchpadsPlugin {
                         .get_window_geometry(window_address)
                         .await
                     {
-                        let new_visibility = !geometry.workspace.starts_with("special:");
+                        let allow_special = self
+                            .validated_configs
+                            .get(scratchpad_name)
+                            .is_some_and(|config| config.allow_special_workspaces);
+                        let new_visibility = Self::counts_as_visible(
+                            &geometry.workspace,
+                            allow_special,
+                            &open_special,
+                        );
                         if window_state.is_visible != new_visibility {
                             window_state.is_visible = new_visibility;
                             debug!(
@@ -3035,6 +5262,18 @@ impl ScratchpadsPlugin {
     }
 
     async fn handle_window_closed(&mut self, window_address: &str) {
+        self.parked_windows.remove(window_address);
+
+        // A marked window that closes takes its ad-hoc scratchpad with it
+        let marked = self
+            .marked
+            .iter()
+            .find(|(_, marked)| marked.address == window_address)
+            .map(|(name, _)| name.clone());
+        if let Some(name) = marked {
+            self.forget_marked_window(&name, window_address);
+        }
+
         // Remove from window mapping
         if let Some(scratchpad_name) = self.window_to_scratchpad.remove(window_address) {
             debug!(
@@ -3127,813 +5366,3185 @@ impl ScratchpadsPlugin {
         }
     }
 
-    /// Simple hide scheduling with hysteresis (Pyprland-style)
+    /// Simple hide scheduling with hysteresis (Pyprland-style). Idempotent: each
+    /// call leaves exactly one hysteresis timer, a fresh one running the full delay
     async fn schedule_simple_hide(&mut self, scratchpad_name: String, hysteresis_seconds: f32) {
-        // Cancel any existing hide timer
-        self.cancel_hide_timer(&scratchpad_name).await;
+        // Cancel any existing hide timer, including a pending hide_delay
+        self.cancel_pending_hides(&scratchpad_name);
 
-        // Create simple timer
         let delay_ms = (hysteresis_seconds * 1000.0) as u64;
+        let timer = self.hide_timer(scratchpad_name.clone(), delay_ms);
+        self.hysteresis_tasks.insert(scratchpad_name, timer);
+    }
+
+    /// Hide command on a scratchpad with `hide_delay`: hide once the delay elapsed
+    fn schedule_delayed_hide(&mut self, scratchpad_name: &str, delay_ms: u32) -> Result<String> {
+        self.cancel_pending_hides(scratchpad_name);
+
+        let address = self
+            .states
+            .get(scratchpad_name)
+            .and_then(|state| state.windows.iter().find(|w| w.is_visible))
+            .map(|w| w.address.clone())
+            .ok_or_else(|| anyhow::anyhow!("Scratchpad '{}' is not visible", scratchpad_name))?;
+        let timer = self.hide_timer(scratchpad_name.to_string(), delay_ms as u64);
+        self.hide_tasks.insert(address, timer);
+
+        Ok(format!(
+            "Scratchpad '{scratchpad_name}' hiding in {delay_ms}ms"
+        ))
+    }
+
+    /// Timer sending a `SimpleHide` for the scratchpad after `delay_ms`
+    fn hide_timer(&self, scratchpad_name: String, delay_ms: u64) -> PendingTimer {
+        let deadline = Instant::now() + Duration::from_millis(delay_ms);
         let sender = self.internal_sender.clone();
-        let scratchpad_name_clone = scratchpad_name.clone();
 
         let handle = tokio::spawn(async move {
             tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
 
             if let Some(sender) = sender {
-                let _ = sender.send(InternalCommand::SimpleHide {
-                    scratchpad_name: scratchpad_name_clone,
-                });
+                let _ = sender.send(InternalCommand::SimpleHide { scratchpad_name });
             }
         });
 
-        self.hysteresis_tasks.insert(scratchpad_name, handle);
+        PendingTimer { handle, deadline }
     }
 
-    /// Cancel hide timer (simplified)
-    async fn cancel_hide_timer(&mut self, scratchpad_name: &str) {
-        if let Some(handle) = self.hysteresis_tasks.remove(scratchpad_name) {
-            handle.abort();
+    /// Cancel both kinds of pending hide for a scratchpad: the unfocus hysteresis
+    /// timer and hide_delay timers on its windows
+    fn cancel_pending_hides(&mut self, scratchpad_name: &str) -> bool {
+        let mut cancelled = false;
+        if let Some(timer) = self.hysteresis_tasks.remove(scratchpad_name) {
+            timer.handle.abort();
+            cancelled = true;
+        }
+        let addresses: Vec<String> = self
+            .states
+            .get(scratchpad_name)
+            .map(|state| state.windows.iter().map(|w| w.address.clone()).collect())
+            .unwrap_or_default();
+        for address in addresses {
+            cancelled |= self.cancel_hide_delay(&address);
         }
+        cancelled
     }
 
-    /// Process internal commands (like hysteresis hide)
-    async fn process_internal_commands(&mut self) {
-        // Collect commands first to avoid borrow conflicts
-        let mut commands = Vec::new();
+    /// Claim the timer behind a `SimpleHide` for the scratchpad: true, dropping the
+    /// timer, if one of its hide timers is due. A hide sent by a timer that was then
+    /// cancelled (focus came back) or replaced by a fresh one finds none and is stale
+    fn take_due_hide(&mut self, scratchpad_name: &str, now: Instant) -> bool {
+        if self
+            .hysteresis_tasks
+            .get(scratchpad_name)
+            .is_some_and(|timer| timer.deadline <= now)
+        {
+            self.hysteresis_tasks.remove(scratchpad_name);
+            return true;
+        }
 
-        if let Some(receiver) = &mut self.internal_receiver {
-            while let Ok(command) = receiver.try_recv() {
-                commands.push(command);
-            }
+        let due = self.states.get(scratchpad_name).and_then(|state| {
+            state
+                .windows
+                .iter()
+                .map(|w| &w.address)
+                .find(|address| {
+                    self.hide_tasks
+                        .get(*address)
+                        .is_some_and(|timer| timer.deadline <= now)
+                })
+                .cloned()
+        });
+        match due {
+            Some(address) => self.hide_tasks.remove(&address).is_some(),
+            None => false,
         }
+    }
 
-        // Process the collected commands (simplified)
-        for command in commands {
-            match command {
-                InternalCommand::SimpleHide { scratchpad_name } => {
-                    debug!("🙈 Processing simple hide for '{}'", scratchpad_name);
-                    if let Err(e) = self.hide_scratchpad_direct(&scratchpad_name).await {
-                        warn!("Failed to hide scratchpad '{}': {}", scratchpad_name, e);
-                    } else {
-                        debug!("✅ Scratchpad '{}' hidden", scratchpad_name);
-                    }
-                }
-            }
+    /// Cancel hide timer (simplified)
+    async fn cancel_hide_timer(&mut self, scratchpad_name: &str) -> bool {
+        if let Some(timer) = self.hysteresis_tasks.remove(scratchpad_name) {
+            timer.handle.abort();
+            return true;
         }
+        false
     }
 
-    /// Restore focus to previously focused window
-    async fn restore_previous_focus(&self, client: &HyprlandClient) -> Result<()> {
-        if let Some(prev_window) = &self.previous_focused_window {
-            // Check if the previous window still exists
-            // Simply try to focus the window - if it fails, the window likely doesn't exist
-            debug!("🎯 Restoring focus to previous window: {}", prev_window);
-            if let Err(e) = client.focus_window(prev_window).await {
-                debug!("⚠️  Failed to focus previous window {}: {}", prev_window, e);
-            }
+    /// Cancel a delayed hide scheduled for a window
+    fn cancel_hide_delay(&mut self, window_address: &str) -> bool {
+        if let Some(timer) = self.hide_tasks.remove(window_address) {
+            timer.handle.abort();
+            return true;
         }
-        Ok(())
+        false
     }
 
-    /// Setup a newly opened scratchpad window with proper geometry and animation
-    async fn setup_scratchpad_window(
-        &self,
+    /// Move a hidden window out of the way, once `after` has elapsed: to the
+    /// special workspace, or to `offscreen` on its current workspace. A window
+    /// that was `faded` out gets its opacity back once it is out of sight
+    async fn stash_hidden_window(
+        &mut self,
+        client: &HyprlandClient,
         window_address: &str,
-        scratchpad_name: &str,
-        _config: &ScratchpadConfigRef,
+        offscreen: Option<(i32, i32)>,
+        after: Duration,
+        faded: bool,
     ) -> Result<()> {
-        info!(
-            "🎬 Setting up scratchpad window: {} for '{}'",
-            window_address, scratchpad_name
-        );
+        self.cancel_stash(window_address);
 
-        // Wait a moment for window to be fully created
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        if after.is_zero() {
+            Self::stash_window(client, window_address, offscreen).await?;
+            if faded {
+                Self::restore_opacity(client, &self.window_animator, window_address).await;
+            }
+            return Ok(());
+        }
 
-        // Get monitor info
-        let monitors = self.get_monitors().await?;
-        let monitor = monitors
-            .iter()
-            .find(|m| m.is_focused)
-            .or_else(|| monitors.first())
-            .ok_or_else(|| anyhow::anyhow!("No monitors found"))?;
+        let client = self.get_hyprland_client().await?;
+        let animator = Arc::clone(&self.window_animator);
+        let address = window_address.to_string();
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(after).await;
+            if let Err(e) = Self::stash_window(&client, &address, offscreen).await {
+                warn!("⚠️ Failed to stash hidden window {}: {}", address, e);
+            }
+            if faded {
+                Self::restore_opacity(&client, &animator, &address).await;
+            }
+        });
 
-        // Get validated config
-        let validated_config = self.validated_configs.get(scratchpad_name).ok_or_else(|| {
-            anyhow::anyhow!("No validated config for scratchpad: {}", scratchpad_name)
-        })?;
-
-        debug!(
-            "📋 Using config for '{}': size='{}', animation={:?}, margin={:?}",
-            scratchpad_name,
-            validated_config.size,
-            validated_config.animation,
-            validated_config.margin
+        self.stash_tasks.insert(
+            window_address.to_string(),
+            PendingTimer {
+                handle,
+                deadline: Instant::now() + after,
+            },
         );
+        Ok(())
+    }
 
-        let client = self.get_hyprland_client().await?;
-
-        // First, make sure the window is floating
-        info!("🔄 Making window floating: {}", window_address);
-        if let Err(e) = client.toggle_floating(window_address).await {
-            warn!("Failed to toggle floating: {}", e);
+    async fn stash_window(
+        client: &HyprlandClient,
+        window_address: &str,
+        offscreen: Option<(i32, i32)>,
+    ) -> Result<()> {
+        match offscreen {
+            Some((x, y)) => client.move_window_pixel(window_address, x, y).await,
+            None => client.hide_window(window_address).await,
         }
+    }
 
-        // Small delay to ensure floating state is applied
-        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    /// Put a faded window back to full opacity, stopping its hide animation first
+    /// so that a late frame cannot fade it out again
+    async fn restore_opacity(
+        client: &HyprlandClient,
+        animator: &Mutex<WindowAnimator>,
+        window_address: &str,
+    ) {
+        if let Err(e) = animator.lock().await.stop_animation(window_address).await {
+            debug!("Failed to stop animation for {}: {}", window_address, e);
+        }
+        if let Err(e) = client.set_window_opacity(window_address, 1.0).await {
+            warn!(
+                "⚠️ Failed to restore opacity of window {}: {}",
+                window_address, e
+            );
+        }
+    }
 
-        // Calculate and apply proper geometry
-        let target_geometry = GeometryCalculator::calculate_geometry(validated_config, monitor)?;
+    /// Cancel a pending move-away of a hidden window, e.g. because it is shown again
+    fn cancel_stash(&mut self, window_address: &str) -> bool {
+        if let Some(timer) = self.stash_tasks.remove(window_address) {
+            timer.handle.abort();
+            return true;
+        }
+        false
+    }
 
-        info!(
-            "📐 Applying geometry: {}x{} at ({}, {}) on monitor '{}'",
-            target_geometry.width,
-            target_geometry.height,
-            target_geometry.x,
-            target_geometry.y,
-            monitor.name
+    /// Where `hide_mode = "offscreen"` parks a window: past the monitor edge its hide
+    /// animation heads to, or below the monitor when the animation has no direction
+    fn offscreen_hide_position(
+        animation: Option<&str>,
+        position: (i32, i32),
+        size: (i32, i32),
+        monitor: &MonitorInfo,
+    ) -> (i32, i32) {
+        let hide_type = animation
+            .map(Self::hide_animation_type)
+            .unwrap_or_else(|| "toBottom".to_string());
+
+        let parked = Self::calculate_hide_position_offscreen(
+            &hide_type,
+            position,
+            size,
+            monitor,
+            OFFSCREEN_HIDE_MARGIN,
         );
-
-        client
-            .move_resize_window(
-                window_address,
-                target_geometry.x,
-                target_geometry.y,
-                target_geometry.width,
-                target_geometry.height,
-            )
-            .await?;
-
-        // Apply animation if configured
-        if validated_config.animation.is_some() {
-            info!(
-                "🎬 Applying show animation for scratchpad '{}'",
-                scratchpad_name
-            );
-
-            // Get the window data for animation
-            let windows = client.get_windows().await?;
-            if let Some(_window) = windows
-                .into_iter()
-                .find(|w| w.address.to_string() == window_address)
-            {
-                // animate_window_show removed - using WindowAnimator instead
-                debug!("Window show animation handled by WindowAnimator");
-            }
+        if parked != position {
+            return parked;
         }
 
-        info!("✅ Scratchpad window '{}' setup complete", scratchpad_name);
-        Ok(())
+        Self::calculate_hide_position_offscreen(
+            "toBottom",
+            position,
+            size,
+            monitor,
+            OFFSCREEN_HIDE_MARGIN,
+        )
     }
 
-    // ============================================================================
-    // STATE MANAGEMENT FOR HOT RELOAD
-    // ============================================================================
+    /// Last known geometry (x, y, width, height) of a scratchpad's window this session
+    async fn remembered_geometry(&self, name: &str) -> Option<(i32, i32, i32, i32)> {
+        let windows = &self.states.get(name)?.windows;
+        if let Some(position) = windows.iter().find_map(|w| w.last_position) {
+            return Some(position);
+        }
 
-    /// Capture the current state of all scratchpads for hot reload preservation
-    pub fn capture_state(&self) -> Result<serde_json::Value> {
-        debug!("🔍 Capturing scratchpads state for hot reload");
+        let cache = self.geometry_cache.read().await;
+        windows
+            .iter()
+            .find_map(|w| cache.get(&w.address))
+            .map(|g| (g.x, g.y, g.width, g.height))
+    }
 
-        // Capture core state information
-        let captured_states: HashMap<String, &ScratchpadState> = self
-            .states
+    /// Monitor holding the center of a window, falling back to the first one
+    fn monitor_at(
+        monitors: &[MonitorInfo],
+        geometry: (i32, i32, i32, i32),
+    ) -> Option<&MonitorInfo> {
+        let (center_x, center_y) = (geometry.0 + geometry.2 / 2, geometry.1 + geometry.3 / 2);
+        monitors
             .iter()
-            .map(|(name, state)| (name.clone(), state))
-            .collect();
+            .find(|m| {
+                (m.x..m.x + m.width as i32).contains(&center_x)
+                    && (m.y..m.y + m.height as i32).contains(&center_y)
+            })
+            .or_else(|| monitors.first())
+    }
 
-        let state_json = serde_json::json!({
-            "plugin_name": "scratchpads",
-            "timestamp": std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs(),
-            "scratchpad_states": captured_states,
-            "window_mappings": self.window_to_scratchpad,
-            "focused_window": self.focused_window,
-            "previous_focused_window": self.previous_focused_window,
-            "config_count": self.scratchpads.len(),
-            "active_scratchpads": captured_states.len()
-        });
+    /// Scratchpad settings from its `[scratchpads.<name>]` table
+    fn parse_scratchpad_config(sc: &toml::map::Map<String, toml::Value>) -> ScratchpadConfig {
+        let command = sc
+            .get("command")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string();
+
+        let class = sc
+            .get("class")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string();
+
+        let size = sc
+            .get("size")
+            .and_then(|v| v.as_str())
+            .unwrap_or("50% 50%")
+            .to_string();
+
+        let animation = sc
+            .get("animation")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+
+        let mut config = ScratchpadConfig {
+            command,
+            class: Some(class),
+            size,
+            animation,
+            ..Default::default()
+        };
 
-        debug!(
-            "📸 Captured state for {} scratchpads with {} windows mapped",
-            captured_states.len(),
-            self.window_to_scratchpad.len()
-        );
+        // Parse additional Pyprland-compatible options
+        if let Some(toml::Value::Boolean(lazy)) = sc.get("lazy") {
+            config.lazy = *lazy;
+        }
+        if let Some(toml::Value::Boolean(pinned)) = sc.get("pinned") {
+            config.pinned = *pinned;
+        }
+        if let Some(toml::Value::Array(excludes)) = sc.get("excludes") {
+            config.excludes = excludes
+                .iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect();
+        } else if let Some(toml::Value::String(exclude_all)) = sc.get("excludes") {
+            if exclude_all == "*" {
+                config.excludes = vec!["*".to_string()];
+            }
+        }
+        if let Some(toml::Value::String(class_regex)) = sc.get("class_regex") {
+            config.class_regex = Some(class_regex.clone());
+        }
+        if let Some(toml::Value::String(group)) = sc.get("group") {
+            config.group = Some(group.clone());
+        }
+        if let Some(toml::Value::Boolean(restore_excluded)) = sc.get("restore_excluded") {
+            config.restore_excluded = *restore_excluded;
+        }
+        if let Some(toml::Value::Boolean(preserve_aspect)) = sc.get("preserve_aspect") {
+            config.preserve_aspect = *preserve_aspect;
+        }
+        match sc.get("force_monitor") {
+            Some(toml::Value::String(force_monitor)) => {
+                config.force_monitor = Some(force_monitor.clone());
+            }
+            Some(toml::Value::Integer(index)) => config.force_monitor = Some(index.to_string()),
+            _ => {}
+        }
+        if let Some(toml::Value::Boolean(alt_toggle)) = sc.get("alt_toggle") {
+            config.alt_toggle = *alt_toggle;
+        }
+        if let Some(toml::Value::Boolean(allow_special)) = sc.get("allow_special_workspaces") {
+            config.allow_special_workspaces = *allow_special;
+        }
+        if let Some(toml::Value::Integer(margin)) = sc.get("margin") {
+            config.margin = Some(*margin as i32);
+        }
+        if let Some(toml::Value::Table(per_monitor)) = sc.get("per_monitor") {
+            config.per_monitor = per_monitor
+                .iter()
+                .filter_map(|(monitor, size)| {
+                    size.as_str()
+                        .map(|size| (monitor.clone(), size.to_string()))
+                })
+                .collect();
+        }
+        if let Some(toml::Value::String(offset)) = sc.get("offset") {
+            config.offset = Some(offset.clone());
+        }
+        if let Some(toml::Value::String(position)) = sc.get("position") {
+            config.position = Some(position.clone());
+        }
+        if let Some(toml::Value::Integer(hide_delay)) = sc.get("hide_delay") {
+            config.hide_delay = Some(*hide_delay as u32);
+        }
+        if let Some(toml::Value::Boolean(close_on_hide)) = sc.get("close_on_hide") {
+            config.close_on_hide = *close_on_hide;
+        }
+        if let Some(toml::Value::String(hide_mode)) = sc.get("hide_mode") {
+            config.hide_mode = hide_mode.clone();
+        }
+        if let Some(toml::Value::Boolean(kill_process_group)) = sc.get("kill_process_group") {
+            config.kill_process_group = *kill_process_group;
+        }
+        if let Some(toml::Value::Boolean(multi_window)) = sc.get("multi_window") {
+            config.multi_window = *multi_window;
+        }
+        if let Some(toml::Value::Integer(max_instances)) = sc.get("max_instances") {
+            config.max_instances = Some(*max_instances as u32);
+        }
+        if let Some(toml::Value::Integer(debounce_ms)) = sc.get("debounce_ms") {
+            config.debounce_ms = Some(*debounce_ms as u32);
+        }
+        if let Some(toml::Value::String(interrupt)) = sc.get("interrupt") {
+            config.interrupt = interrupt.clone();
+        }
 
-        Ok(state_json)
-    }
+        // Parse unfocus field
+        if let Some(toml::Value::String(unfocus_behavior)) = sc.get("unfocus") {
+            config.unfocus = Some(unfocus_behavior.clone());
+        }
 
-    /// Restore the state of scratchpads after hot reload
-    pub fn restore_state(&mut self, state_json: serde_json::Value) -> Result<()> {
-        debug!("🔄 Restoring scratchpads state from hot reload");
+        // Parse hysteresis field
+        if let Some(toml::Value::Float(hysteresis)) = sc.get("hysteresis") {
+            config.hysteresis = Some(*hysteresis as f32);
+        } else if let Some(toml::Value::Integer(hysteresis)) = sc.get("hysteresis") {
+            config.hysteresis = Some(*hysteresis as f32);
+        }
 
-        // Extract timestamp for validation
-        if let Some(timestamp) = state_json.get("timestamp").and_then(|t| t.as_u64()) {
-            let age = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs()
-                - timestamp;
+        // Parse floating field (false = tiled scratchpad)
+        if let Some(toml::Value::Boolean(floating)) = sc.get("floating") {
+            config.floating = *floating;
+        }
 
-            if age > 300 {
-                // 5 minutes
-                warn!(
-                    "⚠️ Scratchpads state is {} seconds old, restoration may be stale",
-                    age
-                );
-            } else {
-                debug!("✅ State age: {}s - within acceptable range", age);
-            }
+        if let Some(toml::Value::Boolean(respect_reserved)) = sc.get("respect_reserved") {
+            config.respect_reserved = *respect_reserved;
         }
 
-        // Restore window mappings if available
-        if let Some(mappings) = state_json.get("window_mappings") {
-            if let Ok(window_mappings) =
-                serde_json::from_value::<HashMap<String, String>>(mappings.clone())
-            {
-                self.window_to_scratchpad = window_mappings;
-                debug!(
-                    "♻️ Restored {} window mappings",
-                    self.window_to_scratchpad.len()
-                );
-            }
+        // Parse restore_focus field
+        if let Some(toml::Value::Boolean(restore_focus)) = sc.get("restore_focus") {
+            config.restore_focus = *restore_focus;
         }
 
-        // Restore focused window state
-        if let Some(focused) = state_json.get("focused_window").and_then(|f| f.as_str()) {
-            self.focused_window = Some(focused.to_string());
-            debug!("♻️ Restored focused window: {}", focused);
+        // Parse Phase 2 animation fields
+        if let Some(toml::Value::Integer(duration)) = sc.get("animation_duration") {
+            config.animation_duration = Some(*duration as u32);
         }
 
-        if let Some(prev_focused) = state_json
-            .get("previous_focused_window")
-            .and_then(|f| f.as_str())
-        {
-            self.previous_focused_window = Some(prev_focused.to_string());
-            debug!("♻️ Restored previous focused window: {}", prev_focused);
+        if let Some(toml::Value::Integer(delay)) = sc.get("animation_delay") {
+            config.animation_delay = Some(*delay as u32);
         }
 
-        // Restore scratchpad states
-        if let Some(states) = state_json.get("scratchpad_states") {
-            if let Ok(restored_states) =
-                serde_json::from_value::<HashMap<String, ScratchpadState>>(states.clone())
-            {
-                for (name, state) in restored_states {
-                    self.states.insert(name.clone(), state);
-                    debug!("♻️ Restored state for scratchpad: {}", name);
-                }
-                debug!("♻️ Restored {} scratchpad states", self.states.len());
-            } else {
-                warn!("⚠️ Failed to deserialize scratchpad states, keeping current states");
-            }
+        if let Some(toml::Value::String(easing)) = sc.get("animation_easing") {
+            config.animation_easing = Some(easing.clone());
         }
 
-        info!(
-            "✅ Scratchpads state restoration complete: {} scratchpads, {} window mappings",
-            self.states.len(),
-            self.window_to_scratchpad.len()
-        );
+        if let Some(toml::Value::String(effect)) = sc.get("arrival_effect") {
+            config.arrival_effect = Some(effect.clone());
+        }
 
-        Ok(())
-    }
+        if let Some(toml::Value::String(preset)) = sc.get("animation_preset") {
+            config.animation_preset = Some(preset.clone());
+        }
 
-    /// Validate that the restored state is compatible with current configuration
-    pub fn validate_restored_state(&self) -> Result<()> {
-        debug!("🔍 Validating restored scratchpad state compatibility");
+        if let Some(toml::Value::Float(scale)) = sc.get("animation_scale_from") {
+            config.animation_scale_from = Some(*scale as f32);
+        } else if let Some(toml::Value::Integer(scale)) = sc.get("animation_scale_from") {
+            config.animation_scale_from = Some(*scale as f32);
+        }
 
-        let mut warnings = Vec::new();
-        let mut valid_states = 0;
+        if let Some(toml::Value::Float(opacity)) = sc.get("animation_opacity_from") {
+            config.animation_opacity_from = Some(*opacity as f32);
+        } else if let Some(toml::Value::Integer(opacity)) = sc.get("animation_opacity_from") {
+            config.animation_opacity_from = Some(*opacity as f32);
+        }
 
-        // Check each restored state against current configuration
-        for name in self.states.keys() {
-            if self.scratchpads.contains_key(name) {
-                valid_states += 1;
-                debug!("✅ Scratchpad '{}' state is compatible", name);
-            } else {
-                warnings.push(format!(
-                    "Scratchpad '{}' has restored state but no current config",
-                    name
-                ));
-            }
+        if let Some(toml::Value::Array(entries)) = sc.get("animation_properties") {
+            // Values may be written as strings ("50%") or bare numbers (0.5)
+            let text = |entry: &toml::Value, key: &str| match entry.get(key) {
+                Some(toml::Value::String(value)) => value.clone(),
+                Some(toml::Value::Integer(value)) => value.to_string(),
+                Some(toml::Value::Float(value)) => value.to_string(),
+                _ => String::new(),
+            };
+            config.animation_properties = Some(
+                entries
+                    .iter()
+                    .map(|entry| AnimationPropertyConfig {
+                        property: text(entry, "property"),
+                        from: text(entry, "from"),
+                        to: text(entry, "to"),
+                        easing: entry
+                            .get("easing")
+                            .and_then(|easing| easing.as_str())
+                            .map(str::to_string),
+                    })
+                    .collect(),
+            );
         }
 
-        // Check for orphaned window mappings
-        let mut orphaned_windows = 0;
-        for (window_addr, scratchpad_name) in &self.window_to_scratchpad {
-            if !self.scratchpads.contains_key(scratchpad_name) {
-                orphaned_windows += 1;
-                debug!(
-                    "⚠️ Window {} mapped to non-existent scratchpad '{}'",
-                    window_addr, scratchpad_name
+        match sc.get("animate") {
+            Some(toml::Value::String(aspect)) => {
+                config.animate = Some(vec![aspect.clone()]);
+            }
+            Some(toml::Value::Array(aspects)) => {
+                config.animate = Some(
+                    aspects
+                        .iter()
+                        .filter_map(|aspect| aspect.as_str().map(str::to_string))
+                        .collect(),
                 );
             }
+            _ => {}
         }
 
-        if orphaned_windows > 0 {
-            warnings.push(format!(
-                "{} windows mapped to non-existent scratchpads",
-                orphaned_windows
-            ));
+        // Parse spring physics parameters
+        if let Some(toml::Value::Float(stiffness)) = sc.get("spring_stiffness") {
+            config.spring_stiffness = Some(*stiffness as f32);
+        } else if let Some(toml::Value::Integer(stiffness)) = sc.get("spring_stiffness") {
+            config.spring_stiffness = Some(*stiffness as f32);
         }
 
-        if !warnings.is_empty() {
-            for warning in &warnings {
-                warn!("⚠️ State validation: {}", warning);
-            }
+        if let Some(toml::Value::Float(damping)) = sc.get("spring_damping") {
+            config.spring_damping = Some(*damping as f32);
+        } else if let Some(toml::Value::Integer(damping)) = sc.get("spring_damping") {
+            config.spring_damping = Some(*damping as f32);
         }
 
-        info!(
-            "✅ State validation complete: {}/{} valid states, {} warnings",
-            valid_states,
-            self.states.len(),
-            warnings.len()
-        );
+        if let Some(toml::Value::Float(mass)) = sc.get("spring_mass") {
+            config.spring_mass = Some(*mass as f32);
+        } else if let Some(toml::Value::Integer(mass)) = sc.get("spring_mass") {
+            config.spring_mass = Some(*mass as f32);
+        }
 
-        Ok(())
-    }
-}
+        if let Some(toml::Value::Float(epsilon)) = sc.get("spring_settle_epsilon") {
+            config.spring_settle_epsilon = Some(*epsilon as f32);
+        }
 
-impl Default for ScratchpadsPlugin {
-    fn default() -> Self {
-        Self::new()
+        config
     }
-}
 
-#[async_trait]
-impl Plugin for ScratchpadsPlugin {
-    fn name(&self) -> &str {
-        "scratchpads"
+    /// Where the scratchpad would land on each monitor, as computed by
+    /// `calculate_geometry`, with whether the position got clamped
+    fn geometry_report(config: &ValidatedConfig, monitors: &[MonitorInfo]) -> serde_json::Value {
+        let placements: Vec<serde_json::Value> = monitors
+            .iter()
+            .map(|monitor| {
+                match GeometryCalculator::calculate_geometry_with_clamping(config, monitor) {
+                    Ok((geometry, clamped)) => serde_json::json!({
+                        "monitor": monitor.name,
+                        "x": geometry.x,
+                        "y": geometry.y,
+                        "width": geometry.width,
+                        "height": geometry.height,
+                        "clamped": clamped,
+                    }),
+                    Err(e) => serde_json::json!({
+                        "monitor": monitor.name,
+                        "error": e.to_string(),
+                    }),
+                }
+            })
+            .collect();
+        serde_json::Value::Array(placements)
     }
 
-    async fn init(&mut self, config: &toml::Value) -> Result<()> {
-        info!("🪟 Initializing scratchpads plugin");
-        debug!("Config: {}", config);
+    /// TOML snippet reproducing a window's current geometry on `monitor`. Placement is
+    /// relative to the monitor's usable area, as `calculate_geometry` reads it: as an
+    /// `offset` (net of `margin`) when the scratchpad is configured that way, else as `position`
+    fn export_snippet(
+        name: &str,
+        config: &ValidatedConfig,
+        geometry: (i32, i32, i32, i32),
+        monitor: &MonitorInfo,
+    ) -> Result<String> {
+        let (x, y, width, height) = geometry;
+        let (area_x, area_y, _, _) = GeometryCalculator::usable_area(config, monitor);
 
-        // Parse variables if present
-        if let toml::Value::Table(map) = config {
-            if let Some(toml::Value::Table(vars)) = map.get("variables") {
-                for (key, value) in vars {
-                    if let toml::Value::String(val_str) = value {
-                        let mut vars = self.variables.write().await;
-                        vars.insert(key.clone(), val_str.clone());
-                        debug!("📝 Loaded variable: {} = {}", key, val_str);
-                    }
+        let mut scratchpad = toml::Table::new();
+        scratchpad.insert(
+            "size".to_string(),
+            toml::Value::String(format!("{width}px {height}px")),
+        );
+        if config.position.is_none() && config.offset.is_some() {
+            let margin = config.margin.unwrap_or(0);
+            scratchpad.insert(
+                "offset".to_string(),
+                toml::Value::String(format!(
+                    "{}px {}px",
+                    x - area_x - margin,
+                    y - area_y - margin
+                )),
+            );
+        } else {
+            scratchpad.insert(
+                "position".to_string(),
+                toml::Value::String(format!("{}px {}px", x - area_x, y - area_y)),
+            );
+        }
+        if config.force_monitor.is_some() {
+            scratchpad.insert(
+                "force_monitor".to_string(),
+                toml::Value::String(monitor.name.clone()),
+            );
+        }
+
+        let mut scratchpads = toml::Table::new();
+        scratchpads.insert(name.to_string(), toml::Value::Table(scratchpad));
+        let mut root = toml::Table::new();
+        root.insert("scratchpads".to_string(), toml::Value::Table(scratchpads));
+        Ok(toml::to_string(&root)?)
+    }
+
+    /// `export [name]`: config snippets for the scratchpads' current geometry
+    async fn export_scratchpads(&self, name: Option<&str>) -> Result<String> {
+        let names: Vec<String> = match name {
+            Some(name) => {
+                if !self.scratchpads.contains_key(name) {
+                    return Err(anyhow::anyhow!("Scratchpad '{}' not found", name));
                 }
+                vec![name.to_string()]
             }
-        }
+            None => {
+                let mut names: Vec<String> = self.scratchpads.keys().cloned().collect();
+                names.sort();
+                names
+            }
+        };
+        let monitors = self.get_monitors().await.unwrap_or_default();
 
-        // Parse scratchpad configurations
-        if let toml::Value::Table(map) = config {
-            for (name, scratchpad_config) in map {
-                // Skip the variables section as it's already processed
-                if name == "variables" {
-                    continue;
+        let mut snippets = Vec::new();
+        let mut skipped = Vec::new();
+        for name in names {
+            let config = self.get_validated_config(&name)?;
+            let geometry = self.remembered_geometry(&name).await;
+            match geometry.zip(geometry.and_then(|g| Self::monitor_at(&monitors, g))) {
+                Some((geometry, monitor)) => {
+                    snippets.push(Self::export_snippet(&name, &config, geometry, monitor)?)
                 }
-                if let toml::Value::Table(sc) = scratchpad_config {
-                    let command = sc
-                        .get("command")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("")
-                        .to_string();
-
-                    let class = sc
-                        .get("class")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("")
-                        .to_string();
-
-                    let size = sc
-                        .get("size")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("50% 50%")
-                        .to_string();
-
-                    let animation = sc
-                        .get("animation")
-                        .and_then(|v| v.as_str())
-                        .map(|s| s.to_string());
-
-                    let mut config = ScratchpadConfig {
-                        command,
-                        class: Some(class),
-                        size,
-                        animation,
-                        ..Default::default()
-                    };
+                None => skipped.push(name),
+            }
+        }
 
-                    // Parse additional Pyprland-compatible options
-                    if let Some(toml::Value::Boolean(lazy)) = sc.get("lazy") {
-                        config.lazy = *lazy;
-                    }
-                    if let Some(toml::Value::Boolean(pinned)) = sc.get("pinned") {
-                        config.pinned = *pinned;
-                    }
-                    if let Some(toml::Value::Array(excludes)) = sc.get("excludes") {
-                        config.excludes = excludes
-                            .iter()
-                            .filter_map(|v| v.as_str().map(|s| s.to_string()))
-                            .collect();
-                    } else if let Some(toml::Value::String(exclude_all)) = sc.get("excludes") {
-                        if exclude_all == "*" {
-                            config.excludes = vec!["*".to_string()];
-                        }
-                    }
-                    if let Some(toml::Value::Boolean(restore_excluded)) = sc.get("restore_excluded")
-                    {
-                        config.restore_excluded = *restore_excluded;
-                    }
-                    if let Some(toml::Value::String(force_monitor)) = sc.get("force_monitor") {
-                        config.force_monitor = Some(force_monitor.clone());
-                    }
-                    if let Some(toml::Value::Integer(margin)) = sc.get("margin") {
-                        config.margin = Some(*margin as i32);
-                    }
-                    if let Some(toml::Value::String(offset)) = sc.get("offset") {
-                        config.offset = Some(offset.clone());
-                    }
-                    if let Some(toml::Value::Integer(hide_delay)) = sc.get("hide_delay") {
-                        config.hide_delay = Some(*hide_delay as u32);
-                    }
-                    if let Some(toml::Value::Boolean(multi_window)) = sc.get("multi_window") {
-                        config.multi_window = *multi_window;
-                    }
-                    if let Some(toml::Value::Integer(max_instances)) = sc.get("max_instances") {
-                        config.max_instances = Some(*max_instances as u32);
-                    }
+        if !skipped.is_empty() {
+            snippets.push(format!(
+                "# No geometry recorded yet for: {}",
+                skipped.join(", ")
+            ));
+        }
+        Ok(snippets.join("\n"))
+    }
 
-                    // Parse unfocus field
-                    if let Some(toml::Value::String(unfocus_behavior)) = sc.get("unfocus") {
-                        config.unfocus = Some(unfocus_behavior.clone());
-                    }
+    /// Resize the visible window of a scratchpad, absolutely or relative to its
+    /// current size (see `GeometryCalculator::resize_geometry`)
+    async fn resize_scratchpad(&mut self, name: &str, request: (&str, &str)) -> Result<String> {
+        let config = self.get_validated_config(name)?;
+        let address = self
+            .states
+            .get(name)
+            .and_then(|state| state.windows.iter().find(|w| w.is_visible))
+            .map(|w| w.address.clone())
+            .ok_or_else(|| anyhow::anyhow!("Scratchpad '{}' is not visible", name))?;
 
-                    // Parse hysteresis field
-                    if let Some(toml::Value::Float(hysteresis)) = sc.get("hysteresis") {
-                        config.hysteresis = Some(*hysteresis as f32);
-                    } else if let Some(toml::Value::Integer(hysteresis)) = sc.get("hysteresis") {
-                        config.hysteresis = Some(*hysteresis as f32);
-                    }
+        let client = self.get_hyprland_client().await?;
+        let window = client.get_window_info(&address).await?;
+        let current = (
+            window.at.0 as i32,
+            window.at.1 as i32,
+            window.size.0 as i32,
+            window.size.1 as i32,
+        );
+        let monitors = self.get_monitors().await?;
+        let monitor = Self::monitor_at(&monitors, current)
+            .ok_or_else(|| anyhow::anyhow!("No monitor found for scratchpad '{}'", name))?;
 
-                    // Parse restore_focus field
-                    if let Some(toml::Value::Boolean(restore_focus)) = sc.get("restore_focus") {
-                        config.restore_focus = *restore_focus;
-                    }
+        let (x, y, width, height) =
+            GeometryCalculator::resize_geometry(&config, monitor, current, request)?;
+        client
+            .resize_and_position_window(&address, x, y, width, height)
+            .await?;
+        info!(
+            "📐 Resized scratchpad '{}' to {}x{} at ({}, {})",
+            name, width, height, x, y
+        );
+        Ok(format!(
+            "Resized scratchpad '{name}' to {width}x{height} at ({x}, {y})"
+        ))
+    }
 
-                    // Parse Phase 2 animation fields
-                    if let Some(toml::Value::Integer(duration)) = sc.get("animation_duration") {
-                        config.animation_duration = Some(*duration as u32);
-                    }
+    /// Pending timers as (scratchpad, kind, remaining), dropping ones that already fired
+    fn pending_timers(&mut self, now: Instant) -> Vec<(String, &'static str, Duration)> {
+        self.hysteresis_tasks
+            .retain(|_, timer| !timer.handle.is_finished());
+        self.hide_tasks
+            .retain(|_, timer| !timer.handle.is_finished());
+        self.stash_tasks
+            .retain(|_, timer| !timer.handle.is_finished());
+
+        let mut timers: Vec<(String, &'static str, Duration)> = self
+            .hysteresis_tasks
+            .iter()
+            .map(|(name, timer)| {
+                (
+                    name.clone(),
+                    "hysteresis",
+                    timer.deadline.saturating_duration_since(now),
+                )
+            })
+            .chain(self.hide_tasks.iter().map(|(address, timer)| {
+                let name = self
+                    .window_to_scratchpad
+                    .get(address)
+                    .cloned()
+                    .unwrap_or_else(|| address.clone());
+                (name, "hide", timer.deadline.saturating_duration_since(now))
+            }))
+            .chain(self.stash_tasks.iter().map(|(address, timer)| {
+                let name = self
+                    .window_to_scratchpad
+                    .get(address)
+                    .cloned()
+                    .unwrap_or_else(|| address.clone());
+                (name, "stash", timer.deadline.saturating_duration_since(now))
+            }))
+            .collect();
+        timers.sort_by_key(|(_, _, remaining)| *remaining);
+        timers
+    }
 
-                    if let Some(toml::Value::Integer(delay)) = sc.get("animation_delay") {
-                        config.animation_delay = Some(*delay as u32);
-                    }
+    /// Cancel pending timers of one scratchpad, or of all when `name` is None
+    async fn cancel_timers(&mut self, name: Option<&str>) -> usize {
+        let names: Vec<String> = match name {
+            Some(name) => vec![name.to_string()],
+            None => self.hysteresis_tasks.keys().cloned().collect(),
+        };
+        let addresses: Vec<String> = self
+            .hide_tasks
+            .keys()
+            .chain(self.stash_tasks.keys())
+            .filter(|address| {
+                name.is_none()
+                    || self.window_to_scratchpad.get(*address).map(String::as_str) == name
+            })
+            .cloned()
+            .collect();
 
-                    if let Some(toml::Value::String(easing)) = sc.get("animation_easing") {
-                        config.animation_easing = Some(easing.clone());
-                    }
+        let mut cancelled = 0;
+        for name in names {
+            if self.cancel_hide_timer(&name).await {
+                cancelled += 1;
+            }
+        }
+        for address in addresses {
+            if self.cancel_hide_delay(&address) | self.cancel_stash(&address) {
+                cancelled += 1;
+            }
+        }
+        cancelled
+    }
 
-                    if let Some(toml::Value::Float(scale)) = sc.get("animation_scale_from") {
-                        config.animation_scale_from = Some(*scale as f32);
-                    } else if let Some(toml::Value::Integer(scale)) = sc.get("animation_scale_from")
-                    {
-                        config.animation_scale_from = Some(*scale as f32);
-                    }
+    /// Cancel running animations and pending hide timers of a scratchpad
+    async fn cancel_pending_transitions(&mut self, scratchpad_name: &str) {
+        self.cancel_hide_timer(scratchpad_name).await;
+        self.animations_in_progress.remove(scratchpad_name);
 
-                    if let Some(toml::Value::Float(opacity)) = sc.get("animation_opacity_from") {
-                        config.animation_opacity_from = Some(*opacity as f32);
-                    } else if let Some(toml::Value::Integer(opacity)) =
-                        sc.get("animation_opacity_from")
-                    {
-                        config.animation_opacity_from = Some(*opacity as f32);
-                    }
+        let addresses: Vec<String> = self
+            .states
+            .get(scratchpad_name)
+            .map(|state| state.windows.iter().map(|w| w.address.clone()).collect())
+            .unwrap_or_default();
 
-                    // Parse spring physics parameters
-                    if let Some(toml::Value::Float(stiffness)) = sc.get("spring_stiffness") {
-                        config.spring_stiffness = Some(*stiffness as f32);
-                    } else if let Some(toml::Value::Integer(stiffness)) = sc.get("spring_stiffness")
-                    {
-                        config.spring_stiffness = Some(*stiffness as f32);
-                    }
+        for address in &addresses {
+            self.cancel_hide_delay(address);
+            self.cancel_stash(address);
+        }
 
-                    if let Some(toml::Value::Float(damping)) = sc.get("spring_damping") {
-                        config.spring_damping = Some(*damping as f32);
-                    } else if let Some(toml::Value::Integer(damping)) = sc.get("spring_damping") {
-                        config.spring_damping = Some(*damping as f32);
-                    }
+        let mut animator = self.window_animator.lock().await;
+        for address in addresses {
+            if let Err(e) = animator.stop_animation(&address).await {
+                debug!("Failed to stop animation for {}: {}", address, e);
+            }
+        }
+    }
 
-                    if let Some(toml::Value::Float(mass)) = sc.get("spring_mass") {
-                        config.spring_mass = Some(*mass as f32);
-                    } else if let Some(toml::Value::Integer(mass)) = sc.get("spring_mass") {
-                        config.spring_mass = Some(*mass as f32);
-                    }
+    /// Process internal commands (like hysteresis hide)
+    async fn process_internal_commands(&mut self) {
+        // Collect commands first to avoid borrow conflicts
+        let mut commands = Vec::new();
 
-                    self.scratchpads.insert(name.clone(), Arc::new(config));
-                    self.states.insert(name.clone(), ScratchpadState::default());
-                    info!("📝 Registered scratchpad: {}", name);
+        if let Some(receiver) = &mut self.internal_receiver {
+            while let Ok(command) = receiver.try_recv() {
+                commands.push(command);
+            }
+        }
+
+        // Process the collected commands (simplified)
+        for command in commands {
+            match command {
+                InternalCommand::SimpleHide { scratchpad_name } => {
+                    if !self.take_due_hide(&scratchpad_name, Instant::now()) {
+                        debug!("🙈 Ignoring stale hide for '{}'", scratchpad_name);
+                        continue;
+                    }
+                    debug!("🙈 Processing simple hide for '{}'", scratchpad_name);
+                    if let Err(e) = self.hide_scratchpad_and_restore(&scratchpad_name).await {
+                        warn!("Failed to hide scratchpad '{}': {}", scratchpad_name, e);
+                    } else {
+                        debug!("✅ Scratchpad '{}' hidden", scratchpad_name);
+                    }
+                }
+                InternalCommand::SyncGeometry { window_address } => {
+                    self.sync_window_geometry(&window_address).await;
                 }
             }
         }
+    }
 
-        // Validate configurations
-        let monitors = self.get_monitors().await.unwrap_or_default();
-        let variables = self.variables.read().await.clone();
-        self.validated_configs =
-            ConfigValidator::validate_configs(&self.scratchpads, &monitors, &variables);
+    /// Restore focus to previously focused window
+    async fn restore_previous_focus(&self, client: &HyprlandClient) -> Result<()> {
+        if let Some(prev_window) = &self.previous_focused_window {
+            // Check if the previous window still exists
+            // Simply try to focus the window - if it fails, the window likely doesn't exist
+            debug!("🎯 Restoring focus to previous window: {}", prev_window);
+            if let Err(e) = client.focus_window(prev_window).await {
+                debug!("⚠️  Failed to focus previous window {}: {}", prev_window, e);
+            }
+        }
+        Ok(())
+    }
 
+    /// Setup a newly opened scratchpad window with proper geometry and animation
+    async fn setup_scratchpad_window(
+        &self,
+        window_address: &str,
+        scratchpad_name: &str,
+        _config: &ScratchpadConfigRef,
+    ) -> Result<()> {
         info!(
-            "✅ Scratchpads plugin initialized with {} scratchpads",
-            self.scratchpads.len()
+            "🎬 Setting up scratchpad window: {} for '{}'",
+            window_address, scratchpad_name
         );
-        Ok(())
-    }
 
-    async fn handle_event(&mut self, event: &HyprlandEvent) -> Result<()> {
-        //debug!("🪟 Scratchpads handling event: {:?}", event);
+        // Wait a moment for window to be fully created
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
 
-        match event {
-            HyprlandEvent::WindowOpened { window } => {
-                debug!("Window opened: {} - checking if it is a scratchpad", window);
-                self.handle_window_opened(window).await;
-            }
-            HyprlandEvent::WindowClosed { window } => {
-                debug!("Window closed: {} - cleaning up if scratchpad", window);
-                self.handle_window_closed(window).await;
-            }
-            HyprlandEvent::WindowMoved { window } => {
-                debug!("Window moved: {} - syncing geometry", window);
-                self.handle_window_moved(window).await;
-            }
-            HyprlandEvent::WorkspaceChanged { workspace } => {
-                debug!("Workspace changed to: {}", workspace);
-                self.handle_workspace_changed(workspace).await;
-            }
-            HyprlandEvent::MonitorChanged { monitor: _ } => {
-                debug!("Monitor changed - invalidating cache");
-                // Invalidate monitor cache
-                {
-                    let mut cache_valid = self.cache_valid_until.write().await;
-                    *cache_valid = Instant::now();
-                }
+        // Get monitor info
+        let monitors = self.get_monitors().await?;
+        let monitor = monitors
+            .iter()
+            .find(|m| m.is_focused)
+            .or_else(|| monitors.first())
+            .ok_or_else(|| anyhow::anyhow!("No monitors found"))?;
 
-                // Monitor layout changed - cache will be refreshed on next access
-            }
-            HyprlandEvent::WindowFocusChanged { window } => {
-                self.handle_focus_changed(window).await;
-            }
-            HyprlandEvent::Other(msg) => {
-                // Reduce log noise for heartbeat events
-                if msg != "heartbeat" {
-                    debug!("Other event: {}", msg);
-                }
-                self.handle_other_event(msg).await;
-            }
+        // Get validated config
+        let validated_config = self.validated_configs.get(scratchpad_name).ok_or_else(|| {
+            anyhow::anyhow!("No validated config for scratchpad: {}", scratchpad_name)
+        })?;
+
+        debug!(
+            "📋 Using config for '{}': size='{}', animation={:?}, margin={:?}",
+            scratchpad_name,
+            validated_config.size,
+            validated_config.animation,
+            validated_config.margin
+        );
+
+        let client = self.get_hyprland_client().await?;
+
+        // First, make sure the window is floating
+        info!("🔄 Making window floating: {}", window_address);
+        if let Err(e) = client.toggle_floating(window_address).await {
+            warn!("Failed to toggle floating: {}", e);
         }
 
-        // Process any pending internal commands (like hysteresis hide)
-        self.process_internal_commands().await;
+        // Small delay to ensure floating state is applied
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
 
-        Ok(())
-    }
+        // Calculate and apply proper geometry
+        let target_geometry = GeometryCalculator::calculate_geometry(validated_config, monitor)?;
 
-    async fn handle_command(&mut self, command: &str, args: &[&str]) -> Result<String> {
-        match command {
-            "toggle" => {
-                if let Some(scratchpad_name) = args.first() {
-                    info!("🔄 Toggling scratchpad: {}", scratchpad_name);
+        info!(
+            "📐 Applying geometry: {}x{} at ({}, {}) on monitor '{}'",
+            target_geometry.width,
+            target_geometry.height,
+            target_geometry.x,
+            target_geometry.y,
+            monitor.name
+        );
 
-                    if self.scratchpads.contains_key(*scratchpad_name) {
-                        match self.toggle_scratchpad(scratchpad_name).await {
-                            Ok(message) => {
-                                info!("✅ {}", message);
-                                Ok(message)
-                            }
-                            Err(e) => {
-                                error!(
-                                    "❌ Failed to toggle scratchpad '{}': {}",
-                                    scratchpad_name, e
-                                );
-                                Err(e)
-                            }
-                        }
-                    } else {
-                        warn!("⚠️  Scratchpad '{}' not found", scratchpad_name);
-                        Err(anyhow::anyhow!(
-                            "Scratchpad '{}' not found",
-                            scratchpad_name
-                        ))
-                    }
-                } else {
-                    Err(anyhow::anyhow!("No scratchpad name provided"))
-                }
-            }
-            "list" => {
-                let mut status_list = Vec::new();
-                for name in self.scratchpads.keys() {
-                    let state = self.states.get(name);
-                    let visible_count = state
-                        .map(|s| s.windows.iter().filter(|w| w.is_visible).count())
-                        .unwrap_or(0);
-                    let total_count = state.map(|s| s.windows.len()).unwrap_or(0);
-                    let spawned = state.map(|s| s.is_spawned).unwrap_or(false);
+        client
+            .move_resize_window(
+                window_address,
+                target_geometry.x,
+                target_geometry.y,
+                target_geometry.width,
+                target_geometry.height,
+            )
+            .await?;
 
-                    let status = if visible_count > 0 {
-                        format!("{name} (visible: {visible_count}/{total_count})")
-                    } else if spawned {
-                        format!("{name} (hidden: {total_count})")
-                    } else {
-                        format!("{name} (not spawned)")
-                    };
-                    status_list.push(status);
-                }
-                Ok(format!("Scratchpads: {}", status_list.join(", ")))
-            }
-            "show" => {
-                if let Some(scratchpad_name) = args.first() {
-                    info!("👁️  Showing scratchpad: {}", scratchpad_name);
-                    if self.scratchpads.contains_key(*scratchpad_name) {
-                        match self.show_scratchpad_direct(scratchpad_name).await {
-                            Ok(message) => {
-                                info!("✅ {}", message);
-                                Ok(message)
-                            }
-                            Err(e) => {
-                                error!("❌ Failed to show scratchpad '{}': {}", scratchpad_name, e);
-                                Err(e)
-                            }
-                        }
-                    } else {
-                        warn!("⚠️  Scratchpad '{}' not found", scratchpad_name);
-                        Err(anyhow::anyhow!(
-                            "Scratchpad '{}' not found",
-                            scratchpad_name
-                        ))
-                    }
-                } else {
-                    Err(anyhow::anyhow!("No scratchpad name provided"))
-                }
-            }
-            "hide" => {
-                if let Some(scratchpad_name) = args.first() {
-                    info!("🙈 Hiding scratchpad: {}", scratchpad_name);
-                    if self.scratchpads.contains_key(*scratchpad_name) {
-                        match self.hide_scratchpad_direct(scratchpad_name).await {
-                            Ok(message) => {
-                                info!("✅ {}", message);
-                                Ok(message)
-                            }
-                            Err(e) => {
-                                error!("❌ Failed to hide scratchpad '{}': {}", scratchpad_name, e);
-                                Err(e)
-                            }
-                        }
-                    } else {
-                        warn!("⚠️  Scratchpad '{}' not found", scratchpad_name);
-                        Err(anyhow::anyhow!(
-                            "Scratchpad '{}' not found",
-                            scratchpad_name
-                        ))
-                    }
-                } else {
-                    Err(anyhow::anyhow!("No scratchpad name provided"))
-                }
-            }
-            "attach" => {
-                if let Some(scratchpad_name) = args.first() {
-                    info!("📌 Toggling attach for scratchpad: {}", scratchpad_name);
-                    if self.scratchpads.contains_key(*scratchpad_name) {
-                        match self.toggle_attach_scratchpad(scratchpad_name).await {
-                            Ok(message) => {
-                                info!("✅ {}", message);
-                                Ok(message)
-                            }
-                            Err(e) => {
-                                error!(
-                                    "❌ Failed to toggle attach for scratchpad '{}': {}",
-                                    scratchpad_name, e
-                                );
-                                Err(e)
-                            }
-                        }
-                    } else {
-                        warn!("⚠️  Scratchpad '{}' not found", scratchpad_name);
-                        Err(anyhow::anyhow!(
-                            "Scratchpad '{}' not found",
-                            scratchpad_name
-                        ))
-                    }
-                } else {
-                    Err(anyhow::anyhow!("No scratchpad name provided"))
-                }
+        // Apply animation if configured
+        if validated_config.animation.is_some() {
+            info!(
+                "🎬 Applying show animation for scratchpad '{}'",
+                scratchpad_name
+            );
+
+            // Get the window data for animation
+            let windows = client.get_windows().await?;
+            if let Some(_window) = windows
+                .into_iter()
+                .find(|w| w.address.to_string() == window_address)
+            {
+                // animate_window_show removed - using WindowAnimator instead
+                debug!("Window show animation handled by WindowAnimator");
             }
-            _ => Err(anyhow::anyhow!("Unknown command: {}", command)),
         }
+
+        info!("✅ Scratchpad window '{}' setup complete", scratchpad_name);
+        Ok(())
     }
 
-    async fn cleanup(&mut self) -> Result<()> {
-        info!("🧹 Cleaning up scratchpads plugin");
+    // ============================================================================
+    // STATE MANAGEMENT FOR HOT RELOAD
+    // ============================================================================
 
-        // Cancel all hide tasks
-        for (window_addr, handle) in self.hide_tasks.drain() {
-            handle.abort();
-            debug!("❌ Cancelled hide task for window: {}", window_addr);
+    /// Current state of all scratchpads, kept across hot reloads and reported by
+    /// `dump-state`
+    fn snapshot_state(&self) -> Result<serde_json::Value> {
+        debug!("🔍 Capturing scratchpads state for hot reload");
+
+        // Capture core state information
+        let captured_states: HashMap<String, &ScratchpadState> = self
+            .states
+            .iter()
+            .map(|(name, state)| (name.clone(), state))
+            .collect();
+
+        let state_json = serde_json::json!({
+            "plugin_name": "scratchpads",
+            "timestamp": std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            "scratchpad_states": captured_states,
+            "window_mappings": self.window_to_scratchpad,
+            "focused_window": self.focused_window,
+            "previous_focused_window": self.previous_focused_window,
+            "config_count": self.scratchpads.len(),
+            "active_scratchpads": captured_states.len()
+        });
+
+        debug!(
+            "📸 Captured state for {} scratchpads with {} windows mapped",
+            captured_states.len(),
+            self.window_to_scratchpad.len()
+        );
+
+        Ok(state_json)
+    }
+
+    /// Restore the state of scratchpads after hot reload
+    fn restore_snapshot(&mut self, state_json: serde_json::Value) -> Result<()> {
+        debug!("🔄 Restoring scratchpads state from hot reload");
+
+        // Extract timestamp for validation
+        if let Some(timestamp) = state_json.get("timestamp").and_then(|t| t.as_u64()) {
+            let age = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs()
+                - timestamp;
+
+            if age > 300 {
+                // 5 minutes
+                warn!(
+                    "⚠️ Scratchpads state is {} seconds old, restoration may be stale",
+                    age
+                );
+            } else {
+                debug!("✅ State age: {}s - within acceptable range", age);
+            }
         }
 
-        // Cancel all hysteresis tasks
-        for (scratchpad_name, handle) in self.hysteresis_tasks.drain() {
-            handle.abort();
-            debug!(
-                "❌ Cancelled hysteresis task for scratchpad: {}",
-                scratchpad_name
-            );
+        // Restore window mappings if available
+        if let Some(mappings) = state_json.get("window_mappings") {
+            if let Ok(window_mappings) =
+                serde_json::from_value::<HashMap<String, String>>(mappings.clone())
+            {
+                self.window_to_scratchpad = window_mappings;
+                debug!(
+                    "♻️ Restored {} window mappings",
+                    self.window_to_scratchpad.len()
+                );
+            }
         }
 
-        // Cancel all sync tasks
-        for (window_addr, handle) in self.sync_tasks.drain() {
-            handle.abort();
-            debug!("❌ Cancelled sync task for window: {}", window_addr);
+        // Restore focused window state
+        if let Some(focused) = state_json.get("focused_window").and_then(|f| f.as_str()) {
+            self.focused_window = Some(focused.to_string());
+            debug!("♻️ Restored focused window: {}", focused);
         }
 
-        info!("✅ Scratchpads plugin cleanup complete");
+        if let Some(prev_focused) = state_json
+            .get("previous_focused_window")
+            .and_then(|f| f.as_str())
+        {
+            self.previous_focused_window = Some(prev_focused.to_string());
+            debug!("♻️ Restored previous focused window: {}", prev_focused);
+        }
+
+        // Restore scratchpad states
+        if let Some(states) = state_json.get("scratchpad_states") {
+            if let Ok(restored_states) =
+                serde_json::from_value::<HashMap<String, ScratchpadState>>(states.clone())
+            {
+                for (name, state) in restored_states {
+                    self.states.insert(name.clone(), state);
+                    debug!("♻️ Restored state for scratchpad: {}", name);
+                }
+                debug!("♻️ Restored {} scratchpad states", self.states.len());
+            } else {
+                warn!("⚠️ Failed to deserialize scratchpad states, keeping current states");
+            }
+        }
+
+        info!(
+            "✅ Scratchpads state restoration complete: {} scratchpads, {} window mappings",
+            self.states.len(),
+            self.window_to_scratchpad.len()
+        );
+
         Ok(())
     }
-}
+
+    /// Validate that the restored state is compatible with current configuration
+    pub fn validate_restored_state(&self) -> Result<()> {
+        debug!("🔍 Validating restored scratchpad state compatibility");
+
+        let mut warnings = Vec::new();
+        let mut valid_states = 0;
+
+        // Check each restored state against current configuration
+        for name in self.states.keys() {
+            if self.scratchpads.contains_key(name) {
+                valid_states += 1;
+                debug!("✅ Scratchpad '{}' state is compatible", name);
+            } else {
+                warnings.push(format!(
+                    "Scratchpad '{}' has restored state but no current config",
+                    name
+                ));
+            }
+        }
+
+        // Check for orphaned window mappings
+        let mut orphaned_windows = 0;
+        for (window_addr, scratchpad_name) in &self.window_to_scratchpad {
+            if !self.scratchpads.contains_key(scratchpad_name) {
+                orphaned_windows += 1;
+                debug!(
+                    "⚠️ Window {} mapped to non-existent scratchpad '{}'",
+                    window_addr, scratchpad_name
+                );
+            }
+        }
+
+        if orphaned_windows > 0 {
+            warnings.push(format!(
+                "{} windows mapped to non-existent scratchpads",
+                orphaned_windows
+            ));
+        }
+
+        if !warnings.is_empty() {
+            for warning in &warnings {
+                warn!("⚠️ State validation: {}", warning);
+            }
+        }
+
+        info!(
+            "✅ State validation complete: {}/{} valid states, {} warnings",
+            valid_states,
+            self.states.len(),
+            warnings.len()
+        );
+
+        Ok(())
+    }
+}
+
+impl Default for ScratchpadsPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Plugin for ScratchpadsPlugin {
+    fn name(&self) -> &str {
+        "scratchpads"
+    }
+
+    async fn set_context(&mut self, context: Arc<PluginContext>) {
+        let client = Arc::clone(&context.hyprland_client);
+        *self.hyprland_client.lock().await = Some(Arc::clone(&client));
+        self.global_cache = Arc::clone(&context.global_cache);
+        self.variables
+            .write()
+            .await
+            .extend(context.variables.clone());
+        self.animation_presets
+            .extend(context.animation_presets.clone());
+
+        // The WindowAnimator drives the shared engine with the same client
+        let mut animator = self.window_animator.lock().await;
+        animator.animation_engine = Arc::clone(&context.animation_engine);
+        animator.set_hyprland_client(client).await;
+    }
+
+    fn dump_state(&self) -> Result<serde_json::Value> {
+        self.snapshot_state()
+    }
+
+    async fn capture_state(&self) -> Result<serde_json::Value> {
+        self.snapshot_state()
+    }
+
+    async fn restore_state(&mut self, state: serde_json::Value) -> Result<()> {
+        self.restore_snapshot(state)?;
+        self.validate_restored_state()
+    }
+
+    fn validate_config(&self, config: &toml::Value) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        let Some(table) = config.as_table() else {
+            report.error("", "Expected a table of scratchpads");
+            return report;
+        };
+
+        let variables: HashMap<String, String> = table
+            .get("variables")
+            .and_then(|vars| vars.clone().try_into().ok())
+            .unwrap_or_default();
+        let presets = table
+            .get("animation_presets")
+            .map(parse_preset_table)
+            .unwrap_or_default();
+
+        let mut scratchpads = HashMap::new();
+        for (name, value) in table {
+            match (name.as_str(), value) {
+                ("variables" | "animation_presets", _) => {}
+                ("default", toml::Value::String(default)) => {
+                    if !table.get(default).is_some_and(toml::Value::is_table) {
+                        report.error(
+                            "default",
+                            format!("Scratchpad '{default}' is not configured"),
+                        );
+                    }
+                }
+                ("default" | "batch_dispatch", toml::Value::Table(_)) => report.error(
+                    name.as_str(),
+                    format!("'{name}' is a reserved setting name, rename this scratchpad"),
+                ),
+                ("default", _) => report.error("default", "Expected a scratchpad name"),
+                ("batch_dispatch", toml::Value::Boolean(_)) => {}
+                ("batch_dispatch", _) => report.error("batch_dispatch", "Expected true or false"),
+                (_, toml::Value::Table(sc)) => {
+                    scratchpads.insert(name.clone(), Arc::new(Self::parse_scratchpad_config(sc)));
+                }
+                _ => report.error(name.as_str(), "Expected a [scratchpads.<name>] table"),
+            }
+        }
+
+        // No monitors here: monitor-dependent checks are skipped, so the size
+        // syntax is checked on its own
+        let validated =
+            ConfigValidator::validate_configs_with_presets(&scratchpads, &[], &variables, &presets);
+        let mut names: Vec<&String> = validated.keys().collect();
+        names.sort();
+        for name in names {
+            let config = &validated[name];
+            for error in &config.validation_errors {
+                report.error(name.as_str(), error.as_str());
+            }
+            for warning in &config.validation_warnings {
+                report.warning(name.as_str(), warning.as_str());
+            }
+            if let Err(e) = GeometryCalculator::parse_size(
+                &config.size,
+                &MonitorInfo::default(),
+                config.max_size.as_deref(),
+            ) {
+                report.error(format!("{name}.size"), format!("Invalid size format: {e}"));
+            }
+        }
+        report
+    }
+
+    async fn init(&mut self, config: &toml::Value) -> Result<()> {
+        info!("🪟 Initializing scratchpads plugin");
+        debug!("Config: {}", config);
+
+        // Parse variables if present
+        if let toml::Value::Table(map) = config {
+            if let Some(toml::Value::Table(vars)) = map.get("variables") {
+                for (key, value) in vars {
+                    if let toml::Value::String(val_str) = value {
+                        let mut vars = self.variables.write().await;
+                        vars.insert(key.clone(), val_str.clone());
+                        debug!("📝 Loaded variable: {} = {}", key, val_str);
+                    }
+                }
+            }
+        }
+
+        // Parse scratchpad configurations
+        if let toml::Value::Table(map) = config {
+            for (name, scratchpad_config) in map {
+                // Skip the variables section as it's already processed
+                if name == "variables" {
+                    continue;
+                }
+                if name == "animation_presets" {
+                    self.animation_presets
+                        .extend(parse_preset_table(scratchpad_config));
+                    continue;
+                }
+                // Reserved settings; a table of the same name is still a scratchpad,
+                // which validation reports
+                match (name.as_str(), scratchpad_config) {
+                    ("default", toml::Value::String(default)) => {
+                        self.default_scratchpad = Some(default.clone());
+                        continue;
+                    }
+                    ("batch_dispatch", toml::Value::Boolean(enabled)) => {
+                        self.window_animator
+                            .lock()
+                            .await
+                            .set_batch_dispatch(*enabled);
+                        continue;
+                    }
+                    ("default" | "batch_dispatch", toml::Value::Table(_)) => error!(
+                        "❌ Scratchpad '{}' uses a reserved setting name, rename it",
+                        name
+                    ),
+                    _ => {}
+                }
+                if let toml::Value::Table(sc) = scratchpad_config {
+                    let config = Self::parse_scratchpad_config(sc);
+                    self.scratchpads.insert(name.clone(), Arc::new(config));
+                    self.states.insert(name.clone(), ScratchpadState::default());
+                    info!("📝 Registered scratchpad: {}", name);
+                }
+            }
+        }
+
+        if let Some(default) = &self.default_scratchpad {
+            if !self.scratchpads.contains_key(default) {
+                error!(
+                    "❌ Default scratchpad '{}' is not configured, ignoring it",
+                    default
+                );
+                self.default_scratchpad = None;
+            }
+        }
+
+        // Validate configurations
+        let monitors = self.get_monitors().await.unwrap_or_default();
+        let variables = self.variables.read().await.clone();
+        self.validated_configs = ConfigValidator::validate_configs_with_presets(
+            &self.scratchpads,
+            &monitors,
+            &variables,
+            &self.animation_presets,
+        );
+        self.geometry_layout = MonitorLayout::of(&monitors);
+
+        // Catch the most common "nothing happens on toggle" cause up front
+        for (name, health) in self.command_health_report().await {
+            if let Err(binary) = health {
+                warn!(
+                    "⚠️ Scratchpad '{}' command binary '{}' was not found on PATH",
+                    name, binary
+                );
+            }
+        }
+
+        info!(
+            "✅ Scratchpads plugin initialized with {} scratchpads",
+            self.scratchpads.len()
+        );
+        Ok(())
+    }
+
+    async fn handle_event(&mut self, event: &HyprlandEvent) -> Result<()> {
+        //debug!("🪟 Scratchpads handling event: {:?}", event);
+
+        match event {
+            HyprlandEvent::WindowOpened { window } => {
+                debug!("Window opened: {} - checking if it is a scratchpad", window);
+                self.handle_window_opened(window).await;
+            }
+            HyprlandEvent::WindowClosed { window } => {
+                debug!("Window closed: {} - cleaning up if scratchpad", window);
+                self.handle_window_closed(window).await;
+            }
+            HyprlandEvent::WindowMoved { window } => {
+                debug!("Window moved: {} - syncing geometry", window);
+                self.handle_window_moved(window).await;
+            }
+            HyprlandEvent::WorkspaceChanged { workspace } => {
+                debug!("Workspace changed to: {}", workspace);
+                self.handle_workspace_changed(workspace).await;
+            }
+            HyprlandEvent::MonitorChanged { monitor: _ } => {
+                debug!("Monitor changed - invalidating cache");
+                self.global_cache.invalidate(MONITORS_CACHE_KEY).await;
+                self.refresh_monitor_geometry().await;
+            }
+            HyprlandEvent::WindowFocusChanged { window } => {
+                self.handle_focus_changed(window).await;
+            }
+            HyprlandEvent::Other(msg) => {
+                // Reduce log noise for heartbeat events
+                if msg != "heartbeat" {
+                    debug!("Other event: {}", msg);
+                }
+                self.handle_other_event(msg).await;
+            }
+        }
+
+        // Process any pending internal commands (like hysteresis hide)
+        self.process_internal_commands().await;
+
+        Ok(())
+    }
+
+    async fn handle_command(&mut self, command: &str, args: &[&str]) -> Result<String> {
+        match command {
+            "toggle" => {
+                if let Some(scratchpad_name) = self.resolve_scratchpad_name(args) {
+                    info!("🔄 Toggling scratchpad: {}", scratchpad_name);
+
+                    if self.scratchpads.contains_key(scratchpad_name.as_str()) {
+                        match self.toggle_scratchpad(&scratchpad_name).await {
+                            Ok(message) => {
+                                info!("✅ {}", message);
+                                Ok(message)
+                            }
+                            Err(e) => {
+                                error!(
+                                    "❌ Failed to toggle scratchpad '{}': {}",
+                                    scratchpad_name, e
+                                );
+                                Err(e)
+                            }
+                        }
+                    } else {
+                        warn!("⚠️  Scratchpad '{}' not found", scratchpad_name);
+                        Err(anyhow::anyhow!(
+                            "Scratchpad '{}' not found",
+                            scratchpad_name
+                        ))
+                    }
+                } else {
+                    Err(anyhow::anyhow!("No scratchpad name provided"))
+                }
+            }
+            "list" => {
+                let mut status_list = Vec::new();
+                for name in self.scratchpads.keys() {
+                    let state = self.states.get(name);
+                    let visible_count = state
+                        .map(|s| s.windows.iter().filter(|w| w.is_visible).count())
+                        .unwrap_or(0);
+                    let total_count = state.map(|s| s.windows.len()).unwrap_or(0);
+                    let spawned = state.map(|s| s.is_spawned).unwrap_or(false);
+
+                    let mut status = if visible_count > 0 {
+                        format!("{name} (visible: {visible_count}/{total_count})")
+                    } else if spawned {
+                        format!("{name} (hidden: {total_count})")
+                    } else {
+                        format!("{name} (not spawned)")
+                    };
+                    if let Some(state) = state.filter(|s| !s.windows.is_empty()) {
+                        let addresses: Vec<&str> =
+                            state.windows.iter().map(|w| w.address.as_str()).collect();
+                        status.push_str(&format!(" [{}]", addresses.join(", ")));
+                    }
+                    status_list.push(status);
+                }
+                Ok(format!("Scratchpads: {}", status_list.join(", ")))
+            }
+            "show" => {
+                if let Some(scratchpad_name) = self.resolve_scratchpad_name(args) {
+                    info!("👁️  Showing scratchpad: {}", scratchpad_name);
+                    if self.scratchpads.contains_key(scratchpad_name.as_str()) {
+                        match self.show_scratchpad_direct(&scratchpad_name).await {
+                            Ok(message) => {
+                                info!("✅ {}", message);
+                                Ok(message)
+                            }
+                            Err(e) => {
+                                error!("❌ Failed to show scratchpad '{}': {}", scratchpad_name, e);
+                                Err(e)
+                            }
+                        }
+                    } else {
+                        warn!("⚠️  Scratchpad '{}' not found", scratchpad_name);
+                        Err(anyhow::anyhow!(
+                            "Scratchpad '{}' not found",
+                            scratchpad_name
+                        ))
+                    }
+                } else {
+                    Err(anyhow::anyhow!("No scratchpad name provided"))
+                }
+            }
+            "hide" => {
+                if let Some(scratchpad_name) = self.resolve_scratchpad_name(args) {
+                    info!("🙈 Hiding scratchpad: {}", scratchpad_name);
+                    let hide_delay = self
+                        .validated_configs
+                        .get(&scratchpad_name)
+                        .and_then(|config| config.hide_delay)
+                        .filter(|delay| *delay > 0 && self.is_scratchpad_visible(&scratchpad_name));
+                    if let Some(delay) = hide_delay {
+                        self.schedule_delayed_hide(&scratchpad_name, delay)
+                    } else if self.scratchpads.contains_key(scratchpad_name.as_str()) {
+                        match self.hide_scratchpad_and_restore(&scratchpad_name).await {
+                            Ok(message) => {
+                                info!("✅ {}", message);
+                                Ok(message)
+                            }
+                            Err(e) => {
+                                error!("❌ Failed to hide scratchpad '{}': {}", scratchpad_name, e);
+                                Err(e)
+                            }
+                        }
+                    } else {
+                        warn!("⚠️  Scratchpad '{}' not found", scratchpad_name);
+                        Err(anyhow::anyhow!(
+                            "Scratchpad '{}' not found",
+                            scratchpad_name
+                        ))
+                    }
+                } else {
+                    Err(anyhow::anyhow!("No scratchpad name provided"))
+                }
+            }
+            "cycle" | "spawn" => {
+                let scratchpad_name = self
+                    .resolve_scratchpad_name(args)
+                    .ok_or_else(|| anyhow::anyhow!("No scratchpad name provided"))?;
+                if !self.scratchpads.contains_key(scratchpad_name.as_str()) {
+                    return Err(anyhow::anyhow!(
+                        "Scratchpad '{}' not found",
+                        scratchpad_name
+                    ));
+                }
+                if command == "cycle" {
+                    self.cycle_instances(&scratchpad_name).await
+                } else {
+                    self.spawn_instance(&scratchpad_name).await
+                }
+            }
+            "is-visible" | "is_visible" => {
+                let scratchpad_name = self
+                    .resolve_scratchpad_name(args)
+                    .ok_or_else(|| anyhow::anyhow!("No scratchpad name provided"))?;
+                if !self.scratchpads.contains_key(scratchpad_name.as_str()) {
+                    return Err(anyhow::anyhow!(
+                        "Scratchpad '{}' not found",
+                        scratchpad_name
+                    ));
+                }
+                Ok(self.is_scratchpad_visible(&scratchpad_name).to_string())
+            }
+            "attach" => {
+                if let Some(scratchpad_name) = args.first() {
+                    info!("📌 Toggling attach for scratchpad: {}", scratchpad_name);
+                    if self.scratchpads.contains_key(*scratchpad_name) {
+                        match self.toggle_attach_scratchpad(scratchpad_name).await {
+                            Ok(message) => {
+                                info!("✅ {}", message);
+                                Ok(message)
+                            }
+                            Err(e) => {
+                                error!(
+                                    "❌ Failed to toggle attach for scratchpad '{}': {}",
+                                    scratchpad_name, e
+                                );
+                                Err(e)
+                            }
+                        }
+                    } else {
+                        warn!("⚠️  Scratchpad '{}' not found", scratchpad_name);
+                        Err(anyhow::anyhow!(
+                            "Scratchpad '{}' not found",
+                            scratchpad_name
+                        ))
+                    }
+                } else {
+                    Err(anyhow::anyhow!("No scratchpad name provided"))
+                }
+            }
+            "mark" | "unmark" => {
+                let name = args
+                    .first()
+                    .ok_or_else(|| anyhow::anyhow!("Usage: {} <scratchpad>", command))?;
+                if command == "mark" {
+                    self.mark_focused_window(name).await
+                } else {
+                    self.unmark_window(name).await
+                }
+            }
+            "export" => self.export_scratchpads(args.first().copied()).await,
+            "resize" => {
+                let [name, width, height] = args else {
+                    return Err(anyhow::anyhow!(
+                        "Usage: resize <scratchpad> <width> <height> (e.g. 800px 60%, +100 -50, +10% +10%)"
+                    ));
+                };
+                self.resize_scratchpad(name, (width, height)).await
+            }
+            "geometry" => {
+                let scratchpad_name = self
+                    .resolve_scratchpad_name(args)
+                    .ok_or_else(|| anyhow::anyhow!("No scratchpad name provided"))?;
+                let config = self
+                    .validated_configs
+                    .get(&scratchpad_name)
+                    .cloned()
+                    .ok_or_else(|| anyhow::anyhow!("Scratchpad '{}' not found", scratchpad_name))?;
+                let monitors = self.get_monitors().await?;
+                Ok(serde_json::to_string_pretty(&Self::geometry_report(
+                    &config, &monitors,
+                ))?)
+            }
+            "doctor" => {
+                let report = self.command_health_report().await;
+                if report.is_empty() {
+                    return Ok("No scratchpads configured".to_string());
+                }
+                let missing = report.iter().filter(|(_, health)| health.is_err()).count();
+                Ok(format!(
+                    "Scratchpad commands ({} missing):\n{}",
+                    missing,
+                    Self::format_command_health(&report)
+                ))
+            }
+            "timers" => {
+                let timers = self.pending_timers(Instant::now());
+                if timers.is_empty() {
+                    return Ok("No pending timers".to_string());
+                }
+                let lines: Vec<String> = timers
+                    .iter()
+                    .map(|(name, kind, remaining)| {
+                        format!("{name}: {kind} in {:.1}s", remaining.as_secs_f32())
+                    })
+                    .collect();
+                Ok(format!("Pending timers:\n{}", lines.join("\n")))
+            }
+            "cancel-timers" => {
+                let name = args.first().copied();
+                if let Some(name) = name {
+                    if !self.scratchpads.contains_key(name) {
+                        return Err(anyhow::anyhow!("Scratchpad '{}' not found", name));
+                    }
+                }
+                let cancelled = self.cancel_timers(name).await;
+                info!("⏹️ Cancelled {} pending timer(s)", cancelled);
+                Ok(format!("Cancelled {cancelled} pending timer(s)"))
+            }
+            "peek-show" | "peek-hide" => {
+                let scratchpad_name = args
+                    .first()
+                    .ok_or_else(|| anyhow::anyhow!("No scratchpad name provided"))?;
+                if !self.scratchpads.contains_key(*scratchpad_name) {
+                    warn!("⚠️  Scratchpad '{}' not found", scratchpad_name);
+                    return Err(anyhow::anyhow!(
+                        "Scratchpad '{}' not found",
+                        scratchpad_name
+                    ));
+                }
+
+                // A new peek supersedes whatever the previous one left running
+                self.cancel_pending_transitions(scratchpad_name).await;
+
+                if command == "peek-show" {
+                    debug!("👀 Peek show: {}", scratchpad_name);
+                    self.show_scratchpad_direct(scratchpad_name).await
+                } else {
+                    debug!("👀 Peek hide: {}", scratchpad_name);
+                    self.hide_scratchpad_and_restore(scratchpad_name).await
+                }
+            }
+            _ => Err(usage(format!(
+                "scratchpads {command} is not a command (expected {})",
+                SCRATCHPADS_SUBCOMMANDS.join("|")
+            ))),
+        }
+    }
+
+    async fn cleanup(&mut self) -> Result<()> {
+        info!("🧹 Cleaning up scratchpads plugin");
+
+        // Cancel all hide tasks
+        for (window_addr, timer) in self.hide_tasks.drain() {
+            timer.handle.abort();
+            debug!("❌ Cancelled hide task for window: {}", window_addr);
+        }
+
+        // Cancel pending moves of hidden windows
+        for (_, timer) in self.stash_tasks.drain() {
+            timer.handle.abort();
+        }
+
+        // Windows caught mid fade-out must not stay transparent
+        if let Ok(client) = self.get_hyprland_client().await {
+            for window_addr in std::mem::take(&mut self.faded_windows) {
+                Self::restore_opacity(&client, &self.window_animator, &window_addr).await;
+            }
+        }
+
+        // Cancel all hysteresis tasks
+        for (scratchpad_name, timer) in self.hysteresis_tasks.drain() {
+            timer.handle.abort();
+            debug!(
+                "❌ Cancelled hysteresis task for scratchpad: {}",
+                scratchpad_name
+            );
+        }
+
+        // Cancel all sync tasks
+        for (window_addr, handle) in self.sync_tasks.drain() {
+            handle.abort();
+            debug!("❌ Cancelled sync task for window: {}", window_addr);
+        }
+
+        info!("✅ Scratchpads plugin cleanup complete");
+        Ok(())
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use tokio_test;
 
-    fn create_test_config() -> toml::Value {
-        toml::from_str(
-            r#"
-            [term]
-            command = "foot --app-id=term"
-            class = "foot"
-            size = "75% 60%"
-            lazy = false
-            pinned = true
-            
-            [browser]
-            command = "firefox --new-window"
-            class = "firefox"
-            size = "80% 70%"
-            lazy = true
-            excludes = ["term"]
-            
-            [variables]
-            term_class = "foot"
-        "#,
+    fn create_test_config() -> toml::Value {
+        toml::from_str(
+            r#"
+            [term]
+            command = "foot --app-id=term"
+            class = "foot"
+            size = "75% 60%"
+            lazy = false
+            pinned = true
+            
+            [browser]
+            command = "firefox --new-window"
+            class = "firefox"
+            size = "80% 70%"
+            lazy = true
+            excludes = ["term"]
+            
+            [variables]
+            term_class = "foot"
+        "#,
+        )
+        .unwrap()
+    }
+
+    fn create_test_monitor() -> MonitorInfo {
+        MonitorInfo {
+            id: 0,
+            name: "DP-1".to_string(),
+            width: 1920,
+            height: 1080,
+            x: 0,
+            y: 0,
+            scale: 1.0,
+            is_focused: true,
+            active_workspace_id: 1,
+            refresh_rate: 60.0,
+            reserved_top: 0,
+            reserved_bottom: 0,
+            reserved_left: 0,
+            reserved_right: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_plugin_initialization() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let config = create_test_config();
+        plugin.init(&config).await.unwrap();
+
+        // Should have loaded 2 scratchpads (term and browser)
+        assert_eq!(plugin.scratchpads.len(), 2);
+
+        // Check term scratchpad config
+        let term_config = plugin.scratchpads.get("term").unwrap();
+        assert_eq!(term_config.command, "foot --app-id=term");
+        assert_eq!(term_config.class, Some("foot".to_string()));
+        assert_eq!(term_config.size, "75% 60%");
+        assert!(!term_config.lazy);
+        assert!(term_config.pinned);
+
+        // Check browser scratchpad config
+        let browser_config = plugin.scratchpads.get("browser").unwrap();
+        assert_eq!(browser_config.command, "firefox --new-window");
+        assert_eq!(browser_config.class, Some("firefox".to_string()));
+        assert!(browser_config.lazy);
+        assert_eq!(browser_config.excludes, vec!["term"]);
+
+        // Check variables
+        // Note: This test would need to be async to properly test Arc<RwLock<HashMap>>
+        // For now, we'll test that the structure exists
+        assert!(!plugin.scratchpads.is_empty());
+
+        // Check validated configs were created
+        assert_eq!(plugin.validated_configs.len(), 2);
+    }
+
+    #[test]
+    fn test_tiled_mode_skips_resize() {
+        let monitor = create_test_monitor();
+        let mut config = ValidatedConfig {
+            size: "75% 60%".to_string(),
+            ..Default::default()
+        };
+
+        // Floating scratchpads get an explicit geometry to resize/move to
+        let geometry = GeometryCalculator::show_geometry(&config, &monitor)
+            .unwrap()
+            .expect("floating scratchpad should be resized");
+        assert!(geometry.floating);
+        assert_eq!((geometry.width, geometry.height), (1440, 648));
+
+        // Tiled scratchpads issue no resize dispatch at all
+        config.floating = false;
+        assert!(GeometryCalculator::show_geometry(&config, &monitor)
+            .unwrap()
+            .is_none());
+        assert!(
+            !GeometryCalculator::calculate_geometry(&config, &monitor)
+                .unwrap()
+                .floating
+        );
+    }
+
+    #[test]
+    fn test_offscreen_hide_position() {
+        // 1920x1080 monitor at the origin, 800x600 window shown at (560, 240)
+        let monitor = create_test_monitor();
+        let position = (560, 240);
+        let size = (800, 600);
+        let park = |animation| {
+            ScratchpadsPlugin::offscreen_hide_position(animation, position, size, &monitor)
+        };
+
+        // Directional animations park past the edge they slide out of
+        assert_eq!(park(Some("fromTop")), (560, -600 - OFFSCREEN_HIDE_MARGIN));
+        assert_eq!(
+            park(Some("fromBottom")),
+            (560, 1080 + OFFSCREEN_HIDE_MARGIN)
+        );
+        assert_eq!(park(Some("fromLeft")), (-800 - OFFSCREEN_HIDE_MARGIN, 240));
+        assert_eq!(park(Some("fromRight")), (1920 + OFFSCREEN_HIDE_MARGIN, 240));
+        assert_eq!(
+            park(Some("fromTopLeft")),
+            (-800 - OFFSCREEN_HIDE_MARGIN, -600 - OFFSCREEN_HIDE_MARGIN)
+        );
+
+        // Without a direction the window goes below the monitor
+        assert_eq!(park(None), (560, 1080 + OFFSCREEN_HIDE_MARGIN));
+        assert_eq!(park(Some("fade")), (560, 1080 + OFFSCREEN_HIDE_MARGIN));
+
+        // Offsets follow the monitor in multi-monitor layouts
+        let mut right = create_test_monitor();
+        right.x = 1920;
+        let parked = ScratchpadsPlugin::offscreen_hide_position(
+            Some("fromRight"),
+            (2480, 240),
+            size,
+            &right,
+        );
+        assert_eq!(parked, (3840 + OFFSCREEN_HIDE_MARGIN, 240));
+    }
+
+    #[test]
+    fn test_pinned_windows_are_parked_offscreen() {
+        let pinned = ValidatedConfig {
+            pinned: true,
+            hide_mode: "special".to_string(),
+            ..Default::default()
+        };
+
+        // A window Hyprland pins keeps its workspace (and pin) when hidden
+        assert!(pinned.parks_offscreen(true));
+        let monitor = create_test_monitor();
+        let parked = ScratchpadsPlugin::offscreen_hide_position(
+            Some("fromTop"),
+            (560, 240),
+            (800, 600),
+            &monitor,
+        );
+        assert!(parked.1 + 600 <= 0, "parked at {parked:?}, still on screen");
+
+        // Unpinned windows keep the configured mode
+        assert!(!pinned.parks_offscreen(false));
+        let unpinned_config = ValidatedConfig {
+            pinned: false,
+            ..pinned.clone()
+        };
+        assert!(!unpinned_config.parks_offscreen(true));
+
+        // Tiled windows can't be parked
+        let tiled = ValidatedConfig {
+            floating: false,
+            ..pinned
+        };
+        assert!(!tiled.parks_offscreen(true));
+    }
+
+    #[test]
+    fn test_hide_mode_validation() {
+        let monitors = vec![create_test_monitor()];
+        let validate = |hide_mode: &str, floating: bool| {
+            let mut configs = HashMap::new();
+            configs.insert(
+                "term".to_string(),
+                Arc::new(ScratchpadConfig {
+                    command: "foot".to_string(),
+                    class: Some("foot".to_string()),
+                    hide_mode: hide_mode.to_string(),
+                    floating,
+                    ..Default::default()
+                }),
+            );
+            ConfigValidator::validate_configs(&configs, &monitors, &HashMap::new())
+                .remove("term")
+                .unwrap()
+        };
+
+        assert_eq!(ScratchpadConfig::default().hide_mode, "special");
+        assert!(validate("special", true).validation_errors.is_empty());
+
+        let offscreen = validate("offscreen", true);
+        assert!(offscreen.validation_errors.is_empty());
+        assert!(offscreen.validation_warnings.is_empty());
+        assert_eq!(offscreen.hide_mode, "offscreen");
+
+        assert!(!validate("offscreen", false).validation_warnings.is_empty());
+        assert!(!validate("minimize", true).validation_warnings.is_empty());
+        assert!(validate("iconify", true)
+            .validation_errors
+            .iter()
+            .any(|e| e.starts_with("Invalid hide_mode")));
+    }
+
+    #[test]
+    fn test_animation_properties_build_show_and_hide() {
+        use crate::animation::{EasingFunction, PropertyValue};
+
+        let validated = |toml: &str| {
+            let table: toml::Value = toml::from_str(toml).unwrap();
+            let configs = HashMap::from([(
+                "term".to_string(),
+                Arc::new(ScratchpadsPlugin::parse_scratchpad_config(
+                    table.as_table().unwrap(),
+                )),
+            )]);
+            let validated = ConfigValidator::validate_configs(
+                &configs,
+                &[create_test_monitor()],
+                &HashMap::new(),
+            );
+            (*validated["term"]).clone()
+        };
+        let config = validated(
+            r#"
+            command = "foot"
+            class = "foot"
+            animation = "fromTop"
+            animation_properties = [
+                { property = "opacity", from = 0, to = "100%" },
+                { property = "scale", from = "50%", to = 1.0, easing = "ease-out-back" },
+                { property = "y", from = "-50%", to = "10%" },
+            ]
+            "#,
+        );
+        assert!(
+            config.validation_errors.is_empty(),
+            "{:?}",
+            config.validation_errors
+        );
+        assert!(config.fades());
+
+        let monitor = MonitorInfo {
+            y: 100,
+            ..create_test_monitor()
+        };
+        let show = config
+            .animation_properties_for((560, -700), (560, 240), true, &monitor)
+            .unwrap();
+        let find = |properties: &[crate::animation::AnimationPropertyConfig], name: &str| {
+            let property = properties.iter().find(|p| p.property == name).unwrap();
+            (
+                property.from.clone(),
+                property.to.clone(),
+                property.easing.clone(),
+            )
+        };
+        assert_eq!(show.len(), 4, "x is kept, y is replaced");
+        assert_eq!(
+            find(&show, "x"),
+            (PropertyValue::Pixels(560), PropertyValue::Pixels(560), None)
+        );
+        // Percentages of y are of the monitor's height, from its top edge
+        assert_eq!(
+            find(&show, "y"),
+            (
+                PropertyValue::Pixels(-440),
+                PropertyValue::Pixels(208),
+                None
+            )
+        );
+        assert_eq!(
+            find(&show, "opacity"),
+            (PropertyValue::Float(0.0), PropertyValue::Float(1.0), None)
+        );
+        assert_eq!(
+            find(&show, "scale"),
+            (
+                PropertyValue::Float(0.5),
+                PropertyValue::Float(1.0),
+                Some(EasingFunction::EaseOutBack)
+            )
+        );
+
+        // Hides play the same properties backwards
+        let hide = config
+            .animation_properties_for((560, 240), (560, -700), false, &monitor)
+            .unwrap();
+        assert_eq!(
+            find(&hide, "scale"),
+            (
+                PropertyValue::Float(1.0),
+                PropertyValue::Float(0.5),
+                Some(EasingFunction::EaseOutBack)
+            )
+        );
+
+        let errors = validated(
+            r#"
+            command = "foot"
+            class = "foot"
+            animation_properties = [
+                { property = "rotation", from = "0", to = "90" },
+                { property = "opacity", from = "transparent", to = "1" },
+            ]
+            "#,
+        )
+        .validation_errors;
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("'rotation' is not supported")));
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("invalid value 'transparent'")));
+    }
+
+    #[test]
+    fn test_animate_selection_builds_properties() {
+        use crate::animation::PropertyValue;
+
+        let with = |animate: Option<Vec<&str>>| ValidatedConfig {
+            animation: Some("fromTop".to_string()),
+            animation_opacity_from: Some(0.2),
+            animate: animate.map(|a| a.into_iter().map(str::to_string).collect()),
+            ..Default::default()
+        };
+        let props = |config: &ValidatedConfig, showing| {
+            config
+                .animation_properties_for((0, -700), (560, 240), showing, &MonitorInfo::default())
+                .map(|properties| {
+                    properties
+                        .into_iter()
+                        .map(|p| (p.property, p.from, p.to))
+                        .collect::<Vec<_>>()
+                })
+        };
+        let px = PropertyValue::Pixels;
+        let xy = |from: (i32, i32), to: (i32, i32)| {
+            vec![
+                ("x".to_string(), px(from.0), px(to.0)),
+                ("y".to_string(), px(from.1), px(to.1)),
+            ]
+        };
+
+        // Unset keeps the animation type's own behavior
+        assert_eq!(props(&with(None), true), None);
+        assert_eq!(with(None).animates("opacity"), None);
+
+        // Slide without fade
+        let slide = with(Some(vec!["position"]));
+        assert_eq!(props(&slide, true), Some(xy((0, -700), (560, 240))));
+
+        // Fade in place: shown at the target, hidden where it currently is
+        let fade = with(Some(vec!["opacity"]));
+        let mut expected = xy((560, 240), (560, 240));
+        expected.push((
+            "opacity".to_string(),
+            PropertyValue::Float(0.2),
+            PropertyValue::Float(1.0),
+        ));
+        assert_eq!(props(&fade, true), Some(expected));
+        let mut expected = xy((0, -700), (0, -700));
+        expected.push((
+            "opacity".to_string(),
+            PropertyValue::Float(1.0),
+            PropertyValue::Float(0.2),
+        ));
+        assert_eq!(props(&fade, false), Some(expected));
+
+        // Both
+        let both = with(Some(vec!["position", "opacity"]));
+        let both_props = props(&both, true).unwrap();
+        assert_eq!(both_props.len(), 3);
+        assert_eq!(both_props[0], xy((0, -700), (560, 240))[0]);
+    }
+
+    #[tokio::test]
+    async fn test_fade_type_fades_show_frames() {
+        let config = |animation: &str, animate: Option<Vec<&str>>| ValidatedConfig {
+            animation: Some(animation.to_string()),
+            animate: animate.map(|a| a.into_iter().map(str::to_string).collect()),
+            ..Default::default()
+        };
+        assert!(config("fade", None).fades());
+        assert!(!config("fromTop", None).fades());
+        // An explicit selection wins over the type
+        assert!(!config("fade", Some(vec!["position"])).fades());
+        assert!(config("fromTop", Some(vec!["opacity"])).fades());
+
+        // A frame moves and fades the window in one batch
+        let geometry = crate::ipc::WindowGeometry {
+            x: 560,
+            y: 240,
+            width: 800,
+            height: 600,
+            workspace: "1".to_string(),
+            monitor: 0,
+            floating: true,
+        };
+        let api = Arc::new(crate::ipc::mock::MockHyprland::new(vec![], vec![]));
+        let client = HyprlandClient::with_api(api.clone());
+        client
+            .dispatch_batch(show_frame_commands(
+                "0x1",
+                Some((560, 100)),
+                &geometry,
+                Some(0.5),
+            ))
+            .await
+            .unwrap();
+        client
+            .dispatch_batch(show_frame_commands("0x1", None, &geometry, Some(1.0)))
+            .await
+            .unwrap();
+        assert_eq!(
+            api.calls(),
+            vec![
+                "dispatch resizewindowpixel exact 800 600,address:0x1",
+                "dispatch movewindowpixel exact 560 100,address:0x1",
+                "dispatch setprop address:0x1 alpha override 0.5",
+                "dispatch setprop address:0x1 alpha override 1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_animate_validation() {
+        let monitors = vec![create_test_monitor()];
+        let validate = |animate: Vec<&str>| {
+            let mut configs = HashMap::new();
+            configs.insert(
+                "term".to_string(),
+                Arc::new(ScratchpadConfig {
+                    command: "foot".to_string(),
+                    class: Some("foot".to_string()),
+                    animate: Some(animate.into_iter().map(str::to_string).collect()),
+                    ..Default::default()
+                }),
+            );
+            ConfigValidator::validate_configs(&configs, &monitors, &HashMap::new())
+                .remove("term")
+                .unwrap()
+        };
+
+        assert!(validate(vec!["position", "opacity"])
+            .validation_errors
+            .is_empty());
+        assert!(validate(vec!["opacity", "wobble"])
+            .validation_errors
+            .iter()
+            .any(|e| e.starts_with("Unknown animate entry 'wobble'")));
+        assert!(!validate(Vec::new()).validation_warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_monitor_change_recalculates_cached_geometry() {
+        use crate::ipc::mock::{monitor, MockHyprland};
+
+        let client = |size: (u16, u16)| {
+            let api = MockHyprland::new(vec![], vec![monitor("DP-1", size, true, 1)]);
+            Some(Arc::new(HyprlandClient::with_api(Arc::new(api))))
+        };
+        let mut plugin = ScratchpadsPlugin::new();
+        *plugin.hyprland_client.lock().await = client((3840, 2160));
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+            size = "50% 50%"
+            position = "25% 25%"
+
+            [term.per_monitor]
+            DP-1 = "40% 40%"
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+        let term = &plugin.validated_configs["term"];
+        assert_eq!(term.parsed_size, Some((1920, 1080)));
+        assert_eq!(term.parsed_position, Some((960, 540)));
+        assert_eq!(term.parsed_monitor_sizes["DP-1"], (1536, 864));
+
+        // Focus moving between outputs leaves the geometry alone
+        plugin
+            .handle_event(&HyprlandEvent::MonitorChanged {
+                monitor: "DP-1".to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            plugin.validated_configs["term"].parsed_size,
+            Some((1920, 1080))
+        );
+
+        // The same output switched from 4K to 1080p
+        *plugin.hyprland_client.lock().await = client((1920, 1080));
+        plugin
+            .handle_event(&HyprlandEvent::MonitorChanged {
+                monitor: "DP-1".to_string(),
+            })
+            .await
+            .unwrap();
+        let term = &plugin.validated_configs["term"];
+        assert_eq!(term.parsed_size, Some((960, 540)));
+        assert_eq!(term.parsed_position, Some((480, 270)));
+
+        // Position and per-monitor size come from the recalculated cache
+        let geometry = GeometryCalculator::calculate_geometry(
+            term,
+            &MonitorInfo::from(&monitor("DP-1", (1920, 1080), true, 1)),
+        )
+        .unwrap();
+        assert_eq!(
+            (geometry.x, geometry.y, geometry.width, geometry.height),
+            (480, 270, 768, 432)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_per_monitor_size_overrides() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+            size = "50% 50%"
+
+            [term.per_monitor]
+            DP-1 = "1200px 800px"
+            eDP-1 = "80% 70%"
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+        assert_eq!(plugin.scratchpads["term"].per_monitor.len(), 2);
+
+        let big = MonitorInfo {
+            name: "DP-1".to_string(),
+            width: 3840,
+            height: 2160,
+            ..create_test_monitor()
+        };
+        let laptop = MonitorInfo {
+            name: "eDP-1".to_string(),
+            x: 3840,
+            width: 1920,
+            height: 1200,
+            ..create_test_monitor()
+        };
+        let other = MonitorInfo {
+            name: "HDMI-A-1".to_string(),
+            width: 2560,
+            height: 1440,
+            ..create_test_monitor()
+        };
+
+        let monitors = vec![big.clone(), laptop.clone()];
+        let validated =
+            ConfigValidator::validate_configs(&plugin.scratchpads, &monitors, &HashMap::new());
+        let term = validated.get("term").unwrap();
+        assert!(term.validation_errors.is_empty());
+        assert_eq!(term.parsed_monitor_sizes["DP-1"], (1200, 800));
+        assert_eq!(term.parsed_monitor_sizes["eDP-1"], (1536, 840));
+
+        let size_on = |monitor: &MonitorInfo| {
+            let geometry = GeometryCalculator::calculate_geometry(term, monitor).unwrap();
+            (geometry.width, geometry.height)
+        };
+        assert_eq!(size_on(&big), (1200, 800));
+        assert_eq!(size_on(&laptop), (1536, 840));
+        // Monitors without an override use the base size
+        assert_eq!(size_on(&other), (1280, 720));
+
+        // Overrides are validated like the base size
+        let mut configs = HashMap::new();
+        configs.insert(
+            "term".to_string(),
+            Arc::new(ScratchpadConfig {
+                command: "foot".to_string(),
+                per_monitor: HashMap::from([("DP-1".to_string(), "huge".to_string())]),
+                ..Default::default()
+            }),
+        );
+        let validated = ConfigValidator::validate_configs(&configs, &monitors, &HashMap::new());
+        assert!(validated["term"]
+            .validation_errors
+            .iter()
+            .any(|e| e.starts_with("Invalid size override for monitor 'DP-1'")));
+
+        // Including overrides inherited through `use`
+        configs.insert(
+            "child".to_string(),
+            Arc::new(ScratchpadConfig {
+                command: "kitty".to_string(),
+                r#use: Some("term".to_string()),
+                ..Default::default()
+            }),
+        );
+        let validated = ConfigValidator::validate_configs(&configs, &monitors, &HashMap::new());
+        assert!(validated["child"]
+            .validation_errors
+            .iter()
+            .any(|e| e.starts_with("Invalid size override for monitor 'DP-1'")));
+    }
+
+    #[tokio::test]
+    async fn test_animation_presets() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [animation_presets.snappy]
+            type = "fromTop"
+            duration = 150
+            easing = "ease-out-back"
+
+            [term]
+            command = "foot"
+            class = "foot"
+            animation_preset = "snappy"
+
+            [music]
+            command = "spotify"
+            class = "spotify"
+            animation_preset = "snappy"
+            animation = "fromRight"
+            animation_duration = 400
+
+            [notes]
+            command = "obsidian"
+            class = "obsidian"
+            animation_preset = "missing"
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+        assert!(!plugin.scratchpads.contains_key("animation_presets"));
+
+        let validated = &plugin.validated_configs;
+        let term = &validated["term"];
+        assert_eq!(term.animation.as_deref(), Some("fromTop"));
+        assert_eq!(term.animation_duration, Some(150));
+        assert_eq!(term.animation_easing.as_deref(), Some("ease-out-back"));
+
+        // Explicit fields override the preset, the rest still comes from it
+        let music = &validated["music"];
+        assert_eq!(music.animation.as_deref(), Some("fromRight"));
+        assert_eq!(music.animation_duration, Some(400));
+        assert_eq!(music.animation_easing.as_deref(), Some("ease-out-back"));
+
+        assert!(validated["notes"]
+            .validation_errors
+            .contains(&"Animation preset 'missing' not found".to_string()));
+    }
+
+    fn test_client(class: &str) -> hyprland::data::Client {
+        serde_json::from_value(serde_json::json!({
+            "address": "0x1", "at": [0, 0], "size": [800, 600],
+            "workspace": {"id": 1, "name": "1"}, "floating": true,
+            "fullscreen": 0, "fullscreenClient": 0, "monitor": 0,
+            "initialClass": class, "class": class, "initialTitle": "", "title": "",
+            "pid": 1, "xwayland": false, "pinned": false, "grouped": [],
+            "mapped": true, "swallowing": null, "focusHistoryID": 0,
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_spawn_and_show_tracks_new_window_on_original_workspace() {
+        use crate::ipc::mock::{monitor, window, MockHyprland};
+
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+            size = "50% 50%"
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        // The user is on workspace 3; the terminal maps there once spawned
+        let api = Arc::new(
+            MockHyprland::new(
+                vec![window("0xa", "firefox", (0, 0), 3)],
+                vec![monitor("DP-1", (1920, 1080), true, 3)],
+            )
+            .spawns(window("0xb", "foot", (480, 270), 3)),
+        );
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+
+        let config = plugin.get_validated_config("term").unwrap();
+        let result = plugin.spawn_and_show_scratchpad("term", &config).await;
+        assert_eq!(result.unwrap(), "Scratchpad 'term' shown");
+
+        let state = &plugin.states["term"];
+        assert!(state.is_spawned);
+        assert_eq!(state.original_workspace.as_deref(), Some("3"));
+        assert_eq!(state.windows.len(), 1);
+        assert_eq!(state.windows[0].address, "0xb");
+        assert!(state.windows[0].is_visible);
+        assert_eq!(state.windows[0].last_position, Some((480, 270, 800, 600)));
+        assert_eq!(state.windows[0].monitor.as_deref(), Some("DP-1"));
+        assert_eq!(plugin.window_to_scratchpad["0xb"], "term");
+        assert!(!plugin.window_to_scratchpad.contains_key("0xa"));
+
+        // Spawned in place, rules applied, then placed, focused and pointed at
+        assert_eq!(
+            api.calls(),
+            vec![
+                "dispatch exec [workspace 3;float;size 960 540;move 480 270] foot",
+                "keyword windowrulev2 float, address:0xb",
+                "keyword windowrulev2 noanim, address:0xb",
+                "keyword windowrulev2 nodecoration, address:0xb",
+                "keyword windowrulev2 noshadow, address:0xb",
+                "keyword windowrulev2 immediate, address:0xb",
+                "dispatch resizewindowpixel exact 960 540,address:0xb",
+                "dispatch movewindowpixel exact 480 270,address:0xb",
+                "dispatch movetoworkspacesilent 3,address:0xb",
+                "dispatch focuswindow address:0xb",
+                "dispatch movecursor 960 540",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spawn_instance_uses_the_scratchpad_spawn_command() {
+        use crate::ipc::mock::{monitor, window, MockHyprland};
+
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+            size = "50% 50%"
+            multi_window = true
+            max_instances = 2
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        let api =
+            Arc::new(
+                MockHyprland::new(vec![], vec![monitor("DP-1", (1920, 1080), true, 3)])
+                    .spawns(window("0xb", "foot", (480, 270), 3)),
+            );
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+
+        let result = plugin.handle_command("spawn", &["term"]).await.unwrap();
+        assert_eq!(result, "Spawned instance 0xb of 'term' (1/2)");
+        assert_eq!(
+            api.calls()[0],
+            "dispatch exec [workspace 3;float;size 960 540;move 480 270] foot"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_preserve_aspect_learns_native_size_on_spawn() {
+        use crate::ipc::mock::{monitor, window, MockHyprland};
+
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+            size = "50% 50%"
+            preserve_aspect = true
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        // foot opens at 800x600 (4:3) when not given a size
+        let api =
+            Arc::new(
+                MockHyprland::new(vec![], vec![monitor("DP-1", (1920, 1080), true, 3)])
+                    .spawns(window("0xb", "foot", (480, 270), 3)),
+            );
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+
+        let config = plugin.get_validated_config("term").unwrap();
+        plugin
+            .spawn_and_show_scratchpad("term", &config)
+            .await
+            .unwrap();
+
+        // Spawned without a size, then fitted in 960x540 by shrinking the width
+        let calls = api.calls();
+        assert_eq!(
+            calls[0],
+            "dispatch exec [workspace 3;float;move 480 270] foot"
+        );
+        assert!(calls.contains(&"dispatch resizewindowpixel exact 720 540,address:0xb".to_string()));
+        assert!(calls.contains(&"dispatch movewindowpixel exact 600 270,address:0xb".to_string()));
+        assert_eq!(
+            plugin.get_validated_config("term").unwrap().native_size,
+            Some((800, 600))
+        );
+
+        // A window not known yet falls back to the configured size
+        let mut unknown = plugin
+            .get_validated_config("term")
+            .unwrap()
+            .as_ref()
+            .clone();
+        unknown.native_size = None;
+        let monitor = create_test_monitor();
+        let geometry = GeometryCalculator::calculate_geometry(&unknown, &monitor).unwrap();
+        assert_eq!((geometry.width, geometry.height), (960, 540));
+    }
+
+    #[tokio::test]
+    async fn test_allow_special_workspaces_toggle_cycle() {
+        use crate::ipc::mock::{monitor, window, with_special, MockHyprland};
+
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+            allow_special_workspaces = true
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        // special:magic is open over workspace 3
+        let api = Arc::new(
+            MockHyprland::new(
+                vec![],
+                vec![with_special(
+                    monitor("DP-1", (1920, 1080), true, 3),
+                    "magic",
+                )],
+            )
+            .spawns(window("0xb", "foot", (480, 270), 3)),
+        );
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+        let workspace_of = |api: &MockHyprland| api.window("0xb").unwrap().workspace.name;
+
+        // Shown on the open special workspace, which counts as visible
+        plugin.toggle_scratchpad("term").await.unwrap();
+        assert_eq!(workspace_of(&api), "special:magic");
+        assert!(plugin.is_scratchpad_visible("term"));
+
+        // So the next toggle hides it instead of showing it again
+        let hidden = plugin.toggle_scratchpad("term").await.unwrap();
+        assert!(hidden.contains("hidden"), "{hidden}");
+        assert_eq!(workspace_of(&api), "special:hidden");
+        assert!(!plugin.is_scratchpad_visible("term"));
+
+        // And the one after brings it back onto special:magic
+        let shown = plugin.toggle_scratchpad("term").await.unwrap();
+        assert_eq!(shown, "Scratchpad 'term' shown");
+        assert_eq!(workspace_of(&api), "special:magic");
+        assert!(plugin.is_scratchpad_visible("term"));
+    }
+
+    #[tokio::test]
+    async fn test_alt_toggle_raises_instead_of_hiding() {
+        use crate::ipc::mock::{monitor, window, MockHyprland};
+
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+            alt_toggle = true
+
+            [music]
+            command = "spotify"
+            class = "spotify"
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        let api = Arc::new(MockHyprland::new(
+            vec![
+                window("0xa", "foot", (100, 100), 3),
+                window("0xb", "spotify", (400, 300), 3),
+            ],
+            vec![monitor("DP-1", (1920, 1080), true, 3)],
+        ));
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+        for (name, address) in [("term", "0xa"), ("music", "0xb")] {
+            let mut state = ScratchpadState::default();
+            state.is_spawned = true;
+            state.windows.push(WindowState {
+                address: address.to_string(),
+                is_visible: true,
+                last_position: None,
+                monitor: None,
+                workspace: None,
+                last_focus: None,
+            });
+            plugin.states.insert(name.to_string(), state);
+            plugin
+                .window_to_scratchpad
+                .insert(address.to_string(), name.to_string());
+        }
+
+        // music has focus over a visible term: toggling term raises it
+        plugin.focused_window = Some("0xb".to_string());
+        assert_eq!(
+            plugin.toggle_scratchpad("term").await.unwrap(),
+            "Scratchpad 'term' raised"
+        );
+        assert!(plugin.is_scratchpad_visible("term"));
+        let calls = api.calls();
+        assert!(calls.ends_with(&[
+            "dispatch focuswindow address:0xa".to_string(),
+            "dispatch alterzorder top,address:0xa".to_string(),
+        ]));
+
+        // Without alt_toggle the same situation hides
+        plugin.focused_window = Some("0xa".to_string());
+        let hidden = plugin.toggle_scratchpad("music").await.unwrap();
+        assert!(hidden.contains("hidden"), "{hidden}");
+
+        // Once term has focus, toggling it hides it as usual
+        let hidden = plugin.toggle_scratchpad("term").await.unwrap();
+        assert!(hidden.contains("hidden"), "{hidden}");
+        assert!(!plugin.is_scratchpad_visible("term"));
+    }
+
+    #[tokio::test]
+    async fn test_fading_hide_restores_opacity() {
+        use crate::ipc::mock::{monitor, window, MockHyprland};
+
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+            animation = "fromTop"
+            animate = ["position", "opacity"]
+            animation_duration = 20
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        let api = Arc::new(MockHyprland::new(
+            vec![window("0xb", "foot", (480, 270), 3)],
+            vec![monitor("DP-1", (1920, 1080), true, 3)],
+        ));
+        let client = Arc::new(HyprlandClient::with_api(api.clone()));
+        *plugin.hyprland_client.lock().await = Some(client.clone());
+        plugin
+            .window_to_scratchpad
+            .insert("0xb".to_string(), "term".to_string());
+
+        let shown = api.window("0xb").unwrap();
+        plugin
+            .hide_scratchpad_window(&client, &shown, "term")
+            .await
+            .unwrap();
+        assert!(plugin.faded_windows.contains("0xb"));
+
+        // Faded and slid out, then moved away, then made opaque again
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        let calls = api.calls();
+        let stashed = calls
+            .iter()
+            .position(|call| call.starts_with("dispatch movetoworkspacesilent special:hidden"))
+            .expect("window moved to the special workspace");
+        assert_eq!(
+            calls[stashed + 1..],
+            ["dispatch setprop address:0xb alpha override 1"]
+        );
+
+        // Shown again before a hide finishes: the opacity is restored right away
+        let hidden = api.window("0xb").unwrap();
+        plugin.faded_windows.insert("0xb".to_string());
+        let config = plugin.get_validated_config("term").unwrap();
+        let _ = plugin
+            .show_scratchpad(&client, &hidden, &config, "term")
+            .await;
+        assert!(!plugin.faded_windows.contains("0xb"));
+        assert!(api.calls()[calls.len()..]
+            .contains(&"dispatch setprop address:0xb alpha override 1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_mark_and_unmark_focused_window() {
+        use crate::ipc::mock::{monitor, window, MockHyprland};
+
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        // A tiled editor window has focus
+        let mut editor = window("0xa", "code", (100, 100), 3);
+        editor.floating = false;
+        let api = Arc::new(MockHyprland::new(
+            vec![editor],
+            vec![monitor("DP-1", (1920, 1080), true, 3)],
+        ));
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+        plugin.focused_window = Some("0xa".to_string());
+        let workspace_of = |api: &MockHyprland| api.window("0xa").unwrap().workspace.name;
+
+        // Marked under a new name: floated, tracked, and toggled like any scratchpad
+        plugin.handle_command("mark", &["notes"]).await.unwrap();
+        assert!(api
+            .calls()
+            .contains(&"dispatch togglefloating address:0xa".to_string()));
+        assert_eq!(plugin.window_to_scratchpad["0xa"], "notes");
+        assert!(plugin.is_scratchpad_visible("notes"));
+        plugin.toggle_scratchpad("notes").await.unwrap();
+        assert_eq!(workspace_of(&api), "special:hidden");
+        plugin.toggle_scratchpad("notes").await.unwrap();
+        assert_eq!(workspace_of(&api), "3");
+
+        // Marking it again as another scratchpad re-assigns it
+        plugin.handle_command("mark", &["term"]).await.unwrap();
+        assert_eq!(plugin.window_to_scratchpad["0xa"], "term");
+        assert_eq!(plugin.window_to_scratchpad.len(), 1);
+        assert!(!plugin.scratchpads.contains_key("notes"));
+        assert_eq!(plugin.states["term"].windows.len(), 1);
+        assert!(!plugin.marked["term"].was_floating);
+
+        // Unmarking a hidden window brings it back, tiled, and stops tracking it
+        plugin.toggle_scratchpad("term").await.unwrap();
+        assert_eq!(workspace_of(&api), "special:hidden");
+        let calls = api.calls().len();
+        plugin.handle_command("unmark", &["term"]).await.unwrap();
+        assert_eq!(workspace_of(&api), "3");
+        assert!(api.calls()[calls..].contains(&"dispatch togglefloating address:0xa".to_string()));
+        assert!(!api.window("0xa").unwrap().floating);
+        assert!(plugin.window_to_scratchpad.is_empty());
+        assert!(plugin.marked.is_empty());
+        assert!(!plugin.states["term"].is_spawned);
+        assert!(plugin.scratchpads.contains_key("term"));
+        assert!(plugin.handle_command("unmark", &["term"]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_close_on_hide_waits_for_hide_animation() {
+        use crate::ipc::mock::{monitor, window, MockHyprland};
+
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+            animation = "fromTop"
+            animation_duration = 60
+            close_on_hide = true
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        let api = Arc::new(MockHyprland::new(
+            vec![window("0xb", "foot", (480, 270), 3)],
+            vec![monitor("DP-1", (1920, 1080), true, 3)],
+        ));
+        let client = Arc::new(HyprlandClient::with_api(api.clone()));
+        *plugin.hyprland_client.lock().await = Some(client.clone());
+        plugin
+            .window_animator
+            .lock()
+            .await
+            .set_hyprland_client(client.clone())
+            .await;
+        plugin
+            .window_to_scratchpad
+            .insert("0xb".to_string(), "term".to_string());
+
+        let shown = api.window("0xb").unwrap();
+        plugin
+            .hide_scratchpad_window(&client, &shown, "term")
+            .await
+            .unwrap();
+
+        // The slide-out played before the window was closed, and nothing after it
+        let calls = api.calls();
+        let closed = calls
+            .iter()
+            .position(|call| call == "dispatch closewindow address:0xb")
+            .expect("window closed");
+        assert!(calls[..closed]
+            .iter()
+            .any(|call| call.starts_with("dispatch movewindowpixel")));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!api.calls()[closed + 1..]
+            .iter()
+            .any(|call| call.starts_with("dispatch movewindowpixel")));
+        assert!(!plugin.window_to_scratchpad.contains_key("0xb"));
+    }
+
+    #[tokio::test]
+    async fn test_class_regex_matching_prefers_exact_class() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [pwa]
+            command = "chromium --app=https://example.com"
+            class_regex = "^chrome-.*"
+
+            [mail]
+            command = "chromium --app=https://mail.example.com"
+            class = "chrome-mail"
+
+            [broken]
+            command = "foot"
+            class_regex = "chrome-("
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        let pwa = &plugin.validated_configs["pwa"];
+        assert!(pwa.matches_class(&test_client("chrome-example.com__-Default")));
+        assert!(!pwa.matches_class(&test_client("firefox")));
+
+        // Both match "chrome-mail", but the exact class wins
+        let mail_window = test_client("chrome-mail");
+        assert!(pwa.matches_class(&mail_window));
+        assert_eq!(
+            plugin.find_scratchpad_for_window(&mail_window).as_deref(),
+            Some("mail")
+        );
+        assert_eq!(
+            plugin
+                .find_scratchpad_for_window(&test_client("chrome-docs"))
+                .as_deref(),
+            Some("pwa")
+        );
+
+        let broken = &plugin.validated_configs["broken"];
+        assert!(broken.compiled_class_regex.is_none());
+        assert!(broken
+            .validation_errors
+            .iter()
+            .any(|e| e.starts_with("Invalid class_regex 'chrome-('")));
+    }
+
+    #[tokio::test]
+    async fn test_shared_class_is_reported_as_conflict() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+
+            [htop]
+            command = "foot -e htop"
+            class = "foot"
+
+            [notes]
+            command = "obsidian"
+            class = "obsidian"
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        let conflicts = |name: &str| {
+            plugin.validated_configs[name]
+                .validation_warnings
+                .iter()
+                .filter(|w| w.contains("also used by"))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            conflicts("term"),
+            vec!["Class 'foot' is also used by scratchpad(s) htop; windows may be claimed by the wrong scratchpad"]
+        );
+        assert_eq!(conflicts("htop").len(), 1);
+        assert!(conflicts("htop")[0].contains("scratchpad(s) term"));
+        assert!(conflicts("notes").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_scratchpad_groups_are_mutually_exclusive() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+            group = "tools"
+
+            [htop]
+            command = "foot -e htop"
+            class = "htop"
+            group = "tools"
+
+            [music]
+            command = "spotify"
+            class = "spotify"
+            group = "tools"
+            excludes = ["notes"]
+
+            [notes]
+            command = "obsidian"
+            class = "obsidian"
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        let excludes = |name: &str| {
+            let mut excludes = plugin.validated_configs[name].excludes.clone();
+            excludes.sort();
+            excludes
+        };
+        assert_eq!(excludes("term"), vec!["htop", "music"]);
+        assert_eq!(excludes("htop"), vec!["music", "term"]);
+        assert_eq!(excludes("music"), vec!["htop", "notes", "term"]);
+        assert!(excludes("notes").is_empty());
+
+        for (name, visible) in [("term", true), ("htop", false), ("notes", true)] {
+            let mut state = ScratchpadState::default();
+            state.windows.push(WindowState {
+                address: format!("0x{name}"),
+                is_visible: visible,
+                last_position: None,
+                monitor: None,
+                workspace: None,
+                last_focus: None,
+            });
+            plugin.states.insert(name.to_string(), state);
+        }
+
+        // Only visible group-mates (and explicit excludes) are hidden
+        let music = plugin.validated_configs["music"].clone();
+        assert_eq!(
+            plugin.scratchpads_to_exclude("music", &music),
+            vec!["notes", "term"]
+        );
+        let htop = plugin.validated_configs["htop"].clone();
+        assert_eq!(plugin.scratchpads_to_exclude("htop", &htop), vec!["term"]);
+        let term = plugin.validated_configs["term"].clone();
+        assert!(plugin.scratchpads_to_exclude("term", &term).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_restore_excluded_reshows_only_previously_visible() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+
+            [music]
+            command = "spotify"
+            class = "spotify"
+
+            [notes]
+            command = "obsidian"
+            class = "obsidian"
+            excludes = "*"
+            restore_excluded = true
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        let window = |name: &str, visible: bool| WindowState {
+            address: format!("0x{name}"),
+            is_visible: visible,
+            last_position: None,
+            monitor: None,
+            workspace: None,
+            last_focus: None,
+        };
+        for (name, visible) in [("term", true), ("music", false)] {
+            let mut state = ScratchpadState::default();
+            state.is_spawned = true;
+            state.windows.push(window(name, visible));
+            plugin.states.insert(name.to_string(), state);
+        }
+
+        // Showing notes hides term, the only visible one; music was already hidden
+        let notes = plugin.validated_configs["notes"].clone();
+        assert_eq!(plugin.scratchpads_to_exclude("notes", &notes), vec!["term"]);
+        let term = plugin.states.get_mut("term").unwrap();
+        term.windows[0].is_visible = false;
+        term.excluded_by.insert("notes".to_string());
+
+        // Hiding notes brings term back, and only term
+        assert_eq!(plugin.take_excluded_to_restore("notes"), vec!["term"]);
+        assert!(plugin.states["term"].excluded_by.is_empty());
+        assert!(plugin.take_excluded_to_restore("notes").is_empty());
+
+        // A scratchpad closed while excluded is not resurrected
+        let term = plugin.states.get_mut("term").unwrap();
+        term.windows.clear();
+        term.excluded_by.insert("notes".to_string());
+        assert!(plugin.take_excluded_to_restore("notes").is_empty());
+        assert!(plugin.states["term"].excluded_by.is_empty());
+    }
+
+    #[test]
+    fn test_spring_settle_epsilon() {
+        let frame = Duration::from_millis(16);
+        let run = |config: &ValidatedConfig| {
+            let mut spring = config.spring_animation();
+            while spring.next_frame(frame).is_some() {}
+            assert!(spring.is_settled(), "underdamped spring hit the cap");
+            spring.elapsed()
+        };
+
+        let mut config = ValidatedConfig {
+            animation_easing: Some("spring".to_string()),
+            spring_stiffness: Some(300.0),
+            spring_damping: Some(8.0),
+            ..Default::default()
+        };
+        let strict = run(&config);
+        config.spring_settle_epsilon = Some(0.05);
+        let loose = run(&config);
+        assert!(loose < strict);
+        assert!(strict < crate::animation::spring::MAX_SPRING_DURATION);
+
+        // Out-of-range thresholds fall back to the default
+        let mut configs = HashMap::new();
+        configs.insert(
+            "term".to_string(),
+            Arc::new(ScratchpadConfig {
+                command: "foot".to_string(),
+                spring_settle_epsilon: Some(0.0),
+                ..Default::default()
+            }),
+        );
+        let validated = ConfigValidator::validate_configs(&configs, &[], &HashMap::new());
+        assert_eq!(validated["term"].spring_settle_epsilon, None);
+        assert!(validated["term"]
+            .validation_warnings
+            .iter()
+            .any(|w| w.starts_with("Spring settle epsilon")));
+    }
+
+    #[test]
+    fn test_hide_uses_the_show_spring_and_its_settle_time() {
+        let config = ValidatedConfig {
+            animation_easing: Some("spring".to_string()),
+            animation_duration: Some(300),
+            spring_stiffness: Some(300.0),
+            spring_damping: Some(8.0),
+            spring_mass: Some(3.0),
+            spring_settle_epsilon: Some(0.01),
+            ..Default::default()
+        };
+        let spring = config.spring_config().unwrap();
+        assert_eq!(spring.mass, 3.0);
+        assert_eq!(spring.settle_epsilon, 0.01);
+
+        // A heavy spring outlasts `animation_duration`: the stash waits for it
+        let settle = config.spring_settle_time();
+        assert!(settle > Duration::from_millis(300), "settled in {settle:?}");
+        assert!(settle < crate::animation::spring::MAX_SPRING_DURATION);
+
+        let eased = ValidatedConfig {
+            animation_easing: Some("ease-out".to_string()),
+            ..config
+        };
+        assert!(eased.spring_config().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_force_monitor_by_name_index_or_current() {
+        use crate::ipc::mock::{monitor, MockHyprland};
+
+        let mut laptop = monitor("eDP-1", (1920, 1200), false, 1);
+        laptop.id = 1;
+        let api = Arc::new(MockHyprland::new(
+            vec![],
+            vec![monitor("DP-1", (2560, 1440), true, 3), laptop],
+        ));
+        let plugin = ScratchpadsPlugin::new();
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+
+        let target = |force_monitor: &str| ValidatedConfig {
+            force_monitor: Some(force_monitor.to_string()),
+            ..Default::default()
+        };
+        for (force_monitor, expected) in [
+            ("eDP-1", "eDP-1"),
+            ("1", "eDP-1"),
+            ("0", "DP-1"),
+            ("current", "DP-1"),
+            ("7", "DP-1"),
+        ] {
+            let monitor = plugin
+                .get_target_monitor(&target(force_monitor))
+                .await
+                .unwrap();
+            assert_eq!(monitor.name, expected, "force_monitor = {force_monitor}");
+        }
+
+        // Unknown names and indices are flagged when validating, "current" is not
+        let monitors = plugin.get_monitors().await.unwrap();
+        let all_configs = HashMap::new();
+        let warnings = |force_monitor: &str| {
+            let mut config = target(force_monitor);
+            config.command = "foot".to_string();
+            ConfigValidator::validate_config("term", &mut config, &monitors, &all_configs);
+            config.validation_warnings
+        };
+        assert!(warnings("1").is_empty());
+        assert!(warnings("current").is_empty());
+        assert!(warnings("3")
+            .iter()
+            .any(|w| w.contains("index 3 is out of range")));
+        assert!(warnings("HDMI-A-1")
+            .iter()
+            .any(|w| w.contains("'HDMI-A-1' not found")));
+
+        // An integer in TOML is an index too
+        let config: toml::Value = toml::from_str("force_monitor = 1").unwrap();
+        let parsed = ScratchpadsPlugin::parse_scratchpad_config(config.as_table().unwrap());
+        assert_eq!(parsed.force_monitor.as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn test_export_snippet_round_trips() {
+        let laptop = MonitorInfo {
+            name: "eDP-1".to_string(),
+            x: 1920,
+            reserved_top: 30,
+            ..create_test_monitor()
+        };
+        let monitors = vec![create_test_monitor(), laptop.clone()];
+        let config = ValidatedConfig {
+            force_monitor: Some("DP-1".to_string()),
+            ..Default::default()
+        };
+
+        // Window was dragged onto the laptop screen, below its bar
+        let geometry = (1920 + 100, 30 + 50, 900, 600);
+        let monitor = ScratchpadsPlugin::monitor_at(&monitors, geometry).unwrap();
+        assert_eq!(monitor.name, "eDP-1");
+
+        let snippet =
+            ScratchpadsPlugin::export_snippet("term", &config, geometry, monitor).unwrap();
+        let parsed: toml::Value = toml::from_str(&snippet).unwrap();
+        let term = &parsed["scratchpads"]["term"];
+        assert_eq!(term["size"].as_str(), Some("900px 600px"));
+        assert_eq!(term["position"].as_str(), Some("100px 50px"));
+        assert_eq!(term["force_monitor"].as_str(), Some("eDP-1"));
+
+        // Feeding the snippet back reproduces the window's geometry
+        let restored = ValidatedConfig {
+            size: term["size"].as_str().unwrap().to_string(),
+            position: term["position"].as_str().map(str::to_string),
+            ..Default::default()
+        };
+        let result = GeometryCalculator::calculate_geometry(&restored, &laptop).unwrap();
+        assert_eq!((result.x, result.y, result.width, result.height), geometry);
+
+        // Without force_monitor the snippet stays monitor-agnostic
+        let snippet = ScratchpadsPlugin::export_snippet(
+            "term",
+            &ValidatedConfig::default(),
+            geometry,
+            monitor,
         )
-        .unwrap()
-    }
+        .unwrap();
+        assert!(!snippet.contains("force_monitor"));
 
-    fn create_test_monitor() -> MonitorInfo {
-        MonitorInfo {
-            id: 0,
-            name: "DP-1".to_string(),
-            width: 1920,
-            height: 1080,
-            x: 0,
-            y: 0,
-            scale: 1.0,
-            is_focused: true,
-            active_workspace_id: 1,
-            refresh_rate: 60.0,
-        }
+        // Offset-based scratchpads keep using offset, net of their margin
+        let offset_config = ValidatedConfig {
+            offset: Some("10px 10px".to_string()),
+            margin: Some(20),
+            ..Default::default()
+        };
+        let snippet =
+            ScratchpadsPlugin::export_snippet("term", &offset_config, geometry, monitor).unwrap();
+        let parsed: toml::Value = toml::from_str(&snippet).unwrap();
+        let term = &parsed["scratchpads"]["term"];
+        assert_eq!(term["offset"].as_str(), Some("80px 30px"));
+        assert!(term.get("position").is_none());
+        let restored = ValidatedConfig {
+            size: term["size"].as_str().unwrap().to_string(),
+            offset: term["offset"].as_str().map(str::to_string),
+            margin: Some(20),
+            ..Default::default()
+        };
+        let result = GeometryCalculator::calculate_geometry(&restored, &laptop).unwrap();
+        assert_eq!((result.x, result.y), (geometry.0, geometry.1));
     }
 
-    #[tokio::test]
-    async fn test_plugin_initialization() {
-        let mut plugin = ScratchpadsPlugin::new();
-        let config = create_test_config();
-        plugin.init(&config).await.unwrap();
+    #[test]
+    fn test_geometry_respects_reserved_top() {
+        let monitor = MonitorInfo {
+            reserved_top: 40,
+            ..create_test_monitor()
+        };
+        let mut config = ValidatedConfig {
+            size: "800px 600px".to_string(),
+            position: Some("0px 0px".to_string()),
+            ..Default::default()
+        };
 
-        // Should have loaded 2 scratchpads (term and browser)
-        assert_eq!(plugin.scratchpads.len(), 2);
+        // Top-anchored scratchpad sits right below the bar
+        let geometry = GeometryCalculator::calculate_geometry(&config, &monitor).unwrap();
+        assert_eq!((geometry.x, geometry.y), (0, 40));
 
-        // Check term scratchpad config
-        let term_config = plugin.scratchpads.get("term").unwrap();
-        assert_eq!(term_config.command, "foot --app-id=term");
-        assert_eq!(term_config.class, Some("foot".to_string()));
-        assert_eq!(term_config.size, "75% 60%");
-        assert!(!term_config.lazy);
-        assert!(term_config.pinned);
+        // Centering uses the remaining 1040px of height
+        config.position = None;
+        let geometry = GeometryCalculator::calculate_geometry(&config, &monitor).unwrap();
+        assert_eq!(geometry.y, 40 + (1040 - 600) / 2);
 
-        // Check browser scratchpad config
-        let browser_config = plugin.scratchpads.get("browser").unwrap();
-        assert_eq!(browser_config.command, "firefox --new-window");
-        assert_eq!(browser_config.class, Some("firefox".to_string()));
-        assert!(browser_config.lazy);
-        assert_eq!(browser_config.excludes, vec!["term"]);
+        // A full-height window is clamped below the bar, not behind it
+        config.size = "800px 1080px".to_string();
+        let geometry = GeometryCalculator::calculate_geometry(&config, &monitor).unwrap();
+        assert_eq!(geometry.y, 40);
+    }
 
-        // Check variables
-        // Note: This test would need to be async to properly test Arc<RwLock<HashMap>>
-        // For now, we'll test that the structure exists
-        assert!(!plugin.scratchpads.is_empty());
+    #[test]
+    fn test_geometry_ignores_reserved_when_disabled() {
+        let monitor = MonitorInfo {
+            reserved_top: 40,
+            ..create_test_monitor()
+        };
+        let config = ValidatedConfig {
+            size: "800px 600px".to_string(),
+            position: Some("0px 0px".to_string()),
+            respect_reserved: false,
+            ..Default::default()
+        };
 
-        // Check validated configs were created
-        assert_eq!(plugin.validated_configs.len(), 2);
+        let geometry = GeometryCalculator::calculate_geometry(&config, &monitor).unwrap();
+        assert_eq!((geometry.x, geometry.y), (0, 0));
+        assert_eq!(
+            GeometryCalculator::usable_area(&config, &monitor),
+            (0, 0, 1920, 1080)
+        );
     }
 
     #[test]
@@ -3961,63 +8572,514 @@ mod tests {
             GeometryCalculator::parse_size("90% 90%", &monitor, Some("1600px 900px")).unwrap();
         assert_eq!(width, 1600); // Constrained by max_size
         assert_eq!(height, 900); // Constrained by max_size
+
+        // preserve_aspect shrinks whichever dimension is too long for the ratio
+        assert_eq!(
+            GeometryCalculator::fit_aspect((1600, 900), (800, 600)),
+            (1200, 900)
+        );
+        assert_eq!(
+            GeometryCalculator::fit_aspect((600, 900), (800, 600)),
+            (600, 450)
+        );
+        assert_eq!(
+            GeometryCalculator::fit_aspect((600, 900), (0, 0)),
+            (600, 900)
+        );
+    }
+
+    #[test]
+    fn test_dimension_parsing() {
+        assert_eq!(
+            GeometryCalculator::parse_dimension("50%", 1920).unwrap(),
+            960
+        );
+        assert_eq!(
+            GeometryCalculator::parse_dimension("75%", 1080).unwrap(),
+            810
+        );
+
+        assert_eq!(
+            GeometryCalculator::parse_dimension("800px", 1920).unwrap(),
+            800
+        );
+        assert_eq!(
+            GeometryCalculator::parse_dimension("600", 1080).unwrap(),
+            600
+        );
+
+        assert!(GeometryCalculator::parse_dimension("invalid", 1920).is_err());
+        assert!(GeometryCalculator::parse_dimension("200%px", 1920).is_err());
+    }
+
+    #[test]
+    fn test_offset_parsing() {
+        let monitor = create_test_monitor();
+
+        let (x, y) = GeometryCalculator::parse_offset(Some("50px 100px"), &monitor).unwrap();
+        assert_eq!(x, 50);
+        assert_eq!(y, 100);
+
+        let (x, y) = GeometryCalculator::parse_offset(Some("10% 20%"), &monitor).unwrap();
+        assert_eq!(x, 192); // 10% of 1920
+        assert_eq!(y, 216); // 20% of 1080
+
+        let (x, y) = GeometryCalculator::parse_offset(None, &monitor).unwrap();
+        assert_eq!(x, 0);
+        assert_eq!(y, 0);
+    }
+
+    #[tokio::test]
+    async fn test_variable_expansion() {
+        let plugin = ScratchpadsPlugin::new();
+        let mut variables = HashMap::new();
+        variables.insert("term_class".to_string(), "foot".to_string());
+
+        let expanded = plugin.expand_command("foot --app-id=[term_class]", &variables);
+        assert_eq!(expanded, "foot --app-id=foot");
+
+        let expanded = plugin.expand_command("echo [missing_var]", &variables);
+        assert_eq!(expanded, "echo [missing_var]"); // Should not expand missing variables
+
+        let expanded = plugin.expand_command("no variables here", &variables);
+        assert_eq!(expanded, "no variables here");
+    }
+
+    #[tokio::test]
+    async fn test_close_on_hide_config_parsing() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot --app-id=term"
+            class = "foot"
+            close_on_hide = true
+            kill_process_group = true
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        let term = plugin.get_validated_config("term").unwrap();
+        assert!(term.close_on_hide);
+        assert!(term.kill_process_group);
+    }
+
+    #[tokio::test]
+    async fn test_command_health_check() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("rustrland-doctor-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let executable = dir.join("fake-term");
+        std::fs::write(&executable, "#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&executable, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let plain_file = dir.join("notes");
+        std::fs::write(&plain_file, "").unwrap();
+
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(&format!(
+            r#"
+            [variables]
+            term = "fake-term"
+
+            [expanded]
+            command = "[term] --class expanded"
+            class = "expanded"
+
+            [with_env]
+            command = "GDK_BACKEND=wayland fake-term"
+            class = "with_env"
+
+            [absolute]
+            command = "{}"
+            class = "absolute"
+
+            [not_executable]
+            command = "{} --open"
+            class = "not_executable"
+
+            [missing]
+            command = "no-such-binary --flag"
+            class = "missing"
+        "#,
+            executable.display(),
+            plain_file.display()
+        ))
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        let variables = plugin.variables.read().await.clone();
+        let report = plugin.check_commands(&variables, dir.as_os_str());
+        let names: Vec<&str> = report.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "absolute",
+                "expanded",
+                "missing",
+                "not_executable",
+                "with_env"
+            ]
+        );
+
+        let health: HashMap<_, _> = report.into_iter().collect();
+        assert_eq!(health["expanded"], Ok(executable.clone()));
+        assert_eq!(health["with_env"], Ok(executable.clone()));
+        assert_eq!(health["absolute"], Ok(executable.clone()));
+        assert_eq!(
+            health["not_executable"],
+            Err(plain_file.display().to_string())
+        );
+        assert_eq!(health["missing"], Err("no-such-binary".to_string()));
+
+        let output = ScratchpadsPlugin::format_command_health(&[(
+            "missing".to_string(),
+            Err("no-such-binary".to_string()),
+        )]);
+        assert_eq!(
+            output,
+            "missing: missing 'no-such-binary' (not found on PATH)"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_does_not_signal_process_group() {
+        use crate::ipc::mock::MockHyprland;
+        use crate::ipc::DryRunHyprland;
+
+        let mut child = tokio::process::Command::new("sleep")
+            .arg("30")
+            .process_group(0)
+            .spawn()
+            .unwrap();
+        let pgid = child.id().unwrap() as i32;
+        let client = HyprlandClient::with_api(Arc::new(DryRunHyprland::new(Arc::new(
+            MockHyprland::new(vec![], vec![]),
+        ))));
+
+        ScratchpadsPlugin::terminate_process_group(&client, pgid)
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(
+            child.try_wait().unwrap().is_none(),
+            "dry run killed the group"
+        );
+        child.kill().await.unwrap();
     }
 
-    #[test]
-    fn test_dimension_parsing() {
+    #[test]
+    fn test_parse_process_group() {
+        let stat = "4242 (foot) S 1 4240 4240 0 -1 4194560 1203 0 0 0";
+        assert_eq!(ScratchpadsPlugin::parse_process_group(stat), Some(4240));
+
+        // Command names may contain spaces and parentheses
+        let stat = "77 (my (weird) app) R 12 70 70 0 -1";
+        assert_eq!(ScratchpadsPlugin::parse_process_group(stat), Some(70));
+
+        assert_eq!(ScratchpadsPlugin::parse_process_group("garbage"), None);
+    }
+
+    #[tokio::test]
+    async fn test_rapid_toggles_during_animation() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+            animation = "fromTop"
+
+            [music]
+            command = "spotify"
+            class = "spotify"
+            animation = "fromTop"
+            interrupt = "ignore"
+
+            [notes]
+            command = "obsidian"
+            class = "obsidian"
+            debounce_ms = 100
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+
+        let start = Instant::now();
+        let second = start + Duration::from_millis(50);
+        let target = (0, -700, 800, 600);
+
+        // Default policy: the second toggle cancels the running hide animation
+        assert_eq!(plugin.check_toggle("term", start), ToggleDecision::Proceed);
+        plugin.track_animation("term", "0x1", Duration::from_millis(300), target);
+        assert_eq!(
+            plugin.check_toggle("term", second),
+            ToggleDecision::CancelAndProceed
+        );
+
+        // "ignore" policy drops it instead
+        assert_eq!(plugin.check_toggle("music", start), ToggleDecision::Proceed);
+        plugin.track_animation("music", "0x2", Duration::from_millis(300), target);
+        assert_eq!(plugin.check_toggle("music", second), ToggleDecision::Ignore);
+
+        // Debounce applies even without any animation
+        assert_eq!(plugin.check_toggle("notes", start), ToggleDecision::Proceed);
+        assert_eq!(plugin.check_toggle("notes", second), ToggleDecision::Ignore);
+        assert_eq!(
+            plugin.check_toggle("notes", start + Duration::from_millis(150)),
+            ToggleDecision::Proceed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_and_cancel_pending_timers() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let now = Instant::now();
+        let sleeper = || tokio::spawn(tokio::time::sleep(Duration::from_secs(60)));
+
+        plugin.hysteresis_tasks.insert(
+            "term".to_string(),
+            PendingTimer {
+                handle: sleeper(),
+                deadline: now + Duration::from_millis(400),
+            },
+        );
+        plugin
+            .window_to_scratchpad
+            .insert("0xabc".to_string(), "browser".to_string());
+        plugin.hide_tasks.insert(
+            "0xabc".to_string(),
+            PendingTimer {
+                handle: sleeper(),
+                deadline: now + Duration::from_secs(2),
+            },
+        );
+
+        let timers = plugin.pending_timers(now);
+        assert_eq!(timers.len(), 2);
+        assert_eq!(
+            timers[0],
+            ("term".to_string(), "hysteresis", Duration::from_millis(400))
+        );
+        assert_eq!(timers[1].0, "browser");
+        assert_eq!(timers[1].1, "hide");
+
+        assert_eq!(plugin.cancel_timers(Some("browser")).await, 1);
+        assert_eq!(plugin.pending_timers(now).len(), 1);
+        assert_eq!(plugin.cancel_timers(None).await, 1);
+        assert!(plugin.pending_timers(now).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_refocus_cancels_hysteresis_hide() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+            unfocus = "hide"
+            hysteresis = 0.05
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+        plugin
+            .window_to_scratchpad
+            .insert("0x1".to_string(), "term".to_string());
+
+        // focus -> unfocus -> focus within the hysteresis: nothing is hidden
+        plugin.handle_focus_changed("0x1").await;
+        plugin.handle_focus_changed("0x2").await;
+        assert_eq!(plugin.hysteresis_tasks.len(), 1);
+        plugin.handle_focus_changed("0x1").await;
+        assert!(plugin.hysteresis_tasks.is_empty());
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let receiver = plugin.internal_receiver.as_mut().unwrap();
+        assert!(receiver.try_recv().is_err(), "no SimpleHide expected");
+
+        // Leaving again restarts a full-length timer, and rescheduling keeps one
+        plugin.handle_focus_changed("0x2").await;
+        plugin.schedule_simple_hide("term".to_string(), 0.05).await;
+        assert_eq!(plugin.hysteresis_tasks.len(), 1);
+        let remaining = plugin.hysteresis_tasks["term"].deadline - Instant::now();
+        assert!(remaining > Duration::from_millis(40));
+
+        // A hide already sent when focus comes back is stale
+        plugin.schedule_simple_hide("term".to_string(), 0.0).await;
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        plugin.handle_focus_changed("0x1").await;
+        assert!(!plugin.take_due_hide("term", Instant::now()));
+
+        // ...while an undisturbed one is due exactly once
+        plugin.schedule_simple_hide("term".to_string(), 0.0).await;
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(plugin.take_due_hide("term", Instant::now()));
+        assert!(!plugin.take_due_hide("term", Instant::now()));
+    }
+
+    #[tokio::test]
+    async fn test_unfocus_and_command_hides_do_not_double_hide() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [term]
+            command = "foot"
+            class = "foot"
+            unfocus = "hide"
+            hysteresis = 0.02
+            hide_delay = 30
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+        assert!(plugin.validated_configs["term"]
+            .validation_warnings
+            .iter()
+            .any(|w| w.starts_with("hide_delay applies to hide commands only")));
+
+        let mut state = ScratchpadState::default();
+        state.is_spawned = true;
+        state.windows.push(WindowState {
+            address: "0x1".to_string(),
+            is_visible: true,
+            last_position: None,
+            monitor: None,
+            workspace: None,
+            last_focus: None,
+        });
+        plugin.states.insert("term".to_string(), state);
+
+        // Focus leaves the scratchpad, then a hide command comes in: only the
+        // command's hide_delay timer is left
+        plugin.schedule_simple_hide("term".to_string(), 0.02).await;
         assert_eq!(
-            GeometryCalculator::parse_dimension("50%", 1920).unwrap(),
-            960
+            plugin.handle_command("hide", &["term"]).await.unwrap(),
+            "Scratchpad 'term' hiding in 30ms"
         );
+        assert!(plugin.hysteresis_tasks.is_empty());
+        assert_eq!(plugin.hide_tasks.len(), 1);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let receiver = plugin.internal_receiver.as_mut().unwrap();
+        let mut hides = 0;
+        while let Ok(command) = receiver.try_recv() {
+            assert!(matches!(command, InternalCommand::SimpleHide { .. }));
+            hides += 1;
+        }
+        assert_eq!(hides, 1);
+
+        // A hide arriving for an already hidden scratchpad does nothing
+        plugin.states.get_mut("term").unwrap().windows[0].is_visible = false;
         assert_eq!(
-            GeometryCalculator::parse_dimension("75%", 1080).unwrap(),
-            810
+            plugin.hide_scratchpad_and_restore("term").await.unwrap(),
+            "Scratchpad 'term' already hidden"
         );
+    }
+
+    #[tokio::test]
+    async fn test_default_scratchpad() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            default = "term"
+
+            [term]
+            command = "foot"
+            class = "foot"
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
 
+        assert!(!plugin.scratchpads.contains_key("default"));
         assert_eq!(
-            GeometryCalculator::parse_dimension("800px", 1920).unwrap(),
-            800
+            plugin.resolve_scratchpad_name(&[]),
+            Some("term".to_string())
         );
         assert_eq!(
-            GeometryCalculator::parse_dimension("600", 1080).unwrap(),
-            600
+            plugin.resolve_scratchpad_name(&[""]),
+            Some("term".to_string())
+        );
+        assert_eq!(
+            plugin.resolve_scratchpad_name(&["other"]),
+            Some("other".to_string())
         );
 
-        assert!(GeometryCalculator::parse_dimension("invalid", 1920).is_err());
-        assert!(GeometryCalculator::parse_dimension("200%px", 1920).is_err());
-    }
-
-    #[test]
-    fn test_offset_parsing() {
-        let monitor = create_test_monitor();
+        // Unknown defaults are dropped and a missing name stays an error
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            default = "missing"
 
-        let (x, y) = GeometryCalculator::parse_offset(Some("50px 100px"), &monitor).unwrap();
-        assert_eq!(x, 50);
-        assert_eq!(y, 100);
+            [term]
+            command = "foot"
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+        assert!(plugin.default_scratchpad.is_none());
+        assert!(plugin.handle_command("toggle", &[]).await.is_err());
 
-        let (x, y) = GeometryCalculator::parse_offset(Some("10% 20%"), &monitor).unwrap();
-        assert_eq!(x, 192); // 10% of 1920
-        assert_eq!(y, 216); // 20% of 1080
+        // A scratchpad named like a reserved setting is kept, and reported
+        let mut plugin = ScratchpadsPlugin::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+            [default]
+            command = "foot"
 
-        let (x, y) = GeometryCalculator::parse_offset(None, &monitor).unwrap();
-        assert_eq!(x, 0);
-        assert_eq!(y, 0);
+            [batch_dispatch]
+            command = "kitty"
+        "#,
+        )
+        .unwrap();
+        plugin.init(&config).await.unwrap();
+        assert!(plugin.scratchpads.contains_key("default"));
+        assert!(plugin.scratchpads.contains_key("batch_dispatch"));
+        assert!(plugin.default_scratchpad.is_none());
+        let report = plugin.validate_config(&config);
+        for name in ["default", "batch_dispatch"] {
+            assert!(
+                report
+                    .errors
+                    .iter()
+                    .any(|e| e.key == name && e.message.contains("reserved")),
+                "{:?}",
+                report.errors
+            );
+        }
     }
 
     #[tokio::test]
-    async fn test_variable_expansion() {
-        let plugin = ScratchpadsPlugin::new();
-        let mut variables = HashMap::new();
-        variables.insert("term_class".to_string(), "foot".to_string());
+    async fn test_unknown_command_is_usage() {
+        use crate::plugins::CommandOutcome;
 
-        let expanded = plugin.expand_command("foot --app-id=[term_class]", &variables);
-        assert_eq!(expanded, "foot --app-id=foot");
+        let mut plugin = ScratchpadsPlugin::new();
+        match CommandOutcome::from(plugin.handle_command("frobnicate", &[]).await) {
+            CommandOutcome::Usage(text) => {
+                assert!(text.contains("toggle|show|hide|list|attach|cycle"))
+            }
+            other => panic!("expected usage, got {other:?}"),
+        }
 
-        let expanded = plugin.expand_command("echo [missing_var]", &variables);
-        assert_eq!(expanded, "echo [missing_var]"); // Should not expand missing variables
+        // Everything the usage text offers is dispatched
+        for command in SCRATCHPADS_SUBCOMMANDS {
+            if let CommandOutcome::Usage(text) =
+                CommandOutcome::from(plugin.handle_command(command, &["missing"]).await)
+            {
+                assert!(!text.contains("is not a command"), "{command}: {text}");
+            }
+        }
 
-        let expanded = plugin.expand_command("no variables here", &variables);
-        assert_eq!(expanded, "no variables here");
+        // Other failures stay errors
+        assert!(matches!(
+            CommandOutcome::from(plugin.handle_command("toggle", &["missing"]).await),
+            CommandOutcome::Error(_)
+        ));
     }
 
     #[test]
@@ -4037,6 +9099,7 @@ mod tests {
         assert!(!config.allow_special_workspaces);
         assert!(config.smart_focus);
         assert!(!config.close_on_hide);
+        assert!(!config.kill_process_group);
         assert!(config.unfocus.is_none());
         assert!(config.max_size.is_none());
         assert!(config.r#use.is_none());
@@ -4073,6 +9136,164 @@ mod tests {
         assert!(term_config.parsed_size.is_some());
     }
 
+    #[test]
+    fn test_invalid_position_is_rejected_and_centered() {
+        let monitor = create_test_monitor();
+        let mut configs = HashMap::new();
+        configs.insert(
+            "term".to_string(),
+            Arc::new(ScratchpadConfig {
+                command: "foot".to_string(),
+                class: Some("foot".to_string()),
+                size: "800px 600px".to_string(),
+                offset: Some("100px 100px".to_string()),
+                position: Some("top-ish left".to_string()),
+                ..Default::default()
+            }),
+        );
+
+        // Flagged at load, with or without monitor information
+        for monitors in [vec![monitor.clone()], Vec::new()] {
+            let validated = ConfigValidator::validate_configs(&configs, &monitors, &HashMap::new());
+            let term = validated.get("term").unwrap();
+            assert!(term.parsed_position.is_none());
+            assert!(term
+                .validation_errors
+                .iter()
+                .any(|e| e.starts_with("Invalid position format")));
+        }
+
+        // At show time the window is centered rather than placed by offset
+        let monitors = vec![monitor.clone()];
+        let validated = ConfigValidator::validate_configs(&configs, &monitors, &HashMap::new());
+        let geometry =
+            GeometryCalculator::calculate_geometry(validated.get("term").unwrap(), &monitor)
+                .unwrap();
+        assert_eq!((geometry.x, geometry.y), (560, 240));
+    }
+
+    #[test]
+    fn test_geometry_report_per_monitor() {
+        let laptop = create_test_monitor();
+        let wide = MonitorInfo {
+            name: "HDMI-A-1".to_string(),
+            width: 2560,
+            height: 1440,
+            x: 1920,
+            ..create_test_monitor()
+        };
+        let mut configs = HashMap::new();
+        configs.insert(
+            "term".to_string(),
+            Arc::new(ScratchpadConfig {
+                command: "foot".to_string(),
+                class: Some("foot".to_string()),
+                size: "800px 600px".to_string(),
+                position: Some("1500px 100px".to_string()),
+                ..Default::default()
+            }),
+        );
+        let validated = ConfigValidator::validate_configs(&configs, &[], &HashMap::new());
+
+        let report = ScratchpadsPlugin::geometry_report(&validated["term"], &[laptop, wide]);
+        assert_eq!(
+            report,
+            serde_json::json!([
+                { "monitor": "DP-1", "x": 1120, "y": 100, "width": 800, "height": 600, "clamped": true },
+                { "monitor": "HDMI-A-1", "x": 3420, "y": 100, "width": 800, "height": 600, "clamped": false },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_relative_resize_clamps_and_stays_on_screen() {
+        let monitor = create_test_monitor();
+        let mut configs = HashMap::new();
+        configs.insert(
+            "term".to_string(),
+            Arc::new(ScratchpadConfig {
+                command: "foot".to_string(),
+                class: Some("foot".to_string()),
+                max_size: Some("1000px 1000px".to_string()),
+                ..Default::default()
+            }),
+        );
+        let validated = ConfigValidator::validate_configs(&configs, &[], &HashMap::new());
+        let resize = |current, width, height| {
+            GeometryCalculator::resize_geometry(
+                &validated["term"],
+                &monitor,
+                current,
+                (width, height),
+            )
+        };
+        let at_100 = (100, 100, 800, 600);
+
+        assert_eq!(resize(at_100, "+100", "-50").unwrap(), (100, 100, 900, 550));
+        // Percentages are of the monitor (1920x1080)
+        assert_eq!(
+            resize(at_100, "+10%", "+10%").unwrap(),
+            (100, 100, 992, 708)
+        );
+        assert_eq!(
+            resize(at_100, "+500px", "50%").unwrap(),
+            (100, 100, 1000, 540)
+        );
+        // Growing past the right edge moves the window back on-screen
+        assert_eq!(
+            resize((1500, 100, 400, 300), "+200", "+0").unwrap(),
+            (1320, 100, 600, 300)
+        );
+
+        assert!(resize(at_100, "-800", "+0").is_err());
+        assert!(resize(at_100, "0", "600").is_err());
+        assert!(resize(at_100, "+abc", "+0").is_err());
+        assert!(resize(at_100, "++5", "+0").is_err());
+    }
+
+    #[test]
+    fn test_position_anchors_respect_margin() {
+        let mut monitor = create_test_monitor();
+        monitor.reserved_top = 30;
+        let validate = |position: &str| {
+            let mut configs = HashMap::new();
+            configs.insert(
+                "term".to_string(),
+                Arc::new(ScratchpadConfig {
+                    command: "foot".to_string(),
+                    class: Some("foot".to_string()),
+                    size: "800px 600px".to_string(),
+                    margin: Some(20),
+                    position: Some(position.to_string()),
+                    ..Default::default()
+                }),
+            );
+            let validated =
+                ConfigValidator::validate_configs(&configs, &[monitor.clone()], &HashMap::new());
+            validated.get("term").unwrap().clone()
+        };
+
+        let placed = |position: &str| {
+            let config = validate(position);
+            assert!(config.validation_errors.is_empty(), "{position}");
+            let geometry = GeometryCalculator::calculate_geometry(&config, &monitor).unwrap();
+            (geometry.x, geometry.y)
+        };
+        // Margin insets from the anchored edges only; the bar is left out of the area
+        assert_eq!(placed("top-left"), (20, 50));
+        assert_eq!(placed("top-center"), (560, 50));
+        assert_eq!(placed("center"), (560, 255));
+        assert_eq!(placed("bottom-right"), (1100, 460));
+        assert_eq!(validate("bottom_right").parsed_position, Some((1100, 430)));
+
+        let unknown = validate("middle");
+        assert!(unknown.parsed_anchor.is_none());
+        assert_eq!(
+            unknown.validation_errors,
+            vec!["Invalid position format: unknown anchor 'middle'"]
+        );
+    }
+
     // ============================================================================
     // TESTS FOR ENHANCED FUNCTIONALITY
     // ============================================================================
@@ -4279,6 +9500,113 @@ mod tests {
         assert_eq!(stats.connection_failures, 0);
     }
 
+    #[tokio::test]
+    async fn test_set_context_shares_services() {
+        let context = Arc::new(PluginContext {
+            hyprland_client: Arc::new(HyprlandClient::new().await.unwrap()),
+            animation_engine: Arc::new(Mutex::new(crate::animation::AnimationEngine::new())),
+            events: tokio::sync::broadcast::channel(4).0,
+            global_cache: Arc::new(crate::core::GlobalStateCache::new()),
+            variables: HashMap::from([("term_class".to_string(), "foot".to_string())]),
+            animation_presets: HashMap::from([(
+                "snappy".to_string(),
+                AnimationPreset {
+                    duration: Some(150),
+                    ..Default::default()
+                },
+            )]),
+        });
+
+        let mut plugin = ScratchpadsPlugin::new();
+        plugin.set_context(Arc::clone(&context)).await;
+
+        assert!(plugin.get_hyprland_client().await.is_ok());
+        assert_eq!(plugin.variables.read().await["term_class"], "foot");
+        assert_eq!(plugin.animation_presets["snappy"].duration, Some(150));
+        let animator = plugin.window_animator.lock().await;
+        assert!(Arc::ptr_eq(
+            &animator.animation_engine,
+            &context.animation_engine
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_is_visible_reports_boolean() {
+        let mut plugin = ScratchpadsPlugin::new();
+        plugin.init(&create_test_config()).await.unwrap();
+
+        assert_eq!(
+            plugin
+                .handle_command("is-visible", &["term"])
+                .await
+                .unwrap(),
+            "false"
+        );
+
+        plugin.mark_window_visible("term", "0x1");
+        assert!(plugin.is_scratchpad_visible("term"));
+        assert_eq!(
+            plugin
+                .handle_command("is-visible", &["term"])
+                .await
+                .unwrap(),
+            "true"
+        );
+
+        assert!(plugin
+            .handle_command("is-visible", &["missing"])
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn test_cycle_wraps_and_prunes_closed_instances() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let mut state = ScratchpadState::default();
+        state.is_spawned = true;
+        for address in ["0x1", "0x2", "0x3"] {
+            state.windows.push(WindowState {
+                address: address.to_string(),
+                is_visible: true,
+                last_position: None,
+                monitor: None,
+                workspace: None,
+                last_focus: None,
+            });
+            plugin
+                .window_to_scratchpad
+                .insert(address.to_string(), "term".to_string());
+        }
+        plugin.states.insert("term".to_string(), state);
+
+        let live: HashSet<String> = ["0x1", "0x2", "0x3"].map(String::from).into();
+        assert_eq!(plugin.next_instance("term", &live).as_deref(), Some("0x1"));
+        assert_eq!(plugin.next_instance("term", &live).as_deref(), Some("0x2"));
+
+        // 0x3 was closed outside rustrland: skipped and forgotten, cycling wraps
+        let live: HashSet<String> = ["0x1", "0x2"].map(String::from).into();
+        assert_eq!(plugin.next_instance("term", &live).as_deref(), Some("0x1"));
+        assert_eq!(plugin.states["term"].windows.len(), 2);
+        assert!(!plugin.window_to_scratchpad.contains_key("0x3"));
+
+        let live = HashSet::new();
+        assert_eq!(plugin.next_instance("term", &live), None);
+        assert!(!plugin.states["term"].is_spawned);
+    }
+
+    #[test]
+    fn test_dump_state_reuses_captured_state() {
+        let mut plugin = ScratchpadsPlugin::new();
+        plugin
+            .states
+            .insert("term".to_string(), ScratchpadState::default());
+
+        let state = plugin.dump_state().unwrap();
+        assert_eq!(state["plugin_name"], "scratchpads");
+        assert!(state["scratchpad_states"].get("term").is_some());
+        assert_eq!(state["active_scratchpads"], 1);
+    }
+
     #[tokio::test]
     async fn test_sync_task_management() {
         let mut plugin = ScratchpadsPlugin::new();
@@ -4294,6 +9622,122 @@ mod tests {
         assert_eq!(task_count, 0);
     }
 
+    #[tokio::test]
+    async fn test_rapid_moves_coalesce_into_single_sync() {
+        let mut plugin = ScratchpadsPlugin::new();
+        let config = create_test_config();
+        plugin.init(&config).await.unwrap();
+
+        let mut state = ScratchpadState::default();
+        state.windows.push(WindowState {
+            address: "0x12345".to_string(),
+            is_visible: true,
+            last_position: Some((100, 100, 800, 600)),
+            monitor: Some("DP-1".to_string()),
+            workspace: Some("1".to_string()),
+            last_focus: None,
+        });
+        plugin.states.insert("term".to_string(), state);
+        plugin
+            .window_to_scratchpad
+            .insert("0x12345".to_string(), "term".to_string());
+
+        for _ in 0..20 {
+            plugin.handle_window_moved("0x12345").await;
+        }
+        // Untracked windows never schedule a sync
+        plugin.handle_window_moved("0xother").await;
+        assert_eq!(plugin.sync_tasks.len(), 1);
+
+        tokio::time::sleep(Duration::from_millis(GEOMETRY_SYNC_DEBOUNCE_MS * 3)).await;
+
+        let receiver = plugin.internal_receiver.as_mut().unwrap();
+        let mut syncs = Vec::new();
+        while let Ok(command) = receiver.try_recv() {
+            if let InternalCommand::SyncGeometry { window_address } = command {
+                syncs.push(window_address);
+            }
+        }
+        assert_eq!(syncs, vec!["0x12345".to_string()]);
+
+        let geometry = WindowGeometry {
+            x: 300,
+            y: 200,
+            width: 800,
+            height: 600,
+            workspace: "1".to_string(),
+            monitor: 0,
+            floating: true,
+        };
+        plugin.record_window_geometry("0x12345", geometry).await;
+
+        assert_eq!(
+            plugin.states["term"].windows[0].last_position,
+            Some((300, 200, 800, 600))
+        );
+        assert!(plugin.geometry_cache.read().await.contains_key("0x12345"));
+    }
+
+    #[test]
+    fn test_reverse_animation_inference() {
+        assert_eq!(ScratchpadsPlugin::hide_animation_type("fromLeft"), "toLeft");
+        assert_eq!(
+            ScratchpadsPlugin::hide_animation_type("from_bottom"),
+            "toBottom"
+        );
+        assert_eq!(ScratchpadsPlugin::hide_animation_type("fade"), "fade");
+        assert_eq!(ScratchpadsPlugin::hide_animation_type("scale"), "scale");
+        assert_eq!(ScratchpadsPlugin::hide_animation_type("spring"), "spring");
+
+        // Unknown directional names only swap the prefix
+        assert_eq!(
+            ScratchpadsPlugin::hide_animation_type("fromCorner"),
+            "toCorner"
+        );
+        assert_eq!(
+            ScratchpadsPlugin::hide_animation_type("from_corner"),
+            "to_corner"
+        );
+
+        // Anything else is returned untouched instead of being mangled
+        assert_eq!(ScratchpadsPlugin::hide_animation_type("wobble"), "wobble");
+        assert_eq!(ScratchpadsPlugin::hide_animation_type("from"), "from");
+        assert_eq!(
+            ScratchpadsPlugin::hide_animation_type("slide_from_left"),
+            "slide_from_left"
+        );
+    }
+
+    #[test]
+    fn test_hide_animation_type_casing() {
+        for show in [
+            "fromTopLeft",
+            "fromtopleft",
+            "FROMTOPLEFT",
+            "from_top_left",
+            "FROM_TOP_LEFT",
+        ] {
+            assert_eq!(ScratchpadsPlugin::hide_animation_type(show), "toTopLeft");
+        }
+
+        for show in ["Fade", "SCALE", "spring"] {
+            assert_eq!(
+                ScratchpadsPlugin::hide_animation_type(show),
+                show.to_lowercase()
+            );
+        }
+
+        // Custom prefixes match in any casing but keep the suffix as written
+        assert_eq!(
+            ScratchpadsPlugin::hide_animation_type("FromCorner"),
+            "toCorner"
+        );
+        assert_eq!(
+            ScratchpadsPlugin::hide_animation_type("FROM_Edge"),
+            "to_Edge"
+        );
+    }
+
     #[tokio::test]
     async fn test_bulk_geometry_sync() {
         let mut plugin = ScratchpadsPlugin::new();
@@ -4371,6 +9815,10 @@ mod tests {
             is_focused: true,
             active_workspace_id: 1,
             refresh_rate: 60.0,
+            reserved_top: 0,
+            reserved_bottom: 0,
+            reserved_left: 0,
+            reserved_right: 0,
         };
 
         let config = ValidatedConfig {
@@ -4384,9 +9832,8 @@ mod tests {
 
         // Test that all animation types can be processed by our helper method
         for animation_type in animation_types {
-            let plugin = ScratchpadsPlugin::new();
             let hide_animation_type =
-                plugin.get_reverse_animation_type(&animation_type.to_string());
+                ScratchpadsPlugin::hide_animation_type(&animation_type.to_string());
 
             // Verify hide animation type mapping
             match animation_type {
@@ -4494,6 +9941,10 @@ mod tests {
                 width: 1920,
                 height: 1080,
                 refresh_rate: 60.0,
+                reserved_top: 0,
+                reserved_bottom: 0,
+                reserved_left: 0,
+                reserved_right: 0,
                 x: 0,
                 y: 0,
                 active_workspace_id: 1,
@@ -4765,6 +10216,10 @@ mod tests {
                 is_focused: true,
                 scale: 1.0,
                 refresh_rate: 60.0,
+                reserved_top: 0,
+                reserved_bottom: 0,
+                reserved_left: 0,
+                reserved_right: 0,
             };
 
             // Test du stockage dans ScratchpadState
@@ -4789,6 +10244,10 @@ mod tests {
                 is_focused: false,
                 scale: 1.0,
                 refresh_rate: 60.0,
+                reserved_top: 0,
+                reserved_bottom: 0,
+                reserved_left: 0,
+                reserved_right: 0,
             };
 
             state.spawn_monitor = Some(monitor_dp3.clone());
@@ -4865,6 +10324,10 @@ mod tests {
                     is_focused: true,
                     scale: 1.0,
                     refresh_rate: 60.0,
+                    reserved_top: 0,
+                    reserved_bottom: 0,
+                    reserved_left: 0,
+                    reserved_right: 0,
                 },
                 MonitorInfo {
                     id: 1,
@@ -4877,6 +10340,10 @@ mod tests {
                     is_focused: false,
                     scale: 1.0,
                     refresh_rate: 60.0,
+                    reserved_top: 0,
+                    reserved_bottom: 0,
+                    reserved_left: 0,
+                    reserved_right: 0,
                 },
                 MonitorInfo {
                     id: 2,
@@ -4889,6 +10356,10 @@ mod tests {
                     is_focused: false,
                     scale: 1.0,
                     refresh_rate: 60.0,
+                    reserved_top: 0,
+                    reserved_bottom: 0,
+                    reserved_left: 0,
+                    reserved_right: 0,
                 },
             ];
 
@@ -9,12 +9,10 @@ use tokio::time::sleep;
 use tracing::{debug, error, info, warn};
 
 use crate::ipc::{HyprlandClient, HyprlandEvent, MonitorInfo, WorkspaceInfo};
-use crate::plugins::Plugin;
+use crate::plugins::{Plugin, PluginContext};
 
 use hyprland::data::{Monitors, Workspaces};
-use hyprland::dispatch::{
-    Dispatch, DispatchType, MonitorIdentifier, WorkspaceIdentifier, WorkspaceIdentifierWithSpecial,
-};
+use hyprland::dispatch::DispatchType;
 use hyprland::shared::{HyprData, HyprDataVec};
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -34,6 +32,49 @@ pub struct ShiftMonitorsConfig {
     /// Enable smooth transitions during shifts (default: true)
     #[serde(default = "default_true")]
     pub enable_animations: bool,
+
+    /// Where `+1` moves each workspace: "right" (the rightmost one wraps to the
+    /// leftmost monitor) or "left" (default: "right")
+    #[serde(default)]
+    pub forward: ShiftDirection,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShiftDirection {
+    #[default]
+    Right,
+    Left,
+}
+
+/// Parse a shift argument: `+1`, `-2`, `prev`/`backward` (-1) or
+/// `next`/`forward` (+1)
+pub fn parse_direction(arg: &str) -> Result<i32> {
+    let direction = match arg {
+        "next" | "forward" => 1,
+        "prev" | "backward" => -1,
+        _ => arg.parse().map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid direction: {}. Use +1/right for forward, -1/left for backward",
+                arg
+            )
+        })?,
+    };
+    if direction == 0 {
+        return Err(anyhow::anyhow!(
+            "Direction cannot be 0. Use +1 for forward, -1 for backward"
+        ));
+    }
+    Ok(direction)
+}
+
+/// Workspaces of monitors ordered left to right after moving each one `steps`
+/// monitors to the right (negative: left), wrapping around at the ends
+pub fn rotate_workspaces(workspaces: &[i32], steps: i32) -> Vec<i32> {
+    let count = workspaces.len() as i32;
+    (0..count)
+        .map(|i| workspaces[(i - steps).rem_euclid(count) as usize])
+        .collect()
 }
 
 fn default_shift_delay() -> u64 {
@@ -55,6 +96,7 @@ impl Default for ShiftMonitorsConfig {
             animation_duration: 300,
             debug_logging: false,
             enable_animations: true,
+            forward: ShiftDirection::Right,
         }
     }
 }
@@ -86,18 +128,7 @@ impl ShiftMonitorsPlugin {
         self.monitors.clear();
 
         for monitor in monitor_vec {
-            let monitor_info = MonitorInfo {
-                id: monitor.id,
-                name: monitor.name.clone(),
-                is_focused: monitor.focused,
-                active_workspace_id: monitor.active_workspace.id,
-                width: monitor.width,
-                height: monitor.height,
-                x: monitor.x,
-                y: monitor.y,
-                scale: monitor.scale,
-                refresh_rate: monitor.refresh_rate,
-            };
+            let monitor_info = MonitorInfo::from(&monitor);
 
             self.monitors.insert(monitor.name, monitor_info);
         }
@@ -153,8 +184,18 @@ impl ShiftMonitorsPlugin {
         monitors
     }
 
-    /// Shift workspaces between monitors in the specified direction
-    async fn shift_workspaces(&mut self, direction: i32) -> Result<String> {
+    async fn get_hyprland_client(&self) -> Result<Arc<HyprlandClient>> {
+        self.hyprland_client
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Hyprland client not available"))
+    }
+
+    /// Rotate the active workspaces of the monitors, ordered by `x`, by `steps`
+    /// monitors to the right (negative: left), wrapping around, then focus the
+    /// monitor now showing the workspace that had focus
+    async fn shift_workspaces(&mut self, steps: i32) -> Result<String> {
         // Check debouncing
         if !self.can_shift() {
             if self.config.debug_logging {
@@ -163,57 +204,27 @@ impl ShiftMonitorsPlugin {
             return Ok("Workspace shift debounced".to_string());
         }
 
-        // Update current state
-        self.update_monitors().await?;
-        self.update_workspaces().await?;
-
-        let monitor_count = self.monitors.len();
+        let client = self.get_hyprland_client().await?;
+        let mut monitors = client.get_monitors().await?;
+        monitors.sort_by_key(|m| m.x);
 
+        let monitor_count = monitors.len();
         if monitor_count < 2 {
-            return Err(anyhow::anyhow!(
-                "Need at least 2 monitors to shift workspaces"
+            return Ok(format!(
+                "Only {monitor_count} monitor connected, no workspaces to shift"
             ));
         }
 
+        let current: Vec<i32> = monitors.iter().map(|m| m.active_workspace.id).collect();
+        let shifted = rotate_workspaces(&current, steps);
+
         if self.config.debug_logging {
             debug!(
-                "🔄 Shifting workspaces in direction {} across {} monitors",
-                direction, monitor_count
+                "🔄 Shifting workspaces by {} across {} monitors: {:?} -> {:?}",
+                steps, monitor_count, current, shifted
             );
         }
 
-        // Collect current workspace assignments
-        let monitor_workspaces: Vec<(String, i32)> = {
-            let ordered_monitors = self.get_ordered_monitors();
-            ordered_monitors
-                .iter()
-                .map(|m| (m.name.clone(), m.active_workspace_id))
-                .collect()
-        };
-
-        if self.config.debug_logging {
-            debug!("Current workspace mapping: {:?}", monitor_workspaces);
-        }
-
-        // Determine shift direction
-        let shift_amount = if direction > 0 {
-            1 // Shift right/forward
-        } else {
-            monitor_workspaces.len() - 1 // Shift left/backward (equivalent to right by n-1)
-        };
-
-        // Create new workspace assignments by rotating
-        let mut new_assignments = Vec::new();
-        for (i, (monitor_name, _)) in monitor_workspaces.iter().enumerate() {
-            let source_index = (i + shift_amount) % monitor_workspaces.len();
-            let source_workspace = monitor_workspaces[source_index].1;
-            new_assignments.push((monitor_name.clone(), source_workspace));
-        }
-
-        if self.config.debug_logging {
-            debug!("New workspace mapping: {:?}", new_assignments);
-        }
-
         // Add transition animation delay if enabled
         if self.config.enable_animations {
             if self.config.debug_logging {
@@ -238,39 +249,57 @@ impl ShiftMonitorsPlugin {
         }
 
         // Apply the workspace shifts by moving workspaces to their new monitors
-        for (monitor_name, new_workspace) in new_assignments {
-            let workspace_identifier = WorkspaceIdentifier::Id(new_workspace);
-            let monitor_name_clone = monitor_name.clone();
-
-            tokio::task::spawn_blocking(move || {
-                let monitor_identifier = MonitorIdentifier::Name(&monitor_name_clone);
-                Dispatch::call(DispatchType::MoveWorkspaceToMonitor(
-                    workspace_identifier,
-                    monitor_identifier,
+        for (monitor, workspace) in monitors.iter().zip(&shifted) {
+            if monitor.active_workspace.id == *workspace {
+                continue;
+            }
+            let args = format!("{} {}", workspace, monitor.name);
+            client
+                .dispatch(DispatchType::Custom(
+                    "moveworkspacetomonitor",
+                    Box::leak(args.into_boxed_str()),
                 ))
-            })
-            .await??;
+                .await?;
 
             if self.config.debug_logging {
                 debug!(
                     "📱 Moved workspace {} to monitor {}",
-                    new_workspace, monitor_name
+                    workspace, monitor.name
                 );
             }
         }
 
+        // Focus (and the cursor) follow the workspace that had focus
+        let focused_workspace = monitors
+            .iter()
+            .find(|m| m.focused)
+            .map(|m| m.active_workspace.id);
+        if let Some(index) = focused_workspace.and_then(|id| shifted.iter().position(|w| *w == id))
+        {
+            let name = monitors[index].name.clone();
+            client
+                .dispatch(DispatchType::Custom(
+                    "focusmonitor",
+                    Box::leak(name.into_boxed_str()),
+                ))
+                .await?;
+        }
+
         // Update last shift time
         self.last_shift_time = Some(Instant::now());
 
-        let direction_text = if direction > 0 { "forward" } else { "backward" };
+        let direction_text = if steps > 0 { "right" } else { "left" };
 
         info!(
-            "🔄 Shifted workspaces {} across {} monitors",
-            direction_text, monitor_count
+            "🔄 Shifted workspaces {} by {} across {} monitors",
+            direction_text,
+            steps.abs(),
+            monitor_count
         );
 
         Ok(format!(
-            "Shifted workspaces {direction_text} across {monitor_count} monitors"
+            "Shifted workspaces {direction_text} by {} across {monitor_count} monitors",
+            steps.abs()
         ))
     }
 
@@ -350,10 +379,12 @@ impl ShiftMonitorsPlugin {
         }
 
         output.push_str("\nUsage:\n");
-        output.push_str("  'shift_monitors'     - Shift workspaces forward (default: +1)\n");
-        output.push_str("  'shift_monitors +1'  - Shift workspaces forward\n");
-        output.push_str("  'shift_monitors -1'  - Shift workspaces backward\n");
-        output.push_str("  'shift_monitors +2'  - Shift workspaces forward by 2 positions\n");
+        output.push_str("  'shift_monitors'       - Shift workspaces forward (default: +1)\n");
+        output.push_str("  'shift_monitors +1'    - Shift workspaces forward\n");
+        output.push_str("  'shift_monitors -1'    - Shift workspaces backward\n");
+        output.push_str("  'shift_monitors +2'    - Shift workspaces forward by 2 positions\n");
+        output.push_str("  'shift_monitors right' - Move workspaces one monitor to the right\n");
+        output.push_str("  'shift_monitors left'  - Move workspaces one monitor to the left\n");
 
         Ok(output)
     }
@@ -371,6 +402,10 @@ impl Plugin for ShiftMonitorsPlugin {
         "shift_monitors"
     }
 
+    async fn set_context(&mut self, context: Arc<PluginContext>) {
+        *self.hyprland_client.lock().await = Some(Arc::clone(&context.hyprland_client));
+    }
+
     async fn init(&mut self, config: &toml::Value) -> Result<()> {
         info!("🔄 Initializing shift_monitors plugin");
 
@@ -435,27 +470,24 @@ impl Plugin for ShiftMonitorsPlugin {
         debug!("🔄 ShiftMonitors command: {} {:?}", command, args);
 
         match command {
-            "" => {
-                // Default behavior: shift forward by 1
-                self.shift_workspaces(1).await
-            }
+            "status" => self.get_status().await,
+            "list" => self.list_monitors().await,
 
-            direction_str => {
-                // Parse direction from command
-                let direction: i32 = direction_str.parse().map_err(|_| {
-                    anyhow::anyhow!(
-                        "Invalid direction: {}. Use +1 for forward, -1 for backward",
-                        direction_str
-                    )
-                })?;
-
-                if direction == 0 {
-                    return Err(anyhow::anyhow!(
-                        "Direction cannot be 0. Use +1 for forward, -1 for backward"
-                    ));
-                }
+            // Physical directions, whatever `forward` is
+            "right" => self.shift_workspaces(1).await,
+            "left" => self.shift_workspaces(-1).await,
 
-                self.shift_workspaces(direction).await
+            direction_str => {
+                // Default behavior: shift forward by 1
+                let direction = match direction_str {
+                    "" => 1,
+                    _ => parse_direction(direction_str)?,
+                };
+                let steps = match self.config.forward {
+                    ShiftDirection::Right => direction,
+                    ShiftDirection::Left => -direction,
+                };
+                self.shift_workspaces(steps).await
             }
         }
     }
@@ -530,6 +562,10 @@ mod tests {
                 y: 0,
                 scale: 1.0,
                 refresh_rate: 60.0,
+                reserved_top: 0,
+                reserved_bottom: 0,
+                reserved_left: 0,
+                reserved_right: 0,
             },
         );
 
@@ -546,6 +582,10 @@ mod tests {
                 y: 0,
                 scale: 1.0,
                 refresh_rate: 60.0,
+                reserved_top: 0,
+                reserved_bottom: 0,
+                reserved_left: 0,
+                reserved_right: 0,
             },
         );
 
@@ -572,6 +612,10 @@ mod tests {
             y: 0,
             scale: 1.0,
             refresh_rate: 60.0,
+            reserved_top: 0,
+            reserved_bottom: 0,
+            reserved_left: 0,
+            reserved_right: 0,
         };
 
         assert_eq!(monitor.name, "DP-1");
@@ -692,4 +736,82 @@ mod tests {
         let invalid_dir: Result<i32, _> = "invalid".parse();
         assert!(invalid_dir.is_err());
     }
+
+    #[test]
+    fn test_rotate_workspaces() {
+        assert_eq!(rotate_workspaces(&[1, 2, 3], 1), vec![3, 1, 2]);
+        assert_eq!(rotate_workspaces(&[1, 2, 3], -1), vec![2, 3, 1]);
+        assert_eq!(rotate_workspaces(&[1, 2, 3], 2), vec![2, 3, 1]);
+        assert_eq!(rotate_workspaces(&[1, 2, 3], 3), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_parse_direction_keywords() {
+        assert_eq!(parse_direction("next").unwrap(), 1);
+        assert_eq!(parse_direction("backward").unwrap(), -1);
+        assert_eq!(parse_direction("+2").unwrap(), 2);
+        assert!(parse_direction("0").is_err());
+        assert!(parse_direction("up").is_err());
+    }
+
+    async fn plugin_with_monitors(
+        monitors: Vec<hyprland::data::Monitor>,
+    ) -> (ShiftMonitorsPlugin, Arc<crate::ipc::mock::MockHyprland>) {
+        let mut plugin = create_test_plugin();
+        plugin.config.enable_animations = false;
+        let api = Arc::new(crate::ipc::mock::MockHyprland::new(vec![], monitors));
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+        (plugin, api)
+    }
+
+    #[tokio::test]
+    async fn test_shift_follows_focused_workspace() {
+        use crate::ipc::mock::monitor;
+
+        // Listed out of order: shifting goes by x
+        let mut right = monitor("HDMI-A-1", (1920, 1080), false, 3);
+        right.x = 3840;
+        let mut middle = monitor("DP-2", (1920, 1080), false, 2);
+        middle.x = 1920;
+        let left = monitor("DP-1", (1920, 1080), true, 1);
+
+        let (mut plugin, api) = plugin_with_monitors(vec![right, middle, left]).await;
+        plugin.handle_command("right", &[]).await.unwrap();
+        assert_eq!(
+            api.calls(),
+            vec![
+                "dispatch moveworkspacetomonitor 3 DP-1",
+                "dispatch moveworkspacetomonitor 1 DP-2",
+                "dispatch moveworkspacetomonitor 2 HDMI-A-1",
+                "dispatch focusmonitor DP-2",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_forward_left_reverses_numeric_shift() {
+        use crate::ipc::mock::monitor;
+
+        let mut right = monitor("DP-2", (1920, 1080), true, 2);
+        right.x = 1920;
+        let (mut plugin, api) =
+            plugin_with_monitors(vec![monitor("DP-1", (1920, 1080), false, 1), right]).await;
+        plugin.config.forward = ShiftDirection::Left;
+
+        let result = plugin.handle_command("+1", &[]).await.unwrap();
+        assert!(result.contains("left"), "{result}");
+        assert_eq!(api.calls().last().unwrap(), "dispatch focusmonitor DP-1");
+    }
+
+    #[tokio::test]
+    async fn test_single_monitor_is_a_no_op() {
+        use crate::ipc::mock::monitor;
+
+        let (mut plugin, api) =
+            plugin_with_monitors(vec![monitor("eDP-1", (1920, 1080), true, 1)]).await;
+        let result = plugin.handle_command("", &[]).await.unwrap();
+        assert!(result.contains("Only 1 monitor"), "{result}");
+        assert!(api.calls().is_empty());
+    }
 }
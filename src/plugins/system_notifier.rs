@@ -3,7 +3,8 @@ use async_trait::async_trait;
 use notify_rust::Notification;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::process::Stdio;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command;
@@ -15,9 +16,9 @@ use crate::animation::{
     AnimationConfig, AnimationEngine, EasingFunction, PropertyValue, WindowAnimator,
 };
 use crate::ipc::{HyprlandClient, HyprlandEvent};
-use crate::plugins::Plugin;
-use std::sync::Arc;
-use std::time::Instant;
+use crate::plugins::{Plugin, ValidationReport};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 // Backward compatibility alias for the advanced animation system
 pub type SimpleAnimationConfig = AnimationConfig;
@@ -35,6 +36,11 @@ pub struct SystemNotifierConfig {
     pub icon: Option<String>,
     /// Default sound for notifications
     pub sound: Option<String>,
+    /// What to do with desktop notifications when no notification daemon is
+    /// reachable: "log" them (default) or drop them ("none")
+    pub fallback: Option<String>,
+    /// Matches within this time of startup are backlog and not notified (ms)
+    pub startup_grace_ms: Option<u64>,
 }
 
 impl Default for SystemNotifierConfig {
@@ -45,10 +51,110 @@ impl Default for SystemNotifierConfig {
             color: Some("#0088ff".to_string()),
             icon: Some("info".to_string()),
             sound: None,
+            fallback: Some("log".to_string()),
+            startup_grace_ms: Some(2000),
         }
     }
 }
 
+/// Reachability of a desktop notification daemon (dunst, mako, ...) over DBus
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackendStatus {
+    /// Not probed yet
+    Unchecked,
+    /// A daemon answered, e.g. "mako 1.8.0"
+    Available(String),
+    /// No daemon answered; the reason
+    Unavailable(String),
+}
+
+impl std::fmt::Display for BackendStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackendStatus::Unchecked => write!(f, "not checked"),
+            BackendStatus::Available(server) => write!(f, "available ({server})"),
+            BackendStatus::Unavailable(reason) => write!(f, "unavailable ({reason})"),
+        }
+    }
+}
+
+/// How long to wait for the notification daemon to answer at init
+const BACKEND_PROBE_TIMEOUT_MS: u64 = 2000;
+
+/// Suppression of backlog lines that monitored commands replay when they start
+#[derive(Debug, Clone)]
+struct StartupFilter {
+    /// When the plugin started
+    started: Instant,
+    /// The same moment on the wall clock, for log timestamps
+    started_at: SystemTime,
+    /// Matches processed within this time of `started` are suppressed
+    grace: Duration,
+}
+
+impl StartupFilter {
+    /// Why a matched line must not be notified, if it is startup backlog
+    fn suppression(&self, parser: &CompiledParser, line: &str) -> Option<String> {
+        let elapsed = self.started.elapsed();
+        if elapsed < self.grace {
+            return Some(format!(
+                "within the {}ms startup grace period",
+                self.grace.as_millis()
+            ));
+        }
+        match parser.line_timestamp(line) {
+            Some(logged_at) if logged_at < self.started_at => {
+                Some("logged before startup".to_string())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Log timestamps: Unix seconds (`1697545555.123`) or ISO 8601
+/// (`2024-10-17T08:30:00`, optional fraction and `Z`/`+02:00` offset)
+static ISO_TIMESTAMP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(\d{4})-(\d{2})-(\d{2})[T ](\d{2}):(\d{2}):(\d{2})(?:[.,]\d+)?(Z|[+-]\d{2}:?\d{2})?$",
+    )
+    .unwrap()
+});
+
+/// Wall-clock time of a log timestamp, UTC when it carries no offset
+fn parse_log_timestamp(text: &str) -> Option<SystemTime> {
+    let text = text.trim();
+    if let Ok(seconds) = text.parse::<f64>() {
+        return (seconds >= 0.0).then(|| UNIX_EPOCH + Duration::from_secs_f64(seconds));
+    }
+
+    let captures = ISO_TIMESTAMP.captures(text)?;
+    let field = |index: usize| captures[index].parse::<i64>().ok();
+    let (year, month, day) = (field(1)?, field(2)?, field(3)?);
+    let (hour, minute, second) = (field(4)?, field(5)?, field(6)?);
+
+    // Days since the epoch of a proleptic Gregorian date
+    let shifted_year = if month <= 2 { year - 1 } else { year };
+    let era = shifted_year.div_euclid(400);
+    let year_of_era = shifted_year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    let offset = match captures.get(7).map(|m| m.as_str()) {
+        None | Some("Z") => 0,
+        Some(offset) => {
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let digits = offset[1..].replace(':', "");
+            sign * (digits[..2].parse::<i64>().ok()? * 3600 + digits[2..].parse::<i64>().ok()? * 60)
+        }
+    };
+
+    let seconds = days * 86_400 + hour * 3600 + minute * 60 + second - offset;
+    u64::try_from(seconds)
+        .ok()
+        .map(|seconds| UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
 /// Configuration for a log source (command to monitor)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SourceConfig {
@@ -75,6 +181,13 @@ pub struct ParserConfig {
     pub icon: Option<String>,
     /// Optional sound for notifications
     pub sound: Option<String>,
+    /// Minimum time between two notifications of this parser (ms)
+    pub throttle_ms: Option<u64>,
+    /// Identical notification texts within this window are coalesced (ms)
+    pub dedupe_window_ms: Option<u64>,
+    /// Regex whose first capture group is the timestamp a line was logged at;
+    /// lines logged before startup are not notified
+    pub timestamp_pattern: Option<String>,
 }
 
 /// Enhanced notification configuration with animation support
@@ -100,6 +213,153 @@ pub struct NotificationAnimation {
     pub smooth_transitions: Option<bool>,
 }
 
+/// Compiled `pattern` and `filter` of a parser, with the sources they came from
+#[derive(Clone)]
+struct ParserRegexes {
+    pattern_source: String,
+    filter_source: Option<String>,
+    timestamp_source: Option<String>,
+    pattern: Regex,
+    filter: Option<Regex>,
+    filter_replacement: Option<String>,
+    timestamp: Option<Regex>,
+}
+
+impl ParserRegexes {
+    fn compile(config: &ParserConfig) -> Result<Self> {
+        let pattern = Regex::new(&config.pattern)
+            .with_context(|| format!("Invalid regex pattern: {}", config.pattern))?;
+
+        let (filter, filter_replacement) = if let Some(filter_str) = &config.filter {
+            // Parse s/pattern/replacement/ format (Pyprland compatible)
+            if filter_str.starts_with("s/") && filter_str.len() > 2 {
+                let parts: Vec<&str> = filter_str[2..].splitn(3, '/').collect();
+                if parts.len() >= 2 {
+                    let filter_pattern = Regex::new(parts[0])
+                        .with_context(|| format!("Invalid filter regex: {}", parts[0]))?;
+                    let replacement = parts.get(1).unwrap_or(&"").to_string();
+                    (Some(filter_pattern), Some(replacement))
+                } else {
+                    return Err(anyhow::anyhow!("Invalid filter format: {}", filter_str));
+                }
+            } else {
+                return Err(anyhow::anyhow!(
+                    "Filter must be in s/pattern/replacement/ format"
+                ));
+            }
+        } else {
+            (None, None)
+        };
+
+        let timestamp = config
+            .timestamp_pattern
+            .as_deref()
+            .map(|source| {
+                Regex::new(source).with_context(|| format!("Invalid timestamp regex: {source}"))
+            })
+            .transpose()?;
+
+        Ok(Self {
+            pattern_source: config.pattern.clone(),
+            filter_source: config.filter.clone(),
+            timestamp_source: config.timestamp_pattern.clone(),
+            pattern,
+            filter,
+            filter_replacement,
+            timestamp,
+        })
+    }
+
+    /// Whether these regexes were compiled from the config's current patterns
+    fn compiled_from(&self, config: &ParserConfig) -> bool {
+        self.pattern_source == config.pattern
+            && self.filter_source == config.filter
+            && self.timestamp_source == config.timestamp_pattern
+    }
+}
+
+/// Regexes of the parsers loaded last, by parser name. The plugin is recreated on
+/// every reload; this outlives it so unchanged patterns are not recompiled and a
+/// parser whose new pattern is invalid keeps its last good one. Parsers gone from
+/// the config are evicted on each load
+static PARSER_REGEXES: LazyLock<Mutex<HashMap<String, ParserRegexes>>> =
+    LazyLock::new(Default::default);
+
+/// How many distinct notification texts a parser remembers for deduplication
+const RECENT_MESSAGES_CAPACITY: usize = 32;
+
+/// A notification text a parser recently matched
+struct RecentMessage {
+    hash: u64,
+    /// When it was last delivered, `None` if every occurrence was suppressed so far
+    sent_at: Option<Instant>,
+    /// Occurrences suppressed since the last delivery
+    suppressed: u32,
+}
+
+/// Small LRU of recent notification texts, least recently seen first
+#[derive(Default)]
+struct RecentMessages {
+    entries: VecDeque<RecentMessage>,
+    last_sent: Option<Instant>,
+}
+
+impl RecentMessages {
+    /// Text to deliver for an occurrence of `text` at `now`, or `None` when it is
+    /// throttled or a duplicate. Suppressed occurrences are counted and reported
+    /// as a `(xN)` suffix on the next delivery of the same text
+    fn admit(
+        &mut self,
+        text: &str,
+        now: Instant,
+        throttle: Option<Duration>,
+        dedupe_window: Option<Duration>,
+    ) -> Option<String> {
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let mut entry = self
+            .entries
+            .iter()
+            .position(|entry| entry.hash == hash)
+            .and_then(|index| self.entries.remove(index))
+            .unwrap_or(RecentMessage {
+                hash,
+                sent_at: None,
+                suppressed: 0,
+            });
+
+        let throttled = throttle
+            .zip(self.last_sent)
+            .is_some_and(|(throttle, last)| now.duration_since(last) < throttle);
+        let duplicate = dedupe_window
+            .zip(entry.sent_at)
+            .is_some_and(|(window, sent_at)| now.duration_since(sent_at) < window);
+
+        let delivered = if throttled || duplicate {
+            entry.suppressed += 1;
+            None
+        } else {
+            let occurrences = entry.suppressed + 1;
+            entry.suppressed = 0;
+            entry.sent_at = Some(now);
+            self.last_sent = Some(now);
+            Some(if occurrences > 1 {
+                format!("{text} (x{occurrences})")
+            } else {
+                text.to_string()
+            })
+        };
+
+        self.entries.push_back(entry);
+        if self.entries.len() > RECENT_MESSAGES_CAPACITY {
+            self.entries.pop_front();
+        }
+        delivered
+    }
+}
+
 /// Internal parser with compiled regex
 #[derive(Clone)]
 struct CompiledParser {
@@ -112,6 +372,60 @@ struct CompiledParser {
     icon: Option<String>,
     sound: Option<String>,
     animation: Option<NotificationAnimation>,
+    timestamp: Option<Regex>,
+    throttle: Option<Duration>,
+    dedupe_window: Option<Duration>,
+    /// Shared by every source using this parser
+    recent: Arc<Mutex<RecentMessages>>,
+}
+
+impl CompiledParser {
+    /// Notification text for a log line, `None` if the line does not match.
+    /// The filter, when set, rewrites the whole line
+    fn notification_text(&self, line: &str) -> Option<String> {
+        let captures = self.pattern.captures(line)?;
+        let text =
+            if let (Some(filter), Some(replacement)) = (&self.filter, &self.filter_replacement) {
+                // Convert sed-style \1, \2 to Rust regex $1, $2 for Pyprland compatibility
+                let rust_replacement = replacement
+                    .replace("\\1", "$1")
+                    .replace("\\2", "$2")
+                    .replace("\\3", "$3")
+                    .replace("\\4", "$4")
+                    .replace("\\5", "$5")
+                    .replace("\\6", "$6")
+                    .replace("\\7", "$7")
+                    .replace("\\8", "$8")
+                    .replace("\\9", "$9");
+                filter.replace(line, rust_replacement.as_str()).to_string()
+            } else {
+                // Use the first capture group if available, otherwise the full match
+                captures
+                    .get(1)
+                    .or_else(|| captures.get(0))
+                    .map(|m| m.as_str())
+                    .unwrap_or(line)
+                    .to_string()
+            };
+        Some(text)
+    }
+
+    /// When a line was logged, per `timestamp_pattern`
+    fn line_timestamp(&self, line: &str) -> Option<SystemTime> {
+        let captures = self.timestamp.as_ref()?.captures(line)?;
+        parse_log_timestamp(captures.get(1).or_else(|| captures.get(0))?.as_str())
+    }
+
+    /// Apply `throttle_ms` and `dedupe_window_ms` to a notification text
+    fn coalesce(&self, text: &str, now: Instant) -> Option<String> {
+        if self.throttle.is_none() && self.dedupe_window.is_none() {
+            return Some(text.to_string());
+        }
+        self.recent
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .admit(text, now, self.throttle, self.dedupe_window)
+    }
 }
 
 /// System Notifier plugin for monitoring logs and sending animated notifications
@@ -125,6 +439,8 @@ pub struct SystemNotifier {
     notification_counter: u32,
     // Startup time to avoid showing old notifications
     startup_time: Instant,
+    // Desktop notification daemon detected at init
+    backend: BackendStatus,
 }
 
 impl SystemNotifier {
@@ -137,11 +453,25 @@ impl SystemNotifier {
             shutdown_tx: None,
             notification_counter: 0,
             startup_time: Instant::now(),
+            backend: BackendStatus::Unchecked,
         }
     }
 
     /// Parse configuration and compile regex patterns
     fn parse_config(&mut self, config: &toml::Value) -> Result<()> {
+        let mut cache = PARSER_REGEXES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.parse_config_with_cache(config, &mut cache)
+    }
+
+    /// `parse_config` against a given regex cache, which ends up holding only the
+    /// parsers of this config
+    fn parse_config_with_cache(
+        &mut self,
+        config: &toml::Value,
+        cache: &mut HashMap<String, ParserRegexes>,
+    ) -> Result<()> {
         // Parse main plugin configuration (new structure)
         if let Ok(main_config) = config.clone().try_into::<SystemNotifierConfig>() {
             // Merge with defaults to ensure all fields have values
@@ -151,6 +481,20 @@ impl SystemNotifier {
             merged_config.color = main_config.color.or(merged_config.color);
             merged_config.icon = main_config.icon.or(merged_config.icon);
             merged_config.sound = main_config.sound.or(merged_config.sound);
+            merged_config.startup_grace_ms = main_config
+                .startup_grace_ms
+                .or(merged_config.startup_grace_ms);
+            merged_config.fallback = match main_config.fallback.as_deref() {
+                None => merged_config.fallback,
+                Some(fallback @ ("log" | "none")) => Some(fallback.to_string()),
+                Some(other) => {
+                    warn!(
+                        "⚠️ Invalid system_notifier fallback '{}', expected 'log' or 'none'; using 'log'",
+                        other
+                    );
+                    merged_config.fallback
+                }
+            };
 
             self.config = merged_config;
             info!("📋 Loaded main system_notifier configuration");
@@ -172,7 +516,9 @@ impl SystemNotifier {
         }
 
         // Parse parsers from [system_notifier.parsers.*] sections with enhanced animation support
-        if let Some(parsers) = config.get("parsers").and_then(|p| p.as_table()) {
+        let parsers = config.get("parsers").and_then(|p| p.as_table());
+        cache.retain(|name, _| parsers.is_some_and(|parsers| parsers.contains_key(name)));
+        if let Some(parsers) = parsers {
             for (name, parser_config) in parsers {
                 // Try enhanced format first (with animation), fallback to basic format
                 let notification_config: NotificationConfig = parser_config
@@ -188,9 +534,11 @@ impl SystemNotifier {
                     })
                     .with_context(|| format!("Failed to parse parser config for '{name}'"))?;
 
-                let compiled = self
-                    .compile_parser(&notification_config)
-                    .with_context(|| format!("Failed to compile parser '{name}'"))?;
+                let Some(regexes) = Self::parser_regexes(cache, name, &notification_config.basic)
+                else {
+                    continue;
+                };
+                let compiled = self.build_parser(&notification_config, regexes);
 
                 self.parsers.insert(name.clone(), compiled);
                 debug!("Loaded parser '{}'", name);
@@ -202,30 +550,49 @@ impl SystemNotifier {
 
     /// Compile a parser configuration into runtime structures
     fn compile_parser(&self, config: &NotificationConfig) -> Result<CompiledParser> {
-        let pattern = Regex::new(&config.basic.pattern)
-            .with_context(|| format!("Invalid regex pattern: {}", config.basic.pattern))?;
+        Ok(self.build_parser(config, ParserRegexes::compile(&config.basic)?))
+    }
 
-        let (filter, filter_replacement) = if let Some(filter_str) = &config.basic.filter {
-            // Parse s/pattern/replacement/ format (Pyprland compatible)
-            if filter_str.starts_with("s/") && filter_str.len() > 2 {
-                let parts: Vec<&str> = filter_str[2..].splitn(3, '/').collect();
-                if parts.len() >= 2 {
-                    let filter_pattern = Regex::new(parts[0])
-                        .with_context(|| format!("Invalid filter regex: {}", parts[0]))?;
-                    let replacement = parts.get(1).unwrap_or(&"").to_string();
-                    (Some(filter_pattern), Some(replacement))
-                } else {
-                    return Err(anyhow::anyhow!("Invalid filter format: {}", filter_str));
-                }
-            } else {
-                return Err(anyhow::anyhow!(
-                    "Filter must be in s/pattern/replacement/ format"
-                ));
+    /// Regexes for a parser: reused when its patterns did not change since the
+    /// last load, compiled otherwise. A bad pattern is reported and falls back to
+    /// the parser's last good regexes, if it had any
+    fn parser_regexes(
+        cache: &mut HashMap<String, ParserRegexes>,
+        name: &str,
+        config: &ParserConfig,
+    ) -> Option<ParserRegexes> {
+        if let Some(cached) = cache
+            .get(name)
+            .filter(|cached| cached.compiled_from(config))
+        {
+            debug!("Reusing compiled patterns of parser '{}'", name);
+            return Some(cached.clone());
+        }
+
+        match ParserRegexes::compile(config) {
+            Ok(regexes) => {
+                cache.insert(name.to_string(), regexes.clone());
+                Some(regexes)
             }
-        } else {
-            (None, None)
-        };
+            Err(e) => {
+                let last_good = cache.get(name).cloned();
+                warn!(
+                    "⚠️ Parser '{}': {:#}; {}",
+                    name,
+                    e,
+                    if last_good.is_some() {
+                        "keeping its previous patterns"
+                    } else {
+                        "skipping it"
+                    }
+                );
+                last_good
+            }
+        }
+    }
 
+    /// Runtime parser from its configuration and compiled regexes
+    fn build_parser(&self, config: &NotificationConfig, regexes: ParserRegexes) -> CompiledParser {
         // Use parser-specific urgency or fall back to main config default
         let urgency_str = config
             .basic
@@ -239,10 +606,10 @@ impl SystemNotifier {
             _ => notify_rust::Urgency::Normal,
         };
 
-        Ok(CompiledParser {
-            pattern,
-            filter,
-            filter_replacement,
+        CompiledParser {
+            pattern: regexes.pattern,
+            filter: regexes.filter,
+            filter_replacement: regexes.filter_replacement,
             // Use parser-specific values or fall back to main config defaults
             color: config
                 .basic
@@ -262,7 +629,11 @@ impl SystemNotifier {
                 .clone()
                 .or_else(|| self.config.sound.clone()),
             animation: config.animation.clone(),
-        })
+            timestamp: regexes.timestamp,
+            throttle: config.basic.throttle_ms.map(Duration::from_millis),
+            dedupe_window: config.basic.dedupe_window_ms.map(Duration::from_millis),
+            recent: Arc::default(),
+        }
     }
 
     /// Start monitoring all configured sources
@@ -290,6 +661,12 @@ impl SystemNotifier {
             }
         }
 
+        let startup = StartupFilter {
+            started: self.startup_time,
+            started_at: SystemTime::now() - self.startup_time.elapsed(),
+            grace: Duration::from_millis(self.config.startup_grace_ms.unwrap_or(0)),
+        };
+
         // Now spawn all the monitoring tasks with shutdown channels
         for (source_name, source_config, parser) in monitor_tasks {
             let task_shutdown_rx = shutdown_tx.subscribe();
@@ -298,7 +675,7 @@ impl SystemNotifier {
                 source_config,
                 parser,
                 task_shutdown_rx,
-                self.startup_time,
+                startup.clone(),
             )
             .await?;
             self.handles.push(handle);
@@ -322,7 +699,7 @@ impl SystemNotifier {
         source_config: SourceConfig,
         parser: CompiledParser,
         mut shutdown_rx: tokio::sync::broadcast::Receiver<()>,
-        startup_time: Instant,
+        startup: StartupFilter,
     ) -> Result<JoinHandle<()>> {
         let handle = tokio::spawn(async move {
             debug!("Starting monitor for source '{}'", source_name);
@@ -334,7 +711,7 @@ impl SystemNotifier {
                         break;
                     }
                     // Monitor command
-                    result = Self::monitor_command(&source_config.command, &parser, startup_time) => {
+                    result = Self::monitor_command(&source_config.command, &parser, &startup) => {
                         match result {
                             Ok(_) => {
                                 debug!("Command completed for source '{}'", source_name);
@@ -367,13 +744,13 @@ impl SystemNotifier {
         source_name: String,
         source_config: SourceConfig,
         parser: CompiledParser,
-        startup_time: Instant,
+        startup: StartupFilter,
     ) -> Result<JoinHandle<()>> {
         let handle = tokio::spawn(async move {
             debug!("Starting monitor for source '{}'", source_name);
 
             loop {
-                match Self::monitor_command(&source_config.command, &parser, startup_time).await {
+                match Self::monitor_command(&source_config.command, &parser, &startup).await {
                     Ok(_) => {
                         debug!("Command completed for source '{}'", source_name);
                     }
@@ -393,7 +770,7 @@ impl SystemNotifier {
     async fn monitor_command(
         command: &str,
         parser: &CompiledParser,
-        startup_time: Instant,
+        startup: &StartupFilter,
     ) -> Result<()> {
         // Modify command to filter out old log entries for common log monitoring commands
         let filtered_command = if command.contains("journalctl") {
@@ -414,10 +791,6 @@ impl SystemNotifier {
                 // Add --since 'now' to prevent old notifications at startup
                 format!("{} --since 'now'", command)
             }
-        } else if command.contains("tail -f") {
-            // For tail -f, we'll add a startup delay to avoid showing recent entries that existed before startup
-            // This is particularly important for pacman.log which might have recent entries
-            command.to_string()
         } else {
             // For other commands, use as-is
             command.to_string()
@@ -437,48 +810,30 @@ impl SystemNotifier {
             let reader = BufReader::new(stdout);
             let mut lines = reader.lines();
 
+            let mut suppressed = 0;
             while let Some(line) = lines.next_line().await? {
-                // For tail -f commands, ignore lines during the first few seconds to avoid old entries
-                if command.contains("tail -f") && startup_time.elapsed().as_secs() < 10 {
+                let Some(text) = parser.notification_text(&line) else {
+                    continue;
+                };
+                // Commands like `tail -f` replay older entries when they start
+                if let Some(reason) = startup.suppression(parser, &line) {
+                    suppressed += 1;
                     debug!(
-                        "🚫 Ignoring line during startup grace period ({}s elapsed): {}",
-                        startup_time.elapsed().as_secs(),
-                        line
+                        "🚫 Suppressed backlog match #{} from '{}' ({}): {}",
+                        suppressed, command, reason, line
                     );
                     continue;
                 }
+                // Dedupe the post-filter text: different raw lines may read the same
+                let Some(notification_text) = parser.coalesce(&text, Instant::now()) else {
+                    debug!("🔁 Coalesced repeated notification: {}", text);
+                    continue;
+                };
 
-                if let Some(captures) = parser.pattern.captures(&line) {
-                    let notification_text = if let (Some(filter), Some(replacement)) =
-                        (&parser.filter, &parser.filter_replacement)
-                    {
-                        // Convert sed-style \1, \2 to Rust regex $1, $2 for Pyprland compatibility
-                        let rust_replacement = replacement
-                            .replace("\\1", "$1")
-                            .replace("\\2", "$2")
-                            .replace("\\3", "$3")
-                            .replace("\\4", "$4")
-                            .replace("\\5", "$5")
-                            .replace("\\6", "$6")
-                            .replace("\\7", "$7")
-                            .replace("\\8", "$8")
-                            .replace("\\9", "$9");
-                        filter.replace(&line, rust_replacement.as_str()).to_string()
-                    } else {
-                        // Use the first capture group if available, otherwise the full match
-                        captures
-                            .get(1)
-                            .or_else(|| captures.get(0))
-                            .map(|m| m.as_str())
-                            .unwrap_or(&line)
-                            .to_string()
-                    };
-
-                    if let Err(e) =
-                        Self::send_animated_notification_static(&notification_text, parser).await
-                    {
-                        error!("Failed to send notification: {}", e);
-                    }
+                if let Err(e) =
+                    Self::send_animated_notification_static(&notification_text, parser).await
+                {
+                    error!("Failed to send notification: {}", e);
                 }
             }
         }
@@ -491,6 +846,42 @@ impl SystemNotifier {
         Ok(())
     }
 
+    /// Ask the session bus for a notification daemon, without showing anything
+    async fn detect_backend() -> BackendStatus {
+        let probe = tokio::task::spawn_blocking(notify_rust::get_server_information);
+        match tokio::time::timeout(
+            tokio::time::Duration::from_millis(BACKEND_PROBE_TIMEOUT_MS),
+            probe,
+        )
+        .await
+        {
+            Ok(Ok(Ok(server))) => {
+                BackendStatus::Available(format!("{} {}", server.name, server.version))
+            }
+            Ok(Ok(Err(e))) => BackendStatus::Unavailable(e.to_string()),
+            Ok(Err(e)) => BackendStatus::Unavailable(format!("probe failed: {e}")),
+            Err(_) => BackendStatus::Unavailable("no answer from DBus".to_string()),
+        }
+    }
+
+    /// Text of a notification as it would be displayed: summary, urgency and body
+    fn format_fallback(text: &str, parser: &CompiledParser) -> String {
+        let urgency = match parser.urgency {
+            notify_rust::Urgency::Low => "low",
+            notify_rust::Urgency::Critical => "critical",
+            _ => "normal",
+        };
+        format!("System Notification [{urgency}]: {text}")
+    }
+
+    /// Deliver a notification the daemon could not show, per the `fallback` setting
+    fn send_fallback_notification(&self, text: &str, parser: &CompiledParser) {
+        match self.config.fallback.as_deref() {
+            Some("none") => debug!("🔕 Dropped notification (no daemon): {}", text),
+            _ => warn!("🔔 {}", Self::format_fallback(text, parser)),
+        }
+    }
+
     /// Send a desktop notification with optional animations (static version for monitoring)
     async fn send_animated_notification_static(text: &str, parser: &CompiledParser) -> Result<()> {
         // For monitoring, use Hyprland native notifications with color/icon support
@@ -532,21 +923,35 @@ impl SystemNotifier {
                 appear_config.animation_type, appear_config.duration
             );
 
+            // Animated notifications go through the notification daemon
+            let needs_daemon = matches!(
+                appear_config.animation_type.as_str(),
+                "fade" | "scale" | "slide"
+            );
+            if needs_daemon && matches!(self.backend, BackendStatus::Unavailable(_)) {
+                self.send_fallback_notification(text, parser);
+                return Ok(());
+            }
+
             // Show animated notification based on type
-            match appear_config.animation_type.as_str() {
-                "fade" => {
-                    Self::show_fade_notification(text, parser, appear_config).await?;
-                }
-                "scale" => {
-                    Self::show_scale_notification(text, parser, appear_config).await?;
-                }
-                "slide" => {
-                    Self::show_slide_notification(text, parser, appear_config).await?;
-                }
+            let shown = match appear_config.animation_type.as_str() {
+                "fade" => Self::show_fade_notification(text, parser, appear_config).await,
+                "scale" => Self::show_scale_notification(text, parser, appear_config).await,
+                "slide" => Self::show_slide_notification(text, parser, appear_config).await,
                 _ => {
                     // Default to standard notification
-                    self.send_standard_notification_static(text, parser).await?;
+                    self.send_standard_notification_static(text, parser).await
+                }
+            };
+            if let Err(e) = shown {
+                if !needs_daemon {
+                    return Err(e);
                 }
+                warn!(
+                    "⚠️ Notification daemon failed to show notification: {:#}",
+                    e
+                );
+                self.send_fallback_notification(text, parser);
             }
         } else {
             // Show main notification immediately
@@ -1053,6 +1458,10 @@ impl SystemNotifier {
             } else {
                 None
             },
+            timestamp: None,
+            throttle: None,
+            dedupe_window: None,
+            recent: Arc::default(),
         };
 
         info!("📋 CREATED MANUAL PARSER:");
@@ -1124,12 +1533,72 @@ impl Plugin for SystemNotifier {
         "system_notifier"
     }
 
+    fn validate_config(&self, config: &toml::Value) -> ValidationReport {
+        let mut report = ValidationReport::default();
+
+        let parsers = config.get("parsers").and_then(|p| p.as_table());
+        for (name, parser) in parsers.into_iter().flatten() {
+            let key = format!("parsers.{name}");
+            match parser.clone().try_into::<ParserConfig>() {
+                Ok(parser) => {
+                    if let Err(e) = ParserRegexes::compile(&parser) {
+                        report.error(key, format!("{e:#}"));
+                    }
+                }
+                Err(e) => report.error(key, e.to_string()),
+            }
+        }
+
+        let sources = config.get("sources").and_then(|s| s.as_table());
+        for (name, source) in sources.into_iter().flatten() {
+            match source.clone().try_into::<SourceConfig>() {
+                Ok(source) => {
+                    if !parsers.is_some_and(|parsers| parsers.contains_key(&source.parser)) {
+                        report.error(
+                            format!("sources.{name}.parser"),
+                            format!("Parser '{}' is not configured", source.parser),
+                        );
+                    }
+                }
+                Err(e) => report.error(format!("sources.{name}"), e.to_string()),
+            }
+        }
+
+        if let Some(fallback) = config.get("fallback").and_then(|f| f.as_str()) {
+            if !matches!(fallback, "log" | "none") {
+                report.warning(
+                    "fallback",
+                    format!("Unknown fallback '{fallback}', expected 'log' or 'none'"),
+                );
+            }
+        }
+        report
+    }
+
     async fn init(&mut self, config: &toml::Value) -> Result<()> {
         info!("🔔 Initializing system_notifier plugin with animation support");
 
         self.parse_config(config)
             .with_context(|| "Failed to parse system_notifier configuration")?;
 
+        self.backend = Self::detect_backend().await;
+        match &self.backend {
+            BackendStatus::Available(server) => {
+                info!("🔔 Notification daemon: {}", server)
+            }
+            BackendStatus::Unavailable(reason) => {
+                let handling = match self.config.fallback.as_deref() {
+                    Some("none") => "dropped",
+                    _ => "written to the log",
+                };
+                warn!(
+                    "⚠️ No notification daemon reachable ({}); animated notifications will be {}",
+                    reason, handling
+                );
+            }
+            BackendStatus::Unchecked => {}
+        }
+
         if !self.sources.is_empty() {
             self.start_monitoring()
                 .await
@@ -1180,12 +1649,18 @@ impl Plugin for SystemNotifier {
             }
             "status" => {
                 Ok(format!(
-                    "System Notifier Status:\n- Simple Mode (Hyprland-native notifications)\n- Sources: {}\n- Parsers: {}\n- Active monitors: {}",
+                    "System Notifier Status:\n- Simple Mode (Hyprland-native notifications)\n- Sources: {}\n- Parsers: {}\n- Active monitors: {}\n- Notification daemon: {}",
                     self.sources.len(),
                     self.parsers.len(),
-                    self.handles.len()
+                    self.handles.len(),
+                    self.backend
                 ))
             }
+            "health" => Ok(format!(
+                "Notification daemon: {}\nFallback: {}",
+                self.backend,
+                self.config.fallback.as_deref().unwrap_or("log")
+            )),
             "list-sources" => {
                 let sources: Vec<String> = self.sources.keys().cloned().collect();
                 Ok(format!("Configured sources: {}", sources.join(", ")))
@@ -1206,6 +1681,10 @@ impl Plugin for SystemNotifier {
                     icon: Some("info".to_string()),
                     sound: None,
                     animation: None,
+                    timestamp: None,
+                    throttle: None,
+                    dedupe_window: None,
+                    recent: Arc::default(),
                 };
                 // Test notification
                 self.send_hyprland_native_notification(test_message, &temp_parser).await?;
@@ -1256,6 +1735,9 @@ mod tests {
                 urgency: Some("normal".to_string()),
                 icon: Some("network-wired".to_string()),
                 sound: None,
+                throttle_ms: None,
+                dedupe_window_ms: None,
+                timestamp_pattern: None,
             },
             animation: Some(NotificationAnimation {
                 appear: Some(AnimationConfig {
@@ -1350,6 +1832,9 @@ timeout = 5000
                 urgency: Some("critical".to_string()),
                 icon: Some("dialog-error".to_string()),
                 sound: Some("/usr/share/sounds/error.wav".to_string()),
+                throttle_ms: None,
+                dedupe_window_ms: None,
+                timestamp_pattern: None,
             },
             animation: None,
         };
@@ -1523,6 +2008,10 @@ icon = "error"
             icon: Some("error".to_string()),
             sound: None,
             animation: None,
+            timestamp: None,
+            throttle: None,
+            dedupe_window: None,
+            recent: Arc::default(),
         };
 
         assert_eq!(plugin.get_hyprland_icon(&parser), "3");
@@ -1541,6 +2030,10 @@ icon = "error"
             icon: None,
             sound: None,
             animation: None,
+            timestamp: None,
+            throttle: None,
+            dedupe_window: None,
+            recent: Arc::default(),
         };
 
         assert_eq!(plugin.get_hyprland_icon(&parser), "3"); // Critical = Error icon
@@ -1615,4 +2108,206 @@ color = "rgb(255,68,68)"
         let override_parser = plugin.parsers.get("override_parser").unwrap();
         assert_eq!(override_parser.color, Some("rgb(255,68,68)".to_string()));
     }
+
+    #[test]
+    fn test_fallback_config() {
+        let mut plugin = SystemNotifier::new();
+        plugin.parse_config(&toml::from_str("").unwrap()).unwrap();
+        assert_eq!(plugin.config.fallback.as_deref(), Some("log"));
+
+        plugin
+            .parse_config(&toml::from_str(r#"fallback = "none""#).unwrap())
+            .unwrap();
+        assert_eq!(plugin.config.fallback.as_deref(), Some("none"));
+
+        plugin
+            .parse_config(&toml::from_str(r#"fallback = "email""#).unwrap())
+            .unwrap();
+        assert_eq!(plugin.config.fallback.as_deref(), Some("log"));
+    }
+
+    #[test]
+    fn test_format_fallback() {
+        let parser = CompiledParser {
+            pattern: Regex::new("disk").unwrap(),
+            filter: None,
+            filter_replacement: None,
+            color: None,
+            timeout: None,
+            urgency: notify_rust::Urgency::Critical,
+            icon: None,
+            sound: None,
+            animation: None,
+            timestamp: None,
+            throttle: None,
+            dedupe_window: None,
+            recent: Arc::default(),
+        };
+        assert_eq!(
+            SystemNotifier::format_fallback("Disk almost full", &parser),
+            "System Notification [critical]: Disk almost full"
+        );
+        assert_eq!(
+            BackendStatus::Unavailable("no answer from DBus".to_string()).to_string(),
+            "unavailable (no answer from DBus)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reload_keeps_last_good_parser_on_bad_pattern() {
+        let mut cache = HashMap::new();
+        let mut load = |config_str: &str| {
+            let mut plugin = SystemNotifier::new();
+            let config: toml::Value = toml::from_str(config_str).unwrap();
+            plugin
+                .parse_config_with_cache(&config, &mut cache)
+                .map(|_| plugin)
+        };
+
+        load(
+            r#"
+[parsers.reload_valid]
+pattern = "disk"
+
+[parsers.reload_broken]
+pattern = "error"
+        "#,
+        )
+        .unwrap();
+
+        // One pattern stays valid, the other becomes invalid, a new one never was
+        let reloaded = load(
+            r#"
+[parsers.reload_valid]
+pattern = "disk (full|low)"
+
+[parsers.reload_broken]
+pattern = "error(["
+
+[parsers.reload_never_valid]
+pattern = "("
+        "#,
+        )
+        .expect("a bad pattern must not abort the reload");
+
+        assert_eq!(
+            reloaded.parsers["reload_valid"].pattern.as_str(),
+            "disk (full|low)"
+        );
+        assert_eq!(reloaded.parsers["reload_broken"].pattern.as_str(), "error");
+        assert!(!reloaded.parsers.contains_key("reload_never_valid"));
+
+        // A parser dropped from the config is forgotten, so bringing it back with
+        // a bad pattern has nothing to fall back to
+        load(
+            r#"
+[parsers.reload_valid]
+pattern = "disk"
+        "#,
+        )
+        .unwrap();
+        let readded = load(
+            r#"
+[parsers.reload_broken]
+pattern = "error(["
+        "#,
+        )
+        .unwrap();
+        assert!(readded.parsers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_repeated_matches_are_coalesced_after_filter() {
+        let mut plugin = SystemNotifier::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+[parsers.disk]
+pattern = "disk (\\w+) full"
+filter = "s/.*disk \\w+ full.*/Disk full/"
+dedupe_window_ms = 1000
+throttle_ms = 100
+        "#,
+        )
+        .unwrap();
+        plugin.parse_config(&config).unwrap();
+        let parser = &plugin.parsers["disk"];
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let deliver = |line: &str, ms| {
+            let text = parser.notification_text(line)?;
+            parser.coalesce(&text, at(ms))
+        };
+
+        assert_eq!(deliver("disk sda full", 0), Some("Disk full".to_string()));
+        // Different raw lines, same text once filtered: duplicates
+        assert_eq!(deliver("kernel: disk sdb full", 200), None);
+        assert_eq!(deliver("disk sdc full", 500), None);
+        assert_eq!(deliver("disk ok", 600), None);
+        // Window elapsed: delivered with the number of matching occurrences
+        assert_eq!(
+            deliver("disk sda full", 1200),
+            Some("Disk full (x3)".to_string())
+        );
+        assert_eq!(
+            deliver("disk sda full", 2300),
+            Some("Disk full".to_string())
+        );
+
+        // Throttling applies across texts
+        let mut recent = RecentMessages::default();
+        let throttle = Some(Duration::from_millis(100));
+        assert!(recent.admit("a", at(0), throttle, None).is_some());
+        assert!(recent.admit("b", at(50), throttle, None).is_none());
+        assert_eq!(
+            recent.admit("b", at(150), throttle, None),
+            Some("b (x2)".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_startup_backlog_is_suppressed() {
+        let mut plugin = SystemNotifier::new();
+        let config: toml::Value = toml::from_str(
+            r#"
+[parsers.app]
+pattern = "ERROR: (.*)"
+timestamp_pattern = "^(\\S+)"
+        "#,
+        )
+        .unwrap();
+        plugin.parse_config(&config).unwrap();
+        let parser = &plugin.parsers["app"];
+        assert_eq!(plugin.config.startup_grace_ms, Some(2000));
+
+        let started_at = parse_log_timestamp("2024-10-17T08:30:00Z").unwrap();
+        assert_eq!(parse_log_timestamp("1729153800"), Some(started_at));
+        assert_eq!(
+            parse_log_timestamp("2024-10-17 10:30:00.250+02:00"),
+            Some(started_at)
+        );
+
+        let just_started = StartupFilter {
+            started: Instant::now(),
+            started_at,
+            grace: Duration::from_millis(2000),
+        };
+        assert!(just_started
+            .suppression(parser, "2024-10-17T09:00:00Z ERROR: disk")
+            .is_some());
+
+        let running = StartupFilter {
+            started: Instant::now() - Duration::from_secs(10),
+            ..just_started
+        };
+        // Replayed entry from before startup, then a live one
+        assert!(running
+            .suppression(parser, "2024-10-17T10:29:59+02:00 ERROR: disk")
+            .is_some());
+        assert_eq!(
+            running.suppression(parser, "2024-10-17T08:30:01Z ERROR: disk"),
+            None
+        );
+        // Without a readable timestamp only the grace period applies
+        assert_eq!(running.suppression(parser, "ERROR: disk"), None);
+    }
 }
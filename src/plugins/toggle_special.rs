@@ -8,8 +8,10 @@ use tokio::sync::Mutex;
 use tokio::time::sleep;
 use tracing::{debug, error, info, warn};
 
-use crate::ipc::{HyprlandClient, HyprlandEvent};
-use crate::plugins::Plugin;
+use crate::animation::{AnimationConfig, WindowAnimator};
+use crate::ipc::{HyprlandClient, HyprlandEvent, MonitorInfo};
+use crate::plugins::scratchpads::{GeometryCalculator, PositionAnchor};
+use crate::plugins::{Plugin, PluginContext, ValidationReport};
 
 use hyprland::data::{Client, Clients, Workspaces};
 use hyprland::dispatch::{Dispatch, DispatchType, WorkspaceIdentifierWithSpecial};
@@ -44,6 +46,85 @@ pub struct ToggleSpecialConfig {
     /// Remember window position when moving to/from special workspace (default: true)
     #[serde(default = "default_true")]
     pub remember_position: bool,
+
+    /// Named special workspaces of which at most one is shown: `show <name>`
+    /// hides the others first (default: none)
+    #[serde(default)]
+    pub workspaces: Vec<String>,
+
+    /// Hide the other named special workspaces when showing one (default: true)
+    #[serde(default = "default_true")]
+    pub exclusive: bool,
+
+    /// Per-name settings, `[toggle_special.specials.<name>]`. These names toggle
+    /// as a whole with `toggle_special <name>` and are exclusive like `workspaces`
+    #[serde(default)]
+    pub specials: HashMap<String, NamedSpecialConfig>,
+}
+
+/// Monitor-relative `(position, size)` of a window
+type Geometry = ((i32, i32), (i32, i32));
+
+/// Animations a named special workspace can slide in with
+const SLIDE_ANIMATIONS: [&str; 4] = ["fromTop", "fromBottom", "fromLeft", "fromRight"];
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct NamedSpecialConfig {
+    /// Slide the windows in from this edge when shown: "fromTop", "fromBottom",
+    /// "fromLeft" or "fromRight" (default: Hyprland's own animation)
+    #[serde(default)]
+    pub animation: Option<String>,
+
+    /// Window size as "width height", e.g. "75% 60%" (default: keep the size)
+    #[serde(default)]
+    pub size: Option<String>,
+
+    /// Gap to the edge the windows slide in from, in pixels (default: 0)
+    #[serde(default)]
+    pub margin: Option<i32>,
+}
+
+impl NamedSpecialConfig {
+    /// Monitor-relative `(position, size)` of a window shown at `current`: the
+    /// configured size against the edge it slides in from, or `current` as is
+    fn shown_geometry(
+        &self,
+        animation: &str,
+        current: Geometry,
+        monitor: &MonitorInfo,
+    ) -> Result<Geometry> {
+        let Some(size) = &self.size else {
+            return Ok(current);
+        };
+        let size = GeometryCalculator::parse_size(size, monitor, None)?;
+        let (left, top) = (monitor.reserved_left as i32, monitor.reserved_top as i32);
+        let area = (
+            monitor.width as i32 - left - monitor.reserved_right as i32,
+            monitor.height as i32 - top - monitor.reserved_bottom as i32,
+        );
+        let anchor = match animation {
+            "fromTop" => PositionAnchor::TopCenter,
+            "fromBottom" => PositionAnchor::BottomCenter,
+            "fromLeft" => PositionAnchor::CenterLeft,
+            "fromRight" => PositionAnchor::CenterRight,
+            _ => PositionAnchor::Center,
+        };
+        let (x, y) = anchor.resolve(area, size, self.margin.unwrap_or(0));
+        Ok(((left + x, top + y), size))
+    }
+}
+
+/// Where a window at `shown` sits just off the edge `animation` slides in from
+fn offscreen_geometry(animation: &str, shown: Geometry, monitor: &MonitorInfo) -> Geometry {
+    let ((x, y), (width, height)) = shown;
+    let position = match animation {
+        "fromTop" => (x, -height),
+        "fromBottom" => (x, monitor.height as i32),
+        "fromLeft" => (-width, y),
+        "fromRight" => (monitor.width as i32, y),
+        _ => (x, y),
+    };
+    (position, (width, height))
 }
 
 fn default_special_name() -> String {
@@ -72,6 +153,9 @@ impl Default for ToggleSpecialConfig {
             enable_animations: true,
             auto_close_empty: true,
             remember_position: true,
+            workspaces: Vec::new(),
+            exclusive: true,
+            specials: HashMap::new(),
         }
     }
 }
@@ -105,6 +189,8 @@ pub struct ToggleSpecialPlugin {
     window_positions: HashMap<String, (i32, i32, i32, i32)>, // address -> (x, y, w, h)
     hyprland_client: Arc<Mutex<Option<Arc<HyprlandClient>>>>,
     last_operation_time: Option<Instant>,
+    window_animator: WindowAnimator,
+    slid_out: HashMap<String, Geometry>, // address -> geometry before sliding out
 }
 
 impl ToggleSpecialPlugin {
@@ -116,6 +202,8 @@ impl ToggleSpecialPlugin {
             window_positions: HashMap::new(),
             hyprland_client: Arc::new(Mutex::new(None)),
             last_operation_time: None,
+            window_animator: WindowAnimator::new(),
+            slid_out: HashMap::new(),
         }
     }
 
@@ -323,13 +411,10 @@ impl ToggleSpecialPlugin {
         }
 
         // Move window to special workspace
-        let special_workspace = format!("special:{special_name}");
-
-        tokio::task::spawn_blocking(move || {
-            let workspace_identifier = WorkspaceIdentifierWithSpecial::Name(&special_workspace);
-            Dispatch::call(DispatchType::MoveToWorkspace(workspace_identifier, None))
-        })
-        .await??;
+        self.get_hyprland_client()
+            .await?
+            .move_window_to_workspace(&focused_window.address, &format!("special:{special_name}"))
+            .await?;
 
         // Add animation delay
         self.animate_transition().await?;
@@ -374,12 +459,10 @@ impl ToggleSpecialPlugin {
         }
 
         // Move window to workspace 1 (or current workspace)
-        let workspace_identifier = WorkspaceIdentifierWithSpecial::Id(1);
-
-        tokio::task::spawn_blocking(move || {
-            Dispatch::call(DispatchType::MoveToWorkspace(workspace_identifier, None))
-        })
-        .await??;
+        self.get_hyprland_client()
+            .await?
+            .move_window_to_workspace(&window.address, "1")
+            .await?;
 
         // Add animation delay
         self.animate_transition().await?;
@@ -398,7 +481,174 @@ impl ToggleSpecialPlugin {
         ))
     }
 
-    /// Toggle special workspace visibility
+    async fn get_hyprland_client(&self) -> Result<Arc<HyprlandClient>> {
+        self.hyprland_client
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Hyprland client not available"))
+    }
+
+    /// Names of the special workspaces open on any monitor, without `special:`
+    async fn open_special_workspaces(client: &HyprlandClient) -> Result<Vec<String>> {
+        let monitors = client.get_monitors().await?;
+        Ok(monitors
+            .iter()
+            .filter_map(|monitor| monitor.special_workspace.name.strip_prefix("special:"))
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Dispatch `togglespecialworkspace`, which takes the name without `special:`
+    async fn dispatch_toggle(client: &HyprlandClient, special_name: &str) -> Result<()> {
+        let name = (special_name != "special").then(|| special_name.to_string());
+        client
+            .dispatch(DispatchType::ToggleSpecialWorkspace(name))
+            .await
+    }
+
+    /// Hide a shown special workspace. `togglespecialworkspace` acts on the focused
+    /// monitor, so when another monitor shows it, that one is focused for the
+    /// toggle and focus is handed back afterwards
+    async fn dispatch_hide(client: &HyprlandClient, special_name: &str) -> Result<()> {
+        let monitors = client.get_monitors().await?;
+        let focused = monitors.iter().find(|monitor| monitor.focused);
+        let shown_on = monitors.iter().find(|monitor| {
+            monitor.special_workspace.name.strip_prefix("special:") == Some(special_name)
+        });
+
+        match (shown_on, focused) {
+            (Some(shown_on), Some(focused)) if shown_on.name != focused.name => {
+                Self::focus_monitor(client, &shown_on.name).await?;
+                Self::dispatch_toggle(client, special_name).await?;
+                Self::focus_monitor(client, &focused.name).await
+            }
+            _ => Self::dispatch_toggle(client, special_name).await,
+        }
+    }
+
+    /// Dispatch `focusmonitor`
+    async fn focus_monitor(client: &HyprlandClient, monitor: &str) -> Result<()> {
+        client
+            .dispatch(DispatchType::Custom(
+                "focusmonitor",
+                Box::leak(monitor.to_string().into_boxed_str()),
+            ))
+            .await
+    }
+
+    /// Whether `special_name` is one of the named special workspaces
+    fn is_named(&self, special_name: &str) -> bool {
+        self.config
+            .workspaces
+            .iter()
+            .any(|name| name == special_name)
+            || self.config.specials.contains_key(special_name)
+    }
+
+    /// Named special workspaces: `workspaces`, then `specials` not listed there
+    fn named_workspaces(&self) -> Vec<&str> {
+        let mut specials: Vec<&str> = self
+            .config
+            .specials
+            .keys()
+            .map(String::as_str)
+            .filter(|name| !self.config.workspaces.iter().any(|w| w == name))
+            .collect();
+        specials.sort_unstable();
+        self.config
+            .workspaces
+            .iter()
+            .map(String::as_str)
+            .chain(specials)
+            .collect()
+    }
+
+    /// Slide the windows of `special_name` in (`showing`) or out with its
+    /// configured `animation`. Returns whether it animated
+    async fn slide(&mut self, client: &HyprlandClient, special_name: &str, showing: bool) -> bool {
+        if !self.config.enable_animations {
+            return false;
+        }
+        let Some(special) = self.config.specials.get(special_name).cloned() else {
+            return false;
+        };
+        let Some(animation) = special.animation.clone() else {
+            return false;
+        };
+
+        match self
+            .slide_windows(client, special_name, &special, &animation, showing)
+            .await
+        {
+            Ok(()) => true,
+            Err(e) => {
+                warn!(
+                    "⚠️ Failed to animate special workspace '{}': {}",
+                    special_name, e
+                );
+                false
+            }
+        }
+    }
+
+    async fn slide_windows(
+        &mut self,
+        client: &HyprlandClient,
+        special_name: &str,
+        special: &NamedSpecialConfig,
+        animation: &str,
+        showing: bool,
+    ) -> Result<()> {
+        let monitor = client.get_focused_monitor().await?;
+        let workspace = format!("special:{special_name}");
+
+        let mut moves = Vec::new();
+        for window in client.get_windows().await? {
+            if window.workspace.name != workspace {
+                continue;
+            }
+            let address = window.address.to_string();
+            let current = (
+                (
+                    window.at.0 as i32 - monitor.x,
+                    window.at.1 as i32 - monitor.y,
+                ),
+                (window.size.0 as i32, window.size.1 as i32),
+            );
+            // A window slid out last time sits off-screen: bring it back where it was
+            let before = if showing {
+                self.slid_out.remove(&address).unwrap_or(current)
+            } else {
+                current
+            };
+            let shown = special.shown_geometry(animation, before, &monitor)?;
+            let offscreen = offscreen_geometry(animation, shown, &monitor);
+            if showing {
+                moves.push((address, offscreen, shown));
+            } else {
+                self.slid_out.insert(address.clone(), current);
+                moves.push((address, current, offscreen));
+            }
+        }
+
+        let config = AnimationConfig {
+            animation_type: animation.to_string(),
+            duration: self.config.animation_duration as u32,
+            ..AnimationConfig::default()
+        };
+        let animations = moves.iter().map(|(address, from, to)| {
+            self.window_animator
+                .animate_geometry(address, *from, *to, config.clone(), &monitor)
+        });
+        for result in futures::future::join_all(animations).await {
+            result?;
+        }
+        Ok(())
+    }
+
+    /// Toggle special workspace visibility. With `exclusive`, showing one of the
+    /// named special workspaces first hides the others that are shown
     async fn toggle_special_visibility(&mut self, special_name: &str) -> Result<String> {
         if self.config.debug_logging {
             debug!(
@@ -407,29 +657,54 @@ impl ToggleSpecialPlugin {
             );
         }
 
-        // Use Hyprland's togglespecialworkspace command
-        let special_workspace = if special_name == "special" {
-            "special".to_string()
+        let client = self.get_hyprland_client().await?;
+        let open = Self::open_special_workspaces(&client).await?;
+        let was_open = open.iter().any(|name| name == special_name);
+
+        let mut hidden = Vec::new();
+        if self.config.exclusive && self.is_named(special_name) && !was_open {
+            let others: Vec<&String> = open.iter().filter(|name| self.is_named(name)).collect();
+            for other in others {
+                self.slide(&client, other, false).await;
+                Self::dispatch_hide(&client, other).await?;
+                hidden.push(other.clone());
+            }
+        }
+
+        // Use Hyprland's togglespecialworkspace command, sliding the windows
+        // out before hiding and in once shown
+        let animated = if was_open {
+            let animated = self.slide(&client, special_name, false).await;
+            Self::dispatch_hide(&client, special_name).await?;
+            animated
         } else {
-            format!("special:{special_name}")
+            Self::dispatch_toggle(&client, special_name).await?;
+            self.slide(&client, special_name, true).await
         };
 
-        tokio::task::spawn_blocking(move || {
-            Dispatch::call(DispatchType::ToggleSpecialWorkspace(Some(
-                special_workspace,
-            )))
-        })
-        .await??;
-
         // Add animation delay
-        self.animate_transition().await?;
+        if !animated {
+            self.animate_transition().await?;
+        }
 
         // Update last operation time
         self.last_operation_time = Some(Instant::now());
 
-        Ok(format!(
-            "Toggled visibility of special workspace '{special_name}'"
-        ))
+        if hidden.is_empty() {
+            Ok(format!(
+                "Toggled visibility of special workspace '{special_name}'"
+            ))
+        } else {
+            info!(
+                "👁️ Showing special workspace '{}', hid {}",
+                special_name,
+                hidden.join(", ")
+            );
+            Ok(format!(
+                "Toggled visibility of special workspace '{special_name}' (hid {})",
+                hidden.join(", ")
+            ))
+        }
     }
 
     /// Main toggle function - intelligently decides what to do
@@ -438,6 +713,11 @@ impl ToggleSpecialPlugin {
             .unwrap_or(&self.config.default_special_name)
             .to_string();
 
+        // Special workspaces with their own settings toggle as a whole
+        if self.config.specials.contains_key(&special_name) {
+            return self.toggle_special_visibility(&special_name).await;
+        }
+
         // Update window state
         self.update_windows().await?;
 
@@ -495,6 +775,25 @@ impl ToggleSpecialPlugin {
             }
         }
 
+        let named = self.named_workspaces();
+        if !named.is_empty() {
+            let client = self.get_hyprland_client().await?;
+            let open = Self::open_special_workspaces(&client).await?;
+            if self.config.exclusive {
+                output.push_str("\n🔀 Exclusive special workspaces:\n");
+            } else {
+                output.push_str("\n🔀 Named special workspaces:\n");
+            }
+            for name in named {
+                let shown = if open.iter().any(|open| open == name) {
+                    "👁️  shown"
+                } else {
+                    "🙈 hidden"
+                };
+                output.push_str(&format!("  {name} ({shown})\n"));
+            }
+        }
+
         output.push_str(&format!(
             "\nConfig: default='{}', animations={}, auto-close={}\n",
             self.config.default_special_name,
@@ -552,6 +851,19 @@ impl ToggleSpecialPlugin {
     }
 }
 
+/// Why `name` can't name a special workspace, if it can't
+fn special_name_issue(name: &str) -> Option<String> {
+    if name.is_empty() || name.chars().any(char::is_whitespace) {
+        Some(format!("Invalid special workspace name '{name}'"))
+    } else if name.starts_with("special:") {
+        Some(format!(
+            "Use the name without the 'special:' prefix: '{name}'"
+        ))
+    } else {
+        None
+    }
+}
+
 impl Default for ToggleSpecialPlugin {
     fn default() -> Self {
         Self::new()
@@ -564,10 +876,94 @@ impl Plugin for ToggleSpecialPlugin {
         "toggle_special"
     }
 
+    async fn set_context(&mut self, context: Arc<PluginContext>) {
+        let client = Arc::clone(&context.hyprland_client);
+        *self.hyprland_client.lock().await = Some(Arc::clone(&client));
+        self.window_animator.animation_engine = Arc::clone(&context.animation_engine);
+        self.window_animator.set_hyprland_client(client).await;
+    }
+
+    fn validate_config(&self, config: &toml::Value) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        let config = config.get("toggle_special").unwrap_or(config);
+
+        if let Some(workspaces) = config.get("workspaces") {
+            if let Some(workspaces) = workspaces.as_array() {
+                let mut seen = std::collections::HashSet::new();
+                for (i, name) in workspaces.iter().enumerate() {
+                    let key = format!("workspaces[{i}]");
+                    let Some(name) = name.as_str() else {
+                        report.error(key, "Expected a special workspace name");
+                        continue;
+                    };
+                    if let Some(message) = special_name_issue(name) {
+                        report.error(key, message);
+                    } else if !seen.insert(name) {
+                        report.error(key, format!("Duplicate special workspace '{name}'"));
+                    }
+                }
+            } else {
+                report.error("workspaces", "Expected a list of special workspace names");
+            }
+        }
+
+        let Some(specials) = config.get("specials") else {
+            return report;
+        };
+        let Some(specials) = specials.as_table() else {
+            report.error("specials", "Expected a table of special workspaces");
+            return report;
+        };
+        for (name, special) in specials {
+            let key = format!("specials.{name}");
+            if let Some(message) = special_name_issue(name) {
+                report.error(&key, message);
+            }
+            let special: NamedSpecialConfig = match special.clone().try_into() {
+                Ok(special) => special,
+                Err(e) => {
+                    report.error(key, e.to_string());
+                    continue;
+                }
+            };
+            if let Some(animation) = &special.animation {
+                if !SLIDE_ANIMATIONS.contains(&animation.as_str()) {
+                    report.error(
+                        format!("{key}.animation"),
+                        format!(
+                            "Unknown animation '{animation}' (expected one of {})",
+                            SLIDE_ANIMATIONS.join(", ")
+                        ),
+                    );
+                }
+            }
+            if let Some(size) = &special.size {
+                if let Err(e) = GeometryCalculator::parse_size(size, &MonitorInfo::new(), None) {
+                    report.error(format!("{key}.size"), e.to_string());
+                }
+            }
+        }
+        report
+    }
+
     async fn init(&mut self, config: &toml::Value) -> Result<()> {
         info!("🎯 Initializing toggle_special plugin");
 
-        if let Some(plugin_config) = config.get("toggle_special") {
+        let report = self.validate_config(config);
+        if let Some(issue) = report.errors.first() {
+            return Err(anyhow::anyhow!(
+                "Invalid toggle_special configuration: {}: {}",
+                issue.key,
+                issue.message
+            ));
+        }
+
+        // The plugin's own section, or a nested [toggle_special] table as before
+        let plugin_config = config.get("toggle_special").unwrap_or(config);
+        if plugin_config
+            .as_table()
+            .is_some_and(|table| !table.is_empty())
+        {
             match plugin_config.clone().try_into() {
                 Ok(config) => self.config = config,
                 Err(e) => {
@@ -911,4 +1307,229 @@ mod tests {
         assert_eq!(focused.unwrap().title, "Focused Window");
         assert_eq!(focused.unwrap().address, "0x67890");
     }
+
+    #[test]
+    fn test_validate_workspace_names() {
+        let plugin = create_test_plugin();
+        let validate = |toml: &str| plugin.validate_config(&toml::from_str(toml).unwrap());
+
+        assert!(!validate(r#"workspaces = ["term", "music"]"#).has_errors());
+        assert!(validate(r#"workspaces = "term""#).has_errors());
+        assert_eq!(
+            validate(r#"workspaces = ["term", "special:music", "term", "my notes", 3]"#)
+                .errors
+                .iter()
+                .map(|issue| issue.key.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "workspaces[1]",
+                "workspaces[2]",
+                "workspaces[3]",
+                "workspaces[4]"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_showing_named_special_hides_the_shown_one() {
+        use crate::ipc::mock::{monitor, with_special, MockHyprland};
+
+        let mut plugin = create_test_plugin();
+        plugin.config.enable_animations = false;
+        plugin.config.workspaces = vec!["term".to_string(), "music".to_string()];
+
+        let api = Arc::new(MockHyprland::new(
+            vec![],
+            vec![with_special(
+                monitor("DP-1", (1920, 1080), true, 1),
+                "music",
+            )],
+        ));
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+
+        let result = plugin.handle_command("show", &["term"]).await.unwrap();
+        assert!(result.contains("hid music"), "{result}");
+        assert_eq!(
+            api.calls(),
+            vec![
+                "dispatch togglespecialworkspace music",
+                "dispatch togglespecialworkspace term",
+            ]
+        );
+
+        // Toggling the shown one, or one outside the list, touches nothing else
+        plugin.handle_command("show", &["music"]).await.unwrap();
+        plugin.handle_command("show", &["scratch"]).await.unwrap();
+        assert_eq!(
+            api.calls()[2..],
+            [
+                "dispatch togglespecialworkspace music",
+                "dispatch togglespecialworkspace scratch",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_special_shown_on_another_monitor_is_hidden_there() {
+        use crate::ipc::mock::{monitor, with_special, MockHyprland};
+
+        let mut plugin = create_test_plugin();
+        plugin.config.enable_animations = false;
+        plugin.config.workspaces = vec!["term".to_string(), "music".to_string()];
+
+        let api = Arc::new(MockHyprland::new(
+            vec![],
+            vec![
+                monitor("DP-1", (1920, 1080), true, 1),
+                with_special(monitor("HDMI-A-1", (1920, 1080), false, 2), "music"),
+            ],
+        ));
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+
+        // Hidden by exclusivity, then directly
+        plugin.handle_command("show", &["term"]).await.unwrap();
+        plugin.handle_command("show", &["music"]).await.unwrap();
+        let hide_music = [
+            "dispatch focusmonitor HDMI-A-1",
+            "dispatch togglespecialworkspace music",
+            "dispatch focusmonitor DP-1",
+        ];
+        let mut expected = hide_music.to_vec();
+        expected.push("dispatch togglespecialworkspace term");
+        expected.extend(hide_music);
+        assert_eq!(api.calls(), expected);
+    }
+
+    #[test]
+    fn test_validate_specials() {
+        let plugin = create_test_plugin();
+        let validate = |toml: &str| plugin.validate_config(&toml::from_str(toml).unwrap());
+
+        assert!(!validate(
+            r#"
+            [specials.chat]
+            animation = "fromRight"
+            size = "30% 90%"
+            margin = 10
+            "#
+        )
+        .has_errors());
+        assert_eq!(
+            validate(
+                r#"
+                [specials.chat]
+                animation = "zoom"
+                size = "30%"
+                [specials."special:music"]
+                "#
+            )
+            .errors
+            .iter()
+            .map(|issue| issue.key.as_str())
+            .collect::<Vec<_>>(),
+            vec![
+                "specials.chat.animation",
+                "specials.chat.size",
+                "specials.special:music"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_slide_geometry() {
+        let mut monitor = MonitorInfo::new();
+        monitor.width = 1920;
+        monitor.height = 1080;
+        monitor.reserved_top = 30;
+        let special = NamedSpecialConfig {
+            animation: Some("fromTop".to_string()),
+            size: Some("50% 50%".to_string()),
+            margin: Some(20),
+        };
+        let current = ((100, 100), (800, 600));
+
+        let shown = special
+            .shown_geometry("fromTop", current, &monitor)
+            .unwrap();
+        assert_eq!(shown, ((480, 50), (960, 540)));
+        assert_eq!(
+            offscreen_geometry("fromTop", shown, &monitor),
+            ((480, -540), (960, 540))
+        );
+        assert_eq!(
+            offscreen_geometry("fromRight", current, &monitor),
+            ((1920, 100), (800, 600))
+        );
+
+        // Without a size the window keeps its geometry
+        let special = NamedSpecialConfig::default();
+        assert_eq!(
+            special
+                .shown_geometry("fromTop", current, &monitor)
+                .unwrap(),
+            current
+        );
+    }
+
+    #[tokio::test]
+    async fn test_named_special_slides_in() {
+        use crate::ipc::mock::{monitor, window, MockHyprland};
+
+        let mut plugin = create_test_plugin();
+        plugin.config.animation_duration = 20;
+        plugin.config.specials.insert(
+            "chat".to_string(),
+            NamedSpecialConfig {
+                animation: Some("fromTop".to_string()),
+                size: Some("50% 50%".to_string()),
+                margin: Some(20),
+            },
+        );
+
+        // On a monitor right of another one: moves are monitor-relative
+        let mut chat = window("0xc4a7", "chat", (1920, 0), -98);
+        chat.workspace.name = "special:chat".to_string();
+        let mut dp1 = monitor("DP-1", (1920, 1080), true, 1);
+        dp1.x = 1920;
+        let api = Arc::new(MockHyprland::new(vec![chat], vec![dp1]));
+        let client = Arc::new(HyprlandClient::with_api(api.clone()));
+        *plugin.hyprland_client.lock().await = Some(Arc::clone(&client));
+        plugin.window_animator.set_hyprland_client(client).await;
+
+        plugin.handle_command("", &["chat"]).await.unwrap();
+        let calls = api.calls();
+        assert_eq!(calls[0], "dispatch togglespecialworkspace chat");
+        assert_eq!(
+            calls[calls.len() - 2..],
+            [
+                "dispatch resizewindowpixel exact 960 540,address:0xc4a7",
+                "dispatch movewindowpixel exact 480 20,address:0xc4a7",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_non_exclusive_named_specials_stay_shown() {
+        use crate::ipc::mock::{monitor, with_special, MockHyprland};
+
+        let mut plugin = create_test_plugin();
+        plugin.config.enable_animations = false;
+        plugin.config.exclusive = false;
+        plugin.config.workspaces = vec!["term".to_string(), "music".to_string()];
+
+        let api = Arc::new(MockHyprland::new(
+            vec![],
+            vec![with_special(
+                monitor("DP-1", (1920, 1080), true, 1),
+                "music",
+            )],
+        ));
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+
+        plugin.handle_command("show", &["term"]).await.unwrap();
+        assert_eq!(api.calls(), vec!["dispatch togglespecialworkspace term"]);
+    }
 }
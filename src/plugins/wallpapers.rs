@@ -1,7 +1,7 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -10,7 +10,7 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::fs;
 use tokio::sync::Mutex;
-use tokio::time::{interval, sleep};
+use tokio::time::{interval_at, sleep};
 use tracing::{debug, error, info, warn};
 
 use crate::ipc::{HyprlandClient, HyprlandEvent};
@@ -24,10 +24,18 @@ pub struct WallpapersConfig {
     /// Wallpaper directories to scan
     pub path: WallpaperPath,
 
-    /// Interval between wallpaper changes in seconds (default: 600)
-    #[serde(default = "default_interval")]
+    /// Interval between wallpaper changes in seconds (default: 600). Also accepts
+    /// a duration string such as `"15m"`, `"1h30m"` or `"45s"`
+    #[serde(
+        default = "default_interval",
+        deserialize_with = "deserialize_interval"
+    )]
     pub interval: u64,
 
+    /// Order in which the rotation goes through the wallpapers (default: shuffle)
+    #[serde(default)]
+    pub order: RotationOrder,
+
     /// Supported image file extensions
     #[serde(default = "default_extensions")]
     pub extensions: Vec<String>,
@@ -55,6 +63,21 @@ pub struct WallpapersConfig {
     /// Preload next wallpapers for faster switching (default: 3)
     #[serde(default = "default_preload_count")]
     pub preload_count: usize,
+
+    /// Monitors with their own wallpapers, by monitor name
+    #[serde(default)]
+    pub monitors: HashMap<String, MonitorWallpapersConfig>,
+}
+
+/// `[wallpapers.monitors.<name>]`: what one monitor rotates through instead of `path`
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MonitorWallpapersConfig {
+    /// Wallpaper directories or image files for this monitor
+    pub path: WallpaperPath,
+
+    /// Command template for this monitor (default: the plugin's `command`)
+    #[serde(default)]
+    pub command: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -64,10 +87,79 @@ pub enum WallpaperPath {
     Multiple(Vec<PathBuf>),
 }
 
+/// How the rotation picks the next wallpaper
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RotationOrder {
+    /// Any wallpaper but the one on screen
+    Random,
+    /// By path, wrapping around
+    Sequential,
+    /// Random order that shows every wallpaper once before starting over
+    #[default]
+    Shuffle,
+}
+
 fn default_interval() -> u64 {
     600 // 10 minutes
 }
 
+fn deserialize_interval<'de, D>(deserializer: D) -> std::result::Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Interval {
+        Seconds(u64),
+        Text(String),
+    }
+
+    match Interval::deserialize(deserializer)? {
+        Interval::Seconds(seconds) => Ok(seconds),
+        Interval::Text(text) => parse_interval(&text).map_err(serde::de::Error::custom),
+    }
+}
+
+/// Seconds in an interval such as `"900"`, `"15m"` or `"1h30m"` (units: s, m, h, d)
+fn parse_interval(text: &str) -> Result<u64> {
+    let text = text.trim();
+    if let Ok(seconds) = text.parse() {
+        return Ok(seconds);
+    }
+
+    let mut total = 0u64;
+    let mut digits = String::new();
+    for c in text.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let unit = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Invalid interval '{text}': unknown unit '{c}'"
+                ))
+            }
+        };
+        let value: u64 = digits
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid interval '{text}': no number before '{c}'"))?;
+        total += value * unit;
+        digits.clear();
+    }
+    if !digits.is_empty() || text.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Invalid interval '{text}': expected a number of seconds or a duration like 15m"
+        ));
+    }
+    Ok(total)
+}
+
 fn default_extensions() -> Vec<String> {
     vec![
         "jpg".to_string(),
@@ -91,6 +183,7 @@ impl Default for WallpapersConfig {
         Self {
             path: WallpaperPath::Single(PathBuf::from("~/Pictures/wallpapers")),
             interval: 600,
+            order: RotationOrder::default(),
             extensions: default_extensions(),
             recurse: false,
             unique: false,
@@ -98,6 +191,7 @@ impl Default for WallpapersConfig {
             clear_command: None,
             debug_logging: false,
             preload_count: 3,
+            monitors: HashMap::new(),
         }
     }
 }
@@ -119,15 +213,295 @@ pub struct MonitorState {
     pub last_change: Instant,
 }
 
+/// Most wallpapers `prev` can walk back through
+const ROTATION_HISTORY: usize = 100;
+
+/// Position of the rotation in the wallpaper list
+#[derive(Debug)]
+struct Rotation {
+    order: RotationOrder,
+    /// Indices shown so far, oldest first
+    history: Vec<usize>,
+    /// Position in `history` of the wallpaper on screen
+    cursor: Option<usize>,
+    /// Indices the shuffle order has yet to show this round
+    deck: Vec<usize>,
+}
+
+impl Rotation {
+    fn new(order: RotationOrder) -> Self {
+        Self {
+            order,
+            history: Vec::new(),
+            cursor: None,
+            deck: Vec::new(),
+        }
+    }
+
+    fn current(&self) -> Option<usize> {
+        self.cursor.map(|cursor| self.history[cursor])
+    }
+
+    /// Index of the next wallpaper out of `count`. After `prev`, this first walks
+    /// forward again through what was already shown
+    fn next(&mut self, count: usize) -> Option<usize> {
+        if count == 0 {
+            return None;
+        }
+        if let Some(cursor) = self.cursor.filter(|c| c + 1 < self.history.len()) {
+            self.cursor = Some(cursor + 1);
+            return self.current().filter(|index| *index < count);
+        }
+
+        let current = self.current();
+        let index = match self.order {
+            RotationOrder::Sequential => current.map_or(0, |index| (index + 1) % count),
+            RotationOrder::Random if count > 1 => {
+                // Draw among the others: skip over the current one
+                let index = thread_rng().gen_range(0..count - 1);
+                match current {
+                    Some(current) if index >= current => index + 1,
+                    _ => index,
+                }
+            }
+            RotationOrder::Random => 0,
+            RotationOrder::Shuffle => {
+                self.deck.retain(|index| *index < count);
+                if self.deck.is_empty() {
+                    self.deck = (0..count).collect();
+                    self.deck.shuffle(&mut thread_rng());
+                    // A new round must not open with the wallpaper that closed the last
+                    if count > 1 && self.deck.last() == current.as_ref() {
+                        self.deck.swap(0, count - 1);
+                    }
+                }
+                self.deck.pop()?
+            }
+        };
+
+        self.history.push(index);
+        if self.history.len() > ROTATION_HISTORY {
+            self.history.remove(0);
+        }
+        self.cursor = Some(self.history.len() - 1);
+        Some(index)
+    }
+
+    /// Index of the wallpaper shown before the current one; sequential order
+    /// keeps stepping backwards past the start of the history
+    fn prev(&mut self, count: usize) -> Option<usize> {
+        match self.cursor {
+            Some(cursor) if cursor > 0 => {
+                self.cursor = Some(cursor - 1);
+                self.current().filter(|index| *index < count)
+            }
+            Some(_) if self.order == RotationOrder::Sequential && count > 0 => {
+                let index = (self.current()? + count - 1) % count;
+                self.history.insert(0, index);
+                self.history.truncate(ROTATION_HISTORY);
+                self.current()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The wallpapers each monitor rotates through and the command that sets them
+#[derive(Debug, Clone, Default)]
+struct Pools {
+    wallpapers: Vec<WallpaperInfo>,
+    command: String,
+    /// Monitors configured under `[wallpapers.monitors]`
+    monitors: HashMap<String, (Vec<WallpaperInfo>, String)>,
+}
+
+impl Pools {
+    fn for_monitor(&self, monitor_name: &str) -> (&[WallpaperInfo], &str) {
+        match self.monitors.get(monitor_name) {
+            Some((wallpapers, command)) => (wallpapers, command),
+            None => (&self.wallpapers, &self.command),
+        }
+    }
+}
+
+/// What the rotation timer task shares with the plugin: the rotation position,
+/// the backend process per monitor and what each monitor shows
+#[derive(Debug)]
+struct RotationState {
+    /// Rotation of the monitors sharing the default wallpapers
+    rotation: Rotation,
+    /// Rotations of monitors stepped on their own, or with their own wallpapers
+    monitor_rotations: HashMap<String, Rotation>,
+    paused: bool,
+    processes: HashMap<String, u32>,
+    monitors: HashMap<String, MonitorState>,
+}
+
+impl RotationState {
+    fn new(order: RotationOrder) -> Self {
+        Self {
+            rotation: Rotation::new(order),
+            monitor_rotations: HashMap::new(),
+            paused: false,
+            processes: HashMap::new(),
+            monitors: HashMap::new(),
+        }
+    }
+
+    /// Start over, e.g. because the wallpaper lists changed
+    fn reset(&mut self, order: RotationOrder) {
+        self.rotation = Rotation::new(order);
+        self.monitor_rotations.clear();
+    }
+
+    /// Run the wallpaper `command` for one monitor, replacing the backend process
+    /// it started last time. `[file]` and `[output]` are substituted
+    fn show(&mut self, command: &str, monitor_name: &str, wallpaper_path: &Path) -> Result<()> {
+        // Kill existing process for this monitor if any
+        if let Some(old_pid) = self.processes.get(monitor_name) {
+            if let Err(e) = Command::new("kill").arg(old_pid.to_string()).output() {
+                debug!("Failed to kill old wallpaper process {}: {}", old_pid, e);
+            }
+        }
+
+        let command = command
+            .replace("[file]", &wallpaper_path.to_string_lossy())
+            .replace("[output]", monitor_name);
+
+        // Add monitor specification if supported
+        let full_command = if command.contains("swaybg") {
+            format!("{} -o {}", command, monitor_name)
+        } else {
+            command
+        };
+
+        debug!(
+            "🖼️  Setting wallpaper on {}: {}",
+            monitor_name,
+            wallpaper_path.display()
+        );
+
+        // Execute the wallpaper command
+        let child = Command::new("sh").arg("-c").arg(&full_command).spawn()?;
+
+        // Store the process ID for cleanup
+        self.processes.insert(monitor_name.to_string(), child.id());
+
+        // Update monitor state
+        let monitor_state = self
+            .monitors
+            .entry(monitor_name.to_string())
+            .or_insert_with(|| MonitorState {
+                name: monitor_name.to_string(),
+                current_wallpaper: None,
+                wallpaper_index: 0,
+                last_change: Instant::now(),
+            });
+
+        monitor_state.current_wallpaper = Some(wallpaper_path.to_path_buf());
+        monitor_state.last_change = Instant::now();
+
+        Ok(())
+    }
+
+    /// Step the rotation forward (or `backwards`) and show where it lands on every
+    /// monitor; with `unique`, each monitor gets the wallpaper after the previous
+    /// one's. Monitors with their own wallpapers step through those instead.
+    /// `None` when there is no wallpaper to step to
+    fn rotate(
+        &mut self,
+        pools: &Pools,
+        monitors: &[String],
+        unique: bool,
+        backwards: bool,
+    ) -> Result<Option<Vec<String>>> {
+        let (own, shared): (Vec<&String>, Vec<&String>) = monitors
+            .iter()
+            .partition(|monitor| pools.monitors.contains_key(*monitor));
+
+        let mut shown = Vec::new();
+        let count = pools.wallpapers.len();
+        let index = match shared.is_empty() {
+            true => None,
+            false if backwards => self.rotation.prev(count),
+            false => self.rotation.next(count),
+        };
+        if let Some(index) = index {
+            for (offset, monitor_name) in shared.into_iter().enumerate() {
+                let wallpaper_index = if unique {
+                    (index + offset) % count
+                } else {
+                    index
+                };
+                let wallpaper = &pools.wallpapers[wallpaper_index];
+                self.show(&pools.command, monitor_name, &wallpaper.path)?;
+                if let Some(monitor_state) = self.monitors.get_mut(monitor_name) {
+                    monitor_state.wallpaper_index = wallpaper_index;
+                }
+                shown.push(format!("{}: {}", monitor_name, wallpaper.filename));
+            }
+        }
+
+        for monitor_name in own {
+            shown.extend(self.rotate_monitor(pools, monitor_name, backwards)?);
+        }
+
+        Ok((!shown.is_empty()).then_some(shown))
+    }
+
+    /// Step only `monitor_name`, leaving the other monitors as they are
+    fn rotate_monitor(
+        &mut self,
+        pools: &Pools,
+        monitor_name: &str,
+        backwards: bool,
+    ) -> Result<Option<String>> {
+        let (wallpapers, command) = pools.for_monitor(monitor_name);
+        let order = self.rotation.order;
+        let rotation = self
+            .monitor_rotations
+            .entry(monitor_name.to_string())
+            .or_insert_with(|| Rotation::new(order));
+        let index = if backwards {
+            rotation.prev(wallpapers.len())
+        } else {
+            rotation.next(wallpapers.len())
+        };
+        let Some(index) = index else {
+            return Ok(None);
+        };
+
+        let wallpaper = &wallpapers[index];
+        self.show(command, monitor_name, &wallpaper.path)?;
+        if let Some(monitor_state) = self.monitors.get_mut(monitor_name) {
+            monitor_state.wallpaper_index = index;
+        }
+        Ok(Some(format!("{}: {}", monitor_name, wallpaper.filename)))
+    }
+}
+
+/// Names of the connected monitors
+fn monitor_names() -> Vec<String> {
+    match Monitors::get() {
+        Ok(monitors) => monitors.iter().map(|m| m.name.clone()).collect(),
+        Err(e) => {
+            warn!("Failed to get monitors from Hyprland: {}", e);
+            vec!["DP-1".to_string()] // Default fallback
+        }
+    }
+}
+
 pub struct WallpapersPlugin {
     config: WallpapersConfig,
     wallpapers: Vec<WallpaperInfo>,
-    monitors: HashMap<String, MonitorState>,
+    /// Wallpapers of the monitors configured under `[wallpapers.monitors]`
+    monitor_wallpapers: HashMap<String, Vec<WallpaperInfo>>,
     hyprland_client: Arc<Mutex<Option<Arc<HyprlandClient>>>>,
     rotation_handle: Option<tokio::task::JoinHandle<()>>,
+    /// Shared with the rotation task
+    state: Arc<std::sync::Mutex<RotationState>>,
     last_scan: Option<Instant>,
     preloaded_images: HashMap<PathBuf, Vec<u8>>, // Cache for better performance
-    active_processes: HashMap<String, u32>, // Track active wallpaper backend processes per monitor
 }
 
 impl Default for WallpapersPlugin {
@@ -141,12 +515,14 @@ impl WallpapersPlugin {
         Self {
             config: WallpapersConfig::default(),
             wallpapers: Vec::new(),
-            monitors: HashMap::new(),
+            monitor_wallpapers: HashMap::new(),
             hyprland_client: Arc::new(Mutex::new(None)),
             rotation_handle: None,
+            state: Arc::new(std::sync::Mutex::new(RotationState::new(
+                RotationOrder::default(),
+            ))),
             last_scan: None,
             preloaded_images: HashMap::new(),
-            active_processes: HashMap::new(),
         }
     }
 
@@ -165,54 +541,103 @@ impl WallpapersPlugin {
 
     /// Scan wallpaper directories and populate the wallpapers list
     async fn scan_wallpapers(&mut self) -> Result<()> {
-        self.wallpapers.clear();
-        let mut wallpapers = Vec::new();
-
-        match &self.config.path {
-            WallpaperPath::Single(path) => {
-                let expanded_path = self.expand_path(path)?;
-                if expanded_path.exists() {
-                    if self.config.recurse {
-                        self.scan_directory_recursive(&expanded_path, &mut wallpapers)
-                            .await?;
-                    } else {
-                        self.scan_directory(&expanded_path, &mut wallpapers).await?;
-                    }
-                } else {
-                    warn!("Wallpaper path does not exist: {}", expanded_path.display());
-                }
-            }
-            WallpaperPath::Multiple(paths) => {
-                for path in paths {
-                    let expanded_path = self.expand_path(path)?;
-                    if expanded_path.exists() {
-                        if self.config.recurse {
-                            self.scan_directory_recursive(&expanded_path, &mut wallpapers)
-                                .await?;
-                        } else {
-                            self.scan_directory(&expanded_path, &mut wallpapers).await?;
-                        }
-                    } else {
-                        warn!("Wallpaper path does not exist: {}", expanded_path.display());
-                    }
-                }
-            }
-        }
-
-        // Randomize order
-        wallpapers.shuffle(&mut thread_rng());
-
-        self.wallpapers = wallpapers;
+        self.wallpapers = self.scan_path(&self.config.path).await?;
         self.last_scan = Some(Instant::now());
 
         info!("🖼️  Found {} wallpapers", self.wallpapers.len());
 
+        let mut monitor_wallpapers = HashMap::new();
+        for (monitor_name, monitor_config) in &self.config.monitors {
+            let wallpapers = self.scan_path(&monitor_config.path).await?;
+            info!(
+                "🖼️  Found {} wallpapers for {}",
+                wallpapers.len(),
+                monitor_name
+            );
+            monitor_wallpapers.insert(monitor_name.clone(), wallpapers);
+        }
+        self.monitor_wallpapers = monitor_wallpapers;
+
+        // Indices into the old lists mean nothing now
+        self.state.lock().unwrap().reset(self.config.order);
+        if self.rotation_handle.is_some() {
+            self.stop_rotation().await?;
+            self.spawn_rotation(Duration::from_secs(self.config.interval))
+                .await?;
+        }
+
         // Preload some images for better performance
         self.preload_images().await?;
 
         Ok(())
     }
 
+    /// Images in the directories (or image files) of `path`, by path
+    async fn scan_path(&self, path: &WallpaperPath) -> Result<Vec<WallpaperInfo>> {
+        let paths = match path {
+            WallpaperPath::Single(path) => std::slice::from_ref(path),
+            WallpaperPath::Multiple(paths) => paths.as_slice(),
+        };
+
+        let mut wallpapers = Vec::new();
+        for path in paths {
+            let expanded_path = self.expand_path(path)?;
+            if expanded_path.is_file() {
+                let metadata = fs::metadata(&expanded_path).await?;
+                wallpapers.push(WallpaperInfo {
+                    filename: expanded_path
+                        .file_name()
+                        .unwrap_or_default()
+                        .to_string_lossy()
+                        .to_string(),
+                    path: expanded_path,
+                    size_bytes: metadata.len(),
+                    last_modified: metadata.modified().unwrap_or(std::time::UNIX_EPOCH),
+                    dimensions: None,
+                });
+            } else if expanded_path.exists() {
+                if self.config.recurse {
+                    self.scan_directory_recursive(&expanded_path, &mut wallpapers)
+                        .await?;
+                } else {
+                    self.scan_directory(&expanded_path, &mut wallpapers).await?;
+                }
+            } else {
+                warn!("Wallpaper path does not exist: {}", expanded_path.display());
+            }
+        }
+
+        // The rotation order decides how to walk through them
+        wallpapers.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(wallpapers)
+    }
+
+    /// Command template that sets the wallpaper of `monitor_name`
+    fn command_for(&self, monitor_name: &str) -> &str {
+        self.config
+            .monitors
+            .get(monitor_name)
+            .and_then(|monitor_config| monitor_config.command.as_deref())
+            .unwrap_or(&self.config.command)
+    }
+
+    /// Wallpapers and commands for the rotation
+    fn pools(&self) -> Pools {
+        let monitors = self
+            .monitor_wallpapers
+            .iter()
+            .map(|(monitor_name, wallpapers)| {
+                let command = self.command_for(monitor_name).to_string();
+                (monitor_name.clone(), (wallpapers.clone(), command))
+            })
+            .collect();
+        Pools {
+            wallpapers: self.wallpapers.clone(),
+            command: self.config.command.clone(),
+            monitors,
+        }
+    }
+
     /// Scan a single directory for images
     async fn scan_directory(&self, path: &Path, wallpapers: &mut Vec<WallpaperInfo>) -> Result<()> {
         let mut entries = fs::read_dir(path).await?;
@@ -290,39 +715,6 @@ impl WallpapersPlugin {
         Ok(())
     }
 
-    /// Get the next wallpaper for a specific monitor
-    fn get_next_wallpaper(&mut self, monitor_name: &str) -> Option<PathBuf> {
-        if self.wallpapers.is_empty() {
-            return None;
-        }
-
-        let monitor_count = self.monitors.len();
-        let monitor_state = self
-            .monitors
-            .entry(monitor_name.to_string())
-            .or_insert_with(|| MonitorState {
-                name: monitor_name.to_string(),
-                current_wallpaper: None,
-                wallpaper_index: 0,
-                last_change: Instant::now(),
-            });
-
-        if self.config.unique {
-            // Each monitor gets a different wallpaper
-            let wallpaper_index =
-                (monitor_state.wallpaper_index + monitor_count) % self.wallpapers.len();
-            monitor_state.wallpaper_index = wallpaper_index;
-        } else {
-            // All monitors get the same wallpaper
-            monitor_state.wallpaper_index =
-                (monitor_state.wallpaper_index + 1) % self.wallpapers.len();
-        }
-
-        self.wallpapers
-            .get(monitor_state.wallpaper_index)
-            .map(|w| w.path.clone())
-    }
-
     /// Set wallpaper for a specific monitor
     async fn set_wallpaper(
         &mut self,
@@ -336,85 +728,75 @@ impl WallpapersPlugin {
             self.get_monitor_names().await?
         };
 
+        let mut state = self.state.lock().unwrap();
         for monitor in monitors {
-            self.set_wallpaper_for_monitor(&monitor, wallpaper_path)
-                .await?;
+            state.show(self.command_for(&monitor), &monitor, wallpaper_path)?;
         }
 
         Ok(())
     }
 
-    /// Set wallpaper for a specific monitor
-    async fn set_wallpaper_for_monitor(
-        &mut self,
-        monitor_name: &str,
-        wallpaper_path: &Path,
-    ) -> Result<()> {
-        // Kill existing process for this monitor if any
-        if let Some(old_pid) = self.active_processes.get(monitor_name) {
-            if let Err(e) = Command::new("kill").arg(old_pid.to_string()).output() {
-                debug!("Failed to kill old wallpaper process {}: {}", old_pid, e);
-            }
+    /// Show the next (or previous) wallpaper of the rotation on every monitor, or
+    /// only on `monitor_name`
+    async fn step(&mut self, backwards: bool, monitor_name: Option<&str>) -> Result<String> {
+        if self.wallpapers.is_empty() && self.monitor_wallpapers.is_empty() {
+            self.scan_wallpapers().await?;
+        }
+        if self.wallpapers.is_empty() && self.monitor_wallpapers.values().all(Vec::is_empty) {
+            return Ok("No wallpapers found".to_string());
         }
 
-        // Replace [file] placeholder with actual file path
-        let command = self
-            .config
-            .command
-            .replace("[file]", &wallpaper_path.to_string_lossy());
-
-        // Add monitor specification if supported
-        let full_command = if command.contains("swaybg") {
-            format!("{} -o {}", command, monitor_name)
-        } else {
-            command
+        let pools = self.pools();
+        let shown = match monitor_name {
+            Some(monitor_name) => self
+                .state
+                .lock()
+                .unwrap()
+                .rotate_monitor(&pools, monitor_name, backwards)?
+                .map(|shown| vec![shown]),
+            None => {
+                let monitors = self.get_monitor_names().await?;
+                self.state.lock().unwrap().rotate(
+                    &pools,
+                    &monitors,
+                    self.config.unique,
+                    backwards,
+                )?
+            }
         };
 
-        debug!(
-            "🖼️  Setting wallpaper on {}: {}",
-            monitor_name,
-            wallpaper_path.display()
-        );
-
-        // Execute the wallpaper command
-        let child = Command::new("sh").arg("-c").arg(&full_command).spawn()?;
-
-        // Store the process ID for cleanup
-        let pid = child.id();
-        self.active_processes.insert(monitor_name.to_string(), pid);
-
-        // Update monitor state
-        let monitor_state = self
-            .monitors
-            .entry(monitor_name.to_string())
-            .or_insert_with(|| MonitorState {
-                name: monitor_name.to_string(),
-                current_wallpaper: None,
-                wallpaper_index: 0,
-                last_change: Instant::now(),
-            });
+        Ok(match shown {
+            Some(shown) => format!("Set wallpapers: {}", shown.join(", ")),
+            None if backwards => "No previous wallpaper".to_string(),
+            None => "No wallpapers found".to_string(),
+        })
+    }
 
-        monitor_state.current_wallpaper = Some(wallpaper_path.to_path_buf());
-        monitor_state.last_change = Instant::now();
+    /// Give a monitor that just appeared a wallpaper, once wallpapers are in use
+    fn assign_new_monitor(&mut self, monitor_name: &str) -> Result<()> {
+        let pools = self.pools();
+        let mut state = self.state.lock().unwrap();
+        if state.monitors.is_empty() || state.monitors.contains_key(monitor_name) {
+            return Ok(());
+        }
 
+        if let Some(shown) = state.rotate_monitor(&pools, monitor_name, false)? {
+            info!("🖥️  New monitor, set wallpaper {}", shown);
+        }
         Ok(())
     }
 
     /// Get list of monitor names from Hyprland
     async fn get_monitor_names(&self) -> Result<Vec<String>> {
-        match Monitors::get() {
-            Ok(monitors) => Ok(monitors.iter().map(|m| m.name.clone()).collect()),
-            Err(e) => {
-                warn!("Failed to get monitors from Hyprland: {}", e);
-                Ok(vec!["DP-1".to_string()]) // Default fallback
-            }
-        }
+        Ok(monitor_names())
     }
 
     /// Clear all wallpapers
     async fn clear_wallpapers(&mut self) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+
         // Kill all active processes
-        for (monitor, pid) in &self.active_processes {
+        for (monitor, pid) in &state.processes {
             if let Err(e) = Command::new("kill").arg(pid.to_string()).output() {
                 debug!(
                     "Failed to kill wallpaper process {} for {}: {}",
@@ -422,7 +804,7 @@ impl WallpapersPlugin {
                 );
             }
         }
-        self.active_processes.clear();
+        state.processes.clear();
 
         // Execute clear command if specified
         if let Some(clear_cmd) = &self.config.clear_command {
@@ -430,7 +812,7 @@ impl WallpapersPlugin {
         }
 
         // Clear monitor states
-        for monitor_state in self.monitors.values_mut() {
+        for monitor_state in state.monitors.values_mut() {
             monitor_state.current_wallpaper = None;
         }
 
@@ -442,59 +824,48 @@ impl WallpapersPlugin {
         if self.rotation_handle.is_some() {
             return Ok(()); // Already running
         }
-
-        let interval_secs = self.config.interval;
-        let unique = self.config.unique;
+        if self.config.interval == 0 {
+            return Err(anyhow::anyhow!(
+                "Wallpaper rotation is disabled (interval = 0)"
+            ));
+        }
 
         info!(
-            "🔄 Starting wallpaper rotation (interval: {}s)",
-            interval_secs
+            "🔄 Starting wallpaper rotation (interval: {}s, order: {:?})",
+            self.config.interval, self.config.order
         );
 
+        self.state.lock().unwrap().paused = false;
+        self.spawn_rotation(Duration::ZERO).await
+    }
+
+    /// Spawn the rotation timer, first firing after `first_tick`
+    async fn spawn_rotation(&mut self, first_tick: Duration) -> Result<()> {
+        let period = Duration::from_secs(self.config.interval);
+        let unique = self.config.unique;
+
         // Clone necessary data for the background task
-        let wallpapers = self.wallpapers.clone();
-        let monitors = self.get_monitor_names().await?;
-        let command = self.config.command.clone();
+        let state = Arc::clone(&self.state);
+        let pools = self.pools();
 
         let handle = tokio::spawn(async move {
-            let mut interval = interval(Duration::from_secs(interval_secs));
-            let mut wallpaper_index = 0;
+            let mut ticks = interval_at(tokio::time::Instant::now() + first_tick, period);
 
             loop {
-                interval.tick().await;
+                ticks.tick().await;
 
-                if wallpapers.is_empty() {
-                    debug!("No wallpapers available for rotation");
+                // Monitors plugged in since the last tick join the rotation
+                let monitors = monitor_names();
+                let mut state = state.lock().unwrap();
+                if state.paused {
                     continue;
                 }
 
-                for (monitor_idx, monitor_name) in monitors.iter().enumerate() {
-                    let wallpaper = if unique {
-                        // Each monitor gets a different wallpaper
-                        &wallpapers[(wallpaper_index + monitor_idx) % wallpapers.len()]
-                    } else {
-                        // All monitors get the same wallpaper
-                        &wallpapers[wallpaper_index % wallpapers.len()]
-                    };
-
-                    let full_command = command.replace("[file]", &wallpaper.path.to_string_lossy());
-                    let full_command = if full_command.contains("swaybg") {
-                        format!("{} -o {}", full_command, monitor_name)
-                    } else {
-                        full_command
-                    };
-
-                    if let Err(e) = Command::new("sh").arg("-c").arg(&full_command).spawn() {
-                        error!("Failed to set wallpaper: {}", e);
-                    } else {
-                        debug!(
-                            "🖼️  Set wallpaper on {}: {}",
-                            monitor_name, wallpaper.filename
-                        );
-                    }
+                match state.rotate(&pools, &monitors, unique, false) {
+                    Ok(Some(shown)) => debug!("🖼️  Rotated wallpapers: {}", shown.join(", ")),
+                    Ok(None) => debug!("No wallpapers available for rotation"),
+                    Err(e) => error!("Failed to set wallpaper: {}", e),
                 }
-
-                wallpaper_index = (wallpaper_index + 1) % wallpapers.len();
             }
         });
 
@@ -520,20 +891,32 @@ impl WallpapersPlugin {
             "  • Wallpapers loaded: {}\n",
             self.wallpapers.len()
         ));
-        output.push_str(&format!("  • Monitors tracked: {}\n", self.monitors.len()));
+        let state = self.state.lock().unwrap();
+        output.push_str(&format!("  • Monitors tracked: {}\n", state.monitors.len()));
         output.push_str(&format!(
             "  • Rotation active: {}\n",
             self.rotation_handle.is_some()
         ));
+        output.push_str(&format!("  • Paused: {}\n", state.paused));
+        output.push_str(&format!("  • Order: {:?}\n", self.config.order));
         output.push_str(&format!("  • Interval: {}s\n", self.config.interval));
         output.push_str(&format!("  • Unique per monitor: {}\n", self.config.unique));
+        let mut own: Vec<_> = self.monitor_wallpapers.iter().collect();
+        own.sort_by_key(|(monitor_name, _)| *monitor_name);
+        for (monitor_name, wallpapers) in own {
+            output.push_str(&format!(
+                "  • {} wallpapers: {} (own list)\n",
+                monitor_name,
+                wallpapers.len()
+            ));
+        }
 
         if let Some(last_scan) = self.last_scan {
             let elapsed = last_scan.elapsed().as_secs();
             output.push_str(&format!("  • Last scan: {}s ago\n", elapsed));
         }
 
-        for monitor_state in self.monitors.values() {
+        for monitor_state in state.monitors.values() {
             output.push_str(&format!("  • {}: ", monitor_state.name));
             if let Some(ref wallpaper) = monitor_state.current_wallpaper {
                 output.push_str(&format!("{}\n", wallpaper.display()));
@@ -583,8 +966,12 @@ impl Plugin for WallpapersPlugin {
         info!("🖼️  Initializing wallpapers plugin");
 
         // Load configuration from plugin section
-        if let Ok(wallpapers_config) = toml::from_str::<WallpapersConfig>(&config.to_string()) {
-            self.config = wallpapers_config;
+        match toml::from_str::<WallpapersConfig>(&config.to_string()) {
+            Ok(wallpapers_config) => self.config = wallpapers_config,
+            Err(e) => warn!(
+                "⚠️  Invalid wallpapers configuration, using defaults: {}",
+                e
+            ),
         }
 
         // Scan for wallpapers
@@ -593,36 +980,18 @@ impl Plugin for WallpapersPlugin {
         Ok(())
     }
 
-    async fn handle_event(&mut self, _event: &HyprlandEvent) -> Result<()> {
-        // Wallpapers plugin doesn't need to handle events
+    async fn handle_event(&mut self, event: &HyprlandEvent) -> Result<()> {
+        if let HyprlandEvent::MonitorChanged { monitor } = event {
+            self.assign_new_monitor(monitor)?;
+        }
         Ok(())
     }
 
     async fn handle_command(&mut self, command: &str, args: &[&str]) -> Result<String> {
         match command {
-            "next" => {
-                if self.wallpapers.is_empty() {
-                    self.scan_wallpapers().await?;
-                    if self.wallpapers.is_empty() {
-                        return Ok("No wallpapers found".to_string());
-                    }
-                }
+            "next" => self.step(false, args.first().copied()).await,
 
-                let monitors = self.get_monitor_names().await?;
-                let mut results = Vec::new();
-
-                for monitor_name in &monitors {
-                    if let Some(wallpaper_path) = self.get_next_wallpaper(monitor_name) {
-                        let filename = wallpaper_path.file_name()
-                            .unwrap_or_default()
-                            .to_string_lossy();
-                        self.set_wallpaper_for_monitor(monitor_name, &wallpaper_path).await?;
-                        results.push(format!("{}: {}", monitor_name, filename));
-                    }
-                }
-
-                Ok(format!("Set wallpapers: {}", results.join(", ")))
-            }
+            "prev" => self.step(true, args.first().copied()).await,
 
             "set" => {
                 if args.is_empty() {
@@ -635,7 +1004,7 @@ impl Plugin for WallpapersPlugin {
                 if let Some(wallpaper) = self.wallpapers.iter().find(|w| w.filename == input) {
                     let wallpaper_path = wallpaper.path.clone();
                     let wallpaper_filename = wallpaper.filename.clone();
-                    self.set_wallpaper(None, &wallpaper_path).await?;
+                    self.set_wallpaper(args.get(1).copied(), &wallpaper_path).await?;
                     return Ok(format!("Set wallpaper: {}", wallpaper_filename));
                 }
 
@@ -658,7 +1027,7 @@ impl Plugin for WallpapersPlugin {
                     return Err(anyhow::anyhow!("File not found: {}. {}", input, suggestion));
                 }
 
-                self.set_wallpaper(None, &expanded_path).await?;
+                self.set_wallpaper(args.get(1).copied(), &expanded_path).await?;
                 Ok(format!("Set wallpaper: {}", expanded_path.display()))
             }
 
@@ -686,7 +1055,20 @@ impl Plugin for WallpapersPlugin {
                 Ok("Stopped wallpaper rotation".to_string())
             }
 
-            _ => Ok(format!("Unknown wallpapers command: {command}. Available: next, set, scan, list, status, clear, start, stop")),
+            "pause" => {
+                self.state.lock().unwrap().paused = true;
+                Ok("Paused wallpaper rotation".to_string())
+            }
+
+            "resume" => {
+                self.state.lock().unwrap().paused = false;
+                if self.rotation_handle.is_none() {
+                    self.start_rotation().await?;
+                }
+                Ok("Resumed wallpaper rotation".to_string())
+            }
+
+            _ => Ok(format!("Unknown wallpapers command: {command}. Available: next, prev, set, scan, list, status, clear, start, stop, pause, resume")),
         }
     }
 
@@ -700,10 +1082,11 @@ impl Plugin for WallpapersPlugin {
         }
 
         // Clean up all active wallpaper backend processes
-        if !self.active_processes.is_empty() {
+        let active_processes = self.state.lock().unwrap().processes.len();
+        if active_processes > 0 {
             debug!(
                 "🔪 Terminating {} active wallpaper processes",
-                self.active_processes.len()
+                active_processes
             );
             let _ = self.clear_wallpapers().await;
         }
@@ -821,4 +1204,125 @@ mod tests {
         assert!(status.contains("Wallpapers loaded: 1"));
         assert!(status.contains("Rotation active: false"));
     }
+
+    #[test]
+    fn test_interval_accepts_durations() {
+        assert_eq!(parse_interval("900").unwrap(), 900);
+        assert_eq!(parse_interval("15m").unwrap(), 900);
+        assert_eq!(parse_interval("1h30m").unwrap(), 5400);
+        assert_eq!(parse_interval("45s").unwrap(), 45);
+        assert!(parse_interval("15x").is_err());
+        assert!(parse_interval("m").is_err());
+        assert!(parse_interval("15m3").is_err());
+
+        let config: WallpapersConfig =
+            toml::from_str("path = \"~/Pictures\"\ninterval = \"15m\"\norder = \"sequential\"")
+                .unwrap();
+        assert_eq!(config.interval, 900);
+        assert_eq!(config.order, RotationOrder::Sequential);
+    }
+
+    #[test]
+    fn test_shuffle_shows_every_wallpaper_before_repeating() {
+        let mut rotation = Rotation::new(RotationOrder::Shuffle);
+
+        for _ in 0..3 {
+            let mut round: Vec<usize> = (0..5).map(|_| rotation.next(5).unwrap()).collect();
+            round.sort();
+            assert_eq!(round, vec![0, 1, 2, 3, 4]);
+        }
+
+        // prev walks back through what was shown, next returns to it
+        let current = rotation.current().unwrap();
+        let previous = rotation.prev(5).unwrap();
+        assert_eq!(previous, rotation.history[rotation.history.len() - 2]);
+        assert_eq!(rotation.next(5), Some(current));
+    }
+
+    #[test]
+    fn test_sequential_prev_wraps_around() {
+        let mut rotation = Rotation::new(RotationOrder::Sequential);
+
+        assert_eq!(rotation.next(3), Some(0));
+        assert_eq!(rotation.prev(3), Some(2));
+        assert_eq!(rotation.prev(3), Some(1));
+        assert_eq!(rotation.next(3), Some(2));
+        assert_eq!(rotation.next(3), Some(0));
+        assert_eq!(rotation.next(3), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_aborts_rotation_timer() {
+        let mut plugin = WallpapersPlugin::new();
+        plugin.config.command = "true".to_string();
+        plugin.config.interval = 60;
+
+        plugin.handle_command("start", &[]).await.unwrap();
+        let timer = plugin.rotation_handle.as_ref().unwrap().abort_handle();
+        assert!(!timer.is_finished());
+
+        plugin.cleanup().await.unwrap();
+        tokio::task::yield_now().await;
+
+        assert!(plugin.rotation_handle.is_none());
+        assert!(timer.is_finished());
+    }
+
+    #[test]
+    fn test_monitor_with_own_wallpapers_rotates_on_its_own() {
+        let pools = Pools {
+            wallpapers: vec![
+                create_test_wallpaper("a.jpg"),
+                create_test_wallpaper("b.jpg"),
+            ],
+            command: "true".to_string(),
+            monitors: HashMap::from([(
+                "DP-2".to_string(),
+                (vec![create_test_wallpaper("wide.jpg")], "true".to_string()),
+            )]),
+        };
+        let monitors = ["DP-1".to_string(), "DP-2".to_string()];
+        let shows = |state: &RotationState, monitor: &str| {
+            state.monitors[monitor]
+                .current_wallpaper
+                .as_ref()
+                .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+        };
+
+        let mut state = RotationState::new(RotationOrder::Sequential);
+        let shown = state.rotate(&pools, &monitors, false, false).unwrap();
+        assert_eq!(shown.unwrap(), vec!["DP-1: a.jpg", "DP-2: wide.jpg"]);
+
+        // `next DP-1` leaves DP-2 alone
+        let shown = state.rotate_monitor(&pools, "DP-1", false).unwrap();
+        assert_eq!(shown.as_deref(), Some("DP-1: a.jpg"));
+        let shown = state.rotate_monitor(&pools, "DP-1", false).unwrap();
+        assert_eq!(shown.as_deref(), Some("DP-1: b.jpg"));
+        assert_eq!(shows(&state, "DP-2").as_deref(), Some("wide.jpg"));
+    }
+
+    #[tokio::test]
+    async fn test_new_monitor_gets_a_wallpaper() {
+        let mut plugin = WallpapersPlugin::new();
+        plugin.config.command = "true".to_string();
+        plugin.wallpapers = vec![create_test_wallpaper("a.jpg")];
+
+        // Nothing happens until wallpapers are in use
+        let event = HyprlandEvent::MonitorChanged {
+            monitor: "HDMI-1".to_string(),
+        };
+        plugin.handle_event(&event).await.unwrap();
+        assert!(plugin.state.lock().unwrap().monitors.is_empty());
+
+        plugin
+            .set_wallpaper(Some("DP-1"), Path::new("/tmp/a.jpg"))
+            .await
+            .unwrap();
+        plugin.handle_event(&event).await.unwrap();
+        let state = plugin.state.lock().unwrap();
+        assert_eq!(
+            state.monitors["HDMI-1"].current_wallpaper,
+            Some(PathBuf::from("/tmp/a.jpg"))
+        );
+    }
 }
@@ -9,7 +9,7 @@ use tokio::time::sleep;
 use tracing::{debug, error, info, warn};
 
 use crate::ipc::{HyprlandClient, HyprlandEvent, MonitorInfo, WorkspaceInfo};
-use crate::plugins::Plugin;
+use crate::plugins::{Plugin, PluginContext};
 // Simplified animation types to avoid circular dependency
 #[derive(Debug, Clone)]
 pub enum SimpleAnimationDirection {
@@ -31,7 +31,7 @@ pub type MonitorInfoRef = Arc<tokio::sync::RwLock<MonitorInfo>>;
 pub type WorkspaceInfoRef = Arc<tokio::sync::RwLock<WorkspaceInfo>>;
 pub type MonitorCache = Arc<tokio::sync::RwLock<HashMap<String, MonitorInfoRef>>>;
 pub type WorkspaceCache = Arc<tokio::sync::RwLock<HashMap<i32, WorkspaceInfoRef>>>;
-use hyprland::data::{Clients, Monitors, Workspaces};
+use hyprland::data::{Client, Clients, FullscreenMode, Monitor, Monitors, Workspaces};
 use hyprland::dispatch::{
     Dispatch, DispatchType, MonitorIdentifier, WorkspaceIdentifier, WorkspaceIdentifierWithSpecial,
 };
@@ -78,6 +78,33 @@ pub struct WorkspacesFollowFocusConfig {
     /// Log workspace switching events (default: false)
     #[serde(default)]
     pub debug_logging: bool,
+
+    /// Take the active window along on `move_next`/`move_prev` (default: false)
+    #[serde(default)]
+    pub drag_window: bool,
+}
+
+/// Where a window at `at` (layout coordinates) on `from` lands on `to`, keeping its
+/// position relative to the monitor's size. Relative to `to`, as `move_window_pixel`
+/// takes it
+fn relative_position(at: (i16, i16), from: &Monitor, to: &Monitor) -> (i32, i32) {
+    let scale = |offset: i16, origin: i32, from_len: u16, to_len: u16| {
+        let ratio = (offset as f64 - origin as f64) / from_len.max(1) as f64;
+        (ratio * to_len as f64).round() as i32
+    };
+    (
+        scale(at.0, from.x, from.width, to.width),
+        scale(at.1, from.y, from.height, to.height),
+    )
+}
+
+/// Argument of Hyprland's `fullscreen` dispatcher that toggles `mode`
+fn fullscreen_arg(mode: &FullscreenMode) -> Option<&'static str> {
+    match mode {
+        FullscreenMode::None => None,
+        FullscreenMode::Maximized => Some("1"),
+        FullscreenMode::Fullscreen | FullscreenMode::MaximizedFullscreen => Some("0"),
+    }
 }
 
 fn default_true() -> bool {
@@ -106,6 +133,7 @@ impl Default for WorkspacesFollowFocusConfig {
             animation_easing: "ease-out".to_string(),
             workspace_switching_delay: 100,
             debug_logging: false,
+            drag_window: false,
         }
     }
 }
@@ -143,18 +171,7 @@ impl WorkspacesFollowFocusPlugin {
         self.monitors.clear();
 
         for monitor in monitor_vec {
-            let monitor_info = MonitorInfo {
-                id: monitor.id,
-                name: monitor.name.clone(),
-                active_workspace_id: monitor.active_workspace.id,
-                width: monitor.width,
-                height: monitor.height,
-                x: monitor.x,
-                y: monitor.y,
-                scale: monitor.scale,
-                is_focused: monitor.focused,
-                refresh_rate: monitor.refresh_rate,
-            };
+            let monitor_info = MonitorInfo::from(&monitor);
 
             if monitor.focused {
                 self.focused_monitor = Some(monitor.name.clone());
@@ -220,6 +237,17 @@ impl WorkspacesFollowFocusPlugin {
             .cloned()
     }
 
+    /// Move workspace `workspace_id` to the monitor named `monitor`
+    async fn move_workspace_to_monitor(&self, workspace_id: i32, monitor: &str) -> Result<()> {
+        self.get_hyprland_client()
+            .await?
+            .dispatch(DispatchType::MoveWorkspaceToMonitor(
+                WorkspaceIdentifier::Id(workspace_id),
+                MonitorIdentifier::Name(Box::leak(monitor.to_string().into_boxed_str())),
+            ))
+            .await
+    }
+
     /// Enforce workspace monitor rules by moving workspace if needed
     async fn enforce_workspace_rules(&mut self, workspace_id: i32) -> Result<()> {
         if let Some(required_monitor) = self.get_locked_monitor_for_workspace(workspace_id) {
@@ -235,16 +263,8 @@ impl WorkspacesFollowFocusPlugin {
                     }
 
                     // Move workspace to required monitor
-                    let workspace_identifier = WorkspaceIdentifier::Id(workspace_id);
-                    let monitor_name = required_monitor.clone();
-                    tokio::task::spawn_blocking(move || {
-                        let monitor_identifier = MonitorIdentifier::Name(&monitor_name);
-                        Dispatch::call(DispatchType::MoveWorkspaceToMonitor(
-                            workspace_identifier,
-                            monitor_identifier,
-                        ))
-                    })
-                    .await??;
+                    self.move_workspace_to_monitor(workspace_id, &required_monitor)
+                        .await?;
 
                     info!(
                         "🔒 Moved workspace {} to required monitor {}",
@@ -418,16 +438,8 @@ impl WorkspacesFollowFocusPlugin {
                     );
 
                     // Move workspace to focused monitor
-                    let workspace_identifier = WorkspaceIdentifier::Id(workspace_id);
-                    let monitor_name = focused_monitor.clone();
-                    tokio::task::spawn_blocking(move || {
-                        let monitor_identifier = MonitorIdentifier::Name(&monitor_name);
-                        Dispatch::call(DispatchType::MoveWorkspaceToMonitor(
-                            workspace_identifier,
-                            monitor_identifier,
-                        ))
-                    })
-                    .await??;
+                    self.move_workspace_to_monitor(workspace_id, &focused_monitor)
+                        .await?;
                 }
             }
         }
@@ -439,11 +451,12 @@ impl WorkspacesFollowFocusPlugin {
         }
 
         // Switch to the workspace
-        let workspace_identifier = WorkspaceIdentifierWithSpecial::Id(workspace_id);
-        tokio::task::spawn_blocking(move || {
-            Dispatch::call(DispatchType::Workspace(workspace_identifier))
-        })
-        .await??;
+        self.get_hyprland_client()
+            .await?
+            .dispatch(DispatchType::Workspace(WorkspaceIdentifierWithSpecial::Id(
+                workspace_id,
+            )))
+            .await?;
 
         // Update last switch time
         self.last_switch_time = Some(Instant::now());
@@ -495,6 +508,139 @@ impl WorkspacesFollowFocusPlugin {
         self.switch_workspace(target_workspace).await
     }
 
+    async fn get_hyprland_client(&self) -> Result<Arc<HyprlandClient>> {
+        self.hyprland_client
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Hyprland client not available"))
+    }
+
+    /// Focus the monitor `steps` to the right (negative: left) of the focused one,
+    /// ordered by `x` and wrapping around. With `drag_window` the active window
+    /// comes along, at the same relative position and fullscreen state
+    async fn move_focus(&mut self, steps: i32) -> Result<String> {
+        let client = self.get_hyprland_client().await?;
+        let mut monitors = client.get_monitors().await?;
+        monitors.sort_by_key(|m| m.x);
+
+        if monitors.len() < 2 {
+            return Ok(format!(
+                "Only {} monitor connected, nowhere to move",
+                monitors.len()
+            ));
+        }
+
+        let current = monitors
+            .iter()
+            .position(|m| m.focused)
+            .ok_or_else(|| anyhow::anyhow!("No focused monitor found"))?;
+        let target = (current as i32 + steps).rem_euclid(monitors.len() as i32) as usize;
+        let (from, to) = (&monitors[current], &monitors[target]);
+
+        let active = if self.config.drag_window {
+            client
+                .get_windows()
+                .await?
+                .into_iter()
+                .filter(|w| w.workspace.id == from.active_workspace.id)
+                .min_by_key(|w| w.focus_history_id)
+        } else {
+            None
+        };
+
+        let message = match active {
+            Some(window) => {
+                self.drag_window_to(&client, &window, from, to).await?;
+                format!("Moved {} to monitor {}", window.class, to.name)
+            }
+            None => {
+                client
+                    .dispatch(DispatchType::Custom(
+                        "focusmonitor",
+                        Box::leak(to.name.clone().into_boxed_str()),
+                    ))
+                    .await?;
+                format!("Focused monitor {}", to.name)
+            }
+        };
+
+        if self.config.debug_logging {
+            debug!("🖥️ {} (from {})", message, from.name);
+        }
+        self.focused_monitor = Some(to.name.clone());
+        Ok(message)
+    }
+
+    /// Move `window` from `from` onto the active workspace of `to` and focus it there
+    async fn drag_window_to(
+        &self,
+        client: &HyprlandClient,
+        window: &Client,
+        from: &Monitor,
+        to: &Monitor,
+    ) -> Result<()> {
+        let address = window.address.to_string();
+        let fullscreen = fullscreen_arg(&window.fullscreen);
+        let custom = |name: &'static str, args: String| {
+            DispatchType::Custom(name, Box::leak(args.into_boxed_str()))
+        };
+
+        // Hyprland keeps a window fullscreen on the monitor it was made fullscreen
+        // on, so drop it before the move and restore it on the destination
+        if let Some(mode) = fullscreen {
+            client
+                .dispatch(custom("fullscreen", mode.to_string()))
+                .await?;
+        }
+
+        client
+            .dispatch(custom(
+                "movetoworkspacesilent",
+                format!("{},address:{}", to.active_workspace.id, address),
+            ))
+            .await?;
+
+        if window.floating {
+            let (x, y) = relative_position(window.at, from, to);
+            client.move_window_pixel(&address, x, y).await?;
+        }
+
+        client.focus_window(&address).await?;
+
+        if let Some(mode) = fullscreen {
+            client
+                .dispatch(custom("fullscreen", mode.to_string()))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Turn `drag_window` on, off or flip it
+    fn set_drag_window(&mut self, mode: Option<&str>) -> Result<String> {
+        self.config.drag_window = match mode.unwrap_or("toggle") {
+            "on" | "true" => true,
+            "off" | "false" => false,
+            "toggle" => !self.config.drag_window,
+            other => {
+                return Err(anyhow::anyhow!(
+                    "Invalid drag mode: {} (expected on, off or toggle)",
+                    other
+                ))
+            }
+        };
+
+        Ok(format!(
+            "Window dragging {}",
+            if self.config.drag_window {
+                "enabled"
+            } else {
+                "disabled"
+            }
+        ))
+    }
+
     /// List workspaces with their monitor assignments
     async fn list_workspaces(&mut self) -> Result<String> {
         self.update_monitors().await?;
@@ -563,6 +709,7 @@ impl WorkspacesFollowFocusPlugin {
             self.config.allow_cross_monitor_switch,
             self.config.follow_urgent_windows
         ));
+        status.push_str(&format!("  - Drag window: {}\n", self.config.drag_window));
 
         status.push_str(&format!(
             "  - Animations: {} ({}ms, {})\n  - Switching delay: {}ms\n",
@@ -599,6 +746,10 @@ impl Plugin for WorkspacesFollowFocusPlugin {
         "workspaces_follow_focus"
     }
 
+    async fn set_context(&mut self, context: Arc<PluginContext>) {
+        *self.hyprland_client.lock().await = Some(Arc::clone(&context.hyprland_client));
+    }
+
     async fn init(&mut self, config: &toml::Value) -> Result<()> {
         info!("🏢 Initializing workspaces_follow_focus plugin");
 
@@ -717,6 +868,10 @@ impl Plugin for WorkspacesFollowFocusPlugin {
                 }
             }
 
+            "move_next" => self.move_focus(1).await,
+            "move_prev" => self.move_focus(-1).await,
+            "drag" => self.set_drag_window(args.first().copied()),
+
             "list" => self.list_workspaces().await,
             "status" => self.get_status().await,
 
@@ -840,6 +995,10 @@ mod tests {
             y: 0,
             scale: 1.0,
             refresh_rate: 60.0,
+            reserved_top: 0,
+            reserved_bottom: 0,
+            reserved_left: 0,
+            reserved_right: 0,
         };
 
         assert_eq!(monitor.name, "DP-1");
@@ -987,4 +1146,88 @@ mod tests {
         assert_eq!(default_animation_easing(), "ease-out");
         assert_eq!(default_switching_delay(), 100);
     }
+
+    async fn plugin_with(
+        windows: Vec<hyprland::data::Client>,
+        monitors: Vec<hyprland::data::Monitor>,
+    ) -> (
+        WorkspacesFollowFocusPlugin,
+        Arc<crate::ipc::mock::MockHyprland>,
+    ) {
+        let plugin = create_test_plugin();
+        let api = Arc::new(crate::ipc::mock::MockHyprland::new(windows, monitors));
+        *plugin.hyprland_client.lock().await =
+            Some(Arc::new(HyprlandClient::with_api(api.clone())));
+        (plugin, api)
+    }
+
+    fn two_monitors() -> Vec<hyprland::data::Monitor> {
+        use crate::ipc::mock::monitor;
+
+        let mut right = monitor("HDMI-A-1", (1280, 720), false, 2);
+        right.x = 1920;
+        vec![right, monitor("DP-1", (1920, 1080), true, 1)]
+    }
+
+    #[test]
+    fn test_relative_position() {
+        let monitors = two_monitors();
+        let (right, left) = (&monitors[0], &monitors[1]);
+
+        // Centre of a 1920x1080 monitor lands in the centre of the 1280x720 one
+        assert_eq!(relative_position((960, 540), left, right), (640, 360));
+        assert_eq!(relative_position((2240, 180), right, left), (480, 270));
+    }
+
+    #[tokio::test]
+    async fn test_move_next_follows_focus_only() {
+        use crate::ipc::mock::window;
+
+        let (mut plugin, api) =
+            plugin_with(vec![window("0x1", "kitty", (100, 100), 1)], two_monitors()).await;
+
+        let result = plugin.handle_command("move_next", &[]).await.unwrap();
+        assert_eq!(result, "Focused monitor HDMI-A-1");
+        assert_eq!(api.calls(), vec!["dispatch focusmonitor HDMI-A-1"]);
+    }
+
+    #[tokio::test]
+    async fn test_move_prev_drags_window_and_refullscreens() {
+        use crate::ipc::mock::window;
+
+        let mut active = window("0x1", "kitty", (960, 540), 1);
+        active.fullscreen = FullscreenMode::Fullscreen;
+        let mut older = window("0x2", "firefox", (0, 0), 1);
+        older.focus_history_id = 1;
+
+        let (mut plugin, api) = plugin_with(vec![older, active], two_monitors()).await;
+        plugin.handle_command("drag", &["on"]).await.unwrap();
+
+        // Wraps around from the leftmost monitor
+        let result = plugin.handle_command("move_prev", &[]).await.unwrap();
+        assert_eq!(result, "Moved kitty to monitor HDMI-A-1");
+        assert_eq!(
+            api.calls(),
+            vec![
+                "dispatch fullscreen 0",
+                "dispatch movetoworkspacesilent 2,address:0x1",
+                "dispatch movewindowpixel exact 640 360,address:0x1",
+                "dispatch focuswindow address:0x1",
+                "dispatch fullscreen 0",
+            ]
+        );
+        assert_eq!(api.window("0x1").unwrap().workspace.id, 2);
+    }
+
+    #[test]
+    fn test_drag_toggle() {
+        let mut plugin = create_test_plugin();
+        assert!(!plugin.config.drag_window);
+
+        plugin.set_drag_window(None).unwrap();
+        assert!(plugin.config.drag_window);
+        plugin.set_drag_window(Some("off")).unwrap();
+        assert!(!plugin.config.drag_window);
+        assert!(plugin.set_drag_window(Some("sideways")).is_err());
+    }
 }